@@ -5,7 +5,7 @@ use crate::{
         AbelianGroup, AbelianGroupAdd, AbelianGroupMul, Addition, CommutativeRing,
         Field, Group, GroupAdd, GroupMul, Identity, Loop, Magma, MagmaAdd,
         MagmaMul, Monoid, MonoidAdd, MonoidMul, Multiplication, One, Quasigroup, Real, Ring,
-        Semigroup, SemigroupAdd, SemigroupMul, Sign, Zero,
+        Semigroup, SemigroupAdd, SemigroupMul, Sign, Zero, Conjugate,
     },
     algebra::abstr::{AbsDiffEq, RelativeEq},
 };
@@ -116,6 +116,15 @@ impl<T> Complex<T>
     }
 }
 
+impl<T> Conjugate for Complex<T>
+    where T: Real
+{
+    fn conj(self: Self) -> Complex<T>
+    {
+        return Complex::conj(self);
+    }
+}
+
 impl<T> Sign for Complex<T>
     where T: Real
 {
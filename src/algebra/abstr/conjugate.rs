@@ -0,0 +1,37 @@
+/// Complex conjugation
+///
+/// For real numbers the conjugate is the number itself.
+pub trait Conjugate: Sized
+{
+    /// Returns the complex conjugate
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mathru::algebra::abstr::{Complex, Conjugate};
+    ///
+    /// let a: Complex<f64> = Complex::new(1.0, 2.0);
+    ///
+    /// assert_eq!(Complex::new(1.0, -2.0), a.conj());
+    /// assert_eq!(-3.0, (-3.0_f64).conj());
+    /// ```
+    fn conj(self: Self) -> Self;
+}
+
+macro_rules! impl_conjugate
+{
+    ($($t:ty),*) =>
+    {
+        $(
+        impl Conjugate for $t
+        {
+            fn conj(self: Self) -> Self
+            {
+                return self;
+            }
+        }
+        )*
+    };
+}
+
+impl_conjugate!(f32, f64);
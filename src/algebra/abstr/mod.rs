@@ -15,6 +15,7 @@ pub use self::{
     relative_eq::{Relative, RelativeEq},
     semigroup::{Semigroup, SemigroupAdd, SemigroupMul},
     complex::Complex,
+    conjugate::Conjugate,
     field::Field,
     integer::Integer,
     natural::Natural,
@@ -32,6 +33,7 @@ mod field;
 mod ring;
 mod scalar;
 mod sign;
+mod conjugate;
 mod semiring;
 mod abeliangroup;
 mod group;
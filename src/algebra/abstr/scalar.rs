@@ -1,6 +1,6 @@
 use crate::algebra::abstr::{
     cast::{FromPrimitive, NumCast, ToPrimitive},
-    Sign,
+    Conjugate, Sign,
 };
 use std::fmt::{Debug, Display};
 use crate::algebra::abstr::Complex;
@@ -11,7 +11,18 @@ use crate::algebra::abstr::Zero;
 /// comparisons, basic numeric operations, and string conversion.
 #[cfg(feature = "native")]
 pub trait Scalar<Rhs = Self, Output = Self>:
-    Sized + Display + ToPrimitive + FromPrimitive + NumCast + Debug + Copy + PartialOrd + Sign
+    Sized
+    + Display
+    + ToPrimitive
+    + FromPrimitive
+    + NumCast
+    + Debug
+    + Copy
+    + PartialOrd
+    + Sign
+    + Conjugate
+    + Send
+    + Sync
 {
 }
 
@@ -27,6 +38,9 @@ pub trait Scalar<Rhs = Self, Output = Self>:
     + Copy
     + PartialOrd
     + Sign
+    + Conjugate
+    + Send
+    + Sync
     + Blas
     + Lapack
 {
@@ -93,6 +107,7 @@ pub trait Lapack: Sized + Zero
              a: &mut [Self],
              lda: i32,
              w: &mut [Self],
+             wi: &mut [Self],
              vl: &mut [Self],
              ldvl: i32,
              vr: &mut [Self],
@@ -107,6 +122,7 @@ pub trait Lapack: Sized + Zero
                        a: &mut [Self],
                        lda: i32,
                        w: &mut [Self],
+                       wi: &mut [Self],
                        vl: &mut [Self],
                        ldvl: i32,
                        vr: &mut [Self],
@@ -172,6 +188,122 @@ pub trait Lapack: Sized + Zero
               b: &mut [Self],
               ldb: i32,
               info: &mut i32);
+
+    fn xsyevd(jobz: u8,
+              uplo: u8,
+              n: i32,
+              a: &mut [Self],
+              lda: i32,
+              w: &mut [Self],
+              work: &mut [Self],
+              lwork: i32,
+              iwork: &mut [i32],
+              liwork: i32,
+              info: &mut i32);
+
+    fn xsyevd_work_size(jobz: u8,
+                        uplo: u8,
+                        n: i32,
+                        a: &mut [Self],
+                        lda: i32,
+                        w: &mut [Self],
+                        info: &mut i32)
+                        -> (i32, i32);
+
+    fn xgesdd(jobz: u8,
+              m: i32,
+              n: i32,
+              a: &mut [Self],
+              lda: i32,
+              s: &mut [Self],
+              u: &mut [Self],
+              ldu: i32,
+              vt: &mut [Self],
+              ldvt: i32,
+              work: &mut [Self],
+              lwork: i32,
+              iwork: &mut [i32],
+              info: &mut i32);
+
+    fn xgesdd_work_size(jobz: u8,
+                        m: i32,
+                        n: i32,
+                        a: &mut [Self],
+                        lda: i32,
+                        s: &mut [Self],
+                        u: &mut [Self],
+                        ldu: i32,
+                        vt: &mut [Self],
+                        ldvt: i32,
+                        info: &mut i32)
+                        -> i32;
+
+    fn xgeqp3(m: i32,
+              n: i32,
+              a: &mut [Self],
+              lda: i32,
+              jpvt: &mut [i32],
+              tau: &mut [Self],
+              work: &mut [Self],
+              lwork: i32,
+              info: &mut i32);
+
+    fn xgeqp3_work_size(m: i32,
+                        n: i32,
+                        a: &mut [Self],
+                        lda: i32,
+                        jpvt: &mut [i32],
+                        tau: &mut [Self],
+                        info: &mut i32)
+                        -> i32;
+
+    fn xgecon(norm: u8,
+              n: i32,
+              a: &[Self],
+              lda: i32,
+              anorm: Self,
+              rcond: &mut Self,
+              info: &mut i32);
+
+    fn xhseqr(job: u8,
+              compz: u8,
+              n: i32,
+              ilo: i32,
+              ihi: i32,
+              h: &mut [Self],
+              ldh: i32,
+              wr: &mut [Self],
+              wi: &mut [Self],
+              z: &mut [Self],
+              ldz: i32,
+              work: &mut [Self],
+              lwork: i32,
+              info: &mut i32);
+
+    fn xhseqr_work_size(job: u8,
+                        compz: u8,
+                        n: i32,
+                        ilo: i32,
+                        ihi: i32,
+                        h: &mut [Self],
+                        ldh: i32,
+                        wr: &mut [Self],
+                        wi: &mut [Self],
+                        z: &mut [Self],
+                        ldz: i32,
+                        info: &mut i32)
+                        -> i32;
+
+    fn xsytrf(uplo: u8,
+              n: i32,
+              a: &mut [Self],
+              lda: i32,
+              ipiv: &mut [i32],
+              work: &mut [Self],
+              lwork: i32,
+              info: &mut i32);
+
+    fn xsytrf_work_size(uplo: u8, n: i32, a: &mut [Self], lda: i32, ipiv: &mut [i32], info: &mut i32) -> i32;
 }
 
 #[cfg(feature = "lapack")]
@@ -0,0 +1,339 @@
+//! Banded matrix
+use super::{BandedCholeskyDec, BandedLUDec};
+use crate::{
+    algebra::{
+        abstr::{Field, Real, Scalar},
+        linear::{matrix::Solve, Matrix, Vector},
+    },
+    error::MathError,
+};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::{clone::Clone, convert::From, ops::Mul};
+
+/// Matrix with kl subdiagonals and ku superdiagonals
+///
+/// The entries are stored column by column in the LAPACK band storage
+/// format, the entry a_ij with $`j - ku \leq i \leq j + kl`$ is located at
+/// data[j * (kl + ku + 1) + ku + i - j]. Array elements, which do not
+/// correspond to an entry of the matrix, are zero.
+///
+/// # Example
+///
+/// ```
+/// # #[macro_use]
+/// # extern crate mathru;
+/// # fn main()
+/// # {
+/// use mathru::algebra::linear::{banded::Banded, matrix::Solve, Matrix, Vector};
+///
+/// let a: Matrix<f64> = matrix![   4.0, 1.0, 0.0, 0.0;
+///                                 2.0, 5.0, 1.0, 0.0;
+///                                 1.0, 2.0, 6.0, 1.0;
+///                                 0.0, 1.0, 2.0, 7.0];
+///
+/// let a_banded: Banded<f64> = Banded::from(&a);
+/// assert_eq!(2, a_banded.kl());
+/// assert_eq!(1, a_banded.ku());
+///
+/// let b: Vector<f64> = vector![5.0; 8.0; 10.0; 10.0];
+/// let x: Vector<f64> = a_banded.solve(&b).unwrap();
+///
+/// assert_relative_eq!(vector![1.0; 1.0; 1.0; 1.0], x, epsilon=1.0e-10);
+/// # }
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct Banded<T>
+{
+    m: usize,
+    n: usize,
+    kl: usize,
+    ku: usize,
+    data: Vec<T>,
+}
+
+impl<T> Banded<T>
+{
+    /// Creates a m x n banded matrix from its band storage
+    ///
+    /// # Arguments
+    ///
+    /// * 'm': Number of rows
+    /// * 'n': Number of columns
+    /// * 'kl': Number of subdiagonals
+    /// * 'ku': Number of superdiagonals
+    /// * 'data': Band storage with n * (kl + ku + 1) entries
+    ///
+    /// # Panics
+    ///
+    /// if the length of data does not match
+    pub fn new(m: usize, n: usize, kl: usize, ku: usize, data: Vec<T>) -> Banded<T>
+    {
+        assert_eq!(data.len(), n * (kl + ku + 1), "Band storage has wrong length");
+
+        return Banded { m,
+                        n,
+                        kl,
+                        ku,
+                        data };
+    }
+
+    /// Returns the matrix dimension
+    pub fn dim(self: &Self) -> (usize, usize)
+    {
+        return (self.m, self.n);
+    }
+
+    /// Returns the number of subdiagonals
+    pub fn kl(self: &Self) -> usize
+    {
+        return self.kl;
+    }
+
+    /// Returns the number of superdiagonals
+    pub fn ku(self: &Self) -> usize
+    {
+        return self.ku;
+    }
+
+    /// Returns the band storage
+    pub fn data(self: &Self) -> &[T]
+    {
+        return &self.data;
+    }
+
+    /// Returns true, if a_ij is located inside of the band
+    fn in_band(self: &Self, i: usize, j: usize) -> bool
+    {
+        return i + self.ku >= j && i <= j + self.kl;
+    }
+
+    fn index(self: &Self, i: usize, j: usize) -> usize
+    {
+        return j * (self.kl + self.ku + 1) + self.ku + i - j;
+    }
+
+    /// Returns a mutable reference to the element a_ij
+    ///
+    /// # Panics
+    ///
+    /// if the index is out of bounds or a_ij is not located inside of the band
+    pub fn get_mut(self: &mut Self, i: usize, j: usize) -> &mut T
+    {
+        assert!(i < self.m);
+        assert!(j < self.n);
+        assert!(self.in_band(i, j), "Element is not located inside of the band");
+
+        let k: usize = self.index(i, j);
+        return &mut self.data[k];
+    }
+}
+
+impl<T> Banded<T> where T: Field + Scalar
+{
+    /// Returns the m x n zero matrix with kl subdiagonals and ku
+    /// superdiagonals
+    pub fn zero(m: usize, n: usize, kl: usize, ku: usize) -> Banded<T>
+    {
+        return Banded::new(m, n, kl, ku, vec![T::zero(); n * (kl + ku + 1)]);
+    }
+
+    /// Returns the element a_ij
+    ///
+    /// # Panics
+    ///
+    /// if the index is out of bounds
+    pub fn get(self: &Self, i: usize, j: usize) -> T
+    {
+        assert!(i < self.m);
+        assert!(j < self.n);
+
+        if !self.in_band(i, j)
+        {
+            return T::zero();
+        }
+        return self.data[self.index(i, j)];
+    }
+
+    /// Decomposes the square matrix into PA = LU with partial pivoting
+    ///
+    /// The upper triangular factor has kl + ku superdiagonals due to the
+    /// row interchanges. The decomposition needs O(n kl (kl + ku))
+    /// operations.
+    ///
+    /// Returns an error if the matrix is singular.
+    ///
+    /// # Panics
+    ///
+    /// if the matrix is not square
+    pub fn dec_lu(self: &Self) -> Result<BandedLUDec<T>, MathError>
+    {
+        assert_eq!(self.m, self.n, "Matrix is not square");
+        return BandedLUDec::new(self);
+    }
+}
+
+impl<T> Banded<T> where T: Real
+{
+    /// Decomposes the symmetric, positive definite matrix into A = L L^T
+    ///
+    /// Only the subdiagonals of A are referenced. The factor L has the same
+    /// number of subdiagonals as A. The decomposition needs O(n kl²)
+    /// operations.
+    ///
+    /// Returns an error if the matrix is not positive definite.
+    ///
+    /// # Panics
+    ///
+    /// if the matrix is not square
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[macro_use]
+    /// # extern crate mathru;
+    /// # fn main()
+    /// # {
+    /// use mathru::algebra::linear::{banded::Banded, banded::Tridiagonal, matrix::Solve, Vector};
+    ///
+    /// let a: Banded<f64> = Banded::from(Tridiagonal::new(vec![-1.0, -1.0],
+    ///                                                    vec![2.0, 2.0, 2.0],
+    ///                                                    vec![-1.0, -1.0]));
+    /// let b: Vector<f64> = Vector::new_column(3, vec![1.0, 0.0, 1.0]);
+    ///
+    /// let x: Vector<f64> = a.dec_cholesky().unwrap().solve(&b).unwrap();
+    ///
+    /// assert_relative_eq!(Vector::one(3), x, epsilon=1.0e-10);
+    /// # }
+    /// ```
+    pub fn dec_cholesky(self: &Self) -> Result<BandedCholeskyDec<T>, MathError>
+    {
+        assert_eq!(self.m, self.n, "Matrix is not square");
+        return BandedCholeskyDec::new(self);
+    }
+}
+
+impl<T> Solve<Vector<T>> for Banded<T> where T: Field + Scalar
+{
+    /// Solves Ax = b with a banded LU decomposition
+    fn solve(self: &Self, rhs: &Vector<T>) -> Result<Vector<T>, MathError>
+    {
+        return self.dec_lu()?.solve(rhs);
+    }
+}
+
+/// Multiplies a banded matrix with a vector
+impl<'a, 'b, T> Mul<&'b Vector<T>> for &'a Banded<T> where T: Field + Scalar
+{
+    type Output = Vector<T>;
+
+    fn mul(self: Self, v: &'b Vector<T>) -> Vector<T>
+    {
+        let (v_m, _v_n): (usize, usize) = v.dim();
+        if self.n != v_m
+        {
+            panic!("Matrix and Vector dimension do not match");
+        }
+
+        let mut prod_data: Vec<T> = vec![T::zero(); self.m];
+
+        for j in 0..self.n
+        {
+            let v_j: T = *v.get(j);
+            let i_start: usize = j.saturating_sub(self.ku);
+            let i_end: usize = (j + self.kl + 1).min(self.m);
+            for i in i_start..i_end
+            {
+                prod_data[i] += self.data[self.index(i, j)] * v_j;
+            }
+        }
+
+        return Vector::new_column(self.m, prod_data);
+    }
+}
+
+impl<T> Mul<Vector<T>> for Banded<T> where T: Field + Scalar
+{
+    type Output = Vector<T>;
+
+    fn mul(self: Self, v: Vector<T>) -> Vector<T>
+    {
+        return &self * &v;
+    }
+}
+
+impl<'a, T> From<&'a Matrix<T>> for Banded<T> where T: Field + Scalar
+{
+    /// Converts a dense matrix, the number of sub- and superdiagonals is
+    /// determined by the outermost non-zero entries.
+    fn from(a: &'a Matrix<T>) -> Banded<T>
+    {
+        let (m, n): (usize, usize) = a.dim();
+
+        let mut kl: usize = 0;
+        let mut ku: usize = 0;
+        for j in 0..n
+        {
+            for i in 0..m
+            {
+                if *a.get(i, j) != T::zero()
+                {
+                    if i > j
+                    {
+                        kl = kl.max(i - j);
+                    }
+                    else
+                    {
+                        ku = ku.max(j - i);
+                    }
+                }
+            }
+        }
+
+        let mut banded: Banded<T> = Banded::zero(m, n, kl, ku);
+        for j in 0..n
+        {
+            for i in j.saturating_sub(ku)..(j + kl + 1).min(m)
+            {
+                *banded.get_mut(i, j) = *a.get(i, j);
+            }
+        }
+
+        return banded;
+    }
+}
+
+impl<T> From<Matrix<T>> for Banded<T> where T: Field + Scalar
+{
+    fn from(a: Matrix<T>) -> Banded<T>
+    {
+        return Banded::from(&a);
+    }
+}
+
+impl<'a, T> From<&'a Banded<T>> for Matrix<T> where T: Field + Scalar
+{
+    fn from(a: &'a Banded<T>) -> Matrix<T>
+    {
+        let mut dense: Matrix<T> = Matrix::zero(a.m, a.n);
+
+        for j in 0..a.n
+        {
+            for i in j.saturating_sub(a.ku)..(j + a.kl + 1).min(a.m)
+            {
+                *dense.get_mut(i, j) = a.data[a.index(i, j)];
+            }
+        }
+
+        return dense;
+    }
+}
+
+impl<T> From<Banded<T>> for Matrix<T> where T: Field + Scalar
+{
+    fn from(a: Banded<T>) -> Matrix<T>
+    {
+        return Matrix::from(&a);
+    }
+}
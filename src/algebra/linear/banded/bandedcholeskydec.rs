@@ -0,0 +1,117 @@
+use super::Banded;
+use crate::{
+    algebra::{
+        abstr::Real,
+        linear::{matrix::Solve, Vector},
+    },
+    error::MathError,
+};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::clone::Clone;
+
+/// Result of a banded Cholesky decomposition
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct BandedCholeskyDec<T>
+{
+    l: Banded<T>,
+}
+
+impl<T> BandedCholeskyDec<T>
+{
+    /// Return the lower triangular factor L
+    pub fn l(self: Self) -> Banded<T>
+    {
+        return self.l;
+    }
+}
+
+impl<T> BandedCholeskyDec<T> where T: Real
+{
+    pub(super) fn new(a: &Banded<T>) -> Result<BandedCholeskyDec<T>, MathError>
+    {
+        let (n, _): (usize, usize) = a.dim();
+        let kd: usize = a.kl();
+
+        let mut l: Banded<T> = Banded::zero(n, n, kd, 0);
+        for j in 0..n
+        {
+            for i in j..(j + kd + 1).min(n)
+            {
+                *l.get_mut(i, j) = a.get(i, j);
+            }
+        }
+
+        for j in 0..n
+        {
+            let l_jj: T = l.get(j, j);
+            if l_jj <= T::zero()
+            {
+                return Err(MathError::NotPositiveDefinite);
+            }
+            let l_jj: T = l_jj.sqrt();
+            *l.get_mut(j, j) = l_jj;
+
+            let i_end: usize = (j + kd + 1).min(n);
+            for i in (j + 1)..i_end
+            {
+                *l.get_mut(i, j) /= l_jj;
+            }
+
+            for c in (j + 1)..i_end
+            {
+                let l_cj: T = l.get(c, j);
+                for i in c..i_end
+                {
+                    let l_ij: T = l.get(i, j);
+                    *l.get_mut(i, c) -= l_ij * l_cj;
+                }
+            }
+        }
+
+        return Ok(BandedCholeskyDec { l });
+    }
+}
+
+impl<T> Solve<Vector<T>> for BandedCholeskyDec<T> where T: Real
+{
+    /// Solves Ax = b
+    ///
+    /// # Panics
+    ///
+    /// if the dimension of b does not match
+    fn solve(self: &Self, rhs: &Vector<T>) -> Result<Vector<T>, MathError>
+    {
+        let (n, _): (usize, usize) = self.l.dim();
+        let kd: usize = self.l.kl();
+        let (b_m, _b_n): (usize, usize) = rhs.dim();
+        assert_eq!(n, b_m, "Matrix and vector dimension do not match");
+
+        let mut x: Vec<T> = rhs.clone().convert_to_vec();
+
+        // L y = b
+        for j in 0..n
+        {
+            x[j] /= self.l.get(j, j);
+            let x_j: T = x[j];
+            for i in (j + 1)..(j + kd + 1).min(n)
+            {
+                x[i] -= self.l.get(i, j) * x_j;
+            }
+        }
+
+        // L^T x = y
+        for i in (0..n).rev()
+        {
+            let mut sum: T = x[i];
+            for k in (i + 1)..(i + kd + 1).min(n)
+            {
+                sum -= self.l.get(k, i) * x[k];
+            }
+            x[i] = sum / self.l.get(i, i);
+        }
+
+        return Ok(Vector::new_column(n, x));
+    }
+}
@@ -0,0 +1,174 @@
+use super::Banded;
+use crate::{
+    algebra::{
+        abstr::{Field, Scalar},
+        linear::{matrix::Solve, Vector},
+    },
+    error::MathError,
+};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::clone::Clone;
+
+/// Result of a banded LU decomposition with partial pivoting
+///
+/// The factors are stored in the LAPACK band storage format with kl
+/// additional superdiagonals for the fill-in caused by the row interchanges.
+/// The unit lower triangular factor is stored as the multipliers of every
+/// elimination step below the diagonal.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct BandedLUDec<T>
+{
+    n: usize,
+    kl: usize,
+    ku: usize,
+    lu: Vec<T>,
+    pivot: Vec<usize>,
+}
+
+impl<T> BandedLUDec<T>
+{
+    /// Position of the entry (i, j) in the band storage with kl + (kl + ku) + 1
+    /// rows
+    fn index(self: &Self, i: usize, j: usize) -> usize
+    {
+        let kv: usize = self.kl + self.ku;
+        return j * (self.kl + kv + 1) + kv + i - j;
+    }
+
+    /// Returns the row, which has been interchanged with row i in the i-th
+    /// elimination step
+    pub fn pivot(self: &Self) -> &[usize]
+    {
+        return &self.pivot;
+    }
+}
+
+impl<T> BandedLUDec<T> where T: Field + Scalar
+{
+    pub(super) fn new(a: &Banded<T>) -> Result<BandedLUDec<T>, MathError>
+    {
+        let (n, _): (usize, usize) = a.dim();
+        let kl: usize = a.kl();
+        let ku: usize = a.ku();
+        let kv: usize = kl + ku;
+
+        let mut dec: BandedLUDec<T> = BandedLUDec { n,
+                                                    kl,
+                                                    ku,
+                                                    lu: vec![T::zero(); n * (kl + kv + 1)],
+                                                    pivot: Vec::with_capacity(n) };
+
+        for j in 0..n
+        {
+            for i in j.saturating_sub(ku)..(j + kl + 1).min(n)
+            {
+                let k: usize = dec.index(i, j);
+                dec.lu[k] = a.get(i, j);
+            }
+        }
+
+        for j in 0..n
+        {
+            let i_end: usize = (j + kl + 1).min(n);
+            // last column, which is affected by the elimination step
+            let c_end: usize = (j + kv + 1).min(n);
+
+            // pivoting
+            let mut p: usize = j;
+            let mut max: T = dec.lu[dec.index(j, j)].abs();
+            for i in (j + 1)..i_end
+            {
+                let p_cand: T = dec.lu[dec.index(i, j)].abs();
+                if p_cand > max
+                {
+                    max = p_cand;
+                    p = i;
+                }
+            }
+            dec.pivot.push(p);
+
+            if max == T::zero()
+            {
+                return Err(MathError::Singular { pivot: j });
+            }
+
+            if p != j
+            {
+                for c in j..c_end
+                {
+                    let k_j: usize = dec.index(j, c);
+                    let k_p: usize = dec.index(p, c);
+                    dec.lu.swap(k_j, k_p);
+                }
+            }
+
+            let a_jj: T = dec.lu[dec.index(j, j)];
+            for i in (j + 1)..i_end
+            {
+                let k: usize = dec.index(i, j);
+                dec.lu[k] /= a_jj;
+            }
+
+            for c in (j + 1)..c_end
+            {
+                let a_jc: T = dec.lu[dec.index(j, c)];
+                if a_jc == T::zero()
+                {
+                    continue;
+                }
+                for i in (j + 1)..i_end
+                {
+                    let l_ij: T = dec.lu[dec.index(i, j)];
+                    let k: usize = dec.index(i, c);
+                    dec.lu[k] -= l_ij * a_jc;
+                }
+            }
+        }
+
+        return Ok(dec);
+    }
+}
+
+impl<T> Solve<Vector<T>> for BandedLUDec<T> where T: Field + Scalar
+{
+    /// Solves Ax = b
+    ///
+    /// # Panics
+    ///
+    /// if the dimension of b does not match
+    fn solve(self: &Self, rhs: &Vector<T>) -> Result<Vector<T>, MathError>
+    {
+        let n: usize = self.n;
+        let (b_m, _b_n): (usize, usize) = rhs.dim();
+        assert_eq!(n, b_m, "Matrix and vector dimension do not match");
+
+        let mut x: Vec<T> = rhs.clone().convert_to_vec();
+
+        // L y = P b
+        for j in 0..n
+        {
+            x.swap(j, self.pivot[j]);
+            let x_j: T = x[j];
+            for i in (j + 1)..(j + self.kl + 1).min(n)
+            {
+                x[i] -= self.lu[self.index(i, j)] * x_j;
+            }
+        }
+
+        // U x = y
+        let kv: usize = self.kl + self.ku;
+        for j in (0..n).rev()
+        {
+            x[j] /= self.lu[self.index(j, j)];
+            let x_j: T = x[j];
+            for i in j.saturating_sub(kv)..j
+            {
+                x[i] -= self.lu[self.index(i, j)] * x_j;
+            }
+        }
+
+        return Ok(Vector::new_column(n, x));
+    }
+}
@@ -0,0 +1,24 @@
+//! Banded matrices
+//!
+//! Storage formats for matrices whose non-zero entries are located on a band
+//! around the main diagonal. Linear systems with such matrices can be solved
+//! in O(n) operations for a fixed bandwidth.
+//!
+//! * [`Tridiagonal`] matrix with one sub- and one superdiagonal, solved with
+//! the Thomas algorithm
+//! * [`Banded`] matrix with kl subdiagonals and ku superdiagonals, solved with
+//! a banded LU decomposition with partial pivoting or a banded Cholesky
+//! decomposition
+//!
+//! Fore more information: <br>
+//! <a href="https://en.wikipedia.org/wiki/Band_matrix">https://en.wikipedia.org/wiki/Band_matrix</a>
+
+mod banded;
+mod bandedcholeskydec;
+mod bandedludec;
+mod tridiagonal;
+
+pub use self::{
+    banded::Banded, bandedcholeskydec::BandedCholeskyDec, bandedludec::BandedLUDec,
+    tridiagonal::Tridiagonal,
+};
@@ -0,0 +1,311 @@
+//! Tridiagonal matrix
+use super::Banded;
+use crate::{
+    algebra::{
+        abstr::{Field, Scalar},
+        linear::{matrix::Solve, Matrix, Vector},
+    },
+    error::MathError,
+};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::{clone::Clone, convert::From, ops::Mul};
+
+/// Square tridiagonal matrix
+///
+/// ```math
+/// \begin{pmatrix}
+/// d_0 & u_0 & & \\
+/// l_0 & d_1 & \ddots & \\
+/// & \ddots & \ddots & u_{n-2} \\
+/// & & l_{n-2} & d_{n-1}
+/// \end{pmatrix}
+/// ```
+///
+/// # Example
+///
+/// ```
+/// # #[macro_use]
+/// # extern crate mathru;
+/// # fn main()
+/// # {
+/// use mathru::algebra::linear::{banded::Tridiagonal, matrix::Solve, Vector};
+///
+/// let a: Tridiagonal<f64> = Tridiagonal::new(vec![-1.0, -1.0], vec![2.0, 2.0, 2.0], vec![-1.0, -1.0]);
+/// let b: Vector<f64> = Vector::new_column(3, vec![1.0, 0.0, 1.0]);
+///
+/// let x: Vector<f64> = a.solve(&b).unwrap();
+///
+/// assert_relative_eq!(Vector::new_column(3, vec![1.0, 1.0, 1.0]), x, epsilon=1.0e-10);
+/// # }
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct Tridiagonal<T>
+{
+    lower: Vec<T>,
+    diag: Vec<T>,
+    upper: Vec<T>,
+}
+
+impl<T> Tridiagonal<T>
+{
+    /// Creates a n x n tridiagonal matrix
+    ///
+    /// # Arguments
+    ///
+    /// * 'lower': Subdiagonal a_{i+1, i}, has n - 1 entries
+    /// * 'diag': Diagonal a_{i, i}, has n entries
+    /// * 'upper': Superdiagonal a_{i, i+1}, has n - 1 entries
+    ///
+    /// # Panics
+    ///
+    /// if the length of the diagonals do not match
+    pub fn new(lower: Vec<T>, diag: Vec<T>, upper: Vec<T>) -> Tridiagonal<T>
+    {
+        let n: usize = diag.len();
+        assert!(n > 0, "Tridiagonal matrix has to have at least one row");
+        assert_eq!(lower.len(), n - 1, "Subdiagonal has wrong length");
+        assert_eq!(upper.len(), n - 1, "Superdiagonal has wrong length");
+
+        return Tridiagonal { lower, diag, upper };
+    }
+
+    /// Returns the matrix dimension
+    pub fn dim(self: &Self) -> (usize, usize)
+    {
+        let n: usize = self.diag.len();
+        return (n, n);
+    }
+
+    /// Returns the subdiagonal
+    pub fn lower(self: &Self) -> &[T]
+    {
+        return &self.lower;
+    }
+
+    /// Returns the diagonal
+    pub fn diag(self: &Self) -> &[T]
+    {
+        return &self.diag;
+    }
+
+    /// Returns the superdiagonal
+    pub fn upper(self: &Self) -> &[T]
+    {
+        return &self.upper;
+    }
+}
+
+impl<T> Tridiagonal<T> where T: Field + Scalar
+{
+    /// Returns the element a_ij
+    ///
+    /// # Panics
+    ///
+    /// if the index is out of bounds
+    pub fn get(self: &Self, i: usize, j: usize) -> T
+    {
+        let n: usize = self.diag.len();
+        assert!(i < n);
+        assert!(j < n);
+
+        return if i == j
+        {
+            self.diag[i]
+        }
+        else if i == j + 1
+        {
+            self.lower[j]
+        }
+        else if j == i + 1
+        {
+            self.upper[i]
+        }
+        else
+        {
+            T::zero()
+        };
+    }
+}
+
+impl<T> Solve<Vector<T>> for Tridiagonal<T> where T: Field + Scalar
+{
+    /// Solves Ax = b with the Thomas algorithm in O(n) operations
+    ///
+    /// No pivoting is done, the algorithm is stable for diagonally dominant
+    /// or symmetric positive definite matrices. For other matrices the
+    /// matrix should be converted to [`Banded`], which uses partial pivoting.
+    ///
+    /// Returns an error if a zero pivot occurs.
+    ///
+    /// # Panics
+    ///
+    /// if the dimension of b does not match
+    fn solve(self: &Self, rhs: &Vector<T>) -> Result<Vector<T>, MathError>
+    {
+        let n: usize = self.diag.len();
+        let (b_m, _b_n): (usize, usize) = rhs.dim();
+        assert_eq!(n, b_m, "Matrix and vector dimension do not match");
+
+        let mut c: Vec<T> = Vec::with_capacity(n);
+        let mut d: Vec<T> = Vec::with_capacity(n);
+
+        for i in 0..n
+        {
+            let mut pivot: T = self.diag[i];
+            let mut d_i: T = *rhs.get(i);
+            if i > 0
+            {
+                pivot -= self.lower[i - 1] * c[i - 1];
+                d_i -= self.lower[i - 1] * d[i - 1];
+            }
+
+            if pivot == T::zero()
+            {
+                return Err(MathError::Singular { pivot: i });
+            }
+
+            c.push(if i + 1 < n { self.upper[i] / pivot } else { T::zero() });
+            d.push(d_i / pivot);
+        }
+
+        for i in (0..n - 1).rev()
+        {
+            let x_next: T = d[i + 1];
+            d[i] -= c[i] * x_next;
+        }
+
+        return Ok(Vector::new_column(n, d));
+    }
+}
+
+/// Multiplies a tridiagonal matrix with a vector
+impl<'a, 'b, T> Mul<&'b Vector<T>> for &'a Tridiagonal<T> where T: Field + Scalar
+{
+    type Output = Vector<T>;
+
+    fn mul(self: Self, v: &'b Vector<T>) -> Vector<T>
+    {
+        let n: usize = self.diag.len();
+        let (v_m, _v_n): (usize, usize) = v.dim();
+        if n != v_m
+        {
+            panic!("Matrix and Vector dimension do not match");
+        }
+
+        let mut prod_data: Vec<T> = Vec::with_capacity(n);
+        for i in 0..n
+        {
+            let mut sum: T = self.diag[i] * *v.get(i);
+            if i > 0
+            {
+                sum += self.lower[i - 1] * *v.get(i - 1);
+            }
+            if i + 1 < n
+            {
+                sum += self.upper[i] * *v.get(i + 1);
+            }
+            prod_data.push(sum);
+        }
+
+        return Vector::new_column(n, prod_data);
+    }
+}
+
+impl<T> Mul<Vector<T>> for Tridiagonal<T> where T: Field + Scalar
+{
+    type Output = Vector<T>;
+
+    fn mul(self: Self, v: Vector<T>) -> Vector<T>
+    {
+        return &self * &v;
+    }
+}
+
+impl<'a, T> From<&'a Matrix<T>> for Tridiagonal<T> where T: Field + Scalar
+{
+    /// Extracts the three diagonals of a square matrix, all other entries are
+    /// ignored
+    ///
+    /// # Panics
+    ///
+    /// if the matrix is not square or empty
+    fn from(a: &'a Matrix<T>) -> Tridiagonal<T>
+    {
+        let (m, n): (usize, usize) = a.dim();
+        assert_eq!(m, n, "Matrix is not square");
+
+        let lower: Vec<T> = (0..n.saturating_sub(1)).map(|i| *a.get(i + 1, i)).collect();
+        let diag: Vec<T> = (0..n).map(|i| *a.get(i, i)).collect();
+        let upper: Vec<T> = (0..n.saturating_sub(1)).map(|i| *a.get(i, i + 1)).collect();
+
+        return Tridiagonal::new(lower, diag, upper);
+    }
+}
+
+impl<T> From<Matrix<T>> for Tridiagonal<T> where T: Field + Scalar
+{
+    fn from(a: Matrix<T>) -> Tridiagonal<T>
+    {
+        return Tridiagonal::from(&a);
+    }
+}
+
+impl<'a, T> From<&'a Tridiagonal<T>> for Matrix<T> where T: Field + Scalar
+{
+    fn from(a: &'a Tridiagonal<T>) -> Matrix<T>
+    {
+        let n: usize = a.diag.len();
+        let mut dense: Matrix<T> = Matrix::zero(n, n);
+
+        for i in 0..n
+        {
+            *dense.get_mut(i, i) = a.diag[i];
+        }
+        for i in 0..n - 1
+        {
+            *dense.get_mut(i + 1, i) = a.lower[i];
+            *dense.get_mut(i, i + 1) = a.upper[i];
+        }
+
+        return dense;
+    }
+}
+
+impl<T> From<Tridiagonal<T>> for Matrix<T> where T: Field + Scalar
+{
+    fn from(a: Tridiagonal<T>) -> Matrix<T>
+    {
+        return Matrix::from(&a);
+    }
+}
+
+impl<'a, T> From<&'a Tridiagonal<T>> for Banded<T> where T: Field + Scalar
+{
+    fn from(a: &'a Tridiagonal<T>) -> Banded<T>
+    {
+        let n: usize = a.diag.len();
+        let mut banded: Banded<T> = Banded::zero(n, n, 1, 1);
+
+        for i in 0..n
+        {
+            *banded.get_mut(i, i) = a.diag[i];
+        }
+        for i in 0..n - 1
+        {
+            *banded.get_mut(i + 1, i) = a.lower[i];
+            *banded.get_mut(i, i + 1) = a.upper[i];
+        }
+
+        return banded;
+    }
+}
+
+impl<T> From<Tridiagonal<T>> for Banded<T> where T: Field + Scalar
+{
+    fn from(a: Tridiagonal<T>) -> Banded<T>
+    {
+        return Banded::from(&a);
+    }
+}
@@ -0,0 +1,15 @@
+//! Conversions from and to the matrix types of other crates
+//!
+//! * `nalgebra`: `DMatrix`, `DVector` and `RowDVector`
+//! * `ndarray`: `Array1`, `Array2` and `ArrayD`
+//!
+//! Both crates store matrices in column-major order like [`Matrix`], owned
+//! matrices are converted without copying the elements if the memory layouts
+//! match.
+//!
+//! [`Matrix`]: crate::algebra::linear::Matrix
+
+#[cfg(feature = "nalgebra")]
+mod nalgebra;
+#[cfg(feature = "ndarray")]
+mod ndarray;
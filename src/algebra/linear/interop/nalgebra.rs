@@ -0,0 +1,165 @@
+use crate::{
+    algebra::{
+        abstr::{Field, Scalar},
+        linear::{Matrix, Vector},
+    },
+    error::MathError,
+};
+use ::nalgebra::{storage::RawStorage, DMatrix, DVector, Dim, RowDVector};
+use std::convert::TryFrom;
+
+impl<T> From<Matrix<T>> for DMatrix<T> where T: Field + Scalar + 'static
+{
+    /// Converts the matrix without copying the elements
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[macro_use]
+    /// # extern crate mathru;
+    /// # fn main()
+    /// # {
+    /// use mathru::algebra::linear::Matrix;
+    /// use nalgebra::DMatrix;
+    ///
+    /// let a: Matrix<f64> = matrix![1.0, 2.0, 3.0; 4.0, 5.0, 6.0];
+    ///
+    /// let b: DMatrix<f64> = DMatrix::from(a);
+    ///
+    /// assert_eq!(DMatrix::from_row_slice(2, 3, &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0]), b);
+    /// # }
+    /// ```
+    fn from(a: Matrix<T>) -> DMatrix<T>
+    {
+        let (m, n): (usize, usize) = a.dim();
+        return DMatrix::from_vec(m, n, a.convert_to_vec());
+    }
+}
+
+impl<'a, T> From<&'a Matrix<T>> for DMatrix<T> where T: Field + Scalar + 'static
+{
+    fn from(a: &'a Matrix<T>) -> DMatrix<T>
+    {
+        return DMatrix::from(a.clone());
+    }
+}
+
+impl<T> From<DMatrix<T>> for Matrix<T> where T: Field + Scalar + 'static
+{
+    /// Converts the matrix without copying the elements
+    fn from(a: DMatrix<T>) -> Matrix<T>
+    {
+        let (m, n): (usize, usize) = a.shape();
+        return Matrix::new(m, n, a.data.into());
+    }
+}
+
+impl<'a, T, R, C, S> From<&'a ::nalgebra::Matrix<T, R, C, S>> for Matrix<T>
+    where T: Field + Scalar + 'static,
+          R: Dim,
+          C: Dim,
+          S: RawStorage<T, R, C>
+{
+    /// Copies a matrix of any storage, e.g. a fixed size matrix or a view
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[macro_use]
+    /// # extern crate mathru;
+    /// # fn main()
+    /// # {
+    /// use mathru::algebra::linear::Matrix;
+    /// use nalgebra::Matrix2x3;
+    ///
+    /// let a: Matrix2x3<f64> = Matrix2x3::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0);
+    ///
+    /// let b: Matrix<f64> = Matrix::from(&a.columns(1, 2));
+    ///
+    /// assert_eq!(matrix![2.0, 3.0; 5.0, 6.0], b);
+    /// # }
+    /// ```
+    fn from(a: &'a ::nalgebra::Matrix<T, R, C, S>) -> Matrix<T>
+    {
+        let (m, n): (usize, usize) = a.shape();
+        return Matrix::new(m, n, a.iter().copied().collect());
+    }
+}
+
+impl<T> From<DVector<T>> for Vector<T> where T: Field + Scalar + 'static
+{
+    /// Converts to a column vector without copying the elements
+    fn from(v: DVector<T>) -> Vector<T>
+    {
+        return Vector::new_column(v.nrows(), v.data.into());
+    }
+}
+
+impl<T> From<RowDVector<T>> for Vector<T> where T: Field + Scalar + 'static
+{
+    /// Converts to a row vector without copying the elements
+    fn from(v: RowDVector<T>) -> Vector<T>
+    {
+        return Vector::new_row(v.ncols(), v.data.into());
+    }
+}
+
+impl<T> TryFrom<Vector<T>> for DVector<T> where T: Field + Scalar + 'static
+{
+    type Error = MathError;
+
+    /// Converts a column vector without copying the elements
+    ///
+    /// # Return
+    ///
+    /// MathError::DimensionMismatch if the vector is a row vector with more
+    /// than one element
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[macro_use]
+    /// # extern crate mathru;
+    /// # fn main()
+    /// # {
+    /// use mathru::algebra::linear::Vector;
+    /// use nalgebra::DVector;
+    /// use std::convert::TryFrom;
+    ///
+    /// let v: Vector<f64> = vector![1.0; 2.0];
+    ///
+    /// assert_eq!(DVector::from_vec(vec![1.0, 2.0]), DVector::try_from(v).unwrap());
+    /// assert!(DVector::try_from(vector![1.0, 2.0]).is_err());
+    /// # }
+    /// ```
+    fn try_from(v: Vector<T>) -> Result<DVector<T>, MathError>
+    {
+        if v.dim().1 != 1
+        {
+            return Err(MathError::DimensionMismatch);
+        }
+
+        return Ok(DVector::from_vec(v.convert_to_vec()));
+    }
+}
+
+impl<T> TryFrom<Vector<T>> for RowDVector<T> where T: Field + Scalar + 'static
+{
+    type Error = MathError;
+
+    /// Converts a row vector without copying the elements
+    ///
+    /// # Return
+    ///
+    /// MathError::DimensionMismatch if the vector is a column vector with more
+    /// than one element
+    fn try_from(v: Vector<T>) -> Result<RowDVector<T>, MathError>
+    {
+        if v.dim().0 != 1
+        {
+            return Err(MathError::DimensionMismatch);
+        }
+
+        return Ok(RowDVector::from_vec(v.convert_to_vec()));
+    }
+}
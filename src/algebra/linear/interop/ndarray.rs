@@ -0,0 +1,154 @@
+use crate::{
+    algebra::{
+        abstr::{Field, Scalar},
+        linear::{Matrix, Vector},
+    },
+    error::MathError,
+};
+use ::ndarray::{Array, Array1, Array2, ArrayD, ArrayView2, Dimension, Ix2, ShapeBuilder};
+use std::{convert::TryFrom, mem};
+
+impl<T> From<Matrix<T>> for Array2<T> where T: Field + Scalar
+{
+    /// Converts the matrix to an array in column-major order without copying
+    /// the elements
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[macro_use]
+    /// # extern crate mathru;
+    /// # fn main()
+    /// # {
+    /// use mathru::algebra::linear::Matrix;
+    /// use ndarray::{arr2, Array2};
+    ///
+    /// let a: Matrix<f64> = matrix![1.0, 2.0, 3.0; 4.0, 5.0, 6.0];
+    ///
+    /// let b: Array2<f64> = Array2::from(a);
+    ///
+    /// assert_eq!(arr2(&[[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]), b);
+    /// # }
+    /// ```
+    fn from(a: Matrix<T>) -> Array2<T>
+    {
+        let (m, n): (usize, usize) = a.dim();
+        return Array2::from_shape_vec((m, n).f(), a.convert_to_vec()).unwrap();
+    }
+}
+
+impl<'a, T> From<&'a Matrix<T>> for Array2<T> where T: Field + Scalar
+{
+    fn from(a: &'a Matrix<T>) -> Array2<T>
+    {
+        return Array2::from(a.clone());
+    }
+}
+
+impl<T> From<Array2<T>> for Matrix<T> where T: Field + Scalar
+{
+    /// Converts the array, the elements are only copied if the array is not
+    /// contiguous in column-major order
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[macro_use]
+    /// # extern crate mathru;
+    /// # fn main()
+    /// # {
+    /// use mathru::algebra::linear::Matrix;
+    /// use ndarray::arr2;
+    ///
+    /// let a: Matrix<f64> = Matrix::from(arr2(&[[1.0, 2.0], [3.0, 4.0]]));
+    ///
+    /// assert_eq!(matrix![1.0, 2.0; 3.0, 4.0], a);
+    /// # }
+    /// ```
+    fn from(a: Array2<T>) -> Matrix<T>
+    {
+        let (m, n): (usize, usize) = a.dim();
+        if a.t().is_standard_layout()
+        {
+            return Matrix::new(m, n, into_vec(a));
+        }
+
+        return Matrix::from(a.view());
+    }
+}
+
+impl<'a, T> From<ArrayView2<'a, T>> for Matrix<T> where T: Field + Scalar
+{
+    /// Copies the elements of the view
+    fn from(a: ArrayView2<'a, T>) -> Matrix<T>
+    {
+        let (m, n): (usize, usize) = a.dim();
+        return Matrix::new(m, n, a.t().iter().copied().collect());
+    }
+}
+
+impl<T> TryFrom<ArrayD<T>> for Matrix<T> where T: Field + Scalar
+{
+    type Error = MathError;
+
+    /// Converts an array with two dimensions
+    ///
+    /// # Return
+    ///
+    /// MathError::DimensionMismatch if the array does not have two dimensions
+    fn try_from(a: ArrayD<T>) -> Result<Matrix<T>, MathError>
+    {
+        return match a.into_dimensionality::<Ix2>()
+        {
+            Ok(a) => Ok(Matrix::from(a)),
+            Err(_) => Err(MathError::DimensionMismatch),
+        };
+    }
+}
+
+impl<T> From<Vector<T>> for Array1<T> where T: Field + Scalar
+{
+    /// Converts a row or column vector without copying the elements
+    fn from(v: Vector<T>) -> Array1<T>
+    {
+        return Array1::from(v.convert_to_vec());
+    }
+}
+
+impl<T> From<Array1<T>> for Vector<T> where T: Field + Scalar
+{
+    /// Converts to a column vector, the elements are only copied if the array
+    /// is not contiguous
+    fn from(v: Array1<T>) -> Vector<T>
+    {
+        let m: usize = v.len();
+        if v.is_standard_layout()
+        {
+            return Vector::new_column(m, into_vec(v));
+        }
+
+        return Vector::new_column(m, v.iter().copied().collect());
+    }
+}
+
+/// Moves the elements of a contiguous array in memory order into a vector
+///
+/// The buffer of an owned array can contain elements before and after the
+/// array, if the array has been sliced in place.
+fn into_vec<T, D>(a: Array<T, D>) -> Vec<T>
+    where D: Dimension
+{
+    let len: usize = a.len();
+    let first: *const T = a.as_ptr();
+    let mut data: Vec<T> = a.into_raw_vec();
+
+    let offset: usize = match mem::size_of::<T>()
+    {
+        0 => 0,
+        size => (first as usize - data.as_ptr() as usize) / size,
+    };
+    data.truncate(offset + len);
+    data.drain(..offset);
+
+    return data;
+}
@@ -6,7 +6,7 @@ use std::os::raw::c_char;
 
 macro_rules! lapack_real (
     ($T: ty, $xgehrd: path, $xorghr: path, $xgeev: path, $xgetrf: path, $xgeqrf: path, $xorgqr: path, $xgetri: path, $xpotrf: path,
-    $xgetrs: path)
+    $xgetrs: path, $xsyevd: path, $xgesdd: path, $xgeqp3: path, $xgecon: path, $xhseqr: path, $xsytrf: path)
     => (
         impl Lapack for $T
        	{
@@ -45,24 +45,22 @@ macro_rules! lapack_real (
             }
 
             fn xgeev(jobvl: u8, jobvr: u8, n: i32, a: &mut [Self], lda: i32,
-                     w: &mut [Self],
+                     w: &mut [Self], wi: &mut [Self],
                      vl: &mut [Self], ldvl: i32, vr: &mut [Self], ldvr: i32,
                      work: &mut [Self], lwork: i32, info: &mut i32)
           	{
-          		let mut wi = Vec::with_capacity(n as usize);
-                unsafe { $xgeev(jobvl, jobvr, n, a, lda, w, &mut wi, vl, ldvl, vr, ldvr, work, lwork, info) }
+                unsafe { $xgeev(jobvl, jobvr, n, a, lda, w, wi, vl, ldvl, vr, ldvr, work, lwork, info) }
             }
 
 
             fn xgeev_work_size(jobvl: u8, jobvr: u8, n: i32, a: &mut [Self], lda: i32,
-                               w: &mut [Self], vl: &mut [Self], ldvl: i32,
+                               w: &mut [Self], wi: &mut [Self], vl: &mut [Self], ldvl: i32,
                                vr: &mut [Self], ldvr: i32, info: &mut i32) -> i32
           	{
                 let mut work = [<$T>::zero()];
-                let mut wi = Vec::with_capacity(n as usize);
                 let lwork = -1 as i32;
 
-                unsafe { $xgeev(jobvl, jobvr, n, a, lda, w, &mut wi, vl, ldvl, vr, ldvr, &mut work, lwork, info) };
+                unsafe { $xgeev(jobvl, jobvr, n, a, lda, w, wi, vl, ldvl, vr, ldvr, &mut work, lwork, info) };
                 work[0] as i32
 			}
 
@@ -135,6 +133,102 @@ macro_rules! lapack_real (
 					$xgetrs('N' as u8, n, nrhs, a, lda, ipiv, b, ldb, info);
 				}
 			}
+
+			// symmetric eigenvalue problem
+			fn xsyevd(jobz: u8, uplo: u8, n: i32, a: &mut [Self], lda: i32, w: &mut [Self], work: &mut [Self], lwork: i32,
+					  iwork: &mut [i32], liwork: i32, info: &mut i32)
+			{
+				unsafe { $xsyevd(jobz, uplo, n, a, lda, w, work, lwork, iwork, liwork, info) };
+			}
+
+			fn xsyevd_work_size(jobz: u8, uplo: u8, n: i32, a: &mut [Self], lda: i32, w: &mut [Self], info: &mut i32) -> (i32, i32)
+			{
+				let mut work = [<$T>::zero()];
+				let mut iwork = [0 as i32];
+				let lwork = -1 as i32;
+
+				unsafe { $xsyevd(jobz, uplo, n, a, lda, w, &mut work, lwork, &mut iwork, lwork, info) };
+				(work[0] as i32, iwork[0])
+			}
+
+			// singular value decomposition, divide and conquer
+			fn xgesdd(jobz: u8, m: i32, n: i32, a: &mut [Self], lda: i32, s: &mut [Self], u: &mut [Self], ldu: i32,
+					  vt: &mut [Self], ldvt: i32, work: &mut [Self], lwork: i32, iwork: &mut [i32], info: &mut i32)
+			{
+				unsafe { $xgesdd(jobz, m, n, a, lda, s, u, ldu, vt, ldvt, work, lwork, iwork, info) };
+			}
+
+			fn xgesdd_work_size(jobz: u8, m: i32, n: i32, a: &mut [Self], lda: i32, s: &mut [Self], u: &mut [Self],
+								ldu: i32, vt: &mut [Self], ldvt: i32, info: &mut i32) -> i32
+			{
+				let mut work = [<$T>::zero()];
+				let mut iwork: Vec<i32> = vec![0; 8 * m.min(n) as usize];
+				let lwork = -1 as i32;
+
+				unsafe { $xgesdd(jobz, m, n, a, lda, s, u, ldu, vt, ldvt, &mut work, lwork, &mut iwork, info) };
+				work[0] as i32
+			}
+
+			// QR decomposition with column pivoting
+			fn xgeqp3(m: i32, n: i32, a: &mut [Self], lda: i32, jpvt: &mut [i32], tau: &mut [Self], work: &mut [Self],
+					  lwork: i32, info: &mut i32)
+			{
+				unsafe { $xgeqp3(m, n, a, lda, jpvt, tau, work, lwork, info) };
+			}
+
+			fn xgeqp3_work_size(m: i32, n: i32, a: &mut [Self], lda: i32, jpvt: &mut [i32], tau: &mut [Self],
+								info: &mut i32) -> i32
+			{
+				let mut work = [<$T>::zero()];
+				let lwork = -1 as i32;
+
+				unsafe { $xgeqp3(m, n, a, lda, jpvt, tau, &mut work, lwork, info) };
+				work[0] as i32
+			}
+
+			// Reciprocal condition number estimate of a LU decomposed matrix
+			fn xgecon(norm: u8, n: i32, a: &[Self], lda: i32, anorm: Self, rcond: &mut Self, info: &mut i32)
+			{
+				let mut work: Vec<$T> = vec![<$T>::zero(); 4 * n as usize];
+				let mut iwork: Vec<i32> = vec![0; n as usize];
+
+				unsafe { $xgecon(norm, n, a, lda, anorm, rcond, &mut work, &mut iwork, info) };
+			}
+
+			// Schur form of a Hessenberg matrix
+			fn xhseqr(job: u8, compz: u8, n: i32, ilo: i32, ihi: i32, h: &mut [Self], ldh: i32, wr: &mut [Self],
+					  wi: &mut [Self], z: &mut [Self], ldz: i32, work: &mut [Self], lwork: i32, info: &mut i32)
+			{
+				unsafe { $xhseqr(job, compz, n, ilo, ihi, h, ldh, wr, wi, z, ldz, work, lwork, info) };
+			}
+
+			fn xhseqr_work_size(job: u8, compz: u8, n: i32, ilo: i32, ihi: i32, h: &mut [Self], ldh: i32,
+								wr: &mut [Self], wi: &mut [Self], z: &mut [Self], ldz: i32, info: &mut i32) -> i32
+			{
+				let mut work = [<$T>::zero()];
+				let lwork = -1 as i32;
+
+				unsafe { $xhseqr(job, compz, n, ilo, ihi, h, ldh, wr, wi, z, ldz, &mut work, lwork, info) };
+
+				work[0] as i32
+			}
+
+			// Bunch-Kaufman factorization of a symmetric matrix
+			fn xsytrf(uplo: u8, n: i32, a: &mut [Self], lda: i32, ipiv: &mut [i32], work: &mut [Self], lwork: i32,
+					  info: &mut i32)
+			{
+				unsafe { $xsytrf(uplo, n, a, lda, ipiv, work, lwork, info) };
+			}
+
+			fn xsytrf_work_size(uplo: u8, n: i32, a: &mut [Self], lda: i32, ipiv: &mut [i32], info: &mut i32) -> i32
+			{
+				let mut work = [<$T>::zero()];
+				let lwork = -1 as i32;
+
+				unsafe { $xsytrf(uplo, n, a, lda, ipiv, &mut work, lwork, info) };
+
+				work[0] as i32
+			}
       	}
     )
 );
@@ -148,7 +242,13 @@ lapack_real!(f32,
              lapack::sorgqr,
              lapack::sgetri,
              lapack::spotrf,
-             lapack::sgetrs);
+             lapack::sgetrs,
+             lapack::ssyevd,
+             lapack::sgesdd,
+             lapack::sgeqp3,
+             lapack::sgecon,
+             lapack::shseqr,
+             lapack::ssytrf);
 
 lapack_real!(f64,
              lapack::dgehrd,
@@ -159,11 +259,17 @@ lapack_real!(f64,
              lapack::dorgqr,
              lapack::dgetri,
              lapack::dpotrf,
-             lapack::dgetrs);
+             lapack::dgetrs,
+             lapack::dsyevd,
+             lapack::dgesdd,
+             lapack::dgeqp3,
+             lapack::dgecon,
+             lapack::dhseqr,
+             lapack::dsytrf);
 
 
 macro_rules! lapack_complex (
-    ($T: ty, $xgehrd: path, $xorghr: path, $xgeev: path, $xgetrf: path, $xgeqrf: path, $xorgqr: path, $xgetri: path, $xpotrf: path, $xgetrs: path)
+    ($T: ty, $xgehrd: path, $xorghr: path, $xgeev: path, $xgetrf: path, $xgeqrf: path, $xorgqr: path, $xgetri: path, $xpotrf: path, $xgetrs: path, $xsyevd: path, $xgesdd: path, $xgeqp3: path, $xgecon: path, $xhseqr: path, $xsytrf: path)
     => (
 		impl Lapack for Complex<$T>
 		{
@@ -204,6 +310,7 @@ macro_rules! lapack_complex (
 					 a: &mut [Self],
 					 lda: i32,
 					 w: &mut [Self],
+					 _wi: &mut [Self],
 					 vl: &mut [Self],
 					 ldvl: i32,
 					 vr: &mut [Self],
@@ -225,6 +332,7 @@ macro_rules! lapack_complex (
 							   a: &mut [Self],
 							   lda: i32,
 							   w: &mut [Self],
+							   _wi: &mut [Self],
 							   vl: &mut [Self],
 							   ldvl: i32,
 							   vr: &mut [Self],
@@ -343,6 +451,248 @@ macro_rules! lapack_complex (
 					$xgetrs(&('N' as c_char), &n, &nrhs, a.as_ptr() as *const _, &lda, ipiv.as_ptr() as *const _, b.as_mut_ptr() as *mut _, &ldb, info as *mut _);
 				}
 			}
+
+			// hermitian eigenvalue problem, the eigenvalues are real
+			fn xsyevd(jobz: u8,
+					  uplo: u8,
+					  n: i32,
+					  a: &mut [Self],
+					  lda: i32,
+					  w: &mut [Self],
+					  work: &mut [Self],
+					  lwork: i32,
+					  iwork: &mut [i32],
+					  liwork: i32,
+					  info: &mut i32)
+			{
+				let mut w_re: Vec<$T> = vec![0.0; n as usize];
+				let lrwork: i32 = if jobz == 'V' as u8 { 1 + 5 * n + 2 * n * n } else { n.max(1) };
+				let mut rwork: Vec<$T> = vec![0.0; lrwork as usize];
+				unsafe
+				{
+					$xsyevd(&(jobz as c_char), &(uplo as c_char), &n, a.as_mut_ptr() as *mut _, &lda, w_re.as_mut_ptr(), work.as_mut_ptr() as *mut _, &lwork, rwork.as_mut_ptr(), &lrwork, iwork.as_mut_ptr(), &liwork, info as *mut _)
+				};
+
+				for (w_i, w_re_i) in w.iter_mut().zip(w_re.iter())
+				{
+					*w_i = Complex::new(*w_re_i, 0.0);
+				}
+			}
+
+			fn xsyevd_work_size(jobz: u8,
+								uplo: u8,
+								n: i32,
+								a: &mut [Self],
+								lda: i32,
+								_w: &mut [Self],
+								info: &mut i32)
+								-> (i32, i32)
+			{
+				let mut work = [Self::zero()];
+				let mut rwork: [$T; 1] = [0.0];
+				let mut iwork = [0 as i32];
+				let mut w_re: Vec<$T> = vec![0.0; n as usize];
+				let lwork = -1 as i32;
+
+				unsafe
+				{
+					$xsyevd(&(jobz as c_char), &(uplo as c_char), &n, a.as_mut_ptr() as *mut _, &lda, w_re.as_mut_ptr(), work.as_mut_ptr() as *mut _, &lwork, rwork.as_mut_ptr(), &lwork, iwork.as_mut_ptr(), &lwork, info as *mut _)
+				};
+				(work[0].re as i32, iwork[0])
+			}
+
+			// singular value decomposition, the singular values are real
+			fn xgesdd(jobz: u8,
+					  m: i32,
+					  n: i32,
+					  a: &mut [Self],
+					  lda: i32,
+					  s: &mut [Self],
+					  u: &mut [Self],
+					  ldu: i32,
+					  vt: &mut [Self],
+					  ldvt: i32,
+					  work: &mut [Self],
+					  lwork: i32,
+					  iwork: &mut [i32],
+					  info: &mut i32)
+			{
+				let mn: i32 = m.min(n);
+				let mx: i32 = m.max(n);
+				let mut s_re: Vec<$T> = vec![0.0; mn as usize];
+				let lrwork: i32 = (5 * mn * mn + 5 * mn).max(2 * mx * mn + 2 * mn * mn + mn).max(1);
+				let mut rwork: Vec<$T> = vec![0.0; lrwork as usize];
+				unsafe
+				{
+					$xgesdd(&(jobz as c_char), &m, &n, a.as_mut_ptr() as *mut _, &lda, s_re.as_mut_ptr(), u.as_mut_ptr() as *mut _, &ldu, vt.as_mut_ptr() as *mut _, &ldvt, work.as_mut_ptr() as *mut _, &lwork, rwork.as_mut_ptr(), iwork.as_mut_ptr(), info as *mut _)
+				};
+
+				for (s_i, s_re_i) in s.iter_mut().zip(s_re.iter())
+				{
+					*s_i = Complex::new(*s_re_i, 0.0);
+				}
+			}
+
+			fn xgesdd_work_size(jobz: u8,
+								m: i32,
+								n: i32,
+								a: &mut [Self],
+								lda: i32,
+								_s: &mut [Self],
+								u: &mut [Self],
+								ldu: i32,
+								vt: &mut [Self],
+								ldvt: i32,
+								info: &mut i32)
+								-> i32
+			{
+				let mn: i32 = m.min(n);
+				let mut work = [Self::zero()];
+				let mut rwork: [$T; 1] = [0.0];
+				let mut iwork: Vec<i32> = vec![0; 8 * mn as usize];
+				let mut s_re: Vec<$T> = vec![0.0; mn as usize];
+				let lwork = -1 as i32;
+
+				unsafe
+				{
+					$xgesdd(&(jobz as c_char), &m, &n, a.as_mut_ptr() as *mut _, &lda, s_re.as_mut_ptr(), u.as_mut_ptr() as *mut _, &ldu, vt.as_mut_ptr() as *mut _, &ldvt, work.as_mut_ptr() as *mut _, &lwork, rwork.as_mut_ptr(), iwork.as_mut_ptr(), info as *mut _)
+				};
+				work[0].re as i32
+			}
+
+			// QR decomposition with column pivoting
+			fn xgeqp3(m: i32,
+					  n: i32,
+					  a: &mut [Self],
+					  lda: i32,
+					  jpvt: &mut [i32],
+					  tau: &mut [Self],
+					  work: &mut [Self],
+					  lwork: i32,
+					  info: &mut i32)
+			{
+				let mut rwork: Vec<$T> = vec![0.0; 2 * n as usize];
+				unsafe
+				{
+					$xgeqp3(&m, &n, a.as_mut_ptr() as *mut _, &lda, jpvt.as_mut_ptr(), tau.as_mut_ptr() as *mut _, work.as_mut_ptr() as *mut _, &lwork, rwork.as_mut_ptr(), info as *mut _)
+				};
+			}
+
+			fn xgeqp3_work_size(m: i32,
+								n: i32,
+								a: &mut [Self],
+								lda: i32,
+								jpvt: &mut [i32],
+								tau: &mut [Self],
+								info: &mut i32)
+								-> i32
+			{
+				let mut work = [Self::zero()];
+				let mut rwork: Vec<$T> = vec![0.0; 2 * n as usize];
+				let lwork = -1 as i32;
+
+				unsafe
+				{
+					$xgeqp3(&m, &n, a.as_mut_ptr() as *mut _, &lda, jpvt.as_mut_ptr(), tau.as_mut_ptr() as *mut _, work.as_mut_ptr() as *mut _, &lwork, rwork.as_mut_ptr(), info as *mut _)
+				};
+				work[0].re as i32
+			}
+
+			// Reciprocal condition number estimate of a LU decomposed matrix
+			fn xgecon(norm: u8,
+					  n: i32,
+					  a: &[Self],
+					  lda: i32,
+					  anorm: Self,
+					  rcond: &mut Self,
+					  info: &mut i32)
+			{
+				let mut work: Vec<Self> = vec![Self::zero(); 2 * n as usize];
+				let mut rwork: Vec<$T> = vec![0.0; 2 * n as usize];
+				let mut rcond_re: $T = 0.0;
+
+				unsafe
+				{
+					$xgecon(&(norm as c_char), &n, a.as_ptr() as *const _, &lda, &anorm.re, &mut rcond_re, work.as_mut_ptr() as *mut _, rwork.as_mut_ptr(), info as *mut _)
+				};
+				*rcond = Self { re: rcond_re, im: 0.0 };
+			}
+
+			// Schur form of a Hessenberg matrix, the eigenvalues are returned in wr
+			fn xhseqr(job: u8,
+					  compz: u8,
+					  n: i32,
+					  ilo: i32,
+					  ihi: i32,
+					  h: &mut [Self],
+					  ldh: i32,
+					  wr: &mut [Self],
+					  _wi: &mut [Self],
+					  z: &mut [Self],
+					  ldz: i32,
+					  work: &mut [Self],
+					  lwork: i32,
+					  info: &mut i32)
+			{
+				unsafe
+				{
+					$xhseqr(&(job as c_char), &(compz as c_char), &n, &ilo, &ihi, h.as_mut_ptr() as *mut _, &ldh, wr.as_mut_ptr() as *mut _, z.as_mut_ptr() as *mut _, &ldz, work.as_mut_ptr() as *mut _, &lwork, info as *mut _)
+				};
+			}
+
+			fn xhseqr_work_size(job: u8,
+								compz: u8,
+								n: i32,
+								ilo: i32,
+								ihi: i32,
+								h: &mut [Self],
+								ldh: i32,
+								wr: &mut [Self],
+								_wi: &mut [Self],
+								z: &mut [Self],
+								ldz: i32,
+								info: &mut i32)
+								-> i32
+			{
+				let mut work = [Self::zero()];
+				let lwork = -1 as i32;
+
+				unsafe
+				{
+					$xhseqr(&(job as c_char), &(compz as c_char), &n, &ilo, &ihi, h.as_mut_ptr() as *mut _, &ldh, wr.as_mut_ptr() as *mut _, z.as_mut_ptr() as *mut _, &ldz, work.as_mut_ptr() as *mut _, &lwork, info as *mut _)
+				};
+
+				work[0].re as i32
+			}
+
+			// Bunch-Kaufman factorization of a complex symmetric matrix
+			fn xsytrf(uplo: u8,
+					  n: i32,
+					  a: &mut [Self],
+					  lda: i32,
+					  ipiv: &mut [i32],
+					  work: &mut [Self],
+					  lwork: i32,
+					  info: &mut i32)
+			{
+				unsafe
+				{
+					$xsytrf(&(uplo as c_char), &n, a.as_mut_ptr() as *mut _, &lda, ipiv.as_mut_ptr(), work.as_mut_ptr() as *mut _, &lwork, info as *mut _)
+				};
+			}
+
+			fn xsytrf_work_size(uplo: u8, n: i32, a: &mut [Self], lda: i32, ipiv: &mut [i32], info: &mut i32) -> i32
+			{
+				let mut work = [Self::zero()];
+				let lwork = -1 as i32;
+
+				unsafe
+				{
+					$xsytrf(&(uplo as c_char), &n, a.as_mut_ptr() as *mut _, &lda, ipiv.as_mut_ptr(), work.as_mut_ptr() as *mut _, &lwork, info as *mut _)
+				};
+
+				work[0].re as i32
+			}
 		}
 	)
 );
@@ -356,7 +706,13 @@ lapack_complex!(f32,
              ffi::cungqr_,
              ffi::cgetri_,
              ffi::cpotrf_,
-             ffi::cgetrs_);
+             ffi::cgetrs_,
+             ffi::cheevd_,
+             ffi::cgesdd_,
+             ffi::cgeqp3_,
+             ffi::cgecon_,
+             ffi::chseqr_,
+             ffi::csytrf_);
 
 lapack_complex!(f64,
              ffi::zgehrd_,
@@ -367,4 +723,10 @@ lapack_complex!(f64,
              ffi::zungqr_,
              ffi::zgetri_,
              ffi::zpotrf_,
-             ffi::zgetrs_);
\ No newline at end of file
+             ffi::zgetrs_,
+             ffi::zheevd_,
+             ffi::zgesdd_,
+             ffi::zgeqp3_,
+             ffi::zgecon_,
+             ffi::zhseqr_,
+             ffi::zsytrf_);
\ No newline at end of file
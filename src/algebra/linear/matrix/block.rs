@@ -0,0 +1,229 @@
+use crate::{
+    algebra::{
+        abstr::{Field, Scalar},
+        linear::Matrix,
+    },
+    error::MathError,
+};
+
+/// Macro to construct a matrix from blocks
+///
+/// The blocks of a block row are separated by commas, the block rows by
+/// semicolons. The macro evaluates to `Result<Matrix<T>, MathError>`, the
+/// result is an error if the dimensions of the blocks do not match.
+///
+/// ```
+/// # #[macro_use]
+/// # extern crate mathru;
+/// # fn main()
+/// # {
+/// use mathru::algebra::linear::{matrix::Transpose, Matrix};
+///
+/// let a: Matrix<f64> = matrix![   2.0, 1.0;
+///                                 1.0, 2.0];
+/// let b: Matrix<f64> = matrix![   1.0;
+///                                 1.0];
+/// let zero: Matrix<f64> = Matrix::zero(1, 1);
+///
+/// // saddle point matrix
+/// let k: Matrix<f64> = block![a, b; b.clone().transpose(), zero].unwrap();
+///
+/// assert_eq!(matrix![ 2.0, 1.0, 1.0;
+///                     1.0, 2.0, 1.0;
+///                     1.0, 1.0, 0.0], k);
+/// # }
+/// ```
+#[macro_export]
+macro_rules! block
+{
+    ($( $( $x: expr ),*);*) =>
+    {
+        Matrix::block(&[ $( &[ $( &$x ),* ] ),* ])
+    }
+}
+
+impl<T> Matrix<T> where T: Field + Scalar
+{
+    /// Concatenates matrices horizontally
+    ///
+    /// # Return
+    ///
+    /// MathError::DimensionMismatch if the matrices do not have the same number
+    /// of rows
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[macro_use]
+    /// # extern crate mathru;
+    /// # fn main()
+    /// # {
+    /// use mathru::algebra::linear::Matrix;
+    ///
+    /// let a: Matrix<f64> = matrix![1.0; 2.0];
+    /// let b: Matrix<f64> = matrix![3.0, 4.0; 5.0, 6.0];
+    ///
+    /// let c: Matrix<f64> = Matrix::hstack(&[&a, &b]).unwrap();
+    ///
+    /// assert_eq!(matrix![1.0, 3.0, 4.0; 2.0, 5.0, 6.0], c);
+    /// # }
+    /// ```
+    pub fn hstack(blocks: &[&Matrix<T>]) -> Result<Matrix<T>, MathError>
+    {
+        let m: usize = match blocks.first()
+        {
+            Some(block) => block.m,
+            None => return Ok(Matrix::zero(0, 0)),
+        };
+
+        if blocks.iter().any(|block| block.m != m)
+        {
+            return Err(MathError::DimensionMismatch);
+        }
+
+        let n: usize = blocks.iter().map(|block| block.n).sum();
+        let mut data: Vec<T> = Vec::with_capacity(m * n);
+        for block in blocks
+        {
+            data.extend_from_slice(&block.data);
+        }
+
+        return Ok(Matrix::new(m, n, data));
+    }
+
+    /// Concatenates matrices vertically
+    ///
+    /// # Return
+    ///
+    /// MathError::DimensionMismatch if the matrices do not have the same number
+    /// of columns
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[macro_use]
+    /// # extern crate mathru;
+    /// # fn main()
+    /// # {
+    /// use mathru::algebra::linear::Matrix;
+    ///
+    /// let a: Matrix<f64> = matrix![1.0, 2.0];
+    /// let b: Matrix<f64> = matrix![3.0, 4.0; 5.0, 6.0];
+    ///
+    /// let c: Matrix<f64> = Matrix::vstack(&[&a, &b]).unwrap();
+    ///
+    /// assert_eq!(matrix![1.0, 2.0; 3.0, 4.0; 5.0, 6.0], c);
+    /// # }
+    /// ```
+    pub fn vstack(blocks: &[&Matrix<T>]) -> Result<Matrix<T>, MathError>
+    {
+        let n: usize = match blocks.first()
+        {
+            Some(block) => block.n,
+            None => return Ok(Matrix::zero(0, 0)),
+        };
+
+        if blocks.iter().any(|block| block.n != n)
+        {
+            return Err(MathError::DimensionMismatch);
+        }
+
+        let m: usize = blocks.iter().map(|block| block.m).sum();
+        let mut data: Vec<T> = Vec::with_capacity(m * n);
+        for j in 0..n
+        {
+            for block in blocks
+            {
+                data.extend_from_slice(&block.data[j * block.m..(j + 1) * block.m]);
+            }
+        }
+
+        return Ok(Matrix::new(m, n, data));
+    }
+
+    /// Constructs a matrix from block rows
+    ///
+    /// The blocks of each block row are concatenated horizontally, afterwards
+    /// the block rows are concatenated vertically.
+    ///
+    /// # Return
+    ///
+    /// MathError::DimensionMismatch if the blocks of a block row do not have
+    /// the same number of rows or the block rows do not have the same number
+    /// of columns
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[macro_use]
+    /// # extern crate mathru;
+    /// # fn main()
+    /// # {
+    /// use mathru::algebra::linear::Matrix;
+    ///
+    /// let a: Matrix<f64> = matrix![1.0, 2.0];
+    /// let b: Matrix<f64> = matrix![3.0];
+    /// let c: Matrix<f64> = matrix![4.0, 5.0, 6.0];
+    ///
+    /// let d: Matrix<f64> = Matrix::block(&[&[&a, &b], &[&c]]).unwrap();
+    ///
+    /// assert_eq!(matrix![1.0, 2.0, 3.0; 4.0, 5.0, 6.0], d);
+    /// # }
+    /// ```
+    pub fn block(blocks: &[&[&Matrix<T>]]) -> Result<Matrix<T>, MathError>
+    {
+        let rows: Vec<Matrix<T>> = blocks.iter()
+                                         .map(|row| Matrix::hstack(row))
+                                         .collect::<Result<Vec<Matrix<T>>, MathError>>()?;
+        let rows_ref: Vec<&Matrix<T>> = rows.iter().collect();
+
+        return Matrix::vstack(&rows_ref);
+    }
+
+    /// Constructs a block diagonal matrix
+    ///
+    /// ```math
+    /// \begin{pmatrix} A_1 & & \\ & \ddots & \\ & & A_k \end{pmatrix}
+    /// ```
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[macro_use]
+    /// # extern crate mathru;
+    /// # fn main()
+    /// # {
+    /// use mathru::algebra::linear::Matrix;
+    ///
+    /// let a: Matrix<f64> = matrix![1.0, 2.0];
+    /// let b: Matrix<f64> = matrix![3.0; 4.0];
+    ///
+    /// let c: Matrix<f64> = Matrix::block_diag(&[&a, &b]);
+    ///
+    /// assert_eq!(matrix![ 1.0, 2.0, 0.0;
+    ///                     0.0, 0.0, 3.0;
+    ///                     0.0, 0.0, 4.0], c);
+    /// # }
+    /// ```
+    pub fn block_diag(blocks: &[&Matrix<T>]) -> Matrix<T>
+    {
+        let m: usize = blocks.iter().map(|block| block.m).sum();
+        let n: usize = blocks.iter().map(|block| block.n).sum();
+        let mut diag: Matrix<T> = Matrix::zero(m, n);
+
+        let mut row: usize = 0;
+        let mut column: usize = 0;
+        for block in blocks
+        {
+            for j in 0..block.n
+            {
+                let offset: usize = (column + j) * m + row;
+                diag.data[offset..offset + block.m].copy_from_slice(&block.data[j * block.m..(j + 1) * block.m]);
+            }
+            row += block.m;
+            column += block.n;
+        }
+
+        return diag;
+    }
+}
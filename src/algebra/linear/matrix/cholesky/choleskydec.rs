@@ -1,10 +1,15 @@
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
-use crate::algebra::linear::Matrix;
+use crate::{
+    algebra::{
+        abstr::Real,
+        linear::{Matrix, Vector},
+    },
+    error::MathError,
+};
 use std::clone::Clone;
 
-
 /// Result of a Cholesky decomposition
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(Debug, Clone)]
@@ -29,3 +34,145 @@ impl<T> CholeskyDec<T>
         return self.l;
     }
 }
+
+impl<T> CholeskyDec<T> where T: Real
+{
+    /// Updates the decomposition of A to the decomposition of A + x x^T
+    ///
+    /// The factor L is updated with Givens rotations in O(n^2) operations.
+    ///
+    /// # Return
+    ///
+    /// MathError::DimensionMismatch if the dimension of x does not match the
+    /// dimension of A
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[macro_use]
+    /// # extern crate mathru;
+    /// # fn main()
+    /// # {
+    /// use mathru::algebra::linear::{matrix::{CholeskyDec, Transpose}, Matrix, Vector};
+    ///
+    /// let a: Matrix<f64> = matrix![   2.0, -1.0, 0.0;
+    ///                                -1.0, 2.0, -1.0;
+    ///                                 0.0, -1.0,  2.0];
+    /// let x: Vector<f64> = vector![1.0; 2.0; -1.0];
+    ///
+    /// let mut dec: CholeskyDec<f64> = a.dec_cholesky().unwrap();
+    /// dec.update(&x).unwrap();
+    ///
+    /// let a_x: Matrix<f64> = &a + &(&Matrix::from(x.clone()) * &Matrix::from(x.transpose()));
+    ///
+    /// assert_relative_eq!(a_x.dec_cholesky().unwrap().l(), dec.l(), epsilon=1.0e-10);
+    /// # }
+    /// ```
+    pub fn update(self: &mut Self, x: &Vector<T>) -> Result<(), MathError>
+    {
+        let mut x: Vec<T> = self.rank_one_vector(x)?;
+        let (n, _): (usize, usize) = self.l.dim();
+
+        for k in 0..n
+        {
+            let (c, s): (T, T) = Matrix::givens_cosine_sine_pair(*self.l.get(k, k), x[k]);
+            for i in k..n
+            {
+                let l_ik: T = *self.l.get(i, k);
+                *self.l.get_mut(i, k) = c * l_ik - s * x[i];
+                x[i] = s * l_ik + c * x[i];
+            }
+
+            // keep the diagonal of L positive
+            if *self.l.get(k, k) < T::zero()
+            {
+                for i in k..n
+                {
+                    *self.l.get_mut(i, k) = -*self.l.get(i, k);
+                }
+            }
+        }
+
+        return Ok(());
+    }
+
+    /// Updates the decomposition of A to the decomposition of A - x x^T
+    ///
+    /// The factor L is updated with hyperbolic rotations in O(n^2)
+    /// operations. The decomposition remains unchanged, if an error is
+    /// returned.
+    ///
+    /// # Return
+    ///
+    /// MathError::DimensionMismatch if the dimension of x does not match the
+    /// dimension of A
+    ///
+    /// MathError::NotPositiveDefinite if A - x x^T is not positive definite
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[macro_use]
+    /// # extern crate mathru;
+    /// # fn main()
+    /// # {
+    /// use mathru::algebra::linear::{matrix::CholeskyDec, Matrix, Vector};
+    ///
+    /// let a: Matrix<f64> = matrix![   2.0, -1.0, 0.0;
+    ///                                -1.0, 2.0, -1.0;
+    ///                                 0.0, -1.0,  2.0];
+    /// let x: Vector<f64> = vector![1.0; 0.0; 0.0];
+    ///
+    /// let mut dec: CholeskyDec<f64> = a.dec_cholesky().unwrap();
+    /// dec.downdate(&x).unwrap();
+    ///
+    /// let a_x: Matrix<f64> = matrix![ 1.0, -1.0, 0.0;
+    ///                                -1.0, 2.0, -1.0;
+    ///                                 0.0, -1.0,  2.0];
+    ///
+    /// assert_relative_eq!(a_x.dec_cholesky().unwrap().l(), dec.l(), epsilon=1.0e-10);
+    /// # }
+    /// ```
+    pub fn downdate(self: &mut Self, x: &Vector<T>) -> Result<(), MathError>
+    {
+        let mut x: Vec<T> = self.rank_one_vector(x)?;
+        let (n, _): (usize, usize) = self.l.dim();
+        let mut l: Matrix<T> = self.l.clone();
+
+        for k in 0..n
+        {
+            let l_kk: T = *l.get(k, k);
+            let r_square: T = l_kk * l_kk - x[k] * x[k];
+            if r_square <= T::zero()
+            {
+                return Err(MathError::NotPositiveDefinite);
+            }
+            let r: T = r_square.sqrt();
+            let c: T = r / l_kk;
+            let s: T = x[k] / l_kk;
+
+            *l.get_mut(k, k) = r;
+            for i in (k + 1)..n
+            {
+                let l_ik: T = (*l.get(i, k) - s * x[i]) / c;
+                *l.get_mut(i, k) = l_ik;
+                x[i] = c * x[i] - s * l_ik;
+            }
+        }
+
+        self.l = l;
+        return Ok(());
+    }
+
+    fn rank_one_vector(self: &Self, x: &Vector<T>) -> Result<Vec<T>, MathError>
+    {
+        let (n, _): (usize, usize) = self.l.dim();
+        let (x_m, x_n): (usize, usize) = x.dim();
+        if x_m * x_n != n
+        {
+            return Err(MathError::DimensionMismatch);
+        }
+
+        return Ok(x.iter().copied().collect());
+    }
+}
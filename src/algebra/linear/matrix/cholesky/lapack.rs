@@ -2,7 +2,8 @@ use crate::{
     algebra::{
         abstr::{Field, Scalar},
         linear::{matrix::CholeskyDec, Matrix},
-    }
+    },
+    error::MathError,
 };
 
 impl<T> Matrix<T>
@@ -35,7 +36,7 @@ impl<T> Matrix<T>
     /// let l: (Matrix<f64>) = a.dec_cholesky().unwrap().l();
     /// # }
     /// ```
-    pub fn dec_cholesky(self: &Self) -> Result<CholeskyDec<T>, ()>
+    pub fn dec_cholesky(self: &Self) -> Result<CholeskyDec<T>, MathError>
     {
         let (m, n): (usize, usize) = self.dim();
         assert_eq!(m, n);
@@ -49,9 +50,14 @@ impl<T> Matrix<T>
 
         T::xpotrf('L', n_i32, l_data.as_mut_slice(), n_i32, &mut info);
 
+        if info > 0
+        {
+            return Err(MathError::NotPositiveDefinite);
+        }
+
         if info < 0
         {
-            return Err(());
+            return Err(MathError::LapackInfo(info));
         }
 
         let mut l: Matrix<T> = Matrix::new(n, n, l_data);
@@ -3,6 +3,7 @@ use crate::{
         linear::{matrix::CholeskyDec, Matrix},
     },
     elementary::Power,
+    error::MathError,
 };
 use crate::algebra::abstr::{Complex, Real, Scalar};
 use crate::algebra::abstr::Zero;
@@ -37,7 +38,7 @@ impl<T> Matrix<T>
     /// let l: (Matrix<f64>) = a.dec_cholesky().unwrap().l();
     /// # }
     /// ```
-    pub fn dec_cholesky(self: &Self) -> Result<CholeskyDec<T>, ()>
+    pub fn dec_cholesky(self: &Self) -> Result<CholeskyDec<T>, MathError>
     {
         let (m, n): (usize, usize) = self.dim();
         assert_eq!(m, n);
@@ -57,7 +58,12 @@ impl<T> Matrix<T>
 
                 if i == j
                 {
-                    *l.get_mut(i, j) = (*self.get(i, i) - sum).sqrt();
+                    let d: T = *self.get(i, i) - sum;
+                    if d <= T::zero()
+                    {
+                        return Err(MathError::NotPositiveDefinite);
+                    }
+                    *l.get_mut(i, j) = d.sqrt();
                 }
                 else
                 {
@@ -99,7 +105,7 @@ impl<T> Matrix<Complex<T>>
     /// let l: (Matrix<f64>) = a.dec_cholesky().unwrap().l();
     /// # }
     /// ```
-    pub fn dec_cholesky(self: &Self) -> Result<CholeskyDec<Complex<T>>, ()>
+    pub fn dec_cholesky(self: &Self) -> Result<CholeskyDec<Complex<T>>, MathError>
     {
         let (m, n): (usize, usize) = self.dim();
         assert_eq!(m, n);
@@ -119,7 +125,12 @@ impl<T> Matrix<Complex<T>>
 
                 if i == j
                 {
-                    *l.get_mut(i, j) = (*self.get(i, i) - sum).sqrt();
+                    let d: Complex<T> = *self.get(i, i) - sum;
+                    if d.re <= T::zero()
+                    {
+                        return Err(MathError::NotPositiveDefinite);
+                    }
+                    *l.get_mut(i, j) = d.sqrt();
                 }
                 else
                 {
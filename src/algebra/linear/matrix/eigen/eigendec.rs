@@ -13,25 +13,27 @@ pub struct EigenDec<T>
 
 impl<T> EigenDec<T>
 {
-    pub(super) fn new(value: Vector<T>, vector: Matrix<T>) -> EigenDec<T>
+    pub(in crate::algebra::linear) fn new(value: Vector<T>, vector: Matrix<T>) -> EigenDec<T>
     {
         return EigenDec { value, vector };
     }
 
+    /// Return the eigenvalues
     pub fn value(self: Self) -> Vector<T>
     {
         return self.value;
     }
 
+    /// Return the eigenvectors, the i-th column is the eigenvector of the
+    /// i-th eigenvalue
     pub fn vector(self: Self) -> Matrix<T>
     {
-        unimplemented!();
-        // return self.vector;
+        return self.vector;
     }
 
+    /// Return the eigenvalues and the eigenvectors
     pub fn pair(self: Self) -> (Vector<T>, Matrix<T>)
     {
-        unimplemented!();
-        // return (self.value, self.vector);
+        return (self.value, self.vector);
     }
 }
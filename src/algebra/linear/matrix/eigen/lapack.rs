@@ -1,59 +1,79 @@
 use crate::{
     algebra::{
-        abstr::{Field, Scalar},
-        linear::{matrix::EigenDec, Matrix, Vector, matrix::Transpose},
+        abstr::{Complex, Real, Scalar, Zero},
+        linear::{
+            matrix::EigenDec,
+            Matrix, Vector,
+        },
     },
-    elementary::Power,
+    error::MathError,
 };
 
-impl<T> Matrix<T> where T: Field + Scalar + Power
+impl<T> Matrix<T> where T: Real, Complex<T>: Scalar
 {
-    /// Computes the eigenvalues of a real matrix
-    ///
-    ///
-    /// # Arguments
+    /// Computes the eigenvalues and eigenvectors of a real matrix
     ///
+    /// Pairs of complex conjugate eigenvalues are returned with the positive
+    /// imaginary part first. The eigenvectors are normalized to unit length.
     ///
+    /// # Panics
     ///
+    /// If the matrix is not square or empty
     ///
     /// # Example
     ///
     /// ```
-    /// use mathru::algebra::linear::{matrix::EigenDec, Matrix, Vector};
+    /// # #[macro_use]
+    /// # extern crate mathru;
+    /// # fn main()
+    /// # {
+    /// use mathru::algebra::abstr::Complex;
+    /// use mathru::algebra::linear::{Matrix, Vector};
     ///
-    /// let a: Matrix<f64> = Matrix::new(3, 3, vec![1.0, -3.0, 3.0, 3.0, -5.0, 3.0, 6.0, -6.0, 4.0]);
-    /// let eigen: EigenDec<f64> = a.dec_eigen().unwrap();
+    /// // rotation by 90 degrees
+    /// let a: Matrix<f64> = matrix![   0.0, -1.0;
+    ///                                 1.0, 0.0];
+    ///
+    /// let (value, vector): (Vector<Complex<f64>>, Matrix<Complex<f64>>) = a.dec_eigen().unwrap().pair();
+    ///
+    /// assert_relative_eq!(vector![Complex::new(0.0, 1.0); Complex::new(0.0, -1.0)], value, epsilon=Complex::new(1.0e-10, 1.0e-10));
+    /// # }
     /// ```
-    pub fn dec_eigen(self: &Self) -> Result<EigenDec<T>, ()>
+    pub fn dec_eigen(self: Self) -> Result<EigenDec<Complex<T>>, MathError>
     {
         let (m, n): (usize, usize) = self.dim();
         assert_eq!(m, n, "Unable to compute the eigen value of a non-square matrix");
         assert_ne!(m, 0, "Unable to compute the eigen value of an empty matrix.");
 
-        let (m, n): (usize, usize) = self.dim();
-
-        let mut self_data: Vec<T> = self.clone().transpose().data;
+        let mut self_data: Vec<T> = self.data;
         let n_i32: i32 = n as i32;
 
         let mut info: i32 = 0;
 
-        let mut w: Vec<T> = vec![T::zero(); n];
+        let mut wr: Vec<T> = vec![T::zero(); n];
+        let mut wi: Vec<T> = vec![T::zero(); n];
 
-        let mut temp1 = [T::zero()];
-        let mut temp2 = vec![T::zero(); n * n];
+        let mut vl: [T; 1] = [T::zero()];
+        let mut vr: Vec<T> = vec![T::zero(); n * n];
 
         let lwork = T::xgeev_work_size('N' as u8,
                                        'V' as u8,
                                        n_i32,
                                        &mut self_data[..],
                                        n_i32,
-                                       w.as_mut_slice(),
-                                       &mut temp1,
-                                       n_i32,
-                                       &mut temp2,
+                                       wr.as_mut_slice(),
+                                       wi.as_mut_slice(),
+                                       &mut vl,
+                                       1 as i32,
+                                       &mut vr,
                                        n_i32,
                                        &mut info);
 
+        if info != 0
+        {
+            return Err(MathError::LapackInfo(info));
+        }
+
         let mut work: Vec<T> = vec![T::zero(); lwork as usize];
 
         T::xgeev('N' as u8,
@@ -61,19 +81,216 @@ impl<T> Matrix<T> where T: Field + Scalar + Power
                  n_i32,
                  &mut self_data[..],
                  n_i32,
-                 w.as_mut_slice(),
-                 &mut temp1,
+                 wr.as_mut_slice(),
+                 wi.as_mut_slice(),
+                 &mut vl,
                  1 as i32,
-                 &mut temp2,
+                 &mut vr,
                  n_i32,
                  &mut work,
                  lwork,
                  &mut info);
 
-        if info != 0 {
-            return Err(())
+        if info != 0
+        {
+            return Err(MathError::LapackInfo(info));
+        }
+
+        // a complex eigenvector is stored as real part and imaginary part in
+        // two consecutive columns
+        let mut value: Vec<Complex<T>> = Vec::with_capacity(n);
+        let mut vector: Matrix<Complex<T>> = Matrix::zero(n, n);
+        let mut j: usize = 0;
+        while j < n
+        {
+            if wi[j] == T::zero()
+            {
+                value.push(Complex::new(wr[j], T::zero()));
+                for i in 0..n
+                {
+                    *vector.get_mut(i, j) = Complex::new(vr[j * n + i], T::zero());
+                }
+                j += 1;
+            }
+            else
+            {
+                value.push(Complex::new(wr[j], wi[j]));
+                value.push(Complex::new(wr[j + 1], wi[j + 1]));
+                for i in 0..n
+                {
+                    let v_ij: Complex<T> = Complex::new(vr[j * n + i], vr[(j + 1) * n + i]);
+                    *vector.get_mut(i, j) = v_ij;
+                    *vector.get_mut(i, j + 1) = v_ij.conj();
+                }
+                j += 2;
+            }
+        }
+
+        return Ok(EigenDec::new(Vector::new_column(n, value), vector));
+    }
+}
+
+impl<T> Matrix<Complex<T>> where T: Real, Complex<T>: Scalar
+{
+    /// Computes the eigenvalues of a complex matrix
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mathru::algebra::abstr::Complex;
+    /// use mathru::algebra::linear::{matrix::EigenDec, Matrix, Vector};
+    ///
+    /// let a: Matrix<Complex<f64>> = Matrix::new(2, 2, vec![Complex::new(1.0, 0.0), Complex::new(0.0, 0.0), Complex::new(0.0, 0.0), Complex::new(2.0, 0.0)]);
+    /// let eigen: EigenDec<Complex<f64>> = a.dec_eigen().unwrap();
+    /// ```
+    pub fn dec_eigen(self: Self) -> Result<EigenDec<Complex<T>>, MathError>
+    {
+        let (m, n): (usize, usize) = self.dim();
+        assert_eq!(m, n, "Unable to compute the eigen value of a non-square matrix");
+        assert_ne!(m, 0, "Unable to compute the eigen value of an empty matrix.");
+
+        let mut self_data: Vec<Complex<T>> = self.data;
+        let n_i32: i32 = n as i32;
+
+        let mut info: i32 = 0;
+
+        let mut w: Vec<Complex<T>> = vec![Complex::zero(); n];
+        let mut wi: [Complex<T>; 0] = [];
+
+        let mut vl: [Complex<T>; 1] = [Complex::zero()];
+        let mut vr: Vec<Complex<T>> = vec![Complex::zero(); n * n];
+
+        let lwork = Complex::<T>::xgeev_work_size('N' as u8,
+                                                  'V' as u8,
+                                                  n_i32,
+                                                  &mut self_data[..],
+                                                  n_i32,
+                                                  w.as_mut_slice(),
+                                                  &mut wi,
+                                                  &mut vl,
+                                                  1 as i32,
+                                                  &mut vr,
+                                                  n_i32,
+                                                  &mut info);
+
+        if info != 0
+        {
+            return Err(MathError::LapackInfo(info));
+        }
+
+        let mut work: Vec<Complex<T>> = vec![Complex::zero(); lwork as usize];
+
+        Complex::<T>::xgeev('N' as u8,
+                            'V' as u8,
+                            n_i32,
+                            &mut self_data[..],
+                            n_i32,
+                            w.as_mut_slice(),
+                            &mut wi,
+                            &mut vl,
+                            1 as i32,
+                            &mut vr,
+                            n_i32,
+                            &mut work,
+                            lwork,
+                            &mut info);
+
+        if info != 0
+        {
+            return Err(MathError::LapackInfo(info));
+        }
+
+        return Ok(EigenDec::new(Vector::new_column(m, w), Matrix::new(n, n, vr)));
+    }
+}
+
+impl<T> Matrix<T> where T: Real
+{
+    /// Computes the eigenvalues and eigenvectors of a real symmetric matrix
+    ///
+    /// The eigenvalues are sorted in ascending order, the eigenvectors are
+    /// the columns of an orthogonal matrix.
+    ///
+    /// Only the lower triangular part of the matrix is referenced.
+    ///
+    /// # Return
+    ///
+    /// MathError::NotFinite if an entry of the matrix is infinite or NaN
+    ///
+    /// # Panics
+    ///
+    /// If the matrix is not square or empty
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[macro_use]
+    /// # extern crate mathru;
+    /// # fn main()
+    /// # {
+    /// use mathru::algebra::linear::{matrix::EigenDec, Matrix, Vector};
+    ///
+    /// let a: Matrix<f64> = matrix![   2.0, 1.0;
+    ///                                 1.0, 2.0];
+    ///
+    /// let (value, vector): (Vector<f64>, Matrix<f64>) = a.dec_eigen_symmetric().unwrap().pair();
+    ///
+    /// assert_relative_eq!(vector![1.0; 3.0], value, epsilon=1.0e-10);
+    ///
+    /// let x: Vector<f64> = vector.get_column(1);
+    /// assert_relative_eq!(&a * &x, &x * &3.0, epsilon=1.0e-10);
+    /// # }
+    /// ```
+    pub fn dec_eigen_symmetric(self: &Self) -> Result<EigenDec<T>, MathError>
+    {
+        let (m, n): (usize, usize) = self.dim();
+        assert_eq!(m, n, "Unable to compute the eigen value of a non-square matrix");
+        assert_ne!(m, 0, "Unable to compute the eigen value of an empty matrix.");
+
+        if !self.is_finite()
+        {
+            return Err(MathError::NotFinite);
+        }
+
+        let mut a_data: Vec<T> = self.clone().data;
+        let n_i32: i32 = n as i32;
+
+        let mut info: i32 = 0;
+        let mut w: Vec<T> = vec![T::zero(); n];
+
+        let (lwork, liwork): (i32, i32) = T::xsyevd_work_size('V' as u8,
+                                                              'L' as u8,
+                                                              n_i32,
+                                                              &mut a_data[..],
+                                                              n_i32,
+                                                              w.as_mut_slice(),
+                                                              &mut info);
+
+        if info != 0
+        {
+            return Err(MathError::LapackInfo(info));
+        }
+
+        let mut work: Vec<T> = vec![T::zero(); lwork as usize];
+        let mut iwork: Vec<i32> = vec![0; liwork as usize];
+
+        T::xsyevd('V' as u8,
+                  'L' as u8,
+                  n_i32,
+                  &mut a_data[..],
+                  n_i32,
+                  w.as_mut_slice(),
+                  &mut work,
+                  lwork,
+                  &mut iwork,
+                  liwork,
+                  &mut info);
+
+        if info != 0
+        {
+            return Err(MathError::LapackInfo(info));
         }
 
-        return Ok(EigenDec::new(Vector::new_column(m, w), Matrix::new(n, n, temp2)));
+        return Ok(EigenDec::new(Vector::new_column(n, w), Matrix::new(n, n, a_data)));
     }
 }
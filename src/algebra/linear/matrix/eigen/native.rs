@@ -1,171 +1,1083 @@
-use crate::algebra::{
-    linear::{
-        matrix::{Transpose, EigenDec, Solve},
-        Matrix, Vector,
+use crate::{
+    algebra::{
+        abstr::{AbsDiffEq, Complex, Field, One, Real, Scalar, Zero},
+        linear::{
+            matrix::{
+                schur::complex::{abs, norm_1, scale, schur_complex},
+                EigenDec, Solve,
+            },
+            Matrix, Vector,
+        },
     },
+    elementary::Power,
+    error::MathError,
 };
-use crate::algebra::abstr::{Field, Scalar, AbsDiffEq};
-use crate::elementary::Power;
 
-impl<T> Matrix<T> where T: Field + Scalar + Power + AbsDiffEq<Epsilon = T>
+impl<T> Matrix<Complex<T>> where T: Real, Complex<T>: Scalar
 {
-    /// Computes the eigenvalues of a real matrix
+    /// Computes the eigenvalues and eigenvectors of a complex matrix
+    ///
+    /// The matrix is reduced to the complex Schur form A = Z T Z^H with the
+    /// shifted QR algorithm. The eigenvalues are the diagonal elements of T,
+    /// the eigenvectors are computed by back substitution and are normalized
+    /// to unit length.
     ///
-    /// # Arguments
+    /// # Panics
+    ///
+    /// If the matrix is not square or empty
     ///
     /// # Example
     ///
     /// ```
+    /// # #[macro_use]
+    /// # extern crate mathru;
+    /// # fn main()
+    /// # {
+    /// use mathru::algebra::abstr::Complex;
     /// use mathru::algebra::linear::{matrix::EigenDec, Matrix, Vector};
     ///
-    /// let a: Matrix<f64> = Matrix::new(3, 3, vec![1.0, -3.0, 3.0, 3.0, -5.0, 3.0, 6.0, -6.0, 4.0]);
-    /// let eigen: EigenDec<f64> = a.dec_eigen().unwrap();
+    /// let a: Matrix<Complex<f64>> = Matrix::new(2, 2, vec![Complex::new(1.0, 0.0), Complex::new(0.0, 0.0), Complex::new(0.0, 1.0), Complex::new(2.0, 0.0)]);
+    /// let (value, vector): (Vector<Complex<f64>>, Matrix<Complex<f64>>) = a.clone().dec_eigen().unwrap().pair();
+    ///
+    /// let v_1: Vector<Complex<f64>> = vector.get_column(1);
+    /// assert_relative_eq!(&a * &v_1, &v_1 * value.get(1), epsilon=Complex::new(1.0e-10, 1.0e-10));
+    /// # }
     /// ```
-    pub fn dec_eigen(self: Self) -> Result<EigenDec<T>, ()>
+    pub fn dec_eigen(self: Self) -> Result<EigenDec<Complex<T>>, MathError>
     {
         let (m, n): (usize, usize) = self.dim();
         assert_eq!(m, n, "Unable to compute the eigen value of a non-square matrix");
         assert_ne!(m, 0, "Unable to compute the eigen value of an empty matrix.");
 
-        let value: Vector<T> = self.eigenvalue_r();
-        let vector: Matrix<T> = self.eigenvector_r(&value);
+        let (z, t): (Matrix<Complex<T>>, Matrix<Complex<T>>) = schur_complex(&self)?;
+
+        let value: Vector<Complex<T>> = Vector::new_column(n, (0..n).map(|i| *t.get(i, i)).collect());
+        let vector: Matrix<Complex<T>> = Matrix::eigenvector_triangular(&t, &z);
 
         return Ok(EigenDec::new(value, vector));
     }
 
-    pub fn eigenvalue_r(self: &Self) -> Vector<T>
+    /// Computes the eigenvectors of a matrix A = Z T Z^H from its complex
+    /// Schur form T
+    ///
+    /// The eigenvectors of T are computed by back substitution and
+    /// transformed with Z. Every eigenvector is normalized to unit length.
+    fn eigenvector_triangular(t: &Matrix<Complex<T>>, z: &Matrix<Complex<T>>) -> Matrix<Complex<T>>
     {
-        let (m, _n): (usize, usize) = self.dim();
+        let (n, _): (usize, usize) = t.dim();
+        let norm: T = norm_1(t);
+        let small: T = T::default_epsilon() * if norm == T::zero() { T::one() } else { norm };
+
+        let mut vector: Matrix<Complex<T>> = Matrix::zero(n, n);
+        for k in 0..n
+        {
+            let lambda: Complex<T> = *t.get(k, k);
+            let mut y: Vec<Complex<T>> = vec![Complex::zero(); k + 1];
+            y[k] = Complex::one();
+            for i in (0..k).rev()
+            {
+                let mut sum: Complex<T> = *t.get(i, k);
+                for j in (i + 1)..k
+                {
+                    sum += *t.get(i, j) * y[j];
+                }
+                let mut d: Complex<T> = *t.get(i, i) - lambda;
+                if abs(&d) < small
+                {
+                    d = Complex::new(small, T::zero());
+                }
+                y[i] = -sum / d;
+            }
+
+            let mut norm_k: T = T::zero();
+            for i in 0..n
+            {
+                let mut v_ik: Complex<T> = Complex::zero();
+                for j in 0..=k
+                {
+                    v_ik += *z.get(i, j) * y[j];
+                }
+                norm_k += v_ik.re * v_ik.re + v_ik.im * v_ik.im;
+                *vector.get_mut(i, k) = v_ik;
+            }
+            let norm_k: T = norm_k.sqrt();
+            for i in 0..n
+            {
+                let v_ik: Complex<T> = *vector.get(i, k);
+                *vector.get_mut(i, k) = scale(T::one() / norm_k, v_ik);
+            }
+        }
+
+        return vector;
+    }
+}
+
+impl<T> Matrix<T> where T: Real, Complex<T>: Scalar
+{
+    /// Computes the eigenvalues and eigenvectors of a real matrix
+    ///
+    /// The matrix is reduced to Hessenberg form and afterwards to the real
+    /// Schur form with the Francis double shift QR algorithm. The 2 x 2
+    /// blocks on the diagonal of the Schur form yield pairs of complex
+    /// conjugate eigenvalues. The eigenvectors are computed by back
+    /// substitution and are normalized to unit length.
+    ///
+    /// # Panics
+    ///
+    /// If the matrix is not square or empty
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[macro_use]
+    /// # extern crate mathru;
+    /// # fn main()
+    /// # {
+    /// use mathru::algebra::abstr::Complex;
+    /// use mathru::algebra::linear::{Matrix, Vector};
+    ///
+    /// // rotation by 90 degrees
+    /// let a: Matrix<f64> = matrix![   0.0, -1.0;
+    ///                                 1.0, 0.0];
+    ///
+    /// let (value, vector): (Vector<Complex<f64>>, Matrix<Complex<f64>>) = a.dec_eigen().unwrap().pair();
+    ///
+    /// assert_relative_eq!(vector![Complex::new(0.0, 1.0); Complex::new(0.0, -1.0)], value, epsilon=Complex::new(1.0e-10, 1.0e-10));
+    /// # }
+    /// ```
+    pub fn dec_eigen(self: Self) -> Result<EigenDec<Complex<T>>, MathError>
+    {
+        let (m, n): (usize, usize) = self.dim();
+        assert_eq!(m, n, "Unable to compute the eigen value of a non-square matrix");
+        assert_ne!(m, 0, "Unable to compute the eigen value of an empty matrix.");
+
+        let (q, h): (Matrix<T>, Matrix<T>) = self.dec_hessenberg().qh();
+        let mut t: Matrix<T> = h;
+        let mut z: Matrix<T> = q;
+        let mut re: Vec<T> = vec![T::zero(); n];
+        let mut im: Vec<T> = vec![T::zero(); n];
+
+        Matrix::francis_double_shift(&mut t, &mut z, &mut re, &mut im)?;
+
+        let value: Vector<Complex<T>> = Vector::new_column(n,
+                                                           re.iter()
+                                                             .zip(im.iter())
+                                                             .map(|(re_i, im_i)| Complex::new(*re_i, *im_i))
+                                                             .collect());
+        let vector: Matrix<Complex<T>> = Matrix::eigenvector_quasi_triangular(t, &z, &re, &im);
+
+        return Ok(EigenDec::new(value, vector));
+    }
+
+    /// Computes the eigenvectors of a matrix A = Z T Z^T from its real Schur
+    /// form T
+    ///
+    /// The eigenvectors of T are computed by back substitution and
+    /// transformed with Z. Every eigenvector is normalized to unit length.
+    fn eigenvector_quasi_triangular(mut t: Matrix<T>, z: &Matrix<T>, re: &[T], im: &[T])
+                                    -> Matrix<Complex<T>>
+    {
+        let (nn, _): (usize, usize) = t.dim();
+        let epsilon: T = T::default_epsilon();
+
+        let mut norm: T = T::zero();
+        for i in 0..nn
+        {
+            for j in i.saturating_sub(1)..nn
+            {
+                norm += t.get(i, j).abs();
+            }
+        }
+
+        let mut v: Matrix<T> = z.clone();
+
+        if norm != T::zero()
+        {
+            let mut r: T = T::zero();
+            let mut s: T = T::zero();
+            let mut zz: T = T::zero();
+
+            for n in (0..nn).rev()
+            {
+                let p: T = re[n];
+                let q: T = im[n];
+
+                if q == T::zero()
+                {
+                    // real vector
+                    let mut l: usize = n;
+                    *t.get_mut(n, n) = T::one();
+                    for i in (0..n).rev()
+                    {
+                        let w: T = *t.get(i, i) - p;
+                        r = T::zero();
+                        for j in l..=n
+                        {
+                            r += *t.get(i, j) * *t.get(j, n);
+                        }
+                        if im[i] < T::zero()
+                        {
+                            zz = w;
+                            s = r;
+                        }
+                        else
+                        {
+                            l = i;
+                            if im[i] == T::zero()
+                            {
+                                *t.get_mut(i, n) = if w != T::zero() { -r / w } else { -r / (epsilon * norm) };
+                            }
+                            else
+                            {
+                                // solve real equations
+                                let x: T = *t.get(i, i + 1);
+                                let y: T = *t.get(i + 1, i);
+                                let q_i: T = (re[i] - p) * (re[i] - p) + im[i] * im[i];
+                                let t_in: T = (x * s - zz * r) / q_i;
+                                *t.get_mut(i, n) = t_in;
+                                *t.get_mut(i + 1, n) = if x.abs() > zz.abs()
+                                {
+                                    (-r - w * t_in) / x
+                                }
+                                else
+                                {
+                                    (-s - y * t_in) / zz
+                                };
+                            }
+
+                            // overflow control
+                            let t_max: T = t.get(i, n).abs();
+                            if (epsilon * t_max) * t_max > T::one()
+                            {
+                                for j in i..=n
+                                {
+                                    *t.get_mut(j, n) /= t_max;
+                                }
+                            }
+                        }
+                    }
+                }
+                else if q < T::zero()
+                {
+                    // complex vector, the real part is stored in column n - 1
+                    // and the imaginary part in column n
+                    let mut l: usize = n - 1;
+
+                    // last vector component imaginary so matrix is triangular
+                    if t.get(n, n - 1).abs() > t.get(n - 1, n).abs()
+                    {
+                        *t.get_mut(n - 1, n - 1) = q / *t.get(n, n - 1);
+                        *t.get_mut(n - 1, n) = -(*t.get(n, n) - p) / *t.get(n, n - 1);
+                    }
+                    else
+                    {
+                        let c: Complex<T> = Complex::new(T::zero(), -*t.get(n - 1, n))
+                                            / Complex::new(*t.get(n - 1, n - 1) - p, q);
+                        *t.get_mut(n - 1, n - 1) = c.re;
+                        *t.get_mut(n - 1, n) = c.im;
+                    }
+                    *t.get_mut(n, n - 1) = T::zero();
+                    *t.get_mut(n, n) = T::one();
+
+                    for i in (0..(n - 1)).rev()
+                    {
+                        let mut ra: T = T::zero();
+                        let mut sa: T = T::zero();
+                        for j in l..=n
+                        {
+                            ra += *t.get(i, j) * *t.get(j, n - 1);
+                            sa += *t.get(i, j) * *t.get(j, n);
+                        }
+                        let w: T = *t.get(i, i) - p;
 
-        let h: Matrix<T> = self.dec_hessenberg().h();
+                        if im[i] < T::zero()
+                        {
+                            zz = w;
+                            r = ra;
+                            s = sa;
+                        }
+                        else
+                        {
+                            l = i;
+                            if im[i] == T::zero()
+                            {
+                                let c: Complex<T> = Complex::new(-ra, -sa) / Complex::new(w, q);
+                                *t.get_mut(i, n - 1) = c.re;
+                                *t.get_mut(i, n) = c.im;
+                            }
+                            else
+                            {
+                                // solve complex equations
+                                let x: T = *t.get(i, i + 1);
+                                let y: T = *t.get(i + 1, i);
+                                let mut vr: T = (re[i] - p) * (re[i] - p) + im[i] * im[i] - q * q;
+                                let vi: T = (re[i] - p) * T::from_f64(2.0) * q;
+                                if vr == T::zero() && vi == T::zero()
+                                {
+                                    vr = epsilon * norm * (w.abs() + q.abs() + x.abs() + y.abs() + zz.abs());
+                                }
+                                let c: Complex<T> = Complex::new(x * r - zz * ra + q * sa, x * s - zz * sa - q * ra)
+                                                    / Complex::new(vr, vi);
+                                *t.get_mut(i, n - 1) = c.re;
+                                *t.get_mut(i, n) = c.im;
+                                if x.abs() > zz.abs() + q.abs()
+                                {
+                                    *t.get_mut(i + 1, n - 1) = (-ra - w * c.re + q * c.im) / x;
+                                    *t.get_mut(i + 1, n) = (-sa - w * c.im - q * c.re) / x;
+                                }
+                                else
+                                {
+                                    let c_1: Complex<T> = Complex::new(-r - y * c.re, -s - y * c.im)
+                                                          / Complex::new(zz, q);
+                                    *t.get_mut(i + 1, n - 1) = c_1.re;
+                                    *t.get_mut(i + 1, n) = c_1.im;
+                                }
+                            }
 
-        let (_u, t): (Matrix<T>, Matrix<T>) = h.francis();
+                            // overflow control
+                            let t_max: T = t.get(i, n - 1).abs().max(t.get(i, n).abs());
+                            if (epsilon * t_max) * t_max > T::one()
+                            {
+                                for j in i..=n
+                                {
+                                    *t.get_mut(j, n - 1) /= t_max;
+                                    *t.get_mut(j, n) /= t_max;
+                                }
+                            }
+                        }
+                    }
+                }
+            }
 
-        let mut eig: Vector<T> = Vector::zero(m);
+            // back transformation to the eigenvectors of the original matrix
+            for j in (0..nn).rev()
+            {
+                for i in 0..nn
+                {
+                    let mut sum: T = T::zero();
+                    for k in 0..=j
+                    {
+                        sum += *z.get(i, k) * *t.get(k, j);
+                    }
+                    *v.get_mut(i, j) = sum;
+                }
+            }
+        }
 
-        for i in 0..m
+        let mut vector: Matrix<Complex<T>> = Matrix::zero(nn, nn);
+        let mut j: usize = 0;
+        while j < nn
         {
-            *eig.get_mut(i) = *t.get(i, i);
+            if im[j] == T::zero()
+            {
+                let mut norm_j: T = T::zero();
+                for i in 0..nn
+                {
+                    norm_j += *v.get(i, j) * *v.get(i, j);
+                }
+                let norm_j: T = norm_j.sqrt();
+                for i in 0..nn
+                {
+                    *vector.get_mut(i, j) = Complex::new(*v.get(i, j) / norm_j, T::zero());
+                }
+                j += 1;
+            }
+            else
+            {
+                let mut norm_j: T = T::zero();
+                for i in 0..nn
+                {
+                    norm_j += *v.get(i, j) * *v.get(i, j) + *v.get(i, j + 1) * *v.get(i, j + 1);
+                }
+                let norm_j: T = norm_j.sqrt();
+                for i in 0..nn
+                {
+                    let v_ij: Complex<T> = Complex::new(*v.get(i, j) / norm_j, *v.get(i, j + 1) / norm_j);
+                    *vector.get_mut(i, j) = v_ij;
+                    *vector.get_mut(i, j + 1) = v_ij.conj();
+                }
+                j += 2;
+            }
         }
 
-        return eig;
+        return vector;
     }
+}
 
-    fn francis(mut self: Self) -> (Matrix<T>, Matrix<T>)
+impl<T> Matrix<T> where T: Field + Scalar + Power + AbsDiffEq<Epsilon = T>
+{
+    /// Computes the eigenvectors with one step of inverse iteration
+    ///
+    /// The eigenvalues are slightly perturbed, such that A - λI is regular.
+    /// Every eigenvector is scaled, such that its largest component is one.
+    ///
+    /// # Return
+    ///
+    /// The error of the solver, if the perturbed matrix A - λI is singular
+    pub fn eigenvector_r(self: &Self, value: &Vector<T>) -> Result<Matrix<T>, MathError>
     {
-        let epsilon: T = T::default_epsilon();
+        let eye: Matrix<T> = Matrix::one(self.m);
+        let one_vector: Vector<T> = Vector::one(self.m);
+        let mut vectors: Matrix<T> = Matrix::zero(self.m, self.m);
 
-        let (m, n): (usize, usize) = self.dim();
+        for (c, val) in value.iter().enumerate()
+        {
+            let shift: T = T::default_epsilon() * (T::one() + val.abs());
+            let diff: Matrix<T> = self - &(&eye * &(*val + shift));
+            let x: Vector<T> = diff.solve(&one_vector)?;
+
+            let mut x_max: T = T::zero();
+            for x_i in x.iter()
+            {
+                if x_i.abs() > x_max.abs()
+                {
+                    x_max = *x_i;
+                }
+            }
+            vectors.set_column(&(x / x_max), c);
+        }
 
-        let mut u: Matrix<T> = Matrix::one(m);
+        return Ok(vectors);
+    }
+}
+
+impl<T> Matrix<T> where T: Real
+{
+    /// Reduces the Hessenberg matrix h to the real Schur form with the
+    /// Francis double shift QR algorithm
+    ///
+    /// The orthogonal transformations are accumulated in z. On return re and
+    /// im contain the real and imaginary parts of the eigenvalues, a pair of
+    /// complex conjugate eigenvalues is stored with the positive imaginary
+    /// part first.
+    pub(in crate::algebra::linear::matrix) fn francis_double_shift(h: &mut Matrix<T>, z: &mut Matrix<T>, re: &mut [T], im: &mut [T])
+                                                                     -> Result<(), MathError>
+    {
+        let (nn, _): (usize, usize) = h.dim();
+        let epsilon: T = T::default_epsilon();
+        let max_iter: usize = 100;
 
-        let mut p: usize = n;
-        let mut q: usize;
+        let mut exshift: T = T::zero();
+        let mut p: T = T::zero();
+        let mut q: T = T::zero();
+        let mut r: T = T::zero();
+        let mut s: T;
+        let mut w: T;
+        let mut x: T;
+        let mut y: T;
+        let mut zz: T;
 
-        while p > 2
+        let mut norm: T = T::zero();
+        for i in 0..nn
         {
-            q = p - 1;
+            for j in i.saturating_sub(1)..nn
+            {
+                norm += h.get(i, j).abs();
+            }
+        }
 
-            // Bulge generating
-            let s: T = *self.get(q - 1, q - 1) + *self.get(p - 1, p - 1);
-            let t: T = *self.get(q - 1, q - 1) * *self.get(p - 1, p - 1)
-                       - *self.get(q - 1, p - 1) * *self.get(p - 1, q - 1);
+        let mut n: isize = nn as isize - 1;
+        let mut iter: usize = 0;
+        while n >= 0
+        {
+            let nu: usize = n as usize;
 
-            // compute first 3 elements of first column of M
-            let mut x: T = self.get(0, 0).pow(T::from_f64(2.0))
-                           + *self.get(0, 1) * *self.get(1, 0)
-                           - s * *self.get(0, 0)
-                           + t;
-            let mut y: T = *self.get(1, 0) * (*self.get(0, 0) + *self.get(1, 1) - s);
-            let mut z: T = *self.get(1, 0) * *self.get(2, 1);
+            // look for a single small subdiagonal element
+            let mut l: usize = nu;
+            while l > 0
+            {
+                s = h.get(l - 1, l - 1).abs() + h.get(l, l).abs();
+                if s == T::zero()
+                {
+                    s = norm;
+                }
+                if h.get(l, l - 1).abs() < epsilon * s
+                {
+                    break;
+                }
+                l -= 1;
+            }
 
-            for k in 0..(p - 2)
+            if l == nu
+            {
+                // one root found
+                *h.get_mut(nu, nu) += exshift;
+                re[nu] = *h.get(nu, nu);
+                im[nu] = T::zero();
+                n -= 1;
+                iter = 0;
+            }
+            else if l == nu - 1
             {
-                let b: Vector<T> = Vector::new_column(3, vec![x, y, z]);
-                let h: Matrix<T> = Matrix::householder(&b, 0);
+                // two roots found
+                w = *h.get(nu, nu - 1) * *h.get(nu - 1, nu);
+                p = (*h.get(nu - 1, nu - 1) - *h.get(nu, nu)) / T::from_f64(2.0);
+                q = p * p + w;
+                zz = q.abs().sqrt();
+                *h.get_mut(nu, nu) += exshift;
+                *h.get_mut(nu - 1, nu - 1) += exshift;
+                x = *h.get(nu, nu);
 
-                //Determine the Householder reflector P with P [x; y; z] = αe1 ;
+                if q >= T::zero()
                 {
-                    let r: usize = k.max(1);
+                    // real pair, the block is transformed to upper triangular form
+                    zz = if p >= T::zero() { p + zz } else { p - zz };
+                    re[nu - 1] = x + zz;
+                    re[nu] = re[nu - 1];
+                    if zz != T::zero()
+                    {
+                        re[nu] = x - w / zz;
+                    }
+                    im[nu - 1] = T::zero();
+                    im[nu] = T::zero();
 
-                    let temp = &h * &self.get_slice(k, k + 2, r - 1, n - 1);
-                    self = self.set_slice(&temp, k, r - 1);
+                    x = *h.get(nu, nu - 1);
+                    s = x.abs() + zz.abs();
+                    p = x / s;
+                    q = zz / s;
+                    r = (p * p + q * q).sqrt();
+                    p /= r;
+                    q /= r;
+
+                    for j in (nu - 1)..nn
+                    {
+                        zz = *h.get(nu - 1, j);
+                        *h.get_mut(nu - 1, j) = q * zz + p * *h.get(nu, j);
+                        *h.get_mut(nu, j) = q * *h.get(nu, j) - p * zz;
+                    }
+                    for i in 0..=nu
+                    {
+                        zz = *h.get(i, nu - 1);
+                        *h.get_mut(i, nu - 1) = q * zz + p * *h.get(i, nu);
+                        *h.get_mut(i, nu) = q * *h.get(i, nu) - p * zz;
+                    }
+                    for i in 0..nn
+                    {
+                        zz = *z.get(i, nu - 1);
+                        *z.get_mut(i, nu - 1) = q * zz + p * *z.get(i, nu);
+                        *z.get_mut(i, nu) = q * *z.get(i, nu) - p * zz;
+                    }
+                }
+                else
+                {
+                    // complex pair
+                    re[nu - 1] = x + p;
+                    re[nu] = x + p;
+                    im[nu - 1] = zz;
+                    im[nu] = -zz;
                 }
+                n -= 2;
+                iter = 0;
+            }
+            else
+            {
+                // form shift
+                x = *h.get(nu, nu);
+                y = *h.get(nu - 1, nu - 1);
+                w = *h.get(nu, nu - 1) * *h.get(nu - 1, nu);
 
+                // exceptional shifts
+                if iter == 10
+                {
+                    exshift += x;
+                    for i in 0..=nu
+                    {
+                        *h.get_mut(i, i) -= x;
+                    }
+                    s = h.get(nu, nu - 1).abs() + h.get(nu - 1, nu - 2).abs();
+                    x = T::from_f64(0.75) * s;
+                    y = x;
+                    w = T::from_f64(-0.4375) * s * s;
+                }
+                if iter == 30
                 {
-                    let h_trans: Matrix<T> = h.transpose();
-                    let r: usize = p.min(k + 4);
-                    let temp: Matrix<T> = &self.get_slice(0, r - 1, k, k + 2) * &h_trans;
-                    self = self.set_slice(&temp, 0, k);
+                    s = (y - x) / T::from_f64(2.0);
+                    s = s * s + w;
+                    if s > T::zero()
+                    {
+                        s = s.sqrt();
+                        if y < x
+                        {
+                            s = -s;
+                        }
+                        s = x - w / ((y - x) / T::from_f64(2.0) + s);
+                        for i in 0..=nu
+                        {
+                            *h.get_mut(i, i) -= s;
+                        }
+                        exshift += s;
+                        x = T::from_f64(0.964);
+                        y = x;
+                        w = x;
+                    }
+                }
 
-                    let temp1: Matrix<T> = &u.get_slice(0, n - 1, k, k + 2) * &h_trans;
+                iter += 1;
+                if iter > max_iter
+                {
+                    return Err(MathError::NoConvergence { iterations: max_iter });
+                }
 
-                    u = u.set_slice(&temp1, 0, k);
+                // look for two consecutive small subdiagonal elements
+                let mut m: usize = nu - 2;
+                loop
+                {
+                    zz = *h.get(m, m);
+                    r = x - zz;
+                    s = y - zz;
+                    p = (r * s - w) / *h.get(m + 1, m) + *h.get(m, m + 1);
+                    q = *h.get(m + 1, m + 1) - zz - r - s;
+                    r = *h.get(m + 2, m + 1);
+                    s = p.abs() + q.abs() + r.abs();
+                    p /= s;
+                    q /= s;
+                    r /= s;
+                    if m == l
+                    {
+                        break;
+                    }
+                    if h.get(m, m - 1).abs() * (q.abs() + r.abs())
+                       < epsilon
+                         * (p.abs()
+                            * (h.get(m - 1, m - 1).abs() + zz.abs() + h.get(m + 1, m + 1).abs()))
+                    {
+                        break;
+                    }
+                    m -= 1;
                 }
 
-                x = *self.get(k + 1, k);
-                y = *self.get(k + 2, k);
-                if k < (p - 3)
+                for i in (m + 2)..=nu
                 {
-                    z = *self.get(k + 3, k);
+                    *h.get_mut(i, i - 2) = T::zero();
+                    if i > m + 2
+                    {
+                        *h.get_mut(i, i - 3) = T::zero();
+                    }
+                }
+
+                // double QR step involving rows l..=n and columns m..=n
+                for k in m..nu
+                {
+                    let notlast: bool = k != nu - 1;
+                    if k != m
+                    {
+                        p = *h.get(k, k - 1);
+                        q = *h.get(k + 1, k - 1);
+                        r = if notlast { *h.get(k + 2, k - 1) } else { T::zero() };
+                        x = p.abs() + q.abs() + r.abs();
+                        if x == T::zero()
+                        {
+                            continue;
+                        }
+                        p /= x;
+                        q /= x;
+                        r /= x;
+                    }
+
+                    s = (p * p + q * q + r * r).sqrt();
+                    if p < T::zero()
+                    {
+                        s = -s;
+                    }
+                    if s != T::zero()
+                    {
+                        if k != m
+                        {
+                            *h.get_mut(k, k - 1) = -s * x;
+                        }
+                        else if l != m
+                        {
+                            *h.get_mut(k, k - 1) = -*h.get(k, k - 1);
+                        }
+                        p += s;
+                        x = p / s;
+                        y = q / s;
+                        zz = r / s;
+                        q /= p;
+                        r /= p;
+
+                        // row modification
+                        for j in k..nn
+                        {
+                            p = *h.get(k, j) + q * *h.get(k + 1, j);
+                            if notlast
+                            {
+                                p += r * *h.get(k + 2, j);
+                                *h.get_mut(k + 2, j) -= p * zz;
+                            }
+                            *h.get_mut(k, j) -= p * x;
+                            *h.get_mut(k + 1, j) -= p * y;
+                        }
+
+                        // column modification
+                        for i in 0..=nu.min(k + 3)
+                        {
+                            p = x * *h.get(i, k) + y * *h.get(i, k + 1);
+                            if notlast
+                            {
+                                p += zz * *h.get(i, k + 2);
+                                *h.get_mut(i, k + 2) -= p * r;
+                            }
+                            *h.get_mut(i, k) -= p;
+                            *h.get_mut(i, k + 1) -= p * q;
+                        }
+
+                        // accumulate transformations
+                        for i in 0..nn
+                        {
+                            p = x * *z.get(i, k) + y * *z.get(i, k + 1);
+                            if notlast
+                            {
+                                p += zz * *z.get(i, k + 2);
+                                *z.get_mut(i, k + 2) -= p * r;
+                            }
+                            *z.get_mut(i, k) -= p;
+                            *z.get_mut(i, k + 1) -= p * q;
+                        }
+                    }
                 }
             }
+        }
 
-            // Determine the Givens rotation P with P [x; y]T = αe1 ;
-            let (c, s): (T, T) = Matrix::givens_cosine_sine_pair(x, y);
-            let g: Matrix<T> = Matrix::givens(2, 0, 1, c, s);
+        return Ok(());
+    }
 
+    /// Computes the eigenvalues and eigenvectors of a real symmetric matrix
+    ///
+    /// The matrix is reduced to tridiagonal form with Householder
+    /// reflections, afterwards the eigenvalues of the tridiagonal matrix are
+    /// computed with the implicit QL algorithm. The eigenvalues are sorted in
+    /// ascending order, the eigenvectors are the columns of an orthogonal
+    /// matrix.
+    ///
+    /// Only the lower triangular part of the matrix is referenced.
+    ///
+    /// # Return
+    ///
+    /// MathError::NotFinite if an entry of the matrix is infinite or NaN
+    ///
+    /// # Panics
+    ///
+    /// If the matrix is not square or empty
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[macro_use]
+    /// # extern crate mathru;
+    /// # fn main()
+    /// # {
+    /// use mathru::algebra::linear::{matrix::EigenDec, Matrix, Vector};
+    ///
+    /// let a: Matrix<f64> = matrix![   2.0, 1.0;
+    ///                                 1.0, 2.0];
+    ///
+    /// let (value, vector): (Vector<f64>, Matrix<f64>) = a.dec_eigen_symmetric().unwrap().pair();
+    ///
+    /// assert_relative_eq!(vector![1.0; 3.0], value, epsilon=1.0e-10);
+    ///
+    /// let x: Vector<f64> = vector.get_column(1);
+    /// assert_relative_eq!(&a * &x, &x * &3.0, epsilon=1.0e-10);
+    /// # }
+    /// ```
+    pub fn dec_eigen_symmetric(self: &Self) -> Result<EigenDec<T>, MathError>
+    {
+        let (m, n): (usize, usize) = self.dim();
+        assert_eq!(m, n, "Unable to compute the eigen value of a non-square matrix");
+        assert_ne!(m, 0, "Unable to compute the eigen value of an empty matrix.");
+
+        if !self.is_finite()
+        {
+            return Err(MathError::NotFinite);
+        }
+
+        // mirror the lower triangular part
+        let mut v: Matrix<T> = self.clone();
+        for j in 0..n
+        {
+            for i in 0..j
             {
-                let temp: Matrix<T> = &g * &self.get_slice(q - 1, p - 1, p - 3, n - 1);
-                self = self.set_slice(&temp, q - 1, p - 3);
+                *v.get_mut(i, j) = *self.get(j, i);
             }
+        }
+
+        let mut d: Vec<T> = vec![T::zero(); n];
+        let mut e: Vec<T> = vec![T::zero(); n];
+
+        Matrix::tridiagonalize_symmetric(&mut v, &mut d, &mut e);
+        Matrix::ql_implicit(&mut v, &mut d, &mut e)?;
+
+        // sort eigenvalues and eigenvectors in ascending order
+        let mut order: Vec<usize> = (0..n).collect();
+        order.sort_by(|a, b| d[*a].partial_cmp(&d[*b]).unwrap());
 
+        let mut value: Vector<T> = Vector::zero(n);
+        let mut vector: Matrix<T> = Matrix::zero(n, n);
+        for (c, k) in order.iter().enumerate()
+        {
+            *value.get_mut(c) = d[*k];
+            for i in 0..n
             {
-                let g_trans: Matrix<T> = g.transpose();
-                let temp: Matrix<T> = &self.get_slice(0, p - 1, p - 2, p - 1) * &g_trans;
-                self = self.set_slice(&temp, 0, p - 2);
+                *vector.get_mut(i, c) = *v.get(i, *k);
+            }
+        }
+
+        return Ok(EigenDec::new(value, vector));
+    }
+
+    /// Householder reduction of the symmetric matrix v to tridiagonal form
+    ///
+    /// On return d contains the diagonal and e[1..n] the subdiagonal of the
+    /// tridiagonal matrix, v contains the accumulated orthogonal
+    /// transformation.
+    fn tridiagonalize_symmetric(v: &mut Matrix<T>, d: &mut [T], e: &mut [T])
+    {
+        let (n, _): (usize, usize) = v.dim();
+
+        for j in 0..n
+        {
+            d[j] = *v.get(n - 1, j);
+        }
 
-                let u_slice = &self.get_slice(0, n - 1, p - 2, p - 1) * &g_trans;
-                u = u.set_slice(&u_slice, 0, p - 2);
+        for i in (1..n).rev()
+        {
+            let mut scale: T = T::zero();
+            let mut h: T = T::zero();
+            for k in 0..i
+            {
+                scale += d[k].abs();
             }
 
-            // check for convergence
-            let m: T = self.get(q - 1, q - 1).abs();
-            let n: T = self.get(p - 1, p - 1).abs();
-            if self.get(p - 1, q - 1).abs() < epsilon * (m + n)
+            if scale == T::zero()
             {
-                *self.get_mut(p - 1, q - 1) = T::zero();
-                p = p - 1;
+                e[i] = d[i - 1];
+                for j in 0..i
+                {
+                    d[j] = *v.get(i - 1, j);
+                    *v.get_mut(i, j) = T::zero();
+                    *v.get_mut(j, i) = T::zero();
+                }
             }
             else
             {
-                let k: T = self.get(q - 2, q - 2).abs();
-                let l: T = self.get(q - 1, q - 1).abs();
-                if self.get(p - 2, q - 2).abs() < epsilon * (k + l)
+                // generate the Householder vector
+                for k in 0..i
+                {
+                    d[k] /= scale;
+                    h += d[k] * d[k];
+                }
+                let mut f: T = d[i - 1];
+                let mut g: T = h.sqrt();
+                if f > T::zero()
+                {
+                    g = -g;
+                }
+                e[i] = scale * g;
+                h -= f * g;
+                d[i - 1] = f - g;
+                for j in 0..i
+                {
+                    e[j] = T::zero();
+                }
+
+                // apply the similarity transformation to the remaining columns
+                for j in 0..i
                 {
-                    *self.get_mut(p - 2, q - 2) = T::zero();
-                    p = p - 2;
+                    f = d[j];
+                    *v.get_mut(j, i) = f;
+                    g = e[j] + *v.get(j, j) * f;
+                    for k in (j + 1)..i
+                    {
+                        g += *v.get(k, j) * d[k];
+                        e[k] += *v.get(k, j) * f;
+                    }
+                    e[j] = g;
+                }
+                f = T::zero();
+                for j in 0..i
+                {
+                    e[j] /= h;
+                    f += e[j] * d[j];
+                }
+                let hh: T = f / (h + h);
+                for j in 0..i
+                {
+                    e[j] -= hh * d[j];
+                }
+                for j in 0..i
+                {
+                    f = d[j];
+                    g = e[j];
+                    for k in j..i
+                    {
+                        *v.get_mut(k, j) -= f * e[k] + g * d[k];
+                    }
+                    d[j] = *v.get(i - 1, j);
+                    *v.get_mut(i, j) = T::zero();
                 }
             }
-            p = p - 1;
+            d[i] = h;
         }
 
-        return (u, self);
+        // accumulate the transformations
+        for i in 0..(n - 1)
+        {
+            *v.get_mut(n - 1, i) = *v.get(i, i);
+            *v.get_mut(i, i) = T::one();
+            let h: T = d[i + 1];
+            if h != T::zero()
+            {
+                for k in 0..=i
+                {
+                    d[k] = *v.get(k, i + 1) / h;
+                }
+                for j in 0..=i
+                {
+                    let mut g: T = T::zero();
+                    for k in 0..=i
+                    {
+                        g += *v.get(k, i + 1) * *v.get(k, j);
+                    }
+                    for k in 0..=i
+                    {
+                        *v.get_mut(k, j) -= g * d[k];
+                    }
+                }
+            }
+            for k in 0..=i
+            {
+                *v.get_mut(k, i + 1) = T::zero();
+            }
+        }
+        for j in 0..n
+        {
+            d[j] = *v.get(n - 1, j);
+            *v.get_mut(n - 1, j) = T::zero();
+        }
+        *v.get_mut(n - 1, n - 1) = T::one();
+        e[0] = T::zero();
     }
 
-    pub fn eigenvector_r(self: &Self, value: &Vector<T>) -> Matrix<T>
+    /// Implicit QL algorithm for a symmetric tridiagonal matrix
+    ///
+    /// On return d contains the eigenvalues and v the eigenvectors.
+    fn ql_implicit(v: &mut Matrix<T>, d: &mut [T], e: &mut [T]) -> Result<(), MathError>
     {
-        let eye: Matrix<T> = Matrix::one(self.m);
-        let zero_vector: Vector<T> = Vector::zero(self.m);
-        let mut vectors: Matrix<T> = Matrix::zero(self.m, self.m);
+        let (n, _): (usize, usize) = v.dim();
+        let max_iter: usize = 30 * n;
 
-        for (c, val) in value.iter().enumerate()
+        for i in 1..n
         {
-            let diff: Matrix<T> = self - &(&eye * val);
-            let vec: Vector<T> = diff.solve(&zero_vector).unwrap();
-            vectors.set_column(&vec, c);
+            e[i - 1] = e[i];
+        }
+        e[n - 1] = T::zero();
+
+        let epsilon: T = T::default_epsilon();
+        let mut f: T = T::zero();
+        let mut tst1: T = T::zero();
+
+        for l in 0..n
+        {
+            // find a small subdiagonal element
+            tst1 = tst1.max(d[l].abs() + e[l].abs());
+            let mut m: usize = l;
+            while m < n - 1
+            {
+                if e[m].abs() <= epsilon * tst1
+                {
+                    break;
+                }
+                m += 1;
+            }
+
+            if m > l
+            {
+                let mut iter: usize = 0;
+                loop
+                {
+                    iter += 1;
+                    if iter > max_iter
+                    {
+                        return Err(MathError::NoConvergence { iterations: max_iter });
+                    }
+
+                    // compute the implicit shift
+                    let mut g: T = d[l];
+                    let mut p: T = (d[l + 1] - g) / (T::from_f64(2.0) * e[l]);
+                    let mut r: T = Matrix::hypot(p, T::one());
+                    if p < T::zero()
+                    {
+                        r = -r;
+                    }
+                    d[l] = e[l] / (p + r);
+                    d[l + 1] = e[l] * (p + r);
+                    let dl1: T = d[l + 1];
+                    let mut h: T = g - d[l];
+                    for i in (l + 2)..n
+                    {
+                        d[i] -= h;
+                    }
+                    f += h;
+
+                    // implicit QL transformation
+                    p = d[m];
+                    let mut c: T = T::one();
+                    let mut c2: T = c;
+                    let mut c3: T = c;
+                    let el1: T = e[l + 1];
+                    let mut s: T = T::zero();
+                    let mut s2: T = T::zero();
+                    for i in (l..m).rev()
+                    {
+                        c3 = c2;
+                        c2 = c;
+                        s2 = s;
+                        g = c * e[i];
+                        h = c * p;
+                        r = Matrix::hypot(p, e[i]);
+                        e[i + 1] = s * r;
+                        s = e[i] / r;
+                        c = p / r;
+                        p = c * d[i] - s * g;
+                        d[i + 1] = h + s * (c * g + s * d[i]);
+
+                        // accumulate the transformation
+                        for k in 0..n
+                        {
+                            let v_ki: T = *v.get(k, i);
+                            let v_ki1: T = *v.get(k, i + 1);
+                            *v.get_mut(k, i + 1) = s * v_ki + c * v_ki1;
+                            *v.get_mut(k, i) = c * v_ki - s * v_ki1;
+                        }
+                    }
+                    p = -s * s2 * c3 * el1 * e[l] / dl1;
+                    e[l] = s * p;
+                    d[l] = c * p;
+
+                    if e[l].abs() <= epsilon * tst1
+                    {
+                        break;
+                    }
+                }
+            }
+            d[l] += f;
+            e[l] = T::zero();
         }
 
-        return vectors;
+        return Ok(());
+    }
+
+    /// sqrt(a² + b²) without overflow or underflow
+    fn hypot(a: T, b: T) -> T
+    {
+        let a_abs: T = a.abs();
+        let b_abs: T = b.abs();
+        let (max, min): (T, T) = if a_abs > b_abs { (a_abs, b_abs) } else { (b_abs, a_abs) };
+        if max == T::zero()
+        {
+            return T::zero();
+        }
+        let ratio: T = min / max;
+        return max * (T::one() + ratio * ratio).sqrt();
     }
 }
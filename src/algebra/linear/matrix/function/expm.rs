@@ -0,0 +1,165 @@
+use crate::{
+    algebra::{
+        abstr::Real,
+        linear::{matrix::Solve, Matrix},
+    },
+    error::MathError,
+};
+
+const B_3: [f64; 4] = [120.0, 60.0, 12.0, 1.0];
+const B_5: [f64; 6] = [30240.0, 15120.0, 3360.0, 420.0, 30.0, 1.0];
+const B_7: [f64; 8] = [17297280.0, 8648640.0, 1995840.0, 277200.0, 25200.0, 1512.0, 56.0, 1.0];
+const B_9: [f64; 10] = [17643225600.0,
+                        8821612800.0,
+                        2075673600.0,
+                        302702400.0,
+                        30270240.0,
+                        2162160.0,
+                        110880.0,
+                        3960.0,
+                        90.0,
+                        1.0];
+const B_13: [f64; 14] = [64764752532480000.0,
+                         32382376266240000.0,
+                         7771770303897600.0,
+                         1187353796428800.0,
+                         129060195264000.0,
+                         10559470521600.0,
+                         670442572800.0,
+                         33522128640.0,
+                         1323241920.0,
+                         40840800.0,
+                         960960.0,
+                         16380.0,
+                         182.0,
+                         1.0];
+
+/// Maximal 1-norm of the matrix for which the Padé approximant of degree 3, 5,
+/// 7 and 9 reaches double precision accuracy
+const PADE: [(f64, &[f64]); 4] = [(1.495585217958292e-2, &B_3),
+                                  (2.53939833006323e-1, &B_5),
+                                  (9.504178996162932e-1, &B_7),
+                                  (2.097847961257068e0, &B_9)];
+
+const THETA_13: f64 = 5.371920351148152e0;
+
+impl<T> Matrix<T> where T: Real
+{
+    /// Computes the matrix exponential
+    ///
+    /// ```math
+    /// e^A = \sum_{k=0}^{\infty} \frac{1}{k!} A^k
+    /// ```
+    ///
+    /// The exponential is computed with the scaling and squaring method and a
+    /// Padé approximant of degree 3, 5, 7, 9 or 13 (Higham 2005).
+    ///
+    /// # Return
+    ///
+    /// MathError::NotFinite if an entry of the matrix is infinite or NaN
+    ///
+    /// # Panics
+    ///
+    /// If the matrix is not square
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[macro_use]
+    /// # extern crate mathru;
+    /// # fn main()
+    /// # {
+    /// use mathru::algebra::linear::Matrix;
+    ///
+    /// let a: Matrix<f64> = matrix![   0.0, 1.0;
+    ///                                 0.0, 0.0];
+    ///
+    /// let exp_a: Matrix<f64> = a.expm().unwrap();
+    ///
+    /// assert_relative_eq!(matrix![1.0, 1.0; 0.0, 1.0], exp_a, epsilon=1.0e-10);
+    /// # }
+    /// ```
+    pub fn expm(self: &Self) -> Result<Matrix<T>, MathError>
+    {
+        let (m, n): (usize, usize) = self.dim();
+        assert_eq!(m, n, "Unable to compute the exponential of a non-square matrix");
+
+        if !self.is_finite()
+        {
+            return Err(MathError::NotFinite);
+        }
+
+        let norm: T = self.norm_1();
+
+        for (theta, b) in PADE.iter()
+        {
+            if norm <= T::from_f64(*theta)
+            {
+                return Matrix::pade(self, b);
+            }
+        }
+
+        // scale the matrix such that its norm is smaller than theta_13
+        let theta_13: T = T::from_f64(THETA_13);
+        let mut s: u32 = 0;
+        let mut norm_scaled: T = norm;
+        while norm_scaled > theta_13
+        {
+            norm_scaled /= T::from_f64(2.0);
+            s += 1;
+        }
+        let a: Matrix<T> = self / &T::from_f64(2.0f64.powi(s as i32));
+
+        let mut r: Matrix<T> = Matrix::pade_13(&a)?;
+        for _k in 0..s
+        {
+            r = &r * &r;
+        }
+
+        return Ok(r);
+    }
+
+    /// Diagonal Padé approximant of degree 3, 5, 7 or 9
+    ///
+    /// U = A Σ b_{2k+1} A^{2k}, V = Σ b_{2k} A^{2k}, r(A) = (V - U)^-1 (V + U)
+    fn pade(a: &Matrix<T>, b: &[f64]) -> Result<Matrix<T>, MathError>
+    {
+        let (n, _): (usize, usize) = a.dim();
+        let a_2: Matrix<T> = a * a;
+
+        let mut a_2k: Matrix<T> = Matrix::one(n);
+        let mut u: Matrix<T> = &a_2k * &T::from_f64(b[1]);
+        let mut v: Matrix<T> = &a_2k * &T::from_f64(b[0]);
+        for k in 1..(b.len() / 2)
+        {
+            a_2k = &a_2k * &a_2;
+            u = &u + &(&a_2k * &T::from_f64(b[2 * k + 1]));
+            v = &v + &(&a_2k * &T::from_f64(b[2 * k]));
+        }
+        u = a * &u;
+
+        return (&v - &u).solve(&(&v + &u));
+    }
+
+    /// Diagonal Padé approximant of degree 13
+    fn pade_13(a: &Matrix<T>) -> Result<Matrix<T>, MathError>
+    {
+        let (n, _): (usize, usize) = a.dim();
+        let b: Vec<T> = B_13.iter().map(|b_k| T::from_f64(*b_k)).collect();
+
+        let ident: Matrix<T> = Matrix::one(n);
+        let a_2: Matrix<T> = a * a;
+        let a_4: Matrix<T> = &a_2 * &a_2;
+        let a_6: Matrix<T> = &a_2 * &a_4;
+
+        let u_1: Matrix<T> = &(&(&a_6 * &b[13]) + &(&a_4 * &b[11])) + &(&a_2 * &b[9]);
+        let u_2: Matrix<T> = &(&(&(&a_6 * &b[7]) + &(&a_4 * &b[5])) + &(&a_2 * &b[3])) + &(&ident * &b[1]);
+        let u: Matrix<T> = a * &(&(&a_6 * &u_1) + &u_2);
+
+        let v_1: Matrix<T> = &(&(&a_6 * &b[12]) + &(&a_4 * &b[10])) + &(&a_2 * &b[8]);
+        let v_2: Matrix<T> = &(&(&(&a_6 * &b[6]) + &(&a_4 * &b[4])) + &(&a_2 * &b[2])) + &(&ident * &b[0]);
+        let v: Matrix<T> = &(&a_6 * &v_1) + &v_2;
+
+        return (&v - &u).solve(&(&v + &u));
+    }
+}
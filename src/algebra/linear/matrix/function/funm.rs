@@ -0,0 +1,101 @@
+use super::triangular::back_transform;
+use crate::{
+    algebra::{
+        abstr::{Complex, Real, Scalar, Zero},
+        linear::{
+            matrix::schur::complex::{abs, schur},
+            Matrix,
+        },
+    },
+    error::MathError,
+};
+
+impl<T> Matrix<T> where T: Real, Complex<T>: Scalar
+{
+    /// Computes the matrix function f(A) of an analytic scalar function f
+    ///
+    /// The function is evaluated at the eigenvalues of the triangular factor
+    /// of the complex Schur decomposition, the remaining entries are computed
+    /// with Parlett's recurrence.
+    ///
+    /// # Arguments
+    ///
+    /// * 'f': Analytic function, which satisfies f(conj(z)) = conj(f(z)) on the
+    /// spectrum of A, such that f(A) is real.
+    ///
+    /// # Return
+    ///
+    /// An error is returned, if f(A) is not real or if the matrix has repeated
+    /// eigenvalues, which are coupled in the Schur form (e.g. a Jordan block).
+    /// Repeated eigenvalues of normal matrices are supported.
+    ///
+    /// MathError::DimensionMismatch is returned, if the matrix is not square or
+    /// empty.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[macro_use]
+    /// # extern crate mathru;
+    /// # fn main()
+    /// # {
+    /// use mathru::algebra::{abstr::Complex, linear::Matrix};
+    /// use mathru::elementary::Trigonometry;
+    ///
+    /// let a: Matrix<f64> = matrix![   1.0, 2.0;
+    ///                                 0.0, 3.0];
+    ///
+    /// let cos_a: Matrix<f64> = a.funm(|z: Complex<f64>| z.cos()).unwrap();
+    /// let cos_a_ref: Matrix<f64> = matrix![   1.0f64.cos(), 3.0f64.cos() - 1.0f64.cos();
+    ///                                         0.0, 3.0f64.cos()];
+    ///
+    /// assert_relative_eq!(cos_a_ref, cos_a, epsilon=1.0e-10);
+    /// # }
+    /// ```
+    pub fn funm<F>(self: &Self, f: F) -> Result<Matrix<T>, MathError>
+        where F: Fn(Complex<T>) -> Complex<T>
+    {
+        let (z, t): (Matrix<Complex<T>>, Matrix<Complex<T>>) = schur(self)?;
+        let (n, _): (usize, usize) = t.dim();
+
+        let mut f_t: Matrix<Complex<T>> = Matrix::zero(n, n);
+        let mut t_max: T = T::one();
+        let mut f_max: T = T::one();
+        for i in 0..n
+        {
+            let f_ii: Complex<T> = f(*t.get(i, i));
+            *f_t.get_mut(i, i) = f_ii;
+            t_max = t_max.max(abs(t.get(i, i)));
+            f_max = f_max.max(abs(&f_ii));
+        }
+
+        let tol: T = T::default_epsilon().sqrt();
+        for p in 1..n
+        {
+            for i in 0..(n - p)
+            {
+                let j: usize = i + p;
+                let mut sum: Complex<T> = *t.get(i, j) * (*f_t.get(j, j) - *f_t.get(i, i));
+                for k in (i + 1)..j
+                {
+                    sum += *t.get(i, k) * *f_t.get(k, j) - *f_t.get(i, k) * *t.get(k, j);
+                }
+
+                let d: Complex<T> = *t.get(j, j) - *t.get(i, i);
+                if abs(&d) <= tol * t_max
+                {
+                    // equal eigenvalues, which are not coupled
+                    if abs(t.get(i, j)) <= tol * t_max && abs(&sum) <= tol * t_max * f_max
+                    {
+                        *f_t.get_mut(i, j) = Complex::zero();
+                        continue;
+                    }
+                    return Err(MathError::Singular { pivot: j });
+                }
+                *f_t.get_mut(i, j) = sum / d;
+            }
+        }
+
+        return back_transform(&z, &f_t);
+    }
+}
@@ -0,0 +1,106 @@
+use super::{
+    sqrtm::sqrtm_triangular,
+    triangular::{back_transform, solve_upper},
+};
+use crate::{
+    algebra::{
+        abstr::{Complex, Real, Scalar, Zero},
+        linear::{
+            matrix::schur::complex::{norm_1, schur},
+            Matrix,
+        },
+    },
+    error::MathError,
+};
+
+/// Nodes and weights of the 8 point Gauss-Legendre quadrature on [-1, 1]
+const GAUSS_LEGENDRE: [(f64, f64); 8] = [(-0.9602898564975363, 0.1012285362903763),
+                                         (-0.7966664774136267, 0.2223810344533745),
+                                         (-0.525532409916329, 0.3137066458778873),
+                                         (-0.1834346424956498, 0.362683783378362),
+                                         (0.1834346424956498, 0.362683783378362),
+                                         (0.525532409916329, 0.3137066458778873),
+                                         (0.7966664774136267, 0.2223810344533745),
+                                         (0.9602898564975363, 0.1012285362903763)];
+
+impl<T> Matrix<T> where T: Real, Complex<T>: Scalar
+{
+    /// Computes the principal logarithm of the matrix
+    ///
+    /// X = log(A), e^X = A
+    ///
+    /// The logarithm is computed with the inverse scaling and squaring method
+    /// on the triangular factor of the Schur decomposition. Square roots are
+    /// taken until the factor is close to the identity, then the logarithm is
+    /// approximated by the diagonal Padé approximant of degree 8 in partial
+    /// fraction form.
+    ///
+    /// # Return
+    ///
+    /// An error is returned, if the matrix is singular or if the principal
+    /// logarithm is not real, i.e. the matrix has eigenvalues on the negative
+    /// real axis.
+    ///
+    /// MathError::DimensionMismatch is returned, if the matrix is not square or
+    /// empty.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[macro_use]
+    /// # extern crate mathru;
+    /// # fn main()
+    /// # {
+    /// use mathru::algebra::linear::Matrix;
+    ///
+    /// let a: Matrix<f64> = matrix![   1.0, 2.0;
+    ///                                 0.0, 3.0];
+    ///
+    /// let x: Matrix<f64> = a.logm().unwrap();
+    ///
+    /// assert_relative_eq!(a, x.expm().unwrap(), epsilon=1.0e-10);
+    /// # }
+    /// ```
+    pub fn logm(self: &Self) -> Result<Matrix<T>, MathError>
+    {
+        let (z, mut t): (Matrix<Complex<T>>, Matrix<Complex<T>>) = schur(self)?;
+        let (n, _): (usize, usize) = t.dim();
+
+        for i in 0..n
+        {
+            if *t.get(i, i) == Complex::zero()
+            {
+                return Err(MathError::Singular { pivot: i });
+            }
+        }
+
+        let ident: Matrix<Complex<T>> = Matrix::one(n);
+        let max_sqrt: usize = 64;
+        let mut s: usize = 0;
+        while norm_1(&(&t - &ident)) > T::from_f64(0.25)
+        {
+            if s == max_sqrt
+            {
+                return Err(MathError::NoConvergence { iterations: max_sqrt });
+            }
+            t = sqrtm_triangular(&t)?;
+            s += 1;
+        }
+
+        // log(I + X) = sum w_j X (I + x_j X)^-1
+        let x: Matrix<Complex<T>> = &t - &ident;
+        let mut log: Matrix<Complex<T>> = Matrix::zero(n, n);
+        for (node, weight) in GAUSS_LEGENDRE.iter()
+        {
+            let x_j: Complex<T> = Complex::new(T::from_f64((node + 1.0) / 2.0), T::zero());
+            let w_j: Complex<T> = Complex::new(T::from_f64(weight / 2.0), T::zero());
+            let y: Matrix<Complex<T>> = solve_upper(&(&ident + &(&x * &x_j)), &x)?;
+            log = &log + &(&y * &w_j);
+        }
+
+        let scale: Complex<T> = Complex::new(T::from_f64(2.0f64.powi(s as i32)), T::zero());
+        log = &log * &scale;
+
+        return back_transform(&z, &log);
+    }
+}
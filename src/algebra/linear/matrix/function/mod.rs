@@ -0,0 +1,16 @@
+//! Matrix functions
+//!
+//! * [`Matrix::expm`] matrix exponential
+//! * [`Matrix::logm`] principal matrix logarithm
+//! * [`Matrix::sqrtm`] principal matrix square root
+//! * [`Matrix::powm`] real matrix power
+//! * [`Matrix::funm`] matrix function of an analytic scalar function
+//!
+//! Fore more information: <br>
+//! <a href="https://en.wikipedia.org/wiki/Matrix_function">https://en.wikipedia.org/wiki/Matrix_function</a>
+mod expm;
+mod funm;
+mod logm;
+mod powm;
+mod sqrtm;
+mod triangular;
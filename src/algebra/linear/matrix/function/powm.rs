@@ -0,0 +1,74 @@
+use crate::{
+    algebra::{
+        abstr::{Complex, Real, Scalar},
+        linear::{matrix::Inverse, Matrix},
+    },
+    error::MathError,
+};
+
+impl<T> Matrix<T> where T: Real, Complex<T>: Scalar
+{
+    /// Computes the real power of the matrix
+    ///
+    /// Integer powers are computed by repeated squaring, negative integer
+    /// powers are powers of the inverse. For any other exponent the power is
+    /// computed with the principal logarithm:
+    ///
+    /// A^p = e^(p log(A))
+    ///
+    /// # Return
+    ///
+    /// An error is returned, if the exponent is negative and the matrix is
+    /// singular, or if the exponent is not an integer and the principal
+    /// logarithm does not exist or is not real.
+    ///
+    /// # Panics
+    ///
+    /// If the matrix is not square
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[macro_use]
+    /// # extern crate mathru;
+    /// # fn main()
+    /// # {
+    /// use mathru::algebra::linear::Matrix;
+    ///
+    /// let a: Matrix<f64> = matrix![   4.0, 1.0;
+    ///                                 0.0, 9.0];
+    ///
+    /// assert_relative_eq!(&a * &a, a.powm(2.0).unwrap(), epsilon=1.0e-10);
+    /// assert_relative_eq!(a.sqrtm().unwrap(), a.powm(0.5).unwrap(), epsilon=1.0e-10);
+    /// # }
+    /// ```
+    pub fn powm(self: &Self, p: T) -> Result<Matrix<T>, MathError>
+    {
+        let (m, n): (usize, usize) = self.dim();
+        assert_eq!(m, n, "Unable to compute the power of a non-square matrix");
+
+        if p != p.floor()
+        {
+            return (&self.logm()? * &p).expm();
+        }
+
+        let mut base: Matrix<T> = if p < T::zero() { self.inv()? } else { self.clone() };
+        let mut exponent: u64 = p.abs().to_u64();
+        let mut power: Matrix<T> = Matrix::one(n);
+
+        while exponent > 0
+        {
+            if exponent % 2 == 1
+            {
+                power = &power * &base;
+            }
+            exponent /= 2;
+            if exponent > 0
+            {
+                base = &base * &base;
+            }
+        }
+
+        return Ok(power);
+    }
+}
@@ -0,0 +1,88 @@
+use super::triangular::back_transform;
+use crate::{
+    algebra::{
+        abstr::{Complex, Real, Scalar, Zero},
+        linear::{matrix::schur::complex::schur, Matrix},
+    },
+    elementary::Power,
+    error::MathError,
+};
+
+impl<T> Matrix<T> where T: Real, Complex<T>: Scalar
+{
+    /// Computes the principal square root of the matrix
+    ///
+    /// X = A^(1/2), X X = A
+    ///
+    /// The square root of the triangular factor of the Schur decomposition is
+    /// computed with the recurrence of Björck and Hammarling.
+    ///
+    /// # Return
+    ///
+    /// An error is returned, if the principal square root is not real, i.e.
+    /// the matrix has eigenvalues on the negative real axis, or if it does not
+    /// exist.
+    ///
+    /// MathError::DimensionMismatch is returned, if the matrix is not square or
+    /// empty.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[macro_use]
+    /// # extern crate mathru;
+    /// # fn main()
+    /// # {
+    /// use mathru::algebra::linear::Matrix;
+    ///
+    /// let a: Matrix<f64> = matrix![   4.0, 1.0;
+    ///                                 0.0, 9.0];
+    ///
+    /// let x: Matrix<f64> = a.sqrtm().unwrap();
+    ///
+    /// assert_relative_eq!(a, &x * &x, epsilon=1.0e-10);
+    /// # }
+    /// ```
+    pub fn sqrtm(self: &Self) -> Result<Matrix<T>, MathError>
+    {
+        let (z, t): (Matrix<Complex<T>>, Matrix<Complex<T>>) = schur(self)?;
+        let r: Matrix<Complex<T>> = sqrtm_triangular(&t)?;
+
+        return back_transform(&z, &r);
+    }
+}
+
+/// Principal square root of an upper triangular matrix
+pub(super) fn sqrtm_triangular<T>(t: &Matrix<Complex<T>>) -> Result<Matrix<Complex<T>>, MathError>
+    where T: Real,
+          Complex<T>: Scalar
+{
+    let (n, _): (usize, usize) = t.dim();
+    let mut r: Matrix<Complex<T>> = Matrix::zero(n, n);
+
+    for j in 0..n
+    {
+        *r.get_mut(j, j) = t.get(j, j).sqrt();
+        for i in (0..j).rev()
+        {
+            let mut sum: Complex<T> = *t.get(i, j);
+            for k in (i + 1)..j
+            {
+                sum -= *r.get(i, k) * *r.get(k, j);
+            }
+
+            let denominator: Complex<T> = *r.get(i, i) + *r.get(j, j);
+            if denominator == Complex::zero()
+            {
+                if sum == Complex::zero()
+                {
+                    continue;
+                }
+                return Err(MathError::Singular { pivot: j });
+            }
+            *r.get_mut(i, j) = sum / denominator;
+        }
+    }
+
+    return Ok(r);
+}
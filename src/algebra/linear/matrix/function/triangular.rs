@@ -0,0 +1,76 @@
+//! Back transformation and triangular solves of the Schur based matrix
+//! functions
+use crate::{
+    algebra::{
+        abstr::{Complex, Real, Scalar, Zero},
+        linear::Matrix,
+    },
+    error::MathError,
+};
+
+/// Computes Z T Z^H and returns its real part
+///
+/// Returns an error, if the imaginary part is not negligible. This is the case,
+/// if the matrix function of a real matrix is not real, e.g. the square root of
+/// a matrix with negative eigenvalues.
+pub(super) fn back_transform<T>(z: &Matrix<Complex<T>>, t: &Matrix<Complex<T>>) -> Result<Matrix<T>, MathError>
+    where T: Real,
+          Complex<T>: Scalar
+{
+    let (n, _): (usize, usize) = z.dim();
+    let zt: Matrix<Complex<T>> = z * t;
+
+    let mut real: Matrix<T> = Matrix::zero(n, n);
+    let mut max_re: T = T::zero();
+    let mut max_im: T = T::zero();
+    for j in 0..n
+    {
+        for i in 0..n
+        {
+            let mut sum: Complex<T> = Complex::zero();
+            for k in 0..n
+            {
+                sum += *zt.get(i, k) * z.get(j, k).conj();
+            }
+            *real.get_mut(i, j) = sum.re;
+            max_re = max_re.max(sum.re.abs());
+            max_im = max_im.max(sum.im.abs());
+        }
+    }
+
+    let tol: T = T::default_epsilon().sqrt() * max_re.max(T::one());
+    if max_im > tol
+    {
+        return Err(MathError::NotReal);
+    }
+
+    return Ok(real);
+}
+
+/// Solves the upper triangular system T X = B
+pub(super) fn solve_upper<T>(t: &Matrix<Complex<T>>, b: &Matrix<Complex<T>>) -> Result<Matrix<Complex<T>>, MathError>
+    where T: Real,
+          Complex<T>: Scalar
+{
+    let (n, b_n): (usize, usize) = b.dim();
+    let mut x: Matrix<Complex<T>> = b.clone();
+    for j in 0..b_n
+    {
+        for i in (0..n).rev()
+        {
+            let t_ii: Complex<T> = *t.get(i, i);
+            if t_ii == Complex::zero()
+            {
+                return Err(MathError::Singular { pivot: i });
+            }
+            let mut sum: Complex<T> = *x.get(i, j);
+            for k in (i + 1)..n
+            {
+                sum -= *t.get(i, k) * *x.get(k, j);
+            }
+            *x.get_mut(i, j) = sum / t_ii;
+        }
+    }
+
+    return Ok(x);
+}
@@ -51,6 +51,7 @@ impl<T> Matrix<T> where T: Field + Scalar + Power
             h = &h.clone() * &househ;
         }
 
-        return HessenbergDec::new(q, h);
+        // h = q * self * q^T
+        return HessenbergDec::new(q.transpose(), h);
     }
 }
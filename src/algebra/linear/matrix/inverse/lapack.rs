@@ -1,7 +1,10 @@
-use crate::algebra::{
-    abstr::{Field, Scalar, Zero},
-    linear::Matrix,
-    linear::matrix::{Inverse, Transpose}
+use crate::{
+    algebra::{
+        abstr::{Field, Scalar, Zero},
+        linear::Matrix,
+        linear::matrix::{Inverse, Transpose}
+    },
+    error::MathError,
 };
 
 impl<T> Inverse<T> for Matrix<T> where T: Field + Scalar
@@ -16,7 +19,7 @@ impl<T> Inverse<T> for Matrix<T> where T: Field + Scalar
     /// let a: Matrix<f64> = Matrix::new(2, 2, vec![1.0, 0.0, 3.0, -7.0]);
     /// let b_inv: Matrix<f64> = a.inv().unwrap();
     /// ```
-    fn inv(self: &Self) -> Result<Matrix<T>, ()>
+    fn inv(self: &Self) -> Result<Matrix<T>, MathError>
     {
         return self.inv_r();
     }
@@ -24,7 +27,7 @@ impl<T> Inverse<T> for Matrix<T> where T: Field + Scalar
 
 impl<T> Matrix<T> where T: Field + Scalar
 {
-    pub fn inv_r(self: &Self) -> Result<Matrix<T>, ()>
+    pub fn inv_r(self: &Self) -> Result<Matrix<T>, MathError>
     {
         let (m, n): (usize, usize) = self.dim();
         let m_i32: i32 = m as i32;
@@ -44,9 +47,14 @@ impl<T> Matrix<T> where T: Field + Scalar
                   ipiv.as_mut_slice(),
                   &mut info);
 
+        if info > 0
+        {
+            return Err(MathError::Singular { pivot: (info - 1) as usize });
+        }
+
         if info != 0
         {
-            return Err(());
+            return Err(MathError::LapackInfo(info));
         }
 
         let lwork: i32 =
@@ -54,7 +62,7 @@ impl<T> Matrix<T> where T: Field + Scalar
 
         if info != 0
         {
-            return Err(());
+            return Err(MathError::LapackInfo(info));
         }
 
         let mut work: Vec<T> = vec![T::zero(); lwork as usize];
@@ -69,7 +77,7 @@ impl<T> Matrix<T> where T: Field + Scalar
 
         if info != 0
         {
-            return Err(());
+            return Err(MathError::LapackInfo(info));
         }
 
         let self_inv: Matrix<T> = Matrix::new(n, m, self_data);
@@ -3,7 +3,10 @@ pub mod lapack;
 #[cfg(feature = "native")]
 pub mod native;
 
-use crate::algebra::linear::Matrix;
+use crate::{
+    algebra::linear::Matrix,
+    error::MathError,
+};
 
 pub trait Inverse<T>
 {
@@ -17,5 +20,5 @@ pub trait Inverse<T>
     /// let a: Matrix<f64> = Matrix::new(2, 2, vec![1.0, 0.0, 3.0, -7.0]);
     /// let b_inv: Matrix<f64> = a.inv().unwrap();
     /// ```
-    fn inv(self: &Self) -> Result<Matrix<T>, ()>;
+    fn inv(self: &Self) -> Result<Matrix<T>, MathError>;
 }
@@ -0,0 +1,128 @@
+use super::ldldec::permutation;
+use crate::{
+    algebra::{
+        abstr::Real,
+        linear::{matrix::LDLDec, Matrix},
+    },
+    error::MathError,
+};
+
+impl<T> Matrix<T> where T: Real
+{
+    /// Decomposes the symmetric matrix A into a unit lower triangular matrix
+    /// L, a block diagonal matrix D with 1 x 1 and 2 x 2 blocks and a
+    /// permutation matrix P
+    ///
+    /// P A P^T = L D L^T
+    ///
+    /// The pivots are chosen with the Bunch-Kaufman strategy. In contrast to
+    /// the Cholesky decomposition, A does not need to be positive definite.
+    /// Only the lower triangle of A is referenced.
+    ///
+    /// # Return
+    ///
+    /// MathError::DimensionMismatch if A is not a square matrix
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[macro_use]
+    /// # extern crate mathru;
+    /// # fn main()
+    /// # {
+    /// use mathru::algebra::linear::{matrix::Transpose, Matrix};
+    ///
+    /// let a: Matrix<f64> = matrix![   0.0, 1.0, 2.0;
+    ///                                 1.0, 0.0, 3.0;
+    ///                                 2.0, 3.0, 0.0];
+    ///
+    /// let (l, d, p): (Matrix<f64>, Matrix<f64>, Matrix<f64>) = a.dec_ldl().unwrap().ldp();
+    ///
+    /// assert_relative_eq!(&(&p * &a) * &p.transpose(), &(&l * &d) * &l.transpose(), epsilon=1.0e-10);
+    /// # }
+    /// ```
+    pub fn dec_ldl(self: &Self) -> Result<LDLDec<T>, MathError>
+    {
+        let (m, n): (usize, usize) = self.dim();
+        if m != n
+        {
+            return Err(MathError::DimensionMismatch);
+        }
+
+        let n_i32: i32 = n as i32;
+        let mut self_data: Vec<T> = self.clone().data;
+        let mut ipiv: Vec<i32> = vec![0; n];
+        let mut info: i32 = 0;
+
+        let lwork: i32 = T::xsytrf_work_size(b'L', n_i32, &mut self_data[..], n_i32, &mut ipiv[..], &mut info);
+
+        if info != 0
+        {
+            return Err(MathError::LapackInfo(info));
+        }
+
+        let mut work: Vec<T> = vec![T::zero(); lwork as usize];
+
+        T::xsytrf(b'L',
+                  n_i32,
+                  &mut self_data[..],
+                  n_i32,
+                  &mut ipiv[..],
+                  &mut work[..],
+                  lwork,
+                  &mut info);
+
+        // info > 0 indicates an exactly zero pivot in D, the factorization is
+        // complete nevertheless
+        if info < 0
+        {
+            return Err(MathError::LapackInfo(info));
+        }
+
+        let a: Matrix<T> = Matrix::new(n, n, self_data);
+        let mut l: Matrix<T> = Matrix::one(n);
+        let mut d: Matrix<T> = Matrix::zero(n, n);
+        let mut perm: Vec<usize> = (0..n).collect();
+
+        // LAPACK applies the interchanges of a step only to the trailing
+        // submatrix, they are applied to the previous columns of L here
+        let mut k: usize = 0;
+        while k < n
+        {
+            let k_step: usize = if ipiv[k] > 0 { 1 } else { 2 };
+            let k_k: usize = k + k_step - 1;
+            let k_p: usize = (ipiv[k].abs() - 1) as usize;
+
+            if k_p != k_k
+            {
+                for j in 0..k
+                {
+                    let temp: T = *l.get(k_k, j);
+                    *l.get_mut(k_k, j) = *l.get(k_p, j);
+                    *l.get_mut(k_p, j) = temp;
+                }
+                perm.swap(k_k, k_p);
+            }
+
+            for j in k..(k + k_step)
+            {
+                for i in (k + k_step)..n
+                {
+                    *l.get_mut(i, j) = *a.get(i, j);
+                }
+            }
+
+            *d.get_mut(k, k) = *a.get(k, k);
+            if k_step == 2
+            {
+                *d.get_mut(k + 1, k) = *a.get(k + 1, k);
+                *d.get_mut(k, k + 1) = *a.get(k + 1, k);
+                *d.get_mut(k + 1, k + 1) = *a.get(k + 1, k + 1);
+            }
+
+            k += k_step;
+        }
+
+        return Ok(LDLDec::new(l, d, permutation(&perm)));
+    }
+}
@@ -0,0 +1,327 @@
+use crate::{
+    algebra::{
+        abstr::Real,
+        linear::{
+            matrix::{Solve, Transpose},
+            Matrix, Vector,
+        },
+    },
+    error::MathError,
+};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::clone::Clone;
+
+/// Result of a symmetric indefinite LDL^T decomposition
+///
+/// P A P^T = L D L^T
+///
+/// L is unit lower triangular, D is block diagonal with 1 x 1 and 2 x 2
+/// blocks and P is a permutation matrix.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct LDLDec<T>
+{
+    l: Matrix<T>,
+    d: Matrix<T>,
+    p: Matrix<T>,
+}
+
+impl<T> LDLDec<T>
+{
+    pub(super) fn new(l: Matrix<T>, d: Matrix<T>, p: Matrix<T>) -> LDLDec<T>
+    {
+        return LDLDec { l, d, p };
+    }
+
+    /// Return the unit lower triangular matrix L
+    pub fn l(self: Self) -> Matrix<T>
+    {
+        return self.l;
+    }
+
+    /// Return the block diagonal matrix D
+    pub fn d(self: Self) -> Matrix<T>
+    {
+        return self.d;
+    }
+
+    /// Return the permutation matrix P
+    pub fn p(self: Self) -> Matrix<T>
+    {
+        return self.p;
+    }
+
+    /// Return the matrices L, D and P
+    pub fn ldp(self: Self) -> (Matrix<T>, Matrix<T>, Matrix<T>)
+    {
+        return (self.l, self.d, self.p);
+    }
+}
+
+impl<T> LDLDec<T> where T: Real
+{
+    /// Determinant of the decomposed matrix
+    ///
+    /// det(A) = det(D)
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[macro_use]
+    /// # extern crate mathru;
+    /// # fn main()
+    /// # {
+    /// use mathru::algebra::linear::Matrix;
+    ///
+    /// let a: Matrix<f64> = matrix![   0.0, 1.0;
+    ///                                 1.0, 0.0];
+    ///
+    /// assert_relative_eq!(-1.0, a.dec_ldl().unwrap().det(), epsilon=1.0e-10);
+    /// # }
+    /// ```
+    pub fn det(self: &Self) -> T
+    {
+        let n: usize = self.d.nrows();
+        let mut det: T = T::one();
+
+        let mut k: usize = 0;
+        while k < n
+        {
+            if self.is_block(k)
+            {
+                let d_11: T = *self.d.get(k, k);
+                let d_21: T = *self.d.get(k + 1, k);
+                let d_22: T = *self.d.get(k + 1, k + 1);
+                det *= d_11 * d_22 - d_21 * d_21;
+                k += 2;
+            }
+            else
+            {
+                det *= *self.d.get(k, k);
+                k += 1;
+            }
+        }
+
+        return det;
+    }
+
+    /// Inertia of the decomposed matrix
+    ///
+    /// By Sylvester's law of inertia A and D have the same number of
+    /// positive, negative and zero eigenvalues. An eigenvalue of D is
+    /// considered as zero, if its absolute value is not greater than
+    /// n ε max |d_ij|.
+    ///
+    /// # Return
+    ///
+    /// (number of positive eigenvalues, number of negative eigenvalues,
+    /// number of zero eigenvalues)
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[macro_use]
+    /// # extern crate mathru;
+    /// # fn main()
+    /// # {
+    /// use mathru::algebra::linear::Matrix;
+    ///
+    /// // KKT matrix of an equality constrained quadratic program
+    /// let k: Matrix<f64> = matrix![   2.0, 0.0, 1.0;
+    ///                                 0.0, 2.0, 1.0;
+    ///                                 1.0, 1.0, 0.0];
+    ///
+    /// assert_eq!((2, 1, 0), k.dec_ldl().unwrap().inertia());
+    /// # }
+    /// ```
+    pub fn inertia(self: &Self) -> (usize, usize, usize)
+    {
+        let n: usize = self.d.nrows();
+        let d_max: T = self.d.iter().fold(T::zero(), |max, d_ij| max.max(d_ij.abs()));
+        let tol: T = T::default_epsilon() * T::from_f64(n as f64) * d_max;
+
+        let mut positive: usize = 0;
+        let mut negative: usize = 0;
+        let mut zero: usize = 0;
+        let mut count = |lambda: T| {
+            if lambda.abs() <= tol
+            {
+                zero += 1;
+            }
+            else if lambda > T::zero()
+            {
+                positive += 1;
+            }
+            else
+            {
+                negative += 1;
+            }
+        };
+
+        let mut k: usize = 0;
+        while k < n
+        {
+            if self.is_block(k)
+            {
+                // eigenvalues of the symmetric 2 x 2 block
+                let d_11: T = *self.d.get(k, k);
+                let d_21: T = *self.d.get(k + 1, k);
+                let d_22: T = *self.d.get(k + 1, k + 1);
+                let mean: T = (d_11 + d_22) / T::from_f64(2.0);
+                let diff: T = (d_11 - d_22) / T::from_f64(2.0);
+                let radius: T = (diff * diff + d_21 * d_21).sqrt();
+                count(mean + radius);
+                count(mean - radius);
+                k += 2;
+            }
+            else
+            {
+                count(*self.d.get(k, k));
+                k += 1;
+            }
+        }
+
+        return (positive, negative, zero);
+    }
+
+    fn is_block(self: &Self, k: usize) -> bool
+    {
+        return k + 1 < self.d.nrows() && *self.d.get(k + 1, k) != T::zero();
+    }
+
+    /// Solves L D L^T P X = P B
+    fn solve_columns(self: &Self, b: &Matrix<T>) -> Result<Matrix<T>, MathError>
+    {
+        let n: usize = self.d.nrows();
+        let (b_m, b_n): (usize, usize) = b.dim();
+        if b_m != n
+        {
+            return Err(MathError::DimensionMismatch);
+        }
+
+        let mut x: Matrix<T> = &self.p * b;
+
+        for j in 0..b_n
+        {
+            // L y = P b
+            for i in 0..n
+            {
+                let mut sum: T = *x.get(i, j);
+                for k in 0..i
+                {
+                    sum -= *self.l.get(i, k) * *x.get(k, j);
+                }
+                *x.get_mut(i, j) = sum;
+            }
+
+            // D z = y
+            let mut k: usize = 0;
+            while k < n
+            {
+                if self.is_block(k)
+                {
+                    let d_11: T = *self.d.get(k, k);
+                    let d_21: T = *self.d.get(k + 1, k);
+                    let d_22: T = *self.d.get(k + 1, k + 1);
+                    let det: T = d_11 * d_22 - d_21 * d_21;
+                    if det == T::zero()
+                    {
+                        return Err(MathError::Singular { pivot: k });
+                    }
+                    let y_1: T = *x.get(k, j);
+                    let y_2: T = *x.get(k + 1, j);
+                    *x.get_mut(k, j) = (d_22 * y_1 - d_21 * y_2) / det;
+                    *x.get_mut(k + 1, j) = (d_11 * y_2 - d_21 * y_1) / det;
+                    k += 2;
+                }
+                else
+                {
+                    let d_kk: T = *self.d.get(k, k);
+                    if d_kk == T::zero()
+                    {
+                        return Err(MathError::Singular { pivot: k });
+                    }
+                    *x.get_mut(k, j) /= d_kk;
+                    k += 1;
+                }
+            }
+
+            // L^T w = z
+            for i in (0..n).rev()
+            {
+                let mut sum: T = *x.get(i, j);
+                for k in (i + 1)..n
+                {
+                    sum -= *self.l.get(k, i) * *x.get(k, j);
+                }
+                *x.get_mut(i, j) = sum;
+            }
+        }
+
+        // x = P^T w
+        return Ok(&self.p.clone().transpose() * &x);
+    }
+}
+
+impl<T> Solve<Vector<T>> for LDLDec<T> where T: Real
+{
+    /// Solves Ax = b
+    ///
+    /// # Return
+    ///
+    /// MathError::Singular if D is singular
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[macro_use]
+    /// # extern crate mathru;
+    /// # fn main()
+    /// # {
+    /// use mathru::algebra::linear::{matrix::{LDLDec, Solve}, Matrix, Vector};
+    ///
+    /// let a: Matrix<f64> = matrix![   1.0, 2.0, 0.0;
+    ///                                 2.0, -1.0, 3.0;
+    ///                                 0.0, 3.0, 1.0];
+    /// let b: Vector<f64> = vector![3.0; 4.0; 4.0];
+    ///
+    /// let ldl: LDLDec<f64> = a.dec_ldl().unwrap();
+    ///
+    /// assert_relative_eq!(vector![1.0; 1.0; 1.0], ldl.solve(&b).unwrap(), epsilon=1.0e-10);
+    /// # }
+    /// ```
+    fn solve(self: &Self, rhs: &Vector<T>) -> Result<Vector<T>, MathError>
+    {
+        let (m, _): (usize, usize) = rhs.dim();
+        let x: Matrix<T> = self.solve_columns(&Matrix::from(rhs.clone()))?;
+
+        return Ok(Vector::new_column(m, x.convert_to_vec()));
+    }
+}
+
+impl<T> Solve<Matrix<T>> for LDLDec<T> where T: Real
+{
+    /// Solves AX = B
+    ///
+    /// # Return
+    ///
+    /// MathError::Singular if D is singular
+    fn solve(self: &Self, rhs: &Matrix<T>) -> Result<Matrix<T>, MathError>
+    {
+        return self.solve_columns(rhs);
+    }
+}
+
+/// Permutation matrix P with (P A)_i = A_perm[i]
+pub(super) fn permutation<T>(perm: &[usize]) -> Matrix<T> where T: Real
+{
+    let n: usize = perm.len();
+    let mut p: Matrix<T> = Matrix::zero(n, n);
+    for (i, j) in perm.iter().enumerate()
+    {
+        *p.get_mut(i, *j) = T::one();
+    }
+
+    return p;
+}
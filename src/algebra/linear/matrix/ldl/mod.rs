@@ -0,0 +1,7 @@
+#[cfg(feature = "lapack")]
+pub mod lapack;
+#[cfg(feature = "native")]
+pub mod native;
+
+pub mod ldldec;
+pub use self::ldldec::LDLDec;
@@ -0,0 +1,200 @@
+use super::ldldec::permutation;
+use crate::{
+    algebra::{
+        abstr::Real,
+        linear::{matrix::LDLDec, Matrix},
+    },
+    error::MathError,
+};
+
+impl<T> Matrix<T> where T: Real
+{
+    /// Decomposes the symmetric matrix A into a unit lower triangular matrix
+    /// L, a block diagonal matrix D with 1 x 1 and 2 x 2 blocks and a
+    /// permutation matrix P
+    ///
+    /// P A P^T = L D L^T
+    ///
+    /// The pivots are chosen with the Bunch-Kaufman strategy. In contrast to
+    /// the Cholesky decomposition, A does not need to be positive definite.
+    /// Only the lower triangle of A is referenced.
+    ///
+    /// # Return
+    ///
+    /// MathError::DimensionMismatch if A is not a square matrix
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[macro_use]
+    /// # extern crate mathru;
+    /// # fn main()
+    /// # {
+    /// use mathru::algebra::linear::{matrix::Transpose, Matrix};
+    ///
+    /// let a: Matrix<f64> = matrix![   0.0, 1.0, 2.0;
+    ///                                 1.0, 0.0, 3.0;
+    ///                                 2.0, 3.0, 0.0];
+    ///
+    /// let (l, d, p): (Matrix<f64>, Matrix<f64>, Matrix<f64>) = a.dec_ldl().unwrap().ldp();
+    ///
+    /// assert_relative_eq!(&(&p * &a) * &p.transpose(), &(&l * &d) * &l.transpose(), epsilon=1.0e-10);
+    /// # }
+    /// ```
+    pub fn dec_ldl(self: &Self) -> Result<LDLDec<T>, MathError>
+    {
+        let (m, n): (usize, usize) = self.dim();
+        if m != n
+        {
+            return Err(MathError::DimensionMismatch);
+        }
+
+        let alpha: T = (T::one() + T::from_f64(17.0).sqrt()) / T::from_f64(8.0);
+
+        // symmetric working copy of the lower triangle
+        let mut a: Matrix<T> = self.clone();
+        for j in 0..n
+        {
+            for i in 0..j
+            {
+                *a.get_mut(i, j) = *a.get(j, i);
+            }
+        }
+
+        let mut l: Matrix<T> = Matrix::one(n);
+        let mut d: Matrix<T> = Matrix::zero(n, n);
+        let mut perm: Vec<usize> = (0..n).collect();
+
+        let mut k: usize = 0;
+        while k < n
+        {
+            let abs_akk: T = a.get(k, k).abs();
+
+            // largest off-diagonal element in column k
+            let mut r: usize = k;
+            let mut col_max: T = T::zero();
+            for i in (k + 1)..n
+            {
+                let abs_aik: T = a.get(i, k).abs();
+                if abs_aik > col_max
+                {
+                    col_max = abs_aik;
+                    r = i;
+                }
+            }
+
+            let mut k_p: usize = k;
+            let mut k_step: usize = 1;
+
+            if abs_akk.max(col_max) != T::zero() && abs_akk < alpha * col_max
+            {
+                // largest off-diagonal element in row r
+                let mut row_max: T = T::zero();
+                for j in k..n
+                {
+                    if j != r
+                    {
+                        row_max = row_max.max(a.get(r, j).abs());
+                    }
+                }
+
+                if abs_akk * row_max >= alpha * col_max * col_max
+                {
+                    k_p = k;
+                }
+                else if a.get(r, r).abs() >= alpha * row_max
+                {
+                    k_p = r;
+                }
+                else
+                {
+                    k_p = r;
+                    k_step = 2;
+                }
+            }
+
+            let k_k: usize = k + k_step - 1;
+            if k_p != k_k
+            {
+                a.swap_rows(k_k, k_p);
+                swap_columns(&mut a, k_k, k_p);
+                for j in 0..k
+                {
+                    let temp: T = *l.get(k_k, j);
+                    *l.get_mut(k_k, j) = *l.get(k_p, j);
+                    *l.get_mut(k_p, j) = temp;
+                }
+                perm.swap(k_k, k_p);
+            }
+
+            if k_step == 1
+            {
+                let d_kk: T = *a.get(k, k);
+                *d.get_mut(k, k) = d_kk;
+
+                // a zero column leaves a zero pivot in D
+                if d_kk != T::zero()
+                {
+                    for i in (k + 1)..n
+                    {
+                        *l.get_mut(i, k) = *a.get(i, k) / d_kk;
+                    }
+
+                    for j in (k + 1)..n
+                    {
+                        let a_jk: T = *a.get(j, k);
+                        for i in (k + 1)..n
+                        {
+                            *a.get_mut(i, j) -= *l.get(i, k) * a_jk;
+                        }
+                    }
+                }
+            }
+            else
+            {
+                let d_11: T = *a.get(k, k);
+                let d_21: T = *a.get(k + 1, k);
+                let d_22: T = *a.get(k + 1, k + 1);
+                let det: T = d_11 * d_22 - d_21 * d_21;
+
+                *d.get_mut(k, k) = d_11;
+                *d.get_mut(k + 1, k) = d_21;
+                *d.get_mut(k, k + 1) = d_21;
+                *d.get_mut(k + 1, k + 1) = d_22;
+
+                for i in (k + 2)..n
+                {
+                    let a_i1: T = *a.get(i, k);
+                    let a_i2: T = *a.get(i, k + 1);
+                    *l.get_mut(i, k) = (d_22 * a_i1 - d_21 * a_i2) / det;
+                    *l.get_mut(i, k + 1) = (d_11 * a_i2 - d_21 * a_i1) / det;
+                }
+
+                for j in (k + 2)..n
+                {
+                    let a_j1: T = *a.get(j, k);
+                    let a_j2: T = *a.get(j, k + 1);
+                    for i in (k + 2)..n
+                    {
+                        *a.get_mut(i, j) -= *l.get(i, k) * a_j1 + *l.get(i, k + 1) * a_j2;
+                    }
+                }
+            }
+
+            k += k_step;
+        }
+
+        return Ok(LDLDec::new(l, d, permutation(&perm)));
+    }
+}
+
+fn swap_columns<T>(a: &mut Matrix<T>, i: usize, j: usize) where T: Real
+{
+    let (m, _): (usize, usize) = a.dim();
+    for k in 0..m
+    {
+        let temp: T = *a.get(k, i);
+        *a.get_mut(k, i) = *a.get(k, j);
+        *a.get_mut(k, j) = temp;
+    }
+}
@@ -0,0 +1,53 @@
+use crate::algebra::linear::Vector;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::clone::Clone;
+
+/// Result of a linear least squares problem
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct LstsqResult<T>
+{
+    x: Vector<T>,
+    residual: T,
+    rank: usize,
+    singular_values: Vector<T>,
+}
+
+impl<T> LstsqResult<T>
+{
+    pub(super) fn new(x: Vector<T>, residual: T, rank: usize, singular_values: Vector<T>) -> LstsqResult<T>
+    {
+        return LstsqResult { x,
+                             residual,
+                             rank,
+                             singular_values };
+    }
+
+    /// Returns the minimum norm solution
+    pub fn x(self: Self) -> Vector<T>
+    {
+        return self.x;
+    }
+
+    /// Returns the effective rank of the matrix
+    pub fn rank(self: &Self) -> usize
+    {
+        return self.rank;
+    }
+
+    /// Returns the singular values of the matrix in descending order
+    pub fn singular_values(self: &Self) -> &Vector<T>
+    {
+        return &self.singular_values;
+    }
+}
+
+impl<T> LstsqResult<T> where T: Copy
+{
+    /// Returns the norm of the residual ||Ax - b||
+    pub fn residual(self: &Self) -> T
+    {
+        return self.residual;
+    }
+}
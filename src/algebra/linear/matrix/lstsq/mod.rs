@@ -0,0 +1,108 @@
+pub mod lstsqresult;
+pub use self::lstsqresult::LstsqResult;
+
+use crate::{
+    algebra::{
+        abstr::Real,
+        linear::{
+            matrix::{QRPDec, SVDDec},
+            Matrix, Vector,
+        },
+    },
+    error::MathError,
+};
+
+impl<T> Matrix<T> where T: Real
+{
+    /// Solves the linear least squares problem
+    ///
+    /// min ||Ax - b||
+    ///
+    /// The matrix may be over- or underdetermined and rank deficient. Among
+    /// all solutions the one with the smallest norm is returned. The matrix
+    /// is reduced with a QR decomposition with column pivoting A P = Q R,
+    /// afterwards the small triangular factor R is decomposed with the
+    /// singular value decomposition. Singular values smaller or equal to
+    /// ε max(m, n) σ_max are treated as zero.
+    ///
+    /// # Return
+    ///
+    /// MathError::DimensionMismatch, if the number of rows of A and the
+    /// dimension of b differ
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[macro_use]
+    /// # extern crate mathru;
+    /// # fn main()
+    /// # {
+    /// use mathru::algebra::linear::{matrix::LstsqResult, Matrix, Vector};
+    ///
+    /// // fit of a line y = c_0 + c_1 t through three points
+    /// let a: Matrix<f64> = matrix![   1.0, 0.0;
+    ///                                 1.0, 1.0;
+    ///                                 1.0, 2.0];
+    /// let b: Vector<f64> = vector![1.0; 2.0; 4.0];
+    ///
+    /// let result: LstsqResult<f64> = a.lstsq(&b).unwrap();
+    ///
+    /// assert_eq!(2, result.rank());
+    /// assert_relative_eq!(1.0 / 6.0f64.sqrt(), result.residual(), epsilon=1.0e-10);
+    /// assert_relative_eq!(vector![5.0 / 6.0; 1.5], result.x(), epsilon=1.0e-10);
+    /// # }
+    /// ```
+    pub fn lstsq(self: &Self, b: &Vector<T>) -> Result<LstsqResult<T>, MathError>
+    {
+        let (m, n): (usize, usize) = self.dim();
+        let (b_m, _): (usize, usize) = b.dim();
+        if m != b_m
+        {
+            return Err(MathError::DimensionMismatch);
+        }
+
+        let qrp: QRPDec<T> = self.dec_qrp()?;
+        let pivot: Vec<usize> = qrp.pivot().to_vec();
+        let (q, r, _p): (Matrix<T>, Matrix<T>, Matrix<T>) = qrp.qrp();
+        let (k, _): (usize, usize) = r.dim();
+
+        // c = Q^T b
+        let mut c: Vec<T> = vec![T::zero(); k];
+        for (l, c_l) in c.iter_mut().enumerate()
+        {
+            for i in 0..m
+            {
+                *c_l += *q.get(i, l) * *b.get(i);
+            }
+        }
+
+        let svd: SVDDec<T> = r.dec_svd_thin()?;
+        let singular_values: Vector<T> = svd.clone().singular_values();
+        let s_max: T = if k == 0 { T::zero() } else { *singular_values.get(0) };
+        let tol: T = T::default_epsilon() * T::from_f64(m.max(n) as f64) * s_max;
+        let rank: usize = svd.rank(tol);
+
+        // y = V Σ^+ U^T c, x = P y
+        let u: Matrix<T> = svd.clone().u();
+        let vt: Matrix<T> = svd.vt();
+        let mut x: Vector<T> = Vector::zero(n);
+        for l in 0..rank
+        {
+            let mut u_c: T = T::zero();
+            for i in 0..k
+            {
+                u_c += *u.get(i, l) * c[i];
+            }
+            let coeff: T = u_c / *singular_values.get(l);
+            for j in 0..n
+            {
+                *x.get_mut(pivot[j]) += coeff * *vt.get(l, j);
+            }
+        }
+
+        let residual: Vector<T> = &(self * &x) - b;
+        let residual_norm: T = residual.iter().fold(T::zero(), |acc, r_i| acc + *r_i * *r_i).sqrt();
+
+        return Ok(LstsqResult::new(x, residual_norm, rank, singular_values));
+    }
+}
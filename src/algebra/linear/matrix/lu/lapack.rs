@@ -1,10 +1,11 @@
-use crate::algebra::{
-    abstr::{Field, Scalar},
-    linear::{matrix::LUDec, Matrix},
+use crate::{
+    algebra::{
+        abstr::{Field, Scalar, Zero},
+        linear::{matrix::LUDec, Matrix},
+    },
+    error::MathError,
 };
 
-use crate::algebra::abstr::Zero;
-
 impl<T> Matrix<T> where T: Field + Scalar
 {
     /// Decomposes the matrix into a upper and a lower matrix
@@ -23,7 +24,7 @@ impl<T> Matrix<T> where T: Field + Scalar
     ///
     /// let (l, u, p): (Matrix<f64>, Matrix<f64>, Matrix<f64>) = a.dec_lu().unwrap().lup();
     /// ```
-    pub fn dec_lu(self: &Self) -> Result<LUDec<T>, ()>
+    pub fn dec_lu(self: &Self) -> Result<LUDec<T>, MathError>
     {
         let (m, n): (usize, usize) = self.dim();
         assert_eq!(m, n);
@@ -46,9 +47,14 @@ impl<T> Matrix<T> where T: Field + Scalar
                   ipiv.as_mut_slice(),
                   &mut info);
 
+        if info > 0
+        {
+            return Err(MathError::Singular { pivot: (info - 1) as usize });
+        }
+
         if info != 0
         {
-            return Err(());
+            return Err(MathError::LapackInfo(info));
         }
 
         let mat: Matrix<T> = Matrix::new(m, n, self_data);
@@ -1,14 +1,16 @@
-use crate::algebra::{
-    abstr::{Field, Scalar},
-    linear::{
-        matrix::{Inverse, Solve, Substitute},
-        Matrix, Vector,
+use crate::{
+    algebra::{
+        abstr::{AbsDiffEq, Field, Real, Scalar},
+        linear::{
+            matrix::{Inverse, Solve, Substitute, Transpose},
+            Matrix, Vector,
+        },
     },
+    error::MathError,
 };
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 use std::clone::Clone;
-use crate::algebra::abstr::AbsDiffEq;
 
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(Debug, Clone)]
@@ -49,12 +51,174 @@ impl<T> LUDec<T>
     }
 }
 
+impl<T> LUDec<T> where T: Real
+{
+    /// Estimates the 1-norm of the inverse A^{-1} without computing the
+    /// inverse
+    ///
+    /// The estimator of Hager and Higham needs a few solves with A and A^T, which
+    /// reuse the decomposition. The estimate is a lower bound and is usually
+    /// within a factor of 3 of the exact norm.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[macro_use]
+    /// # extern crate mathru;
+    /// # fn main()
+    /// # {
+    /// use mathru::algebra::linear::{matrix::{Inverse, LUDec}, Matrix};
+    ///
+    /// let a: Matrix<f64> = matrix![   4.0, 1.0;
+    ///                                 2.0, 3.0];
+    /// let lu: LUDec<f64> = a.dec_lu().unwrap();
+    ///
+    /// assert_relative_eq!(a.inv().unwrap().norm_1(), lu.norm_1_inv_est().unwrap(), epsilon=1.0e-10);
+    /// # }
+    /// ```
+    pub fn norm_1_inv_est(self: &Self) -> Result<T, MathError>
+    {
+        let n: usize = self.p.nrows();
+        if n == 0
+        {
+            return Ok(T::zero());
+        }
+
+        let x: Vector<T> = Vector::new_column(n, vec![T::one() / T::from_u64(n as u64); n]);
+        let y: Vector<T> = self.solve(&x)?;
+        if n == 1
+        {
+            return Ok(y.get(0).abs());
+        }
+
+        let mut est: T = LUDec::norm_1_vector(&y);
+        let mut xi: Vector<T> = LUDec::sign(&y);
+        let mut z: Vector<T> = self.solve_transpose(xi.clone())?;
+        let mut j: usize = LUDec::arg_max_abs(&z);
+
+        for _iter in 1..5
+        {
+            let mut e_j: Vector<T> = Vector::new_column(n, vec![T::zero(); n]);
+            *e_j.get_mut(j) = T::one();
+
+            let y: Vector<T> = self.solve(&e_j)?;
+            let est_old: T = est;
+            est = LUDec::norm_1_vector(&y);
+
+            let xi_new: Vector<T> = LUDec::sign(&y);
+            if xi_new == xi || est <= est_old
+            {
+                est = est.max(est_old);
+                break;
+            }
+            xi = xi_new;
+
+            z = self.solve_transpose(xi.clone())?;
+            let j_new: usize = LUDec::arg_max_abs(&z);
+            if z.get(j_new).abs() <= z.get(j).abs()
+            {
+                break;
+            }
+            j = j_new;
+        }
+
+        // An alternating sign vector guards against the rare cases, in which
+        // the iteration above underestimates the norm considerably
+        let mut x: Vector<T> = Vector::new_column(n, vec![T::zero(); n]);
+        for i in 0..n
+        {
+            let x_i: T = T::one() + T::from_u64(i as u64) / T::from_u64((n - 1) as u64);
+            *x.get_mut(i) = if i % 2 == 0 { x_i } else { -x_i };
+        }
+        let y: Vector<T> = self.solve(&x)?;
+        let alt_est: T = T::from_f64(2.0) * LUDec::norm_1_vector(&y) / T::from_u64(3 * n as u64);
+
+        return Ok(est.max(alt_est));
+    }
+
+    /// Estimates the reciprocal condition number in the 1-norm
+    ///
+    /// ```math
+    /// rcond(A) = \frac{1}{\lVert A \rVert_{1} \lVert A^{-1} \rVert_{1}}
+    /// ```
+    ///
+    /// A value close to the machine epsilon indicates, that a solution of Ax =
+    /// b computed with this decomposition is not reliable.
+    ///
+    /// # Arguments
+    ///
+    /// * `a_norm_1`: 1-norm of the decomposed matrix A
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[macro_use]
+    /// # extern crate mathru;
+    /// # fn main()
+    /// # {
+    /// use mathru::algebra::linear::{matrix::LUDec, Matrix};
+    ///
+    /// let a: Matrix<f64> = matrix![   1.0, 1.0;
+    ///                                 1.0, 1.0 + 1.0e-12];
+    /// let lu: LUDec<f64> = a.dec_lu().unwrap();
+    ///
+    /// assert!(lu.rcond(a.norm_1()).unwrap() < 1.0e-10);
+    /// # }
+    /// ```
+    pub fn rcond(self: &Self, a_norm_1: T) -> Result<T, MathError>
+    {
+        if a_norm_1 == T::zero()
+        {
+            return Ok(T::zero());
+        }
+
+        let inv_norm_1: T = self.norm_1_inv_est()?;
+        return Ok(T::one() / a_norm_1 / inv_norm_1);
+    }
+
+    /// Solves A^T x = b, with A^T = U^T L^T P
+    fn solve_transpose(self: &Self, b: Vector<T>) -> Result<Vector<T>, MathError>
+    {
+        let w: Vector<T> = self.u.clone().transpose().substitute_forward(b)?;
+        let v: Vector<T> = self.l.clone().transpose().substitute_backward(w)?;
+        return Ok(&self.p.clone().transpose() * &v);
+    }
+
+    fn norm_1_vector(x: &Vector<T>) -> T
+    {
+        return x.iter().fold(T::zero(), |sum, x_i| sum + x_i.abs());
+    }
+
+    fn sign(x: &Vector<T>) -> Vector<T>
+    {
+        return x.clone().apply(&|x_i: &T| if *x_i >= T::zero() { T::one() } else { -T::one() });
+    }
+
+    fn arg_max_abs(x: &Vector<T>) -> usize
+    {
+        let mut j: usize = 0;
+        for (i, x_i) in x.iter().enumerate()
+        {
+            if x_i.abs() > x.get(j).abs()
+            {
+                j = i;
+            }
+        }
+        return j;
+    }
+}
+
 impl<T> Solve<Vector<T>> for LUDec<T> where T: Field + Scalar + AbsDiffEq
 {
     /// Solves Ax = y
     /// where A \in R^{m * n}, x \in R^n, y \in R^m
-    fn solve(self: &Self, rhs: &Vector<T>) -> Result<Vector<T>, ()>
+    fn solve(self: &Self, rhs: &Vector<T>) -> Result<Vector<T>, MathError>
     {
+        if self.p.ncols() != rhs.dim().0
+        {
+            return Err(MathError::DimensionMismatch);
+        }
+
         let b_hat: Vector<T> = &self.p * rhs;
         let y: Vector<T> = self.l.substitute_forward(b_hat)?;
         return self.u.substitute_backward(y);
@@ -80,7 +244,7 @@ impl<T> Inverse<T> for LUDec<T>
     /// let a: Matrix<f64> = Matrix::new(2, 2, vec![1.0, 0.0, 3.0, -7.0]);
     /// let b_inv: Matrix<f64> = a.inv().unwrap();
     /// ```
-    fn inv(self: &Self) -> Result<Matrix<T>, ()>
+    fn inv(self: &Self) -> Result<Matrix<T>, MathError>
     {
         let b = Matrix::one(self.p.nrows());
         let x: Matrix<T> = self.solve(&b)?;
@@ -92,8 +256,13 @@ impl<T> Inverse<T> for LUDec<T>
 impl<T> Solve<Matrix<T>> for LUDec<T>
     where T: Field + Scalar + AbsDiffEq
 {
-    fn solve(self: &Self, rhs: &Matrix<T>) -> Result<Matrix<T>, ()>
+    fn solve(self: &Self, rhs: &Matrix<T>) -> Result<Matrix<T>, MathError>
     {
+        if self.p.ncols() != rhs.dim().0
+        {
+            return Err(MathError::DimensionMismatch);
+        }
+
         let b_hat: Matrix<T> = &self.p * rhs;
 
         let y: Matrix<T> = self.l.substitute_forward(b_hat)?;
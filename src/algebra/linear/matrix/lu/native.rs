@@ -1,6 +1,9 @@
-use crate::algebra::{
-    abstr::{Field, Scalar},
-    linear::{matrix::LUDec, Matrix},
+use crate::{
+    algebra::{
+        abstr::{Field, Scalar},
+        linear::{matrix::LUDec, Matrix},
+    },
+    error::MathError,
 };
 
 impl<T> Matrix<T> where
@@ -22,7 +25,7 @@ impl<T> Matrix<T> where
     ///
     /// let (l, u, p): (Matrix<f64>, Matrix<f64>, Matrix<f64>) = a.dec_lu().unwrap().lup();
     /// ```
-    pub fn dec_lu(self: &Self) -> Result<LUDec<T>, ()>
+    pub fn dec_lu(self: &Self) -> Result<LUDec<T>, MathError>
     {
         let (m, n): (usize, usize) = self.dim();
         assert_eq!(m, n);
@@ -55,16 +58,14 @@ impl<T> Matrix<T> where
                 p.swap_rows(i, i_max);
             }
 
+            if max == T::zero()
+            {
+                return Err(MathError::Singular { pivot: i });
+            }
+
             for j in (i + 1)..a.n
             {
-                let f: T = if a.get(i, i).clone() != T::zero()
-                {
-                    *(a.get(j, i)) / *a.get(i, i)
-                }
-                else
-                {
-                    *(a.get(j, i))
-                };
+                let f: T = *(a.get(j, i)) / *a.get(i, i);
 
                 for k in (i + 1)..a.n
                 {
@@ -1,22 +1,23 @@
 //! Matrix
 use super::{
-    //MatrixColumnIterator,
-    //MatrixColumnIteratorMut,
+    MatrixColumnIterator,
+    MatrixColumnIteratorMut,
     MatrixColumnIntoIterator,
     MatrixIntoIterator,
     MatrixIterator,
     MatrixIteratorMut,
-    //MatrixRowIterator,
+    MatrixRowIterator,
     //MatrixRowIteratorMut,
     MatrixRowIntoIterator
 };
 use crate::{
     algebra::{
-        abstr::{Addition, Field, Identity, Multiplication, Scalar},
-        linear::{matrix::Substitute, Vector, matrix::Transpose},
+        abstr::{Addition, Field, Identity, Multiplication, Real, Scalar},
+        linear::Vector,
         abstr::{AbsDiffEq, RelativeEq},
     },
     elementary::Power,
+    error::MathError,
 };
 use rand::{self, Rng};
 #[cfg(feature = "serde")]
@@ -113,11 +114,11 @@ impl<T> Matrix<T>
     {
         MatrixRowIntoIterator::new(self)
     }
-    // pub fn row_iter(self: &Self) -> MatrixRowIterator<T>
-    //     where T: Zero
-    // {
-    //     MatrixRowIterator::new(self.data.iter())
-    // }
+    /// Returns an iterator over the rows, every row is a view
+    pub fn row_iter(self: &Self) -> MatrixRowIterator<T>
+    {
+        MatrixRowIterator::new(self)
+    }
 
     // pub fn row_iter_mut(self: &mut Self) -> MatrixRowIteratorMut<T>
     //     where T: Zero
@@ -130,15 +131,17 @@ impl<T> Matrix<T>
          MatrixColumnIntoIterator::new(self)
     }
 
-    // pub fn column_iter(self: &Self) -> MatrixColumnIterator<T>
-    // {
-    //     MatrixColumnIterator::new(self.data.iter())
-    // }
+    /// Returns an iterator over the columns, every column is a view
+    pub fn column_iter(self: &Self) -> MatrixColumnIterator<T>
+    {
+        MatrixColumnIterator::new(self)
+    }
 
-    // pub fn column_iter_mut(self: &mut Self) -> MatrixColumnIteratorMut<T>
-    // {
-    //     MatrixColumnIteratorMut::new(self.data.iter_mut())
-    // }
+    /// Returns an iterator over the columns, every column is a mutable view
+    pub fn column_iter_mut(self: &mut Self) -> MatrixColumnIteratorMut<T>
+    {
+        MatrixColumnIteratorMut::new(self)
+    }
 }
 
 impl<T> Matrix<T> where T: Clone
@@ -301,7 +304,6 @@ impl<T> Matrix<T> where T: Field + Scalar + Power
         givens
     }
 
-    #[cfg(feature = "native")]
     /// function [c,s] = Givens(a,b)
     /// Givens rotation computation
     /// Determines cosine-sine pair (c,s) so that [c s;-s c]'*[a;b] = [r;0]
@@ -655,11 +657,33 @@ impl<T> Matrix<T>
 
 impl<T> Matrix<T> where T: Scalar + Clone + Copy
 {
+    /// Creates a matrix with entries uniformly distributed in [0, 1)
     pub fn new_random(m: usize, n: usize) -> Matrix<T>
     {
-        let mut rng = rand::thread_rng();
-        let data: Vec<T> = vec![T::from_f64(rng.gen()); m * n];
-        Matrix::new(m, n, data)
+        return Matrix::new_random_with_rng(m, n, &mut rand::thread_rng());
+    }
+
+    /// Creates a matrix with entries uniformly distributed in [0, 1), which
+    /// are drawn from the given random number generator
+    ///
+    /// A seeded generator makes the matrix reproducible.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mathru::algebra::linear::Matrix;
+    /// use rand::{rngs::StdRng, SeedableRng};
+    ///
+    /// let a: Matrix<f64> = Matrix::new_random_with_rng(3, 2, &mut StdRng::seed_from_u64(7));
+    /// let b: Matrix<f64> = Matrix::new_random_with_rng(3, 2, &mut StdRng::seed_from_u64(7));
+    ///
+    /// assert_eq!(a, b);
+    /// ```
+    pub fn new_random_with_rng<R>(m: usize, n: usize, rng: &mut R) -> Matrix<T>
+        where R: Rng + ?Sized
+    {
+        let data: Vec<T> = (0..m * n).map(|_| T::from_f64(rng.gen())).collect();
+        return Matrix::new(m, n, data);
     }
 }
 
@@ -739,18 +763,44 @@ impl<T> Matrix<T> where T: Field + Scalar
     }
 }
 
-impl<T> Matrix<T> where T: Field + Scalar + Power + AbsDiffEq
+impl<T> Matrix<T> where T: Real
 {
-    /// Calculates the pseudo inverse matrix
+    /// Calculates the Moore-Penrose pseudo inverse matrix
+    ///
+    /// A^+ = V Σ^+ U^T
+    ///
+    /// The pseudo inverse is computed with the singular value decomposition,
+    /// hence it is defined for rank deficient matrices, too. Singular values
+    /// smaller or equal to ε max(m, n) σ_max are treated as zero.
+    ///
+    /// Only scalar types implementing [`Real`] are supported.
     ///
-    /// A^+ = (A^TA)^-1A^T
-    pub fn pinv(self: &Self) -> Result<Matrix<T>, ()>
+    /// # Example
+    ///
+    /// ```
+    /// # #[macro_use]
+    /// # extern crate mathru;
+    /// # fn main()
+    /// # {
+    /// use mathru::algebra::linear::Matrix;
+    ///
+    /// let a: Matrix<f64> = matrix![   1.0, 2.0;
+    ///                                 3.0, 4.0;
+    ///                                 5.0, 6.0];
+    /// let a_pinv: Matrix<f64> = a.pinv().unwrap();
+    ///
+    /// assert_relative_eq!(Matrix::one(2), &a_pinv * &a, epsilon=1.0e-10);
+    /// # }
+    /// ```
+    pub fn pinv(self: &Self) -> Result<Matrix<T>, MathError>
+    {
+        return Ok(self.dec_svd_thin()?.pinv());
+    }
+
+    /// Returns true, if no entry is infinite or NaN
+    pub(crate) fn is_finite(self: &Self) -> bool
     {
-        let r: Matrix<T> = self.dec_qr()?.r();
-        let x: Matrix<T> = r.clone()
-                            .transpose()
-                            .substitute_forward(self.clone().transpose())?;
-        return r.substitute_backward(x);
+        return self.data.iter().all(|a_ij| a_ij.abs() < T::infinity());
     }
 }
 
@@ -1,29 +1,34 @@
-use crate::algebra::abstr::{Field, Scalar};
-use crate::algebra::linear::{Vector};
-use crate::vector;
-use std::slice::Iter;
+use crate::algebra::linear::{matrix::MatrixView, Matrix};
 
+/// Iterator over the columns of a matrix, every column is a m x 1 view
 pub struct MatrixColumnIterator<'a, T>
 {
-    iter: Iter<'a, T>,
-    column: usize
+    m: &'a Matrix<T>,
+    column: usize,
 }
 
 impl<'a, T> MatrixColumnIterator<'a, T>
 {
-    pub fn new(iter: Iter<'a, T>) -> MatrixColumnIterator<'a, T>
+    pub fn new(m: &'a Matrix<T>) -> MatrixColumnIterator<'a, T>
     {
-        MatrixColumnIterator{iter}
+        return MatrixColumnIterator { m, column: 0 };
     }
 }
 
-impl<'a, T> Iterator for MatrixColumnIterator<'a, T> where T: Field + Scalar
+impl<'a, T> Iterator for MatrixColumnIterator<'a, T>
 {
-    type Item = Vector<T>;
+    type Item = MatrixView<'a, T>;
 
-    // just return the str reference
     fn next(&mut self) -> Option<Self::Item>
     {
-        None
+        if self.column < self.m.ncols()
+        {
+            let column: MatrixView<'a, T> = self.m.column(self.column);
+            self.column += 1;
+
+            return Some(column);
+        }
+
+        return None;
     }
 }
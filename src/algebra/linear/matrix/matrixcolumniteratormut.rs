@@ -1,27 +1,31 @@
-use crate::algebra::abstr::{Field, Scalar};
-use crate::algebra::linear::Vector;
-use std::slice::IterMut;
+use crate::algebra::linear::{matrix::MatrixViewMut, Matrix};
+use std::slice::ChunksMut;
 
+/// Iterator over the columns of a matrix, every column is a mutable m x 1
+/// view
 pub struct MatrixColumnIteratorMut<'a, T>
 {
-    iter: IterMut<'a, T>,
+    m: usize,
+    iter: ChunksMut<'a, T>,
 }
 
 impl<'a, T> MatrixColumnIteratorMut<'a, T>
 {
-    pub fn new(iter: IterMut<'a, T>) -> MatrixColumnIteratorMut<'a, T>
+    pub fn new(m: &'a mut Matrix<T>) -> MatrixColumnIteratorMut<'a, T>
     {
-        return MatrixColumnIteratorMut{iter}
+        let rows: usize = m.m;
+        return MatrixColumnIteratorMut { m: rows,
+                                         iter: m.data.chunks_mut(rows.max(1)) };
     }
 }
 
 impl<'a, T> Iterator for MatrixColumnIteratorMut<'a, T>
-    where T: Field + Scalar
 {
-    type Item = &'a mut Vector<T>;
+    type Item = MatrixViewMut<'a, T>;
 
     fn next(&mut self) -> Option<Self::Item>
     {
-        return Some(&mut vector![T::zero()]);
+        let m: usize = self.m;
+        return self.iter.next().map(|column| MatrixViewMut::new(m, 1, 1, m, column));
     }
 }
@@ -1,27 +1,34 @@
-use crate::algebra::abstr::{Field, Scalar, Zero};
-use crate::algebra::linear::{Vector};
-use std::slice::Iter;
+use crate::algebra::linear::{matrix::MatrixView, Matrix};
 
+/// Iterator over the rows of a matrix, every row is a 1 x n view
 pub struct MatrixRowIterator<'a, T>
 {
-    iter: Iter<'a, T>,
+    m: &'a Matrix<T>,
+    row: usize,
 }
 
 impl<'a, T> MatrixRowIterator<'a, T>
-    where T: Zero
 {
-    pub fn new(iter: Iter<'a, T>) -> MatrixRowIterator<'a, T>
+    pub fn new(m: &'a Matrix<T>) -> MatrixRowIterator<'a, T>
     {
-        return MatrixRowIterator{iter};
+        return MatrixRowIterator { m, row: 0 };
     }
 }
 
-impl<'a, T> Iterator for MatrixRowIterator<'a, T> where T: Field + Scalar
+impl<'a, T> Iterator for MatrixRowIterator<'a, T>
 {
-    type Item = &'a Vector<T>;
+    type Item = MatrixView<'a, T>;
 
-    fn next(&'a mut self) -> Option<Self::Item>
+    fn next(&mut self) -> Option<Self::Item>
     {
-        Some(&self.vec)
+        if self.row < self.m.nrows()
+        {
+            let row: MatrixView<'a, T> = self.m.row(self.row);
+            self.row += 1;
+
+            return Some(row);
+        }
+
+        return None;
     }
 }
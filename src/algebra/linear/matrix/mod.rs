@@ -1,53 +1,75 @@
 #[macro_use]
 pub mod matrix;
-//mod matrixcolumniterator;
-//mod matrixcolumniteratormut;
+mod matrixcolumniterator;
+mod matrixcolumniteratormut;
 mod matrixintoiterator;
 mod matrixiterator;
 mod matrixiteratormut;
-//mod matrixrowiterator;
+mod matrixrowiterator;
 //mod matrixrowiteratormut;
 mod matrixcolumnintoiterator;
 mod matrixrowintoiterator;
 mod eigen;
 pub use self::eigen::EigenDec;
 
+mod function;
+
 mod hessenberg;
 pub use self::hessenberg::HessenbergDec;
 
+mod ldl;
+pub use self::ldl::LDLDec;
+
 mod lu;
 pub use self::lu::LUDec;
 
+mod norm;
+
+mod lstsq;
+pub use self::lstsq::LstsqResult;
+
 mod qr;
-pub use self::qr::QRDec;
+pub use self::qr::{QRDec, QRPDec};
+
+mod qz;
+pub use self::qz::QZDec;
+
+mod schur;
+pub use self::schur::SchurDec;
 
 mod add;
+#[macro_use]
+mod block;
 mod div;
 mod inverse;
 mod mul;
+mod product;
 mod sub;
 mod det;
 mod singular;
+pub use self::singular::SVDDec;
 mod cholesky;
 pub use self::cholesky::CholeskyDec;
 
 mod solve;
 mod substitute;
 mod transpose;
+mod view;
 
 pub use self::{
     inverse::Inverse,
     matrix::Matrix,
-    //matrixcolumniterator::MatrixColumnIterator,
-    //matrixcolumniteratormut::MatrixColumnIteratorMut,
+    matrixcolumniterator::MatrixColumnIterator,
+    matrixcolumniteratormut::MatrixColumnIteratorMut,
     matrixcolumnintoiterator::MatrixColumnIntoIterator,
     matrixintoiterator::MatrixIntoIterator,
     matrixiterator::MatrixIterator,
     matrixiteratormut::MatrixIteratorMut,
-    //matrixrowiterator::MatrixRowIterator,
+    matrixrowiterator::MatrixRowIterator,
     //matrixrowiteratormut::MatrixRowIteratorMut,
     matrixrowintoiterator::MatrixRowIntoIterator,
     solve::Solve,
-    substitute::Substitute, transpose::Transpose
+    substitute::Substitute, transpose::{ConjugateTranspose, Transpose},
+    view::{MatrixView, MatrixViewMut},
 
 };
@@ -0,0 +1,267 @@
+//! Cache-blocked general matrix multiplication
+//!
+//! The kernel follows the structure of BLIS: op(B) is partitioned into
+//! panels of NC columns and KC rows, which are packed into a contiguous buffer
+//! of NR wide micro panels. For every panel of op(B), op(A) is partitioned
+//! into blocks of MC rows, which are packed into MR high micro panels. The
+//! micro kernel multiplies a packed MR x KC micro panel of op(A) with a packed
+//! KC x NR micro panel of op(B) and accumulates the product in registers.
+//!
+//! With the feature `rayon` the columns of C are distributed among the
+//! threads of the global thread pool.
+use crate::algebra::abstr::{Field, Scalar};
+
+/// Height of a micro panel of op(A)
+const MR: usize = 4;
+/// Width of a micro panel of op(B)
+const NR: usize = 4;
+/// Number of rows of a block of op(A), the packed block fits into the L2 cache
+const MC: usize = 96;
+/// Depth of the blocks, a packed micro panel of op(B) fits into the L1 cache
+const KC: usize = 256;
+/// Number of columns of a panel of op(B), the packed panel fits into the L3
+/// cache
+const NC: usize = 4096;
+
+/// Products with less multiply-add operations are computed by a single thread
+#[cfg(feature = "rayon")]
+const PARALLEL_THRESHOLD: usize = 64 * 64 * 64;
+
+/// Computes C = alpha op(A) op(B) + beta C
+///
+/// The arguments have the same meaning as the arguments of the BLAS routine
+/// xgemm: op(X) = X if trans_x is false and op(X) = X^T otherwise, op(A) is a
+/// m x k, op(B) a k x n and C a m x n matrix. All matrices are stored in column
+/// major order with the leading dimensions lda, ldb and ldc.
+///
+/// If beta is zero, C need not be initialized.
+#[allow(clippy::too_many_arguments)]
+pub fn gemm<T>(trans_a: bool, trans_b: bool, m: usize, n: usize, k: usize, alpha: T, a: &[T],
+               lda: usize, b: &[T], ldb: usize, beta: T, c: &mut [T], ldc: usize)
+    where T: Field + Scalar
+{
+    if m == 0 || n == 0
+    {
+        return;
+    }
+
+    scale(m, n, beta, c, ldc);
+
+    if k == 0 || alpha == T::zero()
+    {
+        return;
+    }
+
+    #[cfg(feature = "rayon")]
+    {
+        if m * n * k >= PARALLEL_THRESHOLD && rayon::current_num_threads() > 1
+        {
+            return gemm_parallel(trans_a, trans_b, m, n, k, alpha, a, lda, b, ldb, c, ldc);
+        }
+    }
+
+    gemm_serial(trans_a, trans_b, m, n, k, alpha, a, lda, b, ldb, c, ldc);
+}
+
+/// Splits C and op(B) into column panels, which are multiplied in parallel
+#[cfg(feature = "rayon")]
+#[allow(clippy::too_many_arguments)]
+fn gemm_parallel<T>(trans_a: bool, trans_b: bool, m: usize, n: usize, k: usize, alpha: T, a: &[T],
+                    lda: usize, b: &[T], ldb: usize, c: &mut [T], ldc: usize)
+    where T: Field + Scalar
+{
+    use rayon::prelude::*;
+
+    let num_threads: usize = rayon::current_num_threads();
+    let cols: usize = round_up(n.div_ceil(num_threads), NR);
+    let len: usize = (n - 1) * ldc + m;
+
+    c[..len].par_chunks_mut(cols * ldc).enumerate().for_each(|(t, c_t)| {
+                                                 let j: usize = t * cols;
+                                                 let n_t: usize = cols.min(n - j);
+                                                 let b_t: &[T] = if trans_b
+                                                 {
+                                                     &b[j..]
+                                                 }
+                                                 else
+                                                 {
+                                                     &b[j * ldb..]
+                                                 };
+                                                 gemm_serial(trans_a, trans_b, m, n_t, k, alpha,
+                                                             a, lda, b_t, ldb, c_t, ldc);
+                                             });
+}
+
+/// Computes C = alpha op(A) op(B) + C
+#[allow(clippy::too_many_arguments)]
+fn gemm_serial<T>(trans_a: bool, trans_b: bool, m: usize, n: usize, k: usize, alpha: T, a: &[T],
+                  lda: usize, b: &[T], ldb: usize, c: &mut [T], ldc: usize)
+    where T: Field + Scalar
+{
+    let kc_max: usize = KC.min(k);
+    let mut a_packed: Vec<T> = vec![T::zero(); round_up(MC.min(m), MR) * kc_max];
+    let mut b_packed: Vec<T> = vec![T::zero(); round_up(NC.min(n), NR) * kc_max];
+
+    for jc in (0..n).step_by(NC)
+    {
+        let nc: usize = NC.min(n - jc);
+
+        for pc in (0..k).step_by(KC)
+        {
+            let kc: usize = KC.min(k - pc);
+            pack_b(trans_b, kc, nc, b, ldb, pc, jc, &mut b_packed);
+
+            for ic in (0..m).step_by(MC)
+            {
+                let mc: usize = MC.min(m - ic);
+                pack_a(trans_a, mc, kc, a, lda, ic, pc, alpha, &mut a_packed);
+
+                for jr in (0..nc).step_by(NR)
+                {
+                    let nr: usize = NR.min(nc - jr);
+                    let b_panel: &[T] = &b_packed[jr * kc..(jr + NR) * kc];
+
+                    for ir in (0..mc).step_by(MR)
+                    {
+                        let mr: usize = MR.min(mc - ir);
+                        let a_panel: &[T] = &a_packed[ir * kc..(ir + MR) * kc];
+                        let offset: usize = (jc + jr) * ldc + ic + ir;
+
+                        micro_kernel(kc, a_panel, b_panel, mr, nr, &mut c[offset..], ldc);
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Multiplies the packed MR x kc micro panel of alpha op(A) with the packed kc
+/// x NR micro panel of op(B) and adds the upper left mr x nr block of the
+/// product to C
+fn micro_kernel<T>(kc: usize, a: &[T], b: &[T], mr: usize, nr: usize, c: &mut [T], ldc: usize)
+    where T: Field + Scalar
+{
+    let mut ab: [[T; MR]; NR] = [[T::zero(); MR]; NR];
+
+    for (a_p, b_p) in a.chunks_exact(MR).zip(b.chunks_exact(NR)).take(kc)
+    {
+        for (ab_j, b_pj) in ab.iter_mut().zip(b_p.iter())
+        {
+            for (ab_ij, a_ip) in ab_j.iter_mut().zip(a_p.iter())
+            {
+                *ab_ij += *a_ip * *b_pj;
+            }
+        }
+    }
+
+    for (j, ab_j) in ab.iter().enumerate().take(nr)
+    {
+        let c_j: &mut [T] = &mut c[j * ldc..j * ldc + mr];
+        for (c_ij, ab_ij) in c_j.iter_mut().zip(ab_j.iter())
+        {
+            *c_ij += *ab_ij;
+        }
+    }
+}
+
+/// Packs the mc x kc block of alpha op(A) starting at (ic, pc) into micro
+/// panels of MR rows, the last micro panel is padded with zeros
+#[allow(clippy::too_many_arguments)]
+fn pack_a<T>(trans_a: bool, mc: usize, kc: usize, a: &[T], lda: usize, ic: usize, pc: usize,
+             alpha: T, a_packed: &mut [T])
+    where T: Field + Scalar
+{
+    for ir in (0..mc).step_by(MR)
+    {
+        let mr: usize = MR.min(mc - ir);
+        let panel: &mut [T] = &mut a_packed[ir * kc..(ir + MR) * kc];
+
+        for (p, panel_p) in panel.chunks_exact_mut(MR).enumerate()
+        {
+            for (i, a_ip) in panel_p.iter_mut().enumerate()
+            {
+                *a_ip = if i < mr
+                {
+                    alpha * get(trans_a, a, lda, ic + ir + i, pc + p)
+                }
+                else
+                {
+                    T::zero()
+                };
+            }
+        }
+    }
+}
+
+/// Packs the kc x nc panel of op(B) starting at (pc, jc) into micro panels of
+/// NR columns, the last micro panel is padded with zeros
+#[allow(clippy::too_many_arguments)]
+fn pack_b<T>(trans_b: bool, kc: usize, nc: usize, b: &[T], ldb: usize, pc: usize, jc: usize,
+             b_packed: &mut [T])
+    where T: Field + Scalar
+{
+    for jr in (0..nc).step_by(NR)
+    {
+        let nr: usize = NR.min(nc - jr);
+        let panel: &mut [T] = &mut b_packed[jr * kc..(jr + NR) * kc];
+
+        for (p, panel_p) in panel.chunks_exact_mut(NR).enumerate()
+        {
+            for (j, b_pj) in panel_p.iter_mut().enumerate()
+            {
+                *b_pj = if j < nr
+                {
+                    get(trans_b, b, ldb, pc + p, jc + jr + j)
+                }
+                else
+                {
+                    T::zero()
+                };
+            }
+        }
+    }
+}
+
+/// Returns the element (i, j) of op(X)
+fn get<T>(trans: bool, x: &[T], ldx: usize, i: usize, j: usize) -> T
+    where T: Copy
+{
+    return if trans
+    {
+        x[i * ldx + j]
+    }
+    else
+    {
+        x[j * ldx + i]
+    };
+}
+
+/// Computes C = beta C, if beta is zero, C is set to zero
+fn scale<T>(m: usize, n: usize, beta: T, c: &mut [T], ldc: usize)
+    where T: Field + Scalar
+{
+    if beta == T::one()
+    {
+        return;
+    }
+
+    for j in 0..n
+    {
+        for c_ij in c[j * ldc..j * ldc + m].iter_mut()
+        {
+            *c_ij = if beta == T::zero()
+            {
+                T::zero()
+            }
+            else
+            {
+                beta * *c_ij
+            };
+        }
+    }
+}
+
+fn round_up(x: usize, multiple: usize) -> usize
+{
+    return x.div_ceil(multiple) * multiple;
+}
@@ -163,6 +163,45 @@ impl<'a, 'b, T> Mul<&'b Matrix<T>> for &'a Matrix<T> where T: Field + Scalar
 
 impl<'a, 'b, T> Matrix<T> where T: Field + Scalar
 {
+    /// Computes self = alpha op(a) op(b) + beta self
+    ///
+    /// op(X) is X if trans_x is false and X^T otherwise. This is the operation
+    /// of the BLAS routine xgemm.
+    ///
+    /// # Panics
+    ///
+    /// if the dimensions of op(a), op(b) and self do not match
+    pub fn gemm(self: &mut Self, alpha: T, a: &Matrix<T>, trans_a: bool, b: &Matrix<T>,
+                trans_b: bool, beta: T)
+    {
+        let (m, k): (usize, usize) = if trans_a { (a.n, a.m) } else { (a.m, a.n) };
+        let (b_m, n): (usize, usize) = if trans_b { (b.n, b.m) } else { (b.m, b.n) };
+        assert_eq!(k, b_m, "Matrix dimensions do not match");
+        assert_eq!((m, n), self.dim(), "Matrix dimensions do not match");
+
+        if m == 0 || n == 0
+        {
+            return;
+        }
+
+        let transa: u8 = if trans_a { b'T' } else { b'N' };
+        let transb: u8 = if trans_b { b'T' } else { b'N' };
+
+        T::xgemm(transa,
+                 transb,
+                 m as i32,
+                 n as i32,
+                 k as i32,
+                 alpha,
+                 &a.data[..],
+                 a.m.max(1) as i32,
+                 &b.data[..],
+                 b.m.max(1) as i32,
+                 beta,
+                 &mut self.data[..],
+                 m as i32);
+    }
+
     fn mul_scalar(mut self: Self, s: &'b T) -> Matrix<T>
     {
         let (rows, cols): (usize, usize) = self.dim();
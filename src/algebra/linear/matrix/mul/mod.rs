@@ -2,3 +2,5 @@
 pub mod lapack;
 #[cfg(feature = "native")]
 pub mod native;
+#[cfg(feature = "native")]
+mod gemm;
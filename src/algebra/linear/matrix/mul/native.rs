@@ -1,3 +1,4 @@
+use super::gemm::gemm;
 use crate::algebra::{
     abstr::{Field, Scalar},
     linear::{Matrix, Vector},
@@ -133,30 +134,85 @@ impl<'a, 'b, T> Mul<&'b Matrix<T>> for &'a Matrix<T> where T: Field + Scalar
     /// ```
     fn mul(self: Self, rhs: &'b Matrix<T>) -> Self::Output
     {
-        let (l_rows, l_cols) = self.dim();
+        let (l_rows, l_cols): (usize, usize) = self.dim();
         let (r_rows, r_cols): (usize, usize) = rhs.dim();
         assert_eq!(l_cols, r_rows);
 
         let mut prod: Matrix<T> = Matrix::zero(l_rows, r_cols);
 
-        for i in 0..l_rows
-        {
-            for j in 0..r_cols
-            {
-                let mut sum: T = T::zero();
-                for k in 0..l_cols
-                {
-                    sum += *self.get(i, k) * *rhs.get(k, j);
-                }
-                *prod.get_mut(i, j) = sum;
-            }
-        }
+        gemm(false,
+             false,
+             l_rows,
+             r_cols,
+             l_cols,
+             T::one(),
+             &self.data[..],
+             l_rows,
+             &rhs.data[..],
+             r_rows,
+             T::zero(),
+             &mut prod.data[..],
+             l_rows);
+
         return prod;
     }
 }
 
 impl<'a, 'b, T> Matrix<T> where T: Field + Scalar
 {
+    /// Computes self = alpha op(a) op(b) + beta self
+    ///
+    /// op(X) is X if trans_x is false and X^T otherwise. This is the operation
+    /// of the BLAS routine xgemm, the native implementation is cache-blocked
+    /// and multithreaded if the feature `rayon` is enabled.
+    ///
+    /// # Panics
+    ///
+    /// if the dimensions of op(a), op(b) and self do not match
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[macro_use]
+    /// # extern crate mathru;
+    /// # fn main()
+    /// # {
+    /// use mathru::algebra::linear::Matrix;
+    ///
+    /// let a: Matrix<f64> = matrix![   1.0, 2.0;
+    ///                                 3.0, 4.0];
+    /// let b: Matrix<f64> = matrix![   1.0, 0.0;
+    ///                                 1.0, 1.0];
+    /// let mut c: Matrix<f64> = Matrix::one(2);
+    ///
+    /// c.gemm(2.0, &a, true, &b, false, -1.0);
+    ///
+    /// assert_eq!(matrix![7.0, 6.0; 12.0, 7.0], c);
+    /// # }
+    /// ```
+    pub fn gemm(self: &mut Self, alpha: T, a: &Matrix<T>, trans_a: bool, b: &Matrix<T>,
+                trans_b: bool, beta: T)
+    {
+        let (m, k): (usize, usize) = if trans_a { (a.n, a.m) } else { (a.m, a.n) };
+        let (b_m, n): (usize, usize) = if trans_b { (b.n, b.m) } else { (b.m, b.n) };
+        assert_eq!(k, b_m, "Matrix dimensions do not match");
+        assert_eq!((m, n), self.dim(), "Matrix dimensions do not match");
+
+        gemm(trans_a,
+             trans_b,
+             m,
+             n,
+             k,
+             alpha,
+             &a.data[..],
+             a.m,
+             &b.data[..],
+             b.m,
+             beta,
+             &mut self.data[..],
+             m);
+    }
+
     fn mul_scalar(self: Self, m: &'b T) -> Matrix<T>
     {
         self.apply_mut(&|&x| x * *m)
@@ -0,0 +1,62 @@
+use crate::{
+    algebra::{abstr::Real, linear::Matrix},
+    error::MathError,
+};
+
+impl<T> Matrix<T> where T: Real
+{
+    /// Estimates the reciprocal condition number in the 1-norm
+    ///
+    /// ```math
+    /// rcond(A) = \frac{1}{\lVert A \rVert_{1} \lVert A^{-1} \rVert_{1}}
+    /// ```
+    ///
+    /// The matrix is LU decomposed with xgetrf and the condition number is
+    /// estimated with xgecon. If rcond is in the order of the machine epsilon,
+    /// the solution of Ax = b should not be trusted.
+    ///
+    /// # Return
+    ///
+    /// Zero if the matrix is singular, MathError::DimensionMismatch if the
+    /// matrix is not square
+    pub fn rcond(self: &Self) -> Result<T, MathError>
+    {
+        if self.m != self.n
+        {
+            return Err(MathError::DimensionMismatch);
+        }
+
+        let n: i32 = self.n as i32;
+        if n == 0
+        {
+            return Ok(T::zero());
+        }
+
+        let a_norm: T = self.norm_1();
+        let mut a: Vec<T> = self.data.clone();
+        let mut ipiv: Vec<i32> = vec![0; self.n];
+        let mut info: i32 = 0;
+
+        T::xgetrf(n, n, &mut a[..], n, &mut ipiv[..], &mut info);
+
+        if info > 0
+        {
+            return Ok(T::zero());
+        }
+
+        if info != 0
+        {
+            return Err(MathError::LapackInfo(info));
+        }
+
+        let mut rcond: T = T::zero();
+        T::xgecon(b'1', n, &a[..], n, a_norm, &mut rcond, &mut info);
+
+        if info != 0
+        {
+            return Err(MathError::LapackInfo(info));
+        }
+
+        return Ok(rcond);
+    }
+}
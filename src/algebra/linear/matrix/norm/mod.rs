@@ -0,0 +1,6 @@
+#[cfg(feature = "lapack")]
+pub mod lapack;
+#[cfg(feature = "native")]
+pub mod native;
+
+mod norm;
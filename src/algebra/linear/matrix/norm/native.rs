@@ -0,0 +1,59 @@
+use crate::{
+    algebra::{abstr::Real, linear::Matrix},
+    error::MathError,
+};
+
+impl<T> Matrix<T> where T: Real
+{
+    /// Estimates the reciprocal condition number in the 1-norm
+    ///
+    /// ```math
+    /// rcond(A) = \frac{1}{\lVert A \rVert_{1} \lVert A^{-1} \rVert_{1}}
+    /// ```
+    ///
+    /// The matrix is LU decomposed and the norm of the inverse is estimated
+    /// with the Hager/Higham estimator, see [`LUDec::rcond`](crate::algebra::linear::matrix::LUDec::rcond).
+    /// If rcond is in the order of the machine epsilon, the solution of Ax = b
+    /// should not be trusted.
+    ///
+    /// # Return
+    ///
+    /// Zero if the matrix is singular, MathError::DimensionMismatch if the
+    /// matrix is not square
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[macro_use]
+    /// # extern crate mathru;
+    /// # fn main()
+    /// # {
+    /// use mathru::algebra::linear::{matrix::Solve, Matrix, Vector};
+    ///
+    /// let a: Matrix<f64> = matrix![   1.0, 2.0;
+    ///                                 3.0, 4.0];
+    /// let b: Vector<f64> = vector![1.0; 2.0];
+    ///
+    /// if a.rcond().unwrap() < f64::EPSILON
+    /// {
+    ///     panic!("The matrix is ill-conditioned");
+    /// }
+    ///
+    /// let x: Vector<f64> = a.solve(&b).unwrap();
+    /// # }
+    /// ```
+    pub fn rcond(self: &Self) -> Result<T, MathError>
+    {
+        if self.m != self.n
+        {
+            return Err(MathError::DimensionMismatch);
+        }
+
+        return match self.dec_lu()
+        {
+            Ok(lu) => lu.rcond(self.norm_1()),
+            Err(MathError::Singular { .. }) => Ok(T::zero()),
+            Err(e) => Err(e),
+        };
+    }
+}
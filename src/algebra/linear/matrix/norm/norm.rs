@@ -0,0 +1,191 @@
+use crate::{
+    algebra::{
+        abstr::Real,
+        linear::{Matrix, Vector},
+    },
+    error::MathError,
+};
+
+impl<T> Matrix<T> where T: Real
+{
+    /// Computes the 1-norm, the maximum absolute column sum
+    ///
+    /// ```math
+    /// \lVert A \rVert_{1} = \max_{j} \sum_{i=1}^{m} \lvert a_{ij} \rvert
+    /// ```
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[macro_use]
+    /// # extern crate mathru;
+    /// # fn main()
+    /// # {
+    /// use mathru::algebra::linear::Matrix;
+    ///
+    /// let a: Matrix<f64> = matrix![   1.0, -2.0;
+    ///                                 -3.0, 4.0];
+    ///
+    /// assert_eq!(6.0, a.norm_1());
+    /// # }
+    /// ```
+    pub fn norm_1(self: &Self) -> T
+    {
+        let mut norm: T = T::zero();
+        for column in self.data.chunks(self.m.max(1))
+        {
+            let sum: T = column.iter().fold(T::zero(), |s, a_ij| s + a_ij.abs());
+            if sum > norm
+            {
+                norm = sum;
+            }
+        }
+        return norm;
+    }
+
+    /// Computes the infinity norm, the maximum absolute row sum
+    ///
+    /// ```math
+    /// \lVert A \rVert_{\infty} = \max_{i} \sum_{j=1}^{n} \lvert a_{ij} \rvert
+    /// ```
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[macro_use]
+    /// # extern crate mathru;
+    /// # fn main()
+    /// # {
+    /// use mathru::algebra::linear::Matrix;
+    ///
+    /// let a: Matrix<f64> = matrix![   1.0, -2.0;
+    ///                                 -3.0, 4.0];
+    ///
+    /// assert_eq!(7.0, a.norm_inf());
+    /// # }
+    /// ```
+    pub fn norm_inf(self: &Self) -> T
+    {
+        let mut sums: Vec<T> = vec![T::zero(); self.m];
+        for column in self.data.chunks(self.m.max(1))
+        {
+            for (sum, a_ij) in sums.iter_mut().zip(column.iter())
+            {
+                *sum += a_ij.abs();
+            }
+        }
+
+        return sums.into_iter().fold(T::zero(), |norm, sum| if sum > norm { sum } else { norm });
+    }
+
+    /// Computes the Frobenius norm
+    ///
+    /// ```math
+    /// \lVert A \rVert_{F} = \sqrt{\sum_{i=1}^{m} \sum_{j=1}^{n} \lvert a_{ij} \rvert^2}
+    /// ```
+    ///
+    /// The entries are scaled by the largest absolute value to avoid overflow.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[macro_use]
+    /// # extern crate mathru;
+    /// # fn main()
+    /// # {
+    /// use mathru::algebra::linear::Matrix;
+    ///
+    /// let a: Matrix<f64> = matrix![   1.0, -2.0;
+    ///                                 -2.0, 4.0];
+    ///
+    /// assert_eq!(5.0, a.norm_frobenius());
+    /// # }
+    /// ```
+    pub fn norm_frobenius(self: &Self) -> T
+    {
+        let scale: T = self.norm_max();
+        if scale == T::zero()
+        {
+            return T::zero();
+        }
+
+        let sum: T = self.data.iter().fold(T::zero(), |s, a_ij| {
+                                          let a: T = *a_ij / scale;
+                                          s + a * a
+                                      });
+        return scale * sum.sqrt();
+    }
+
+    /// Computes the largest absolute value of all entries
+    ///
+    /// ```math
+    /// \lVert A \rVert_{\max} = \max_{i, j} \lvert a_{ij} \rvert
+    /// ```
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[macro_use]
+    /// # extern crate mathru;
+    /// # fn main()
+    /// # {
+    /// use mathru::algebra::linear::Matrix;
+    ///
+    /// let a: Matrix<f64> = matrix![   1.0, -2.0;
+    ///                                 -3.0, 4.0];
+    ///
+    /// assert_eq!(4.0, a.norm_max());
+    /// # }
+    /// ```
+    pub fn norm_max(self: &Self) -> T
+    {
+        return self.data.iter().fold(T::zero(), |norm, a_ij| {
+                                   let a: T = a_ij.abs();
+                                   if a > norm
+                                   {
+                                       a
+                                   }
+                                   else
+                                   {
+                                       norm
+                                   }
+                               });
+    }
+
+    /// Computes the spectral norm, the largest singular value
+    ///
+    /// ```math
+    /// \lVert A \rVert_{2} = \sigma_{\max}(A)
+    /// ```
+    ///
+    /// # Return
+    ///
+    /// MathError::NoConvergence if the singular value decomposition does not
+    /// converge
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[macro_use]
+    /// # extern crate mathru;
+    /// # fn main()
+    /// # {
+    /// use mathru::algebra::linear::Matrix;
+    ///
+    /// let a: Matrix<f64> = matrix![   3.0, 0.0;
+    ///                                 4.0, 5.0];
+    ///
+    /// assert_relative_eq!(45.0f64.sqrt(), a.norm_2().unwrap(), epsilon=1.0e-10);
+    /// # }
+    /// ```
+    pub fn norm_2(self: &Self) -> Result<T, MathError>
+    {
+        if self.m == 0 || self.n == 0
+        {
+            return Ok(T::zero());
+        }
+
+        let sigma: Vector<T> = self.dec_svd_thin()?.singular_values();
+        return Ok(*sigma.get(0));
+    }
+}
@@ -0,0 +1,87 @@
+use crate::{
+    algebra::{
+        abstr::{Field, Scalar},
+        linear::Matrix,
+    },
+    error::MathError,
+};
+
+impl<T> Matrix<T> where T: Field + Scalar
+{
+    /// Computes the Kronecker product
+    ///
+    /// ```math
+    /// A \otimes B = \begin{pmatrix} a_{11} B & \cdots & a_{1n} B \\ \vdots & \ddots & \vdots \\ a_{m1} B & \cdots & a_{mn} B \end{pmatrix}
+    /// ```
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[macro_use]
+    /// # extern crate mathru;
+    /// # fn main()
+    /// # {
+    /// use mathru::algebra::linear::Matrix;
+    ///
+    /// let a: Matrix<f64> = matrix![1.0, 2.0];
+    /// let b: Matrix<f64> = matrix![1.0; -1.0];
+    ///
+    /// assert_eq!(matrix![1.0, 2.0; -1.0, -2.0], a.kron(&b));
+    /// # }
+    /// ```
+    pub fn kron(self: &Self, rhs: &Matrix<T>) -> Matrix<T>
+    {
+        let (p, q): (usize, usize) = rhs.dim();
+        let m: usize = self.m * p;
+        let n: usize = self.n * q;
+        let mut data: Vec<T> = Vec::with_capacity(m * n);
+
+        for j in 0..self.n
+        {
+            for l in 0..q
+            {
+                for i in 0..self.m
+                {
+                    let a_ij: T = self.data[j * self.m + i];
+                    data.extend(rhs.data[l * p..(l + 1) * p].iter().map(|b_kl| a_ij * *b_kl));
+                }
+            }
+        }
+
+        return Matrix::new(m, n, data);
+    }
+
+    /// Computes the elementwise product
+    ///
+    /// # Return
+    ///
+    /// MathError::DimensionMismatch if the dimensions of the matrices are not
+    /// equal
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[macro_use]
+    /// # extern crate mathru;
+    /// # fn main()
+    /// # {
+    /// use mathru::algebra::linear::Matrix;
+    ///
+    /// let a: Matrix<f64> = matrix![1.0, 2.0; 3.0, 4.0];
+    /// let b: Matrix<f64> = matrix![2.0, 0.5; -1.0, 0.0];
+    ///
+    /// assert_eq!(matrix![2.0, 1.0; -3.0, 0.0], a.hadamard(&b).unwrap());
+    /// # }
+    /// ```
+    pub fn hadamard(self: &Self, rhs: &Matrix<T>) -> Result<Matrix<T>, MathError>
+    {
+        if self.dim() != rhs.dim()
+        {
+            return Err(MathError::DimensionMismatch);
+        }
+
+        let data: Vec<T> = self.data.iter().zip(rhs.data.iter()).map(|(a, b)| *a * *b).collect();
+
+        return Ok(Matrix::new(self.m, self.n, data));
+    }
+}
@@ -1,9 +1,10 @@
 use crate::{
     algebra::{
-        abstr::{Field, Scalar, Zero},
-        linear::{matrix::QRDec, Matrix},
+        abstr::{Field, Real, Scalar, Zero},
+        linear::{matrix::{QRDec, QRPDec}, Matrix},
     },
     elementary::Power,
+    error::MathError,
 };
 
 impl<T> Matrix<T> where T: Field + Scalar + Power
@@ -11,8 +12,8 @@ impl<T> Matrix<T> where T: Field + Scalar + Power
     /// QR Decomposition with Givens rotations
     ///
     /// A = QR \
-    /// Q is an orthogonal matrix \
-    /// R is an upper triangular matrix \
+    /// Q is an m × m orthogonal matrix \
+    /// R is an m × n upper triangular matrix \
     ///
     /// # Panics
     ///
@@ -27,7 +28,7 @@ impl<T> Matrix<T> where T: Field + Scalar + Power
     ///
     /// let (q, r): (Matrix<f64>, Matrix<f64>) = a.dec_qr().unwrap().qr();
     /// ```
-    pub fn dec_qr(self: &Self) -> Result<QRDec<T>, ()>
+    pub fn dec_qr(self: &Self) -> Result<QRDec<T>, MathError>
     {
         let (m, n) = self.dim();
         assert!(m >= n);
@@ -54,7 +55,7 @@ impl<T> Matrix<T> where T: Field + Scalar + Power
 
         if info != 0
         {
-            return Err(())
+            return Err(MathError::LapackInfo(info))
         }
 
         let mut work: Vec<T> = vec![T::zero(); lwork as usize];
@@ -70,30 +71,34 @@ impl<T> Matrix<T> where T: Field + Scalar + Power
 
         if info != 0
         {
-            return Err(())
+            return Err(MathError::LapackInfo(info))
         }
 
         let a: Matrix<T> = Matrix::new(m, n, self_data.clone());
         let r: Matrix<T> = a.r();
 
+        // the reflectors generate the complete m x m matrix Q
+        let mut q_data: Vec<T> = vec![T::zero(); m * m];
+        q_data[..m * n].copy_from_slice(&self_data[..]);
+
         let lwork = T::xorgqr_work_size(m_i32,
-                                        m_n_min as i32,
+                                        m_i32,
                                         tau.len() as i32,
-                                        &mut self_data[..],
+                                        &mut q_data[..],
                                         m_i32,
                                         &mut tau[..],
                                         &mut info);
         if info != 0
         {
-            return Err(())
+            return Err(MathError::LapackInfo(info))
         }
 
         let mut work = vec![T::zero(); lwork as usize];
 
         T::xorgqr(m_i32,
-                  m_n_min as i32,
+                  m_i32,
                   tau.len() as i32,
-                  &mut self_data[..],
+                  &mut q_data[..],
                   m_i32,
                   &mut tau[..],
                   &mut work,
@@ -102,11 +107,10 @@ impl<T> Matrix<T> where T: Field + Scalar + Power
 
         if info != 0
         {
-            return Err(())
+            return Err(MathError::LapackInfo(info))
         }
 
-
-        let q: Matrix<T> = Matrix::new(m, n, self_data);
+        let q: Matrix<T> = Matrix::new(m, m, q_data);
 
         return Ok(QRDec::new(q, r));
     }
@@ -124,3 +128,119 @@ impl<T> Matrix<T> where T: Field + Scalar + Power
         self
     }
 }
+
+impl<T> Matrix<T> where T: Real
+{
+    /// QR decomposition with column pivoting
+    ///
+    /// A P = Q R
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[macro_use]
+    /// # extern crate mathru;
+    /// # fn main()
+    /// # {
+    /// use mathru::algebra::linear::Matrix;
+    ///
+    /// let a: Matrix<f64> = matrix![   1.0, 2.0;
+    ///                                 3.0, 4.0;
+    ///                                 5.0, 6.0];
+    ///
+    /// let (q, r, p): (Matrix<f64>, Matrix<f64>, Matrix<f64>) = a.dec_qrp().unwrap().qrp();
+    ///
+    /// assert_relative_eq!(&a * &p, &q * &r, epsilon=1.0e-10);
+    /// # }
+    /// ```
+    pub fn dec_qrp(self: &Self) -> Result<QRPDec<T>, MathError>
+    {
+        let (m, n): (usize, usize) = self.dim();
+        let k: usize = m.min(n);
+
+        let mut self_data: Vec<T> = self.clone().data;
+
+        let m_i32: i32 = m as i32;
+        let n_i32: i32 = n as i32;
+        let lda: i32 = m_i32.max(1);
+
+        let mut jpvt: Vec<i32> = vec![0; n];
+        let mut tau: Vec<T> = vec![T::zero(); k];
+        let mut info: i32 = 0;
+
+        let lwork: i32 = T::xgeqp3_work_size(m_i32,
+                                             n_i32,
+                                             &mut self_data[..],
+                                             lda,
+                                             &mut jpvt[..],
+                                             &mut tau[..],
+                                             &mut info);
+
+        if info != 0
+        {
+            return Err(MathError::LapackInfo(info));
+        }
+
+        let mut work: Vec<T> = vec![T::zero(); lwork as usize];
+
+        T::xgeqp3(m_i32,
+                  n_i32,
+                  &mut self_data[..],
+                  lda,
+                  &mut jpvt[..],
+                  &mut tau[..],
+                  &mut work[..],
+                  lwork,
+                  &mut info);
+
+        if info != 0
+        {
+            return Err(MathError::LapackInfo(info));
+        }
+
+        let mut r: Matrix<T> = Matrix::zero(k, n);
+        for j in 0..n
+        {
+            for i in 0..k.min(j + 1)
+            {
+                *r.get_mut(i, j) = self_data[j * m + i];
+            }
+        }
+
+        let lwork: i32 = T::xorgqr_work_size(m_i32,
+                                             k as i32,
+                                             k as i32,
+                                             &mut self_data[..],
+                                             lda,
+                                             &mut tau[..],
+                                             &mut info);
+
+        if info != 0
+        {
+            return Err(MathError::LapackInfo(info));
+        }
+
+        let mut work: Vec<T> = vec![T::zero(); lwork as usize];
+
+        T::xorgqr(m_i32,
+                  k as i32,
+                  k as i32,
+                  &mut self_data[..],
+                  lda,
+                  &mut tau[..],
+                  &mut work[..],
+                  lwork,
+                  &mut info);
+
+        if info != 0
+        {
+            return Err(MathError::LapackInfo(info));
+        }
+
+        self_data.truncate(m * k);
+        let q: Matrix<T> = Matrix::new(m, k, self_data);
+        let pivot: Vec<usize> = jpvt.iter().map(|p| (*p - 1) as usize).collect();
+
+        return Ok(QRPDec::new(q, r, pivot));
+    }
+}
@@ -5,3 +5,6 @@ pub mod native;
 
 pub mod qrdec;
 pub use self::qrdec::QRDec;
+
+pub mod qrpdec;
+pub use self::qrpdec::QRPDec;
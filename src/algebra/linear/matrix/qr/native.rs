@@ -1,9 +1,10 @@
 use crate::{
     algebra::{
-        abstr::{Field, Scalar},
-        linear::{matrix::{Transpose, QRDec}, Matrix},
+        abstr::{Field, Real, Scalar},
+        linear::{matrix::{ConjugateTranspose, QRDec, QRPDec}, Matrix},
     },
     elementary::Power,
+    error::MathError,
 };
 use crate::algebra::abstr::AbsDiffEq;
 
@@ -13,8 +14,8 @@ impl<T> Matrix<T>
     /// QR Decomposition with Givens rotations
     ///
     /// A = QR \
-    /// Q is an orthogonal matrix \
-    /// R is an upper triangular matrix \
+    /// Q is an m × m orthogonal matrix, or a unitary matrix if A is complex \
+    /// R is an m × n upper triangular matrix \
     ///
     /// # Panics
     ///
@@ -29,7 +30,7 @@ impl<T> Matrix<T>
     ///
     /// let (q, r): (Matrix<f64>, Matrix<f64>) = a.dec_qr().unwrap().qr();
     /// ```
-    pub fn dec_qr(self: &Self) -> Result<QRDec<T>, ()>
+    pub fn dec_qr(self: &Self) -> Result<QRDec<T>, MathError>
     {
         let (m, n) = self.dim();
         assert!(m >= n);
@@ -44,20 +45,135 @@ impl<T> Matrix<T>
                 let a_jj: T = *r.get(j, j);
                 let a_ij: T = *r.get(i, j);
 
-                let p: T = (a_jj * a_jj + a_ij * a_ij).sqrt();
+                let p: T = (a_jj * a_jj.conj() + a_ij * a_ij.conj()).sqrt();
 
-                if p.abs_diff_ne(&T::zero(), T::default_epsilon()) && a_jj.abs_diff_ne(&T::zero(), T::default_epsilon()) && a_ij.abs_diff_ne(&T::zero(), T::default_epsilon())
+                if p.abs_diff_ne(&T::zero(), T::default_epsilon()) && a_ij.abs_diff_ne(&T::zero(), T::default_epsilon())
                 {
                     let c: T = a_jj / p;
                     let s: T = -a_ij / p;
-                    let g_ij: Matrix<T> = Matrix::givens(r.m, i, j, c, s);
+                    // unitary rotation, the row j is conjugated for complex matrices
+                    let mut g_ij: Matrix<T> = Matrix::givens(r.m, i, j, c, s);
+                    *g_ij.get_mut(j, j) = c.conj();
+                    *g_ij.get_mut(j, i) = -s.conj();
 
                     r = &g_ij * &r;
                     q = &g_ij * &q;
                 }
             }
         }
-        q = q.transpose();
+        q = q.conj_transpose();
         return Ok(QRDec::new(q, r));
     }
 }
+
+impl<T> Matrix<T> where T: Real
+{
+    /// QR decomposition with column pivoting
+    ///
+    /// A P = Q R
+    ///
+    /// In every step the remaining column with the largest norm is moved to
+    /// the front and eliminated with a Householder reflection (Businger and
+    /// Golub). The matrix may have any shape.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[macro_use]
+    /// # extern crate mathru;
+    /// # fn main()
+    /// # {
+    /// use mathru::algebra::linear::Matrix;
+    ///
+    /// let a: Matrix<f64> = matrix![   1.0, 2.0;
+    ///                                 3.0, 4.0;
+    ///                                 5.0, 6.0];
+    ///
+    /// let (q, r, p): (Matrix<f64>, Matrix<f64>, Matrix<f64>) = a.dec_qrp().unwrap().qrp();
+    ///
+    /// assert_relative_eq!(&a * &p, &q * &r, epsilon=1.0e-10);
+    /// # }
+    /// ```
+    pub fn dec_qrp(self: &Self) -> Result<QRPDec<T>, MathError>
+    {
+        let (m, n): (usize, usize) = self.dim();
+        let k: usize = m.min(n);
+
+        let mut a: Vec<T> = self.data.clone();
+        let mut pivot: Vec<usize> = (0..n).collect();
+        let mut reflectors: Vec<(Vec<T>, T)> = Vec::with_capacity(k);
+
+        for j in 0..k
+        {
+            // move the column with the largest remaining norm to the front
+            let mut p: usize = j;
+            let mut norm_max: T = -T::one();
+            for l in j..n
+            {
+                let norm: T = (j..m).fold(T::zero(), |acc, i| acc + a[l * m + i] * a[l * m + i]);
+                if norm > norm_max
+                {
+                    norm_max = norm;
+                    p = l;
+                }
+            }
+            if p != j
+            {
+                for i in 0..m
+                {
+                    a.swap(j * m + i, p * m + i);
+                }
+                pivot.swap(j, p);
+            }
+
+            // Householder reflection H = I - beta v v^T, which maps a[j.., j] to
+            // alpha e_1
+            let norm: T = norm_max.sqrt();
+            let a_jj: T = a[j * m + j];
+            let alpha: T = if a_jj > T::zero() { -norm } else { norm };
+            let mut v: Vec<T> = a[j * m + j..(j + 1) * m].to_vec();
+            v[0] -= alpha;
+            let v_norm: T = v.iter().fold(T::zero(), |acc, v_i| acc + *v_i * *v_i);
+            let beta: T = if v_norm == T::zero() { T::zero() } else { T::from_f64(2.0) / v_norm };
+
+            for l in j..n
+            {
+                let dot: T = (j..m).fold(T::zero(), |acc, i| acc + v[i - j] * a[l * m + i]);
+                for i in j..m
+                {
+                    a[l * m + i] -= beta * dot * v[i - j];
+                }
+            }
+            reflectors.push((v, beta));
+        }
+
+        // Q = H_0 H_1 ... H_{k-1} I_{m x k}
+        let mut q: Matrix<T> = Matrix::zero(m, k);
+        for i in 0..k
+        {
+            *q.get_mut(i, i) = T::one();
+        }
+        for (j, (v, beta)) in reflectors.iter().enumerate().rev()
+        {
+            for l in 0..k
+            {
+                let dot: T = (j..m).fold(T::zero(), |acc, i| acc + v[i - j] * *q.get(i, l));
+                for i in j..m
+                {
+                    *q.get_mut(i, l) -= *beta * dot * v[i - j];
+                }
+            }
+        }
+
+        let mut r: Matrix<T> = Matrix::zero(k, n);
+        for l in 0..n
+        {
+            for i in 0..k.min(l + 1)
+            {
+                *r.get_mut(i, l) = a[l * m + i];
+            }
+        }
+
+        return Ok(QRPDec::new(q, r, pivot));
+    }
+}
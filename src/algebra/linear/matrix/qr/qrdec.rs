@@ -1,4 +1,10 @@
-use crate::algebra::linear::Matrix;
+use crate::{
+    algebra::{
+        abstr::Real,
+        linear::{Matrix, Vector},
+    },
+    error::MathError,
+};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 use std::clone::Clone;
@@ -42,3 +48,335 @@ impl<T> QRDec<T>
         return (self.q, self.r);
     }
 }
+
+impl<T> QRDec<T> where T: Real
+{
+    /// Updates the decomposition of A to the decomposition of the matrix,
+    /// where the row x is inserted before the k-th row of A
+    ///
+    /// The factors are updated with Givens rotations in O(m (m + n))
+    /// operations.
+    ///
+    /// # Return
+    ///
+    /// MathError::DimensionMismatch if k > m or x does not have n entries
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[macro_use]
+    /// # extern crate mathru;
+    /// # fn main()
+    /// # {
+    /// use mathru::algebra::linear::{matrix::QRDec, Matrix, Vector};
+    ///
+    /// let a: Matrix<f64> = matrix![   1.0, 2.0;
+    ///                                 3.0, 4.0];
+    /// let x: Vector<f64> = vector![5.0, 6.0];
+    ///
+    /// let mut dec: QRDec<f64> = a.dec_qr().unwrap();
+    /// dec.insert_row(1, &x).unwrap();
+    /// let (q, r): (Matrix<f64>, Matrix<f64>) = dec.qr();
+    ///
+    /// let a_x: Matrix<f64> = matrix![ 1.0, 2.0;
+    ///                                 5.0, 6.0;
+    ///                                 3.0, 4.0];
+    ///
+    /// assert_relative_eq!(a_x, &q * &r, epsilon=1.0e-10);
+    /// # }
+    /// ```
+    pub fn insert_row(self: &mut Self, k: usize, x: &Vector<T>) -> Result<(), MathError>
+    {
+        let (m, n): (usize, usize) = self.r.dim();
+        if k > m
+        {
+            return Err(MathError::DimensionMismatch);
+        }
+        let x: Vec<T> = entries(x, n)?;
+
+        // [x^T; A] = diag(1, Q) [x^T; R], the first row is moved to row k
+        let mut q: Matrix<T> = Matrix::zero(m + 1, m + 1);
+        *q.get_mut(k, 0) = T::one();
+        for j in 0..m
+        {
+            for i in 0..m
+            {
+                let i_q: usize = if i < k { i } else { i + 1 };
+                *q.get_mut(i_q, j + 1) = *self.q.get(i, j);
+            }
+        }
+
+        let mut r: Matrix<T> = Matrix::zero(m + 1, n);
+        for j in 0..n
+        {
+            *r.get_mut(0, j) = x[j];
+            for i in 0..m.min(j + 1)
+            {
+                *r.get_mut(i + 1, j) = *self.r.get(i, j);
+            }
+        }
+
+        // [x^T; R] is upper Hessenberg
+        for j in 0..n.min(m)
+        {
+            let (c, s): (T, T) = Matrix::givens_cosine_sine_pair(*r.get(j, j), *r.get(j + 1, j));
+            rotate_rows(&mut r, j, j + 1, c, s, j);
+            rotate_columns(&mut q, j, j + 1, c, s);
+            *r.get_mut(j + 1, j) = T::zero();
+        }
+
+        self.q = q;
+        self.r = r;
+        return Ok(());
+    }
+
+    /// Updates the decomposition of A to the decomposition of the matrix,
+    /// where the k-th row of A is deleted
+    ///
+    /// The factors are updated with Givens rotations in O(m (m + n))
+    /// operations.
+    ///
+    /// # Return
+    ///
+    /// MathError::DimensionMismatch if k >= m
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[macro_use]
+    /// # extern crate mathru;
+    /// # fn main()
+    /// # {
+    /// use mathru::algebra::linear::{matrix::QRDec, Matrix};
+    ///
+    /// let a: Matrix<f64> = matrix![   1.0, 2.0;
+    ///                                 5.0, 6.0;
+    ///                                 3.0, 4.0];
+    ///
+    /// let mut dec: QRDec<f64> = a.dec_qr().unwrap();
+    /// dec.delete_row(1).unwrap();
+    /// let (q, r): (Matrix<f64>, Matrix<f64>) = dec.qr();
+    ///
+    /// assert_relative_eq!(matrix![1.0, 2.0; 3.0, 4.0], &q * &r, epsilon=1.0e-10);
+    /// # }
+    /// ```
+    pub fn delete_row(self: &mut Self, k: usize) -> Result<(), MathError>
+    {
+        let (m, n): (usize, usize) = self.r.dim();
+        if k >= m
+        {
+            return Err(MathError::DimensionMismatch);
+        }
+
+        // rotate the k-th row of Q to a multiple of e_1^T, the first row of R
+        // then only contributes to the k-th row of A
+        let mut q_k: Vec<T> = (0..m).map(|j| *self.q.get(k, j)).collect();
+        for j in (0..m.saturating_sub(1)).rev()
+        {
+            let (c, s): (T, T) = Matrix::givens_cosine_sine_pair(q_k[j], q_k[j + 1]);
+            q_k[j] = c * q_k[j] - s * q_k[j + 1];
+            q_k[j + 1] = T::zero();
+            rotate_rows(&mut self.r, j, j + 1, c, s, j);
+            rotate_columns(&mut self.q, j, j + 1, c, s);
+        }
+
+        let mut q: Matrix<T> = Matrix::zero(m - 1, m - 1);
+        for j in 0..(m - 1)
+        {
+            for i in 0..(m - 1)
+            {
+                let i_q: usize = if i < k { i } else { i + 1 };
+                *q.get_mut(i, j) = *self.q.get(i_q, j + 1);
+            }
+        }
+
+        let mut r: Matrix<T> = Matrix::zero(m - 1, n);
+        for j in 0..n
+        {
+            for i in 0..(m - 1).min(j + 1)
+            {
+                *r.get_mut(i, j) = *self.r.get(i + 1, j);
+            }
+        }
+
+        self.q = q;
+        self.r = r;
+        return Ok(());
+    }
+
+    /// Updates the decomposition of A to the decomposition of the matrix,
+    /// where the column x is inserted before the k-th column of A
+    ///
+    /// The factors are updated with Givens rotations in O(m (m + n))
+    /// operations.
+    ///
+    /// # Return
+    ///
+    /// MathError::DimensionMismatch if k > n or x does not have m entries
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[macro_use]
+    /// # extern crate mathru;
+    /// # fn main()
+    /// # {
+    /// use mathru::algebra::linear::{matrix::QRDec, Matrix, Vector};
+    ///
+    /// let a: Matrix<f64> = matrix![   1.0, 2.0;
+    ///                                 3.0, 4.0;
+    ///                                 5.0, 6.0];
+    /// let x: Vector<f64> = vector![1.0; 0.0; -1.0];
+    ///
+    /// let mut dec: QRDec<f64> = a.dec_qr().unwrap();
+    /// dec.insert_column(0, &x).unwrap();
+    /// let (q, r): (Matrix<f64>, Matrix<f64>) = dec.qr();
+    ///
+    /// let a_x: Matrix<f64> = matrix![ 1.0, 1.0, 2.0;
+    ///                                 0.0, 3.0, 4.0;
+    ///                                 -1.0, 5.0, 6.0];
+    ///
+    /// assert_relative_eq!(a_x, &q * &r, epsilon=1.0e-10);
+    /// # }
+    /// ```
+    pub fn insert_column(self: &mut Self, k: usize, x: &Vector<T>) -> Result<(), MathError>
+    {
+        let (m, n): (usize, usize) = self.r.dim();
+        if k > n
+        {
+            return Err(MathError::DimensionMismatch);
+        }
+        let x: Vec<T> = entries(x, m)?;
+
+        // [R_1, Q^T x, R_2]
+        let mut r: Matrix<T> = Matrix::zero(m, n + 1);
+        for j in 0..(n + 1)
+        {
+            if j == k
+            {
+                for i in 0..m
+                {
+                    *r.get_mut(i, j) = (0..m).fold(T::zero(), |sum, l| sum + *self.q.get(l, i) * x[l]);
+                }
+            }
+            else
+            {
+                let j_r: usize = if j < k { j } else { j - 1 };
+                for i in 0..m.min(j_r + 1)
+                {
+                    *r.get_mut(i, j) = *self.r.get(i, j_r);
+                }
+            }
+        }
+
+        for i in ((k + 1)..m).rev()
+        {
+            let (c, s): (T, T) = Matrix::givens_cosine_sine_pair(*r.get(i - 1, k), *r.get(i, k));
+            rotate_rows(&mut r, i - 1, i, c, s, k);
+            rotate_columns(&mut self.q, i - 1, i, c, s);
+            *r.get_mut(i, k) = T::zero();
+        }
+
+        self.r = r;
+        return Ok(());
+    }
+
+    /// Updates the decomposition of A to the decomposition of the matrix,
+    /// where the k-th column of A is deleted
+    ///
+    /// The factors are updated with Givens rotations in O(m (m + n))
+    /// operations.
+    ///
+    /// # Return
+    ///
+    /// MathError::DimensionMismatch if k >= n
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[macro_use]
+    /// # extern crate mathru;
+    /// # fn main()
+    /// # {
+    /// use mathru::algebra::linear::{matrix::QRDec, Matrix};
+    ///
+    /// let a: Matrix<f64> = matrix![   1.0, 1.0, 2.0;
+    ///                                 0.0, 3.0, 4.0;
+    ///                                 -1.0, 5.0, 6.0];
+    ///
+    /// let mut dec: QRDec<f64> = a.dec_qr().unwrap();
+    /// dec.delete_column(1).unwrap();
+    /// let (q, r): (Matrix<f64>, Matrix<f64>) = dec.qr();
+    ///
+    /// assert_relative_eq!(matrix![1.0, 2.0; 0.0, 4.0; -1.0, 6.0], &q * &r, epsilon=1.0e-10);
+    /// # }
+    /// ```
+    pub fn delete_column(self: &mut Self, k: usize) -> Result<(), MathError>
+    {
+        let (m, n): (usize, usize) = self.r.dim();
+        if k >= n
+        {
+            return Err(MathError::DimensionMismatch);
+        }
+
+        let mut r: Matrix<T> = Matrix::zero(m, n - 1);
+        for j in 0..(n - 1)
+        {
+            let j_r: usize = if j < k { j } else { j + 1 };
+            for i in 0..m.min(j_r + 1)
+            {
+                *r.get_mut(i, j) = *self.r.get(i, j_r);
+            }
+        }
+
+        // R without the k-th column is upper Hessenberg from column k on
+        for j in k..(n - 1).min(m.saturating_sub(1))
+        {
+            let (c, s): (T, T) = Matrix::givens_cosine_sine_pair(*r.get(j, j), *r.get(j + 1, j));
+            rotate_rows(&mut r, j, j + 1, c, s, j);
+            rotate_columns(&mut self.q, j, j + 1, c, s);
+            *r.get_mut(j + 1, j) = T::zero();
+        }
+
+        self.r = r;
+        return Ok(());
+    }
+}
+
+fn entries<T>(x: &Vector<T>, n: usize) -> Result<Vec<T>, MathError> where T: Real
+{
+    let (x_m, x_n): (usize, usize) = x.dim();
+    if x_m * x_n != n
+    {
+        return Err(MathError::DimensionMismatch);
+    }
+
+    return Ok(x.iter().copied().collect());
+}
+
+/// Applies [c s; -s c]^T to the rows i and j of a, starting at the column
+/// start
+fn rotate_rows<T>(a: &mut Matrix<T>, i: usize, j: usize, c: T, s: T, start: usize) where T: Real
+{
+    let (_, n): (usize, usize) = a.dim();
+    for l in start..n
+    {
+        let a_il: T = *a.get(i, l);
+        let a_jl: T = *a.get(j, l);
+        *a.get_mut(i, l) = c * a_il - s * a_jl;
+        *a.get_mut(j, l) = s * a_il + c * a_jl;
+    }
+}
+
+/// Applies [c s; -s c] to the columns i and j of a from the right
+fn rotate_columns<T>(a: &mut Matrix<T>, i: usize, j: usize, c: T, s: T) where T: Real
+{
+    let (m, _): (usize, usize) = a.dim();
+    for l in 0..m
+    {
+        let a_li: T = *a.get(l, i);
+        let a_lj: T = *a.get(l, j);
+        *a.get_mut(l, i) = c * a_li - s * a_lj;
+        *a.get_mut(l, j) = s * a_li + c * a_lj;
+    }
+}
@@ -0,0 +1,102 @@
+use crate::algebra::{abstr::Real, linear::Matrix};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::clone::Clone;
+
+/// QR decomposition with column pivoting
+///
+/// A P = Q R
+///
+/// With k = min(m, n), Q is a m x k matrix with orthonormal columns, R is a
+/// k x n upper trapezoidal matrix and P is a n x n permutation matrix. The
+/// columns are permuted such that the absolute values of the diagonal entries
+/// of R are non-increasing.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct QRPDec<T>
+{
+    q: Matrix<T>,
+    r: Matrix<T>,
+    pivot: Vec<usize>,
+}
+
+impl<T> QRPDec<T>
+{
+    pub(super) fn new(q: Matrix<T>, r: Matrix<T>, pivot: Vec<usize>) -> QRPDec<T>
+    {
+        return QRPDec { q, r, pivot };
+    }
+
+    /// Return the q matrix of the decomposition
+    pub fn q(self: Self) -> Matrix<T>
+    {
+        return self.q;
+    }
+
+    /// Return the r matrix of the decomposition
+    pub fn r(self: Self) -> Matrix<T>
+    {
+        return self.r;
+    }
+
+    /// Return the column permutation
+    ///
+    /// Column j of A P is column pivot[j] of A.
+    pub fn pivot(self: &Self) -> &[usize]
+    {
+        return &self.pivot;
+    }
+}
+
+impl<T> QRPDec<T> where T: Real
+{
+    /// Return the permutation matrix P
+    pub fn p(self: Self) -> Matrix<T>
+    {
+        return self.permutation();
+    }
+
+    /// Return the q, r, and p matrix of the decomposition
+    pub fn qrp(self: Self) -> (Matrix<T>, Matrix<T>, Matrix<T>)
+    {
+        let p: Matrix<T> = self.permutation();
+        return (self.q, self.r, p);
+    }
+
+    /// Returns the numerical rank, the number of diagonal entries of R, whose
+    /// absolute values are greater than tol
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[macro_use]
+    /// # extern crate mathru;
+    /// # fn main()
+    /// # {
+    /// use mathru::algebra::linear::Matrix;
+    ///
+    /// let a: Matrix<f64> = matrix![   1.0, 2.0, 3.0;
+    ///                                 4.0, 5.0, 6.0;
+    ///                                 7.0, 8.0, 9.0];
+    ///
+    /// assert_eq!(2, a.dec_qrp().unwrap().rank(1.0e-10));
+    /// # }
+    /// ```
+    pub fn rank(self: &Self, tol: T) -> usize
+    {
+        let (k, _): (usize, usize) = self.r.dim();
+        return (0..k).take_while(|i| self.r.get(*i, *i).abs() > tol).count();
+    }
+
+    fn permutation(self: &Self) -> Matrix<T>
+    {
+        let n: usize = self.pivot.len();
+        let mut p: Matrix<T> = Matrix::zero(n, n);
+        for (j, p_j) in self.pivot.iter().enumerate()
+        {
+            *p.get_mut(*p_j, j) = T::one();
+        }
+
+        return p;
+    }
+}
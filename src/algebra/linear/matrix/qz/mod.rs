@@ -0,0 +1,377 @@
+pub mod qzdec;
+pub use self::qzdec::QZDec;
+
+use super::schur::complex::{abs, complexify, givens, norm_1, scale, wilkinson_shift};
+use crate::{
+    algebra::{
+        abstr::{Complex, Real, Scalar, Zero},
+        linear::Matrix,
+    },
+    error::MathError,
+};
+use std::ops::Range;
+
+impl<T> Matrix<T> where T: Real, Complex<T>: Scalar
+{
+    /// Computes the generalized Schur decomposition of the matrix pencil
+    /// A - λB with the QZ algorithm
+    ///
+    /// A = Q S Z^H \
+    /// B = Q T Z^H
+    ///
+    /// The pencil is reduced to Hessenberg-triangular form, afterwards the
+    /// single shift QZ algorithm of Moler and Stewart is applied in complex
+    /// arithmetic. The generalized eigenvalues are λ_i = s_ii / t_ii. A
+    /// diagonal element of T, which is smaller than n ε ||B||_1, is set to
+    /// zero and moved to the bottom of T, such that the infinite eigenvalues
+    /// are deflated exactly.
+    ///
+    /// # Arguments
+    ///
+    /// * `b`: matrix B of the pencil A - λB
+    ///
+    /// # Return
+    ///
+    /// MathError::DimensionMismatch if A is not square or empty or if A and
+    /// B do not have the same dimension, MathError::NoConvergence if the QZ
+    /// iteration does not converge
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[macro_use]
+    /// # extern crate mathru;
+    /// # fn main()
+    /// # {
+    /// use mathru::algebra::{abstr::Complex, linear::{matrix::QZDec, Matrix, Vector}};
+    ///
+    /// // descriptor system with a singular mass matrix
+    /// let a: Matrix<f64> = matrix![   2.0, 1.0;
+    ///                                 0.0, 1.0];
+    /// let b: Matrix<f64> = matrix![   1.0, 0.0;
+    ///                                 0.0, 0.0];
+    ///
+    /// let qz: QZDec<f64> = a.dec_qz(&b).unwrap();
+    /// let beta: Vector<f64> = qz.beta();
+    /// let value: Vector<Complex<f64>> = qz.value();
+    ///
+    /// assert_eq!(0.0, *beta.get(1));
+    /// assert!(value.get(1).re.is_infinite());
+    /// assert_relative_eq!(Complex::new(2.0, 0.0), *value.get(0), epsilon=Complex::new(1.0e-10, 1.0e-10));
+    /// # }
+    /// ```
+    pub fn dec_qz(self: &Self, b: &Matrix<T>) -> Result<QZDec<T>, MathError>
+    {
+        return complexify(self).dec_qz(&complexify(b));
+    }
+}
+
+impl<T> Matrix<Complex<T>> where T: Real, Complex<T>: Scalar
+{
+    /// Computes the generalized Schur decomposition of the complex matrix
+    /// pencil A - λB with the QZ algorithm
+    ///
+    /// A = Q S Z^H \
+    /// B = Q T Z^H
+    ///
+    /// # Return
+    ///
+    /// MathError::DimensionMismatch if A is not square or empty or if A and
+    /// B do not have the same dimension, MathError::NoConvergence if the QZ
+    /// iteration does not converge
+    pub fn dec_qz(self: &Self, b: &Matrix<Complex<T>>) -> Result<QZDec<T>, MathError>
+    {
+        let (m, n): (usize, usize) = self.dim();
+        if m != n || m == 0 || b.dim() != (m, n)
+        {
+            return Err(MathError::DimensionMismatch);
+        }
+
+        let mut s: Matrix<Complex<T>> = self.clone();
+        let mut t: Matrix<Complex<T>> = b.clone();
+        let mut q: Matrix<Complex<T>> = Matrix::one(n);
+        let mut z: Matrix<Complex<T>> = Matrix::one(n);
+
+        hessenberg_triangular(&mut s, &mut t, &mut q, &mut z);
+
+        let eps: T = T::default_epsilon();
+        let a_tol: T = eps * norm_1(&s);
+        let b_tol: T = eps * T::from_f64(n as f64) * norm_1(&t);
+        let max_iter: usize = 30 * n;
+
+        let mut ilast: usize = n - 1;
+        let mut iter: usize = 0;
+        while ilast > 0
+        {
+            if abs(s.get(ilast, ilast - 1)) <= a_tol
+            {
+                *s.get_mut(ilast, ilast - 1) = Complex::zero();
+                ilast -= 1;
+                iter = 0;
+                continue;
+            }
+
+            if abs(t.get(ilast, ilast)) <= b_tol
+            {
+                // infinite eigenvalue, annihilate s_{ilast, ilast - 1}
+                *t.get_mut(ilast, ilast) = Complex::zero();
+                let (c, sn): (T, Complex<T>) = givens(*s.get(ilast, ilast), *s.get(ilast, ilast - 1));
+                rotate_columns(&mut s, ilast - 1, c, sn, 0..(ilast + 1));
+                rotate_columns(&mut t, ilast - 1, c, sn, 0..ilast);
+                rotate_columns(&mut z, ilast - 1, c, sn, 0..n);
+                *s.get_mut(ilast, ilast - 1) = Complex::zero();
+                ilast -= 1;
+                iter = 0;
+                continue;
+            }
+
+            // look for the beginning of the active block and for zeros on the
+            // diagonal of T
+            let mut ifirst: usize = ilast - 1;
+            let mut chased: bool = false;
+            loop
+            {
+                if abs(t.get(ifirst, ifirst)) <= b_tol
+                {
+                    *t.get_mut(ifirst, ifirst) = Complex::zero();
+                    chase_zero(&mut s, &mut t, &mut q, &mut z, ifirst, ilast);
+                    chased = true;
+                    break;
+                }
+                if ifirst == 0
+                {
+                    break;
+                }
+                if abs(s.get(ifirst, ifirst - 1)) <= a_tol
+                {
+                    *s.get_mut(ifirst, ifirst - 1) = Complex::zero();
+                    break;
+                }
+                ifirst -= 1;
+            }
+
+            if chased
+            {
+                continue;
+            }
+
+            iter += 1;
+            if iter > max_iter
+            {
+                return Err(MathError::NoConvergence { iterations: max_iter });
+            }
+
+            let mu: Complex<T> = if iter % 10 == 0
+            {
+                // exceptional shift
+                *s.get(ilast, ilast) / *t.get(ilast, ilast)
+                + Complex::new(T::from_f64(1.5) * abs(&(*s.get(ilast, ilast - 1) / *t.get(ilast - 1, ilast - 1))),
+                               T::zero())
+            }
+            else
+            {
+                // eigenvalue of the trailing 2 x 2 pencil
+                let t_11: Complex<T> = *t.get(ilast - 1, ilast - 1);
+                let t_12: Complex<T> = *t.get(ilast - 1, ilast);
+                let t_22: Complex<T> = *t.get(ilast, ilast);
+                let a_11: Complex<T> = *s.get(ilast - 1, ilast - 1);
+                let a_12: Complex<T> = *s.get(ilast - 1, ilast);
+                let a_21: Complex<T> = *s.get(ilast, ilast - 1);
+                let a_22: Complex<T> = *s.get(ilast, ilast);
+
+                wilkinson_shift(a_11 / t_11,
+                                (a_12 - a_11 * t_12 / t_11) / t_22,
+                                a_21 / t_11,
+                                (a_22 - a_21 * t_12 / t_11) / t_22)
+            };
+
+            qz_step(&mut s, &mut t, &mut q, &mut z, ifirst, ilast, mu);
+        }
+
+        // remove the rounding errors beneath the diagonal and scale the
+        // diagonal of T to real non negative values
+        for j in 0..n
+        {
+            for i in (j + 1)..n
+            {
+                *s.get_mut(i, j) = Complex::zero();
+                *t.get_mut(i, j) = Complex::zero();
+            }
+
+            let t_jj: Complex<T> = *t.get(j, j);
+            let abs_t_jj: T = abs(&t_jj);
+            if abs_t_jj <= b_tol
+            {
+                *t.get_mut(j, j) = Complex::zero();
+            }
+            else
+            {
+                let phase: Complex<T> = Complex::new(t_jj.re / abs_t_jj, t_jj.im / abs_t_jj);
+                for k in j..n
+                {
+                    *s.get_mut(j, k) *= phase.conj();
+                    *t.get_mut(j, k) *= phase.conj();
+                }
+                for i in 0..n
+                {
+                    *q.get_mut(i, j) *= phase;
+                }
+                *t.get_mut(j, j) = Complex::new(abs_t_jj, T::zero());
+            }
+        }
+
+        return Ok(QZDec::new(q, z, s, t));
+    }
+}
+
+/// Reduces the pencil (S, T) to Hessenberg-triangular form with Givens
+/// rotations
+///
+/// At first T is reduced to upper triangular form, afterwards the elements
+/// of S beneath the subdiagonal are annihilated column by column. The
+/// rotations from the left are accumulated in Q, the rotations from the right
+/// in Z.
+fn hessenberg_triangular<T>(s: &mut Matrix<Complex<T>>,
+                            t: &mut Matrix<Complex<T>>,
+                            q: &mut Matrix<Complex<T>>,
+                            z: &mut Matrix<Complex<T>>)
+    where T: Real,
+          Complex<T>: Scalar
+{
+    let (n, _): (usize, usize) = s.dim();
+
+    for j in 0..n
+    {
+        for i in ((j + 1)..n).rev()
+        {
+            let (c, sn): (T, Complex<T>) = givens(*t.get(i - 1, j), *t.get(i, j));
+            rotate_rows(t, i - 1, c, sn, j..n);
+            rotate_rows(s, i - 1, c, sn, 0..n);
+            rotate_columns(q, i - 1, c, -sn, 0..n);
+            *t.get_mut(i, j) = Complex::zero();
+        }
+    }
+
+    for j in 0..n.saturating_sub(2)
+    {
+        for i in ((j + 2)..n).rev()
+        {
+            let (c, sn): (T, Complex<T>) = givens(*s.get(i - 1, j), *s.get(i, j));
+            rotate_rows(s, i - 1, c, sn, j..n);
+            rotate_rows(t, i - 1, c, sn, (i - 1)..n);
+            rotate_columns(q, i - 1, c, -sn, 0..n);
+            *s.get_mut(i, j) = Complex::zero();
+
+            // annihilate the fill in of T
+            let (c, sn): (T, Complex<T>) = givens(*t.get(i, i), *t.get(i, i - 1));
+            rotate_columns(t, i - 1, c, sn, 0..(i + 1));
+            rotate_columns(s, i - 1, c, sn, 0..n);
+            rotate_columns(z, i - 1, c, sn, 0..n);
+            *t.get_mut(i, i - 1) = Complex::zero();
+        }
+    }
+}
+
+/// Moves the zero on the diagonal of T from position j to position ilast
+///
+/// Rotations from the left shift the zero downwards, rotations from the right
+/// restore the Hessenberg form of S.
+fn chase_zero<T>(s: &mut Matrix<Complex<T>>,
+                 t: &mut Matrix<Complex<T>>,
+                 q: &mut Matrix<Complex<T>>,
+                 z: &mut Matrix<Complex<T>>,
+                 j: usize,
+                 ilast: usize)
+    where T: Real,
+          Complex<T>: Scalar
+{
+    let (n, _): (usize, usize) = s.dim();
+
+    for i in j..ilast
+    {
+        let (c, sn): (T, Complex<T>) = givens(*t.get(i, i + 1), *t.get(i + 1, i + 1));
+        rotate_rows(t, i, c, sn, (i + 1)..n);
+        rotate_rows(s, i, c, sn, i.saturating_sub(1)..n);
+        rotate_columns(q, i, c, -sn, 0..n);
+        *t.get_mut(i + 1, i + 1) = Complex::zero();
+
+        if i > 0
+        {
+            let (c, sn): (T, Complex<T>) = givens(*s.get(i + 1, i), *s.get(i + 1, i - 1));
+            rotate_columns(s, i - 1, c, sn, 0..(i + 2));
+            rotate_columns(t, i - 1, c, sn, 0..i);
+            rotate_columns(z, i - 1, c, sn, 0..n);
+            *s.get_mut(i + 1, i - 1) = Complex::zero();
+        }
+    }
+}
+
+/// Performs one implicitly shifted QZ step on the active block ifirst..=ilast
+fn qz_step<T>(s: &mut Matrix<Complex<T>>,
+              t: &mut Matrix<Complex<T>>,
+              q: &mut Matrix<Complex<T>>,
+              z: &mut Matrix<Complex<T>>,
+              ifirst: usize,
+              ilast: usize,
+              mu: Complex<T>)
+    where T: Real,
+          Complex<T>: Scalar
+{
+    let (n, _): (usize, usize) = s.dim();
+
+    // first column of S T^{-1} - μI
+    let x: Complex<T> = *s.get(ifirst, ifirst) - mu * *t.get(ifirst, ifirst);
+    let y: Complex<T> = *s.get(ifirst + 1, ifirst);
+    let (c, sn): (T, Complex<T>) = givens(x, y);
+    rotate_rows(s, ifirst, c, sn, ifirst..n);
+    rotate_rows(t, ifirst, c, sn, ifirst..n);
+    rotate_columns(q, ifirst, c, -sn, 0..n);
+
+    // chase the bulge
+    for k in ifirst..ilast
+    {
+        let (c, sn): (T, Complex<T>) = givens(*t.get(k + 1, k + 1), *t.get(k + 1, k));
+        rotate_columns(t, k, c, sn, 0..(k + 2));
+        rotate_columns(s, k, c, sn, 0..((k + 2).min(ilast) + 1));
+        rotate_columns(z, k, c, sn, 0..n);
+        *t.get_mut(k + 1, k) = Complex::zero();
+
+        if k + 1 < ilast
+        {
+            let (c, sn): (T, Complex<T>) = givens(*s.get(k + 1, k), *s.get(k + 2, k));
+            rotate_rows(s, k + 1, c, sn, k..n);
+            rotate_rows(t, k + 1, c, sn, (k + 1)..n);
+            rotate_columns(q, k + 1, c, -sn, 0..n);
+            *s.get_mut(k + 2, k) = Complex::zero();
+        }
+    }
+}
+
+/// Applies the rotation [c s; -conj(s) c] to the rows k and k + 1
+fn rotate_rows<T>(a: &mut Matrix<Complex<T>>, k: usize, c: T, s: Complex<T>, columns: Range<usize>)
+    where T: Real,
+          Complex<T>: Scalar
+{
+    for j in columns
+    {
+        let a_kj: Complex<T> = *a.get(k, j);
+        let a_k1j: Complex<T> = *a.get(k + 1, j);
+        *a.get_mut(k, j) = scale(c, a_kj) + s * a_k1j;
+        *a.get_mut(k + 1, j) = scale(c, a_k1j) - s.conj() * a_kj;
+    }
+}
+
+/// Multiplies the columns k and k + 1 with the rotation [c s; -conj(s) c]
+/// from the right
+fn rotate_columns<T>(a: &mut Matrix<Complex<T>>, k: usize, c: T, s: Complex<T>, rows: Range<usize>)
+    where T: Real,
+          Complex<T>: Scalar
+{
+    for i in rows
+    {
+        let a_ik: Complex<T> = *a.get(i, k);
+        let a_ik1: Complex<T> = *a.get(i, k + 1);
+        *a.get_mut(i, k) = scale(c, a_ik) - s.conj() * a_ik1;
+        *a.get_mut(i, k + 1) = scale(c, a_ik1) + s * a_ik;
+    }
+}
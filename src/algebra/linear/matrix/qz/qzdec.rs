@@ -0,0 +1,117 @@
+use crate::algebra::{
+    abstr::{Complex, Real},
+    linear::{Matrix, Vector},
+};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::clone::Clone;
+
+/// Generalized Schur decomposition of a matrix pencil A - λB
+///
+/// A = Q S Z^H \
+/// B = Q T Z^H
+///
+/// Q and Z are unitary, S and T are upper triangular. The diagonal of T is
+/// real and non negative.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct QZDec<T>
+{
+    q: Matrix<Complex<T>>,
+    z: Matrix<Complex<T>>,
+    s: Matrix<Complex<T>>,
+    t: Matrix<Complex<T>>,
+}
+
+impl<T> QZDec<T>
+{
+    pub(super) fn new(q: Matrix<Complex<T>>,
+                      z: Matrix<Complex<T>>,
+                      s: Matrix<Complex<T>>,
+                      t: Matrix<Complex<T>>)
+                      -> QZDec<T>
+    {
+        return QZDec { q, z, s, t };
+    }
+
+    /// Return the unitary matrix Q
+    pub fn q(self: Self) -> Matrix<Complex<T>>
+    {
+        return self.q;
+    }
+
+    /// Return the unitary matrix Z
+    pub fn z(self: Self) -> Matrix<Complex<T>>
+    {
+        return self.z;
+    }
+
+    /// Return the upper triangular matrix S
+    pub fn s(self: Self) -> Matrix<Complex<T>>
+    {
+        return self.s;
+    }
+
+    /// Return the upper triangular matrix T
+    pub fn t(self: Self) -> Matrix<Complex<T>>
+    {
+        return self.t;
+    }
+
+    /// Return the matrices Q, Z, S and T
+    pub fn qzst(self: Self) -> (Matrix<Complex<T>>, Matrix<Complex<T>>, Matrix<Complex<T>>, Matrix<Complex<T>>)
+    {
+        return (self.q, self.z, self.s, self.t);
+    }
+}
+
+impl<T> QZDec<T> where T: Real
+{
+    /// Return the diagonal of S
+    pub fn alpha(self: &Self) -> Vector<Complex<T>>
+    {
+        let (n, _): (usize, usize) = self.s.dim();
+        return Vector::new_column(n, (0..n).map(|i| *self.s.get(i, i)).collect());
+    }
+
+    /// Return the diagonal of T
+    ///
+    /// The eigenvalue λ_i = α_i / β_i is infinite if β_i is zero.
+    pub fn beta(self: &Self) -> Vector<T>
+    {
+        let (n, _): (usize, usize) = self.t.dim();
+        return Vector::new_column(n, (0..n).map(|i| self.t.get(i, i).re).collect());
+    }
+
+    /// Return the generalized eigenvalues λ_i = α_i / β_i
+    ///
+    /// An infinite eigenvalue is returned as a complex number with an
+    /// infinite real part. If α_i and β_i are both zero, the pencil is
+    /// singular and the eigenvalue is NaN.
+    pub fn value(self: &Self) -> Vector<Complex<T>>
+    {
+        let alpha: Vector<Complex<T>> = self.alpha();
+        let beta: Vector<T> = self.beta();
+        let (n, _): (usize, usize) = beta.dim();
+
+        let value: Vec<Complex<T>> = (0..n).map(|i| {
+                                               let a_i: Complex<T> = *alpha.get(i);
+                                               let b_i: T = *beta.get(i);
+                                               if b_i != T::zero()
+                                               {
+                                                   Complex::new(a_i.re / b_i, a_i.im / b_i)
+                                               }
+                                               else if a_i.re == T::zero() && a_i.im == T::zero()
+                                               {
+                                                   Complex::new(T::from_f64(f64::NAN), T::from_f64(f64::NAN))
+                                               }
+                                               else
+                                               {
+                                                   Complex::new(T::infinity(), T::zero())
+                                               }
+                                           })
+                                           .collect();
+
+        return Vector::new_column(n, value);
+    }
+}
@@ -0,0 +1,304 @@
+//! Complex Schur decomposition and complex Givens rotations
+use crate::{
+    algebra::{
+        abstr::{Complex, One, Real, Scalar, Sign, Zero},
+        linear::Matrix,
+    },
+    elementary::Power,
+    error::MathError,
+};
+
+/// Computes the complex Schur decomposition of a real square matrix
+///
+/// A = Z T Z^H
+///
+/// Z is unitary and T is upper triangular with the eigenvalues of A on its
+/// diagonal. The matrix is reduced to Hessenberg form first, afterwards the
+/// shifted QR algorithm with Wilkinson shifts is applied in complex
+/// arithmetic.
+pub(in crate::algebra::linear::matrix) fn schur<T>(a: &Matrix<T>) -> Result<(Matrix<Complex<T>>, Matrix<Complex<T>>), MathError>
+    where T: Real,
+          Complex<T>: Scalar
+{
+    let (m, n): (usize, usize) = a.dim();
+    if m != n || n == 0
+    {
+        return Err(MathError::DimensionMismatch);
+    }
+
+    let (q, h): (Matrix<T>, Matrix<T>) = a.dec_hessenberg().qh();
+
+    return schur_hessenberg(complexify(&h), complexify(&q));
+}
+
+/// Computes the complex Schur decomposition of a complex square matrix
+///
+/// A = Z T Z^H
+///
+/// The matrix is reduced to Hessenberg form by Givens rotations, afterwards
+/// the same shifted QR algorithm as for real matrices is applied.
+pub(in crate::algebra::linear::matrix) fn schur_complex<T>(a: &Matrix<Complex<T>>)
+                                                           -> Result<(Matrix<Complex<T>>, Matrix<Complex<T>>), MathError>
+    where T: Real,
+          Complex<T>: Scalar
+{
+    let (m, n): (usize, usize) = a.dim();
+    if m != n || n == 0
+    {
+        return Err(MathError::DimensionMismatch);
+    }
+
+    let mut h: Matrix<Complex<T>> = a.clone();
+    let mut q: Matrix<Complex<T>> = Matrix::one(n);
+
+    for k in 0..n.saturating_sub(2)
+    {
+        for i in ((k + 2)..n).rev()
+        {
+            let (c, s): (T, Complex<T>) = givens(*h.get(i - 1, k), *h.get(i, k));
+            for j in k..n
+            {
+                let h_i1j: Complex<T> = *h.get(i - 1, j);
+                let h_ij: Complex<T> = *h.get(i, j);
+                *h.get_mut(i - 1, j) = scale(c, h_i1j) + s * h_ij;
+                *h.get_mut(i, j) = scale(c, h_ij) - s.conj() * h_i1j;
+            }
+            *h.get_mut(i, k) = Complex::zero();
+            for l in 0..n
+            {
+                let h_li1: Complex<T> = *h.get(l, i - 1);
+                let h_li: Complex<T> = *h.get(l, i);
+                *h.get_mut(l, i - 1) = scale(c, h_li1) + s.conj() * h_li;
+                *h.get_mut(l, i) = scale(c, h_li) - s * h_li1;
+
+                let q_li1: Complex<T> = *q.get(l, i - 1);
+                let q_li: Complex<T> = *q.get(l, i);
+                *q.get_mut(l, i - 1) = scale(c, q_li1) + s.conj() * q_li;
+                *q.get_mut(l, i) = scale(c, q_li) - s * q_li1;
+            }
+        }
+    }
+
+    return schur_hessenberg(h, q);
+}
+
+/// Reduces the Hessenberg matrix t to upper triangular form with the shifted
+/// QR algorithm, the unitary transformations are accumulated in z
+fn schur_hessenberg<T>(mut t: Matrix<Complex<T>>, mut z: Matrix<Complex<T>>)
+                       -> Result<(Matrix<Complex<T>>, Matrix<Complex<T>>), MathError>
+    where T: Real,
+          Complex<T>: Scalar
+{
+    let (n, _): (usize, usize) = t.dim();
+
+    let eps: T = T::default_epsilon();
+    let norm: T = norm_1(&t);
+    let max_iter: usize = 30 * n;
+
+    let mut hi: usize = n - 1;
+    let mut iter: usize = 0;
+    while hi > 0
+    {
+        // look for a negligible subdiagonal element
+        let mut lo: usize = hi;
+        while lo > 0
+        {
+            let mut scale: T = abs(t.get(lo - 1, lo - 1)) + abs(t.get(lo, lo));
+            if scale == T::zero()
+            {
+                scale = norm;
+            }
+            if abs(t.get(lo, lo - 1)) <= eps * scale
+            {
+                *t.get_mut(lo, lo - 1) = Complex::zero();
+                break;
+            }
+            lo -= 1;
+        }
+
+        if lo == hi
+        {
+            hi -= 1;
+            iter = 0;
+            continue;
+        }
+
+        iter += 1;
+        if iter > max_iter
+        {
+            return Err(MathError::NoConvergence { iterations: max_iter });
+        }
+
+        let mu: Complex<T> = if iter % 10 == 0
+        {
+            // exceptional shift
+            *t.get(hi, hi) + Complex::new(T::from_f64(1.5) * abs(t.get(hi, hi - 1)), T::zero())
+        }
+        else
+        {
+            wilkinson_shift(*t.get(hi - 1, hi - 1),
+                            *t.get(hi - 1, hi),
+                            *t.get(hi, hi - 1),
+                            *t.get(hi, hi))
+        };
+
+        qr_step(&mut t, &mut z, lo, hi, mu);
+    }
+
+    // remove the rounding errors beneath the diagonal
+    for j in 0..n
+    {
+        for i in (j + 1)..n
+        {
+            *t.get_mut(i, j) = Complex::zero();
+        }
+    }
+
+    return Ok((z, t));
+}
+
+/// Performs one explicitly shifted QR step on the active block lo..=hi
+///
+/// T - μI = QR, T <- RQ + μI, Z <- Z Q
+fn qr_step<T>(t: &mut Matrix<Complex<T>>, z: &mut Matrix<Complex<T>>, lo: usize, hi: usize, mu: Complex<T>)
+    where T: Real,
+          Complex<T>: Scalar
+{
+    let (n, _): (usize, usize) = t.dim();
+
+    for k in lo..=hi
+    {
+        *t.get_mut(k, k) -= mu;
+    }
+
+    let mut rotations: Vec<(T, Complex<T>)> = Vec::with_capacity(hi - lo);
+    for k in lo..hi
+    {
+        let (c, s): (T, Complex<T>) = givens(*t.get(k, k), *t.get(k + 1, k));
+        for j in k..n
+        {
+            let t_kj: Complex<T> = *t.get(k, j);
+            let t_k1j: Complex<T> = *t.get(k + 1, j);
+            *t.get_mut(k, j) = scale(c, t_kj) + s * t_k1j;
+            *t.get_mut(k + 1, j) = scale(c, t_k1j) - s.conj() * t_kj;
+        }
+        rotations.push((c, s));
+    }
+
+    for (l, (c, s)) in rotations.iter().enumerate()
+    {
+        let k: usize = lo + l;
+        for i in 0..=(k + 1)
+        {
+            let t_ik: Complex<T> = *t.get(i, k);
+            let t_ik1: Complex<T> = *t.get(i, k + 1);
+            *t.get_mut(i, k) = scale(*c, t_ik) + s.conj() * t_ik1;
+            *t.get_mut(i, k + 1) = scale(*c, t_ik1) - *s * t_ik;
+        }
+        for i in 0..n
+        {
+            let z_ik: Complex<T> = *z.get(i, k);
+            let z_ik1: Complex<T> = *z.get(i, k + 1);
+            *z.get_mut(i, k) = scale(*c, z_ik) + s.conj() * z_ik1;
+            *z.get_mut(i, k + 1) = scale(*c, z_ik1) - *s * z_ik;
+        }
+    }
+
+    for k in lo..=hi
+    {
+        *t.get_mut(k, k) += mu;
+    }
+}
+
+/// Eigenvalue of the 2 x 2 matrix [a b; c d], which is closer to d
+pub(in crate::algebra::linear::matrix) fn wilkinson_shift<T>(a: Complex<T>, b: Complex<T>, c: Complex<T>, d: Complex<T>) -> Complex<T>
+    where T: Real,
+          Complex<T>: Scalar
+{
+    let half: Complex<T> = Complex::new(T::from_f64(0.5), T::zero());
+    let mean: Complex<T> = (a + d) * half;
+    let diff: Complex<T> = (a - d) * half;
+    let disc: Complex<T> = (diff * diff + b * c).sqrt();
+
+    let mu_1: Complex<T> = mean + disc;
+    let mu_2: Complex<T> = mean - disc;
+    if abs(&(mu_1 - d)) <= abs(&(mu_2 - d))
+    {
+        return mu_1;
+    }
+
+    return mu_2;
+}
+
+/// Computes the rotation [c s; -conj(s) c] with real c, which annihilates y in
+/// the vector [x; y]
+pub(in crate::algebra::linear::matrix) fn givens<T>(x: Complex<T>, y: Complex<T>) -> (T, Complex<T>)
+    where T: Real,
+          Complex<T>: Scalar
+{
+    let abs_x: T = abs(&x);
+    let abs_y: T = abs(&y);
+    if abs_y == T::zero()
+    {
+        return (T::one(), Complex::zero());
+    }
+    if abs_x == T::zero()
+    {
+        return (T::zero(), Complex::one());
+    }
+
+    let r: T = (abs_x * abs_x + abs_y * abs_y).sqrt();
+    let phase: Complex<T> = Complex::new(x.re / abs_x, x.im / abs_x);
+    let s: Complex<T> = phase * Complex::new(y.re / r, -y.im / r);
+
+    return (abs_x / r, s);
+}
+
+pub(in crate::algebra::linear::matrix) fn scale<T>(c: T, z: Complex<T>) -> Complex<T>
+    where T: Real
+{
+    return Complex::new(c * z.re, c * z.im);
+}
+
+pub(in crate::algebra::linear::matrix) fn abs<T>(z: &Complex<T>) -> T
+    where T: Real
+{
+    return z.abs().re;
+}
+
+/// Maximum absolute column sum
+pub(in crate::algebra::linear::matrix) fn norm_1<T>(a: &Matrix<Complex<T>>) -> T
+    where T: Real,
+          Complex<T>: Scalar
+{
+    let (m, n): (usize, usize) = a.dim();
+    let mut norm: T = T::zero();
+    for j in 0..n
+    {
+        let mut sum: T = T::zero();
+        for i in 0..m
+        {
+            sum += abs(a.get(i, j));
+        }
+        norm = norm.max(sum);
+    }
+
+    return norm;
+}
+
+pub(in crate::algebra::linear::matrix) fn complexify<T>(a: &Matrix<T>) -> Matrix<Complex<T>>
+    where T: Real,
+          Complex<T>: Scalar
+{
+    let (m, n): (usize, usize) = a.dim();
+    let mut c: Matrix<Complex<T>> = Matrix::zero(m, n);
+    for j in 0..n
+    {
+        for i in 0..m
+        {
+            *c.get_mut(i, j) = Complex::new(*a.get(i, j), T::zero());
+        }
+    }
+
+    return c;
+}
@@ -0,0 +1,81 @@
+use crate::{
+    algebra::{
+        abstr::Real,
+        linear::{matrix::SchurDec, Matrix},
+    },
+    error::MathError,
+};
+
+impl<T> Matrix<T> where T: Real
+{
+    /// Computes the real Schur decomposition
+    ///
+    /// A = Z T Z^T
+    ///
+    /// The matrix is reduced to Hessenberg form, afterwards the Schur form is
+    /// computed with xhseqr.
+    ///
+    /// # Return
+    ///
+    /// MathError::DimensionMismatch if the matrix is not square or empty,
+    /// MathError::LapackInfo if xhseqr fails
+    pub fn dec_schur(self: &Self) -> Result<SchurDec<T>, MathError>
+    {
+        let (m, n): (usize, usize) = self.dim();
+        if m != n || m == 0
+        {
+            return Err(MathError::DimensionMismatch);
+        }
+
+        let (q, h): (Matrix<T>, Matrix<T>) = self.dec_hessenberg().qh();
+        let mut t: Vec<T> = h.data;
+        let mut z: Vec<T> = q.data;
+        let n_i32: i32 = n as i32;
+
+        let mut wr: Vec<T> = vec![T::zero(); n];
+        let mut wi: Vec<T> = vec![T::zero(); n];
+        let mut info: i32 = 0;
+
+        let lwork: i32 = T::xhseqr_work_size(b'S',
+                                             b'V',
+                                             n_i32,
+                                             1,
+                                             n_i32,
+                                             &mut t[..],
+                                             n_i32,
+                                             &mut wr[..],
+                                             &mut wi[..],
+                                             &mut z[..],
+                                             n_i32,
+                                             &mut info);
+
+        if info != 0
+        {
+            return Err(MathError::LapackInfo(info));
+        }
+
+        let mut work: Vec<T> = vec![T::zero(); lwork as usize];
+
+        T::xhseqr(b'S',
+                  b'V',
+                  n_i32,
+                  1,
+                  n_i32,
+                  &mut t[..],
+                  n_i32,
+                  &mut wr[..],
+                  &mut wi[..],
+                  &mut z[..],
+                  n_i32,
+                  &mut work[..],
+                  lwork,
+                  &mut info);
+
+        if info != 0
+        {
+            return Err(MathError::LapackInfo(info));
+        }
+
+        return Ok(SchurDec::new(Matrix::new(n, n, z), Matrix::new(n, n, t)));
+    }
+}
@@ -0,0 +1,8 @@
+#[cfg(feature = "lapack")]
+pub mod lapack;
+#[cfg(feature = "native")]
+pub mod native;
+
+pub(super) mod complex;
+pub mod schurdec;
+pub use self::schurdec::SchurDec;
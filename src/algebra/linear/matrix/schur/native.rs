@@ -0,0 +1,74 @@
+use crate::{
+    algebra::{
+        abstr::Real,
+        linear::{matrix::SchurDec, Matrix},
+    },
+    error::MathError,
+};
+
+impl<T> Matrix<T> where T: Real
+{
+    /// Computes the real Schur decomposition
+    ///
+    /// A = Z T Z^T
+    ///
+    /// The matrix is reduced to Hessenberg form and afterwards to the real
+    /// Schur form with the Francis double shift QR algorithm. Z is orthogonal
+    /// and T is quasi upper triangular with 1 x 1 and 2 x 2 blocks on its
+    /// diagonal, the 2 x 2 blocks belong to pairs of complex conjugate
+    /// eigenvalues.
+    ///
+    /// # Return
+    ///
+    /// MathError::DimensionMismatch if the matrix is not square or empty,
+    /// MathError::NoConvergence if the QR algorithm does not converge
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[macro_use]
+    /// # extern crate mathru;
+    /// # fn main()
+    /// # {
+    /// use mathru::algebra::linear::{matrix::Transpose, Matrix};
+    ///
+    /// let a: Matrix<f64> = matrix![   1.0, -3.0, 2.0;
+    ///                                 4.0, 1.0, -1.0;
+    ///                                 0.0, 2.0, 5.0];
+    ///
+    /// let (z, t): (Matrix<f64>, Matrix<f64>) = a.dec_schur().unwrap().zt();
+    ///
+    /// assert_relative_eq!(a, &(&z * &t) * &z.transpose(), epsilon=1.0e-10);
+    /// # }
+    /// ```
+    pub fn dec_schur(self: &Self) -> Result<SchurDec<T>, MathError>
+    {
+        let (m, n): (usize, usize) = self.dim();
+        if m != n || m == 0
+        {
+            return Err(MathError::DimensionMismatch);
+        }
+
+        let (q, h): (Matrix<T>, Matrix<T>) = self.dec_hessenberg().qh();
+        let mut t: Matrix<T> = h;
+        let mut z: Matrix<T> = q;
+        let mut re: Vec<T> = vec![T::zero(); n];
+        let mut im: Vec<T> = vec![T::zero(); n];
+
+        Matrix::francis_double_shift(&mut t, &mut z, &mut re, &mut im)?;
+
+        // remove the negligible elements beneath the diagonal blocks
+        for j in 0..n
+        {
+            for i in (j + 1)..n
+            {
+                if i > j + 1 || im[j] <= T::zero()
+                {
+                    *t.get_mut(i, j) = T::zero();
+                }
+            }
+        }
+
+        return Ok(SchurDec::new(z, t));
+    }
+}
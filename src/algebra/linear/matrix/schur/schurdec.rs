@@ -0,0 +1,45 @@
+use crate::algebra::linear::Matrix;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::clone::Clone;
+
+/// Real Schur decomposition
+///
+/// A = Z T Z^T
+///
+/// Z is orthogonal and T is quasi upper triangular. The 1 x 1 blocks on the
+/// diagonal of T are the real eigenvalues of A, every 2 x 2 block belongs to
+/// a pair of complex conjugate eigenvalues.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct SchurDec<T>
+{
+    z: Matrix<T>,
+    t: Matrix<T>,
+}
+
+impl<T> SchurDec<T>
+{
+    pub(super) fn new(z: Matrix<T>, t: Matrix<T>) -> SchurDec<T>
+    {
+        return SchurDec { z, t };
+    }
+
+    /// Return the orthogonal matrix Z
+    pub fn z(self: Self) -> Matrix<T>
+    {
+        return self.z;
+    }
+
+    /// Return the quasi upper triangular matrix T
+    pub fn t(self: Self) -> Matrix<T>
+    {
+        return self.t;
+    }
+
+    /// Return the matrices Z and T
+    pub fn zt(self: Self) -> (Matrix<T>, Matrix<T>)
+    {
+        return (self.z, self.t);
+    }
+}
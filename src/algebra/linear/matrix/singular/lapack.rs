@@ -0,0 +1,138 @@
+use crate::{
+    algebra::{
+        abstr::Real,
+        linear::{
+            matrix::{SVDDec, Transpose},
+            Matrix, Vector,
+        },
+    },
+    error::MathError,
+};
+
+impl<T> Matrix<T> where T: Real
+{
+    /// Computes the full singular value decomposition
+    ///
+    /// A = U Σ V^T
+    ///
+    /// U is a orthogonal m x m matrix, Σ is a m x n matrix and V^T is a
+    /// orthogonal n x n matrix. The singular values are sorted in
+    /// descending order.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[macro_use]
+    /// # extern crate mathru;
+    /// # fn main()
+    /// # {
+    /// use mathru::algebra::linear::Matrix;
+    ///
+    /// let a: Matrix<f64> = matrix![   4.0, 1.0, -2.0;
+    ///                                 1.0, 2.0, 0.0;
+    ///                                 0.0, 3.0, -2.0;
+    ///                                 2.0, 1.0, -2.0];
+    ///
+    /// let (u, s, vt): (Matrix<f64>, Matrix<f64>, Matrix<f64>) = a.dec_svd().unwrap().usvt();
+    ///
+    /// assert_relative_eq!(a, &(&u * &s) * &vt, epsilon=1.0e-10);
+    /// # }
+    /// ```
+    pub fn dec_svd(self: &Self) -> Result<SVDDec<T>, MathError>
+    {
+        return self.xgesdd('A' as u8);
+    }
+
+    /// Computes the thin singular value decomposition
+    ///
+    /// A = U Σ V^T
+    ///
+    /// With k = min(m, n), U is a m x k matrix with orthonormal columns, Σ is
+    /// a k x k matrix and V^T is a k x n matrix with orthonormal rows.
+    pub fn dec_svd_thin(self: &Self) -> Result<SVDDec<T>, MathError>
+    {
+        return self.xgesdd('S' as u8);
+    }
+
+    /// Computes the singular value decomposition
+    ///
+    /// M = U * S * V*
+    ///
+    /// # Return
+    ///
+    /// (u, s, v)
+    ///
+    /// The decomposition is the thin singular value decomposition
+    /// [`Matrix::dec_svd_thin`], V is returned instead of V^T.
+    pub fn dec_sv(self: &Self) -> Result<(Self, Self, Self), MathError>
+    {
+        let (u, s, vt): (Matrix<T>, Matrix<T>, Matrix<T>) = self.dec_svd_thin()?.usvt();
+        return Ok((u, s, vt.transpose()));
+    }
+
+    fn xgesdd(self: &Self, jobz: u8) -> Result<SVDDec<T>, MathError>
+    {
+        if !self.is_finite()
+        {
+            return Err(MathError::NotFinite);
+        }
+
+        let (m, n): (usize, usize) = self.dim();
+        let k: usize = m.min(n);
+        let (u_n, vt_m): (usize, usize) = if jobz == 'A' as u8 { (m, n) } else { (k, k) };
+
+        let m_i32: i32 = m as i32;
+        let n_i32: i32 = n as i32;
+        let ldvt: i32 = (vt_m as i32).max(1);
+
+        let mut self_data: Vec<T> = self.clone().convert_to_vec();
+        let mut s: Vec<T> = vec![T::zero(); k];
+        let mut u: Vec<T> = vec![T::zero(); m * u_n];
+        let mut vt: Vec<T> = vec![T::zero(); vt_m * n];
+        let mut info: i32 = 0;
+
+        let lwork: i32 = T::xgesdd_work_size(jobz,
+                                             m_i32,
+                                             n_i32,
+                                             &mut self_data[..],
+                                             m_i32.max(1),
+                                             &mut s[..],
+                                             &mut u[..],
+                                             m_i32.max(1),
+                                             &mut vt[..],
+                                             ldvt,
+                                             &mut info);
+
+        if info != 0
+        {
+            return Err(MathError::LapackInfo(info));
+        }
+
+        let mut work: Vec<T> = vec![T::zero(); lwork as usize];
+        let mut iwork: Vec<i32> = vec![0; 8 * k];
+
+        T::xgesdd(jobz,
+                  m_i32,
+                  n_i32,
+                  &mut self_data[..],
+                  m_i32.max(1),
+                  &mut s[..],
+                  &mut u[..],
+                  m_i32.max(1),
+                  &mut vt[..],
+                  ldvt,
+                  &mut work[..],
+                  lwork,
+                  &mut iwork[..],
+                  &mut info);
+
+        if info != 0
+        {
+            return Err(MathError::LapackInfo(info));
+        }
+
+        return Ok(SVDDec::new(Matrix::new(m, u_n, u),
+                              Vector::new_column(k, s),
+                              Matrix::new(vt_m, n, vt)));
+    }
+}
@@ -1 +1,8 @@
+#[cfg(feature = "lapack")]
+pub mod lapack;
+#[cfg(feature = "native")]
 pub mod native;
+
+pub mod randomized;
+pub mod svddec;
+pub use self::svddec::SVDDec;
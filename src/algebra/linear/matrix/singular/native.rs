@@ -1,123 +1,18 @@
-use crate::algebra::linear::{Matrix, Vector};
-use crate::algebra::abstr::{Field, Scalar};
-use crate::elementary::Power;
-use crate::algebra::linear::matrix::Transpose;
+use crate::{
+    algebra::{
+        abstr::{Field, Real, Scalar},
+        linear::{
+            matrix::{SVDDec, Transpose},
+            Matrix, Vector,
+        },
+    },
+    elementary::Power,
+    error::MathError,
+};
 
 impl<T> Matrix<T>
     where T: Field + Scalar + Power
 {
-    /// Computes the singular value decomposition
-    ///
-    /// M = U * S * V*
-    ///
-    /// # Return
-    ///
-    /// (u, s, v)
-    ///
-    /// # Example
-    ///
-    /// ```
-    /// use mathru::algebra::linear::Matrix;
-    ///
-    /// let a: Matrix<f64> = Matrix::new(4,
-    ///                                  4,
-    ///                                  vec![4.0, 1.0, -2.0, 2.0, 1.0, 2.0, 0.0, -2.0, 0.0, 3.0,
-    ///                                       -2.0, 2.0, 2.0, 1.0, -2.0, -1.0]);
-    ///
-    /// let (u, s, v): (Matrix<f64>, Matrix<f64>, Matrix<f64>) = a.dec_sv();
-    /// ```
-    pub fn dec_sv(self: &Self) -> (Self, Self, Self)
-    {
-        let (mut u, mut b, mut v): (Matrix<T>, Matrix<T>, Matrix<T>) = self.householder_bidiag();
-
-        let (_m, n): (usize, usize) = b.dim();
-        let max_iterations: usize = 500 * n * n;
-
-        let mut u_k: Matrix<T> = Matrix::one(n);
-
-        for _k in 0..max_iterations
-        {
-            let (u_ks, b_k, v_k): (Matrix<T>, Matrix<T>, Matrix<T>) = Matrix::msweep(u_k, b, v);
-            u_k = u_ks;
-            b = b_k;
-            v = v_k;
-        }
-
-        u = u * u_k.transpose();
-
-        let (b_m, _b_n): (usize, usize) = b.dim();
-
-        // check that all singular values are positive
-        for l in 0..b_m
-        {
-            if b.get(l, l) < &T::zero()
-            {
-                *b.get_mut(l, l) = -*b.get(l, l);
-                let mut column_l: Vector<T> = u.get_column(l);
-                column_l = &column_l * &-T::one();
-                u.set_column(&column_l, l);
-            }
-        }
-
-        // null all values beneath the diagonal
-        for l in 0..b_m
-        {
-            for k in 0..b_m
-            {
-                if k != l
-                {
-                    *b.get_mut(k, l) = T::zero();
-                }
-            }
-        }
-
-        // sort singular values in descending order
-        return (u, b, v);
-    }
-
-    fn msweep(mut u: Matrix<T>,
-              mut b: Matrix<T>,
-              mut v: Matrix<T>)
-              -> (Matrix<T>, Matrix<T>, Matrix<T>)
-    {
-        let (_m, n): (usize, usize) = b.dim();
-
-        for k in 0..n - 1
-        {
-            let mut q: Matrix<T> = Matrix::one(n);
-
-            // Construct matrix Q and multiply on the right by Q'.
-            // Q annihilates both B(k-1,k+1) and B(k,k+1)
-            // but makes B(k+1,k) non-zero.
-            let (c_r, s_r, _r_r): (T, T, T) = Matrix::rot(*b.get(k, k), *b.get(k, k + 1));
-
-            *q.get_mut(k, k) = c_r;
-            *q.get_mut(k, k + 1) = s_r;
-            *q.get_mut(k + 1, k) = -s_r;
-            *q.get_mut(k + 1, k + 1) = c_r;
-
-            let q_t: Matrix<T> = q.clone().transpose();
-            b = &b * &q_t;
-            v = &v * &q_t;
-
-
-            // Construct matrix Q and multiply on the left by Q.
-            // Q annihilates B(k+1,k) but makes B(k,k+1) and
-            // B(k,k+2) non-zero.
-            let (c_l, s_l, _r_l): (T, T, T) = Matrix::rot(*b.get(k, k), *b.get(k + 1, k));
-
-            *q.get_mut(k, k) = c_l;
-            *q.get_mut(k, k + 1) = s_l;
-            *q.get_mut(k + 1, k) = -s_l;
-            *q.get_mut(k + 1, k + 1) = c_l;
-
-            b = &q * &b;
-            u = &q * &u;
-        }
-
-        return (u, b, v);
-    }
-
     pub fn rot(f: T, g: T) -> (T, T, T)
     {
         return if f == T::zero()
@@ -214,4 +109,323 @@ impl<T> Matrix<T>
         }
         (u, a_i, v)
     }
-}
\ No newline at end of file
+}
+
+impl<T> Matrix<T> where T: Real
+{
+    /// Computes the full singular value decomposition
+    ///
+    /// A = U Σ V^T
+    ///
+    /// U is a orthogonal m x m matrix, Σ is a m x n matrix and V^T is a
+    /// orthogonal n x n matrix. The singular values are sorted in
+    /// descending order.
+    ///
+    /// # Return
+    ///
+    /// MathError::NotFinite if an entry of the matrix is infinite or NaN,
+    /// MathError::NoConvergence if the Jacobi iteration does not converge
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[macro_use]
+    /// # extern crate mathru;
+    /// # fn main()
+    /// # {
+    /// use mathru::algebra::linear::Matrix;
+    ///
+    /// let a: Matrix<f64> = matrix![   4.0, 1.0, -2.0;
+    ///                                 1.0, 2.0, 0.0;
+    ///                                 0.0, 3.0, -2.0;
+    ///                                 2.0, 1.0, -2.0];
+    ///
+    /// let (u, s, vt): (Matrix<f64>, Matrix<f64>, Matrix<f64>) = a.dec_svd().unwrap().usvt();
+    ///
+    /// assert_relative_eq!(a, &(&u * &s) * &vt, epsilon=1.0e-10);
+    /// # }
+    /// ```
+    pub fn dec_svd(self: &Self) -> Result<SVDDec<T>, MathError>
+    {
+        return self.dec_svd_jacobi(false);
+    }
+
+    /// Computes the thin singular value decomposition
+    ///
+    /// A = U Σ V^T
+    ///
+    /// With k = min(m, n), U is a m x k matrix with orthonormal columns, Σ is
+    /// a k x k matrix and V^T is a k x n matrix with orthonormal rows.
+    ///
+    /// # Return
+    ///
+    /// MathError::NotFinite if an entry of the matrix is infinite or NaN,
+    /// MathError::NoConvergence if the Jacobi iteration does not converge
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[macro_use]
+    /// # extern crate mathru;
+    /// # fn main()
+    /// # {
+    /// use mathru::algebra::linear::Matrix;
+    ///
+    /// let a: Matrix<f64> = matrix![   4.0, 1.0, -2.0;
+    ///                                 1.0, 2.0, 0.0;
+    ///                                 0.0, 3.0, -2.0;
+    ///                                 2.0, 1.0, -2.0];
+    ///
+    /// let (u, s, vt): (Matrix<f64>, Matrix<f64>, Matrix<f64>) = a.dec_svd_thin().unwrap().usvt();
+    ///
+    /// assert_eq!((4, 3), u.dim());
+    /// assert_eq!((3, 3), s.dim());
+    /// assert_relative_eq!(a, &(&u * &s) * &vt, epsilon=1.0e-10);
+    /// # }
+    /// ```
+    pub fn dec_svd_thin(self: &Self) -> Result<SVDDec<T>, MathError>
+    {
+        return self.dec_svd_jacobi(true);
+    }
+
+    /// Computes the singular value decomposition
+    ///
+    /// M = U * S * V*
+    ///
+    /// The decomposition is the thin singular value decomposition
+    /// [`Matrix::dec_svd_thin`], V is returned instead of V^T.
+    ///
+    /// # Return
+    ///
+    /// (u, s, v)
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mathru::algebra::linear::Matrix;
+    ///
+    /// let a: Matrix<f64> = Matrix::new(4,
+    ///                                  4,
+    ///                                  vec![4.0, 1.0, -2.0, 2.0, 1.0, 2.0, 0.0, -2.0, 0.0, 3.0,
+    ///                                       -2.0, 2.0, 2.0, 1.0, -2.0, -1.0]);
+    ///
+    /// let (u, s, v): (Matrix<f64>, Matrix<f64>, Matrix<f64>) = a.dec_sv().unwrap();
+    /// ```
+    pub fn dec_sv(self: &Self) -> Result<(Self, Self, Self), MathError>
+    {
+        let (u, s, vt): (Matrix<T>, Matrix<T>, Matrix<T>) = self.dec_svd_thin()?.usvt();
+        return Ok((u, s, vt.transpose()));
+    }
+
+    /// One-sided Jacobi (Hestenes) method
+    ///
+    /// The columns of A are orthogonalized by plane rotations from the right,
+    /// which are accumulated in V. After convergence the column norms are the
+    /// singular values and the normalized columns are the left singular
+    /// vectors.
+    fn dec_svd_jacobi(self: &Self, thin: bool) -> Result<SVDDec<T>, MathError>
+    {
+        if !self.is_finite()
+        {
+            return Err(MathError::NotFinite);
+        }
+
+        let (m, n): (usize, usize) = self.dim();
+        if m < n
+        {
+            return Ok(self.clone().transpose().dec_svd_jacobi(thin)?.transpose());
+        }
+
+        let mut a: Vec<T> = self.data.clone();
+        let mut v: Vec<T> = Matrix::one(n).data;
+
+        let eps: T = T::default_epsilon();
+        let max_sweeps: usize = 60;
+        let mut converged: bool = false;
+
+        for _sweep in 0..max_sweeps
+        {
+            let mut rotated: bool = false;
+            for p in 0..n
+            {
+                for q in (p + 1)..n
+                {
+                    let mut alpha: T = T::zero();
+                    let mut beta: T = T::zero();
+                    let mut gamma: T = T::zero();
+                    for i in 0..m
+                    {
+                        let a_ip: T = a[p * m + i];
+                        let a_iq: T = a[q * m + i];
+                        alpha += a_ip * a_ip;
+                        beta += a_iq * a_iq;
+                        gamma += a_ip * a_iq;
+                    }
+
+                    if gamma.abs() <= eps * (alpha * beta).sqrt()
+                    {
+                        continue;
+                    }
+                    rotated = true;
+
+                    let zeta: T = (beta - alpha) / (T::from_f64(2.0) * gamma);
+                    let t: T = if zeta >= T::zero()
+                    {
+                        T::one() / (zeta + (T::one() + zeta * zeta).sqrt())
+                    }
+                    else
+                    {
+                        -T::one() / (-zeta + (T::one() + zeta * zeta).sqrt())
+                    };
+                    let c: T = T::one() / (T::one() + t * t).sqrt();
+                    let s: T = c * t;
+
+                    Matrix::rotate_columns(&mut a, m, p, q, c, s);
+                    Matrix::rotate_columns(&mut v, n, p, q, c, s);
+                }
+            }
+
+            if !rotated
+            {
+                converged = true;
+                break;
+            }
+        }
+
+        if !converged
+        {
+            return Err(MathError::NoConvergence { iterations: max_sweeps });
+        }
+
+        let mut sigma: Vec<T> = Vec::with_capacity(n);
+        for j in 0..n
+        {
+            let mut norm: T = T::zero();
+            for i in 0..m
+            {
+                norm += a[j * m + i] * a[j * m + i];
+            }
+            sigma.push(norm.sqrt());
+        }
+
+        let mut order: Vec<usize> = (0..n).collect();
+        order.sort_by(|i, j| sigma[*j].partial_cmp(&sigma[*i]).unwrap());
+
+        let s_max: T = if n == 0 { T::zero() } else { sigma[order[0]] };
+        let tol: T = eps * T::from_f64(m as f64) * s_max;
+
+        let u_n: usize = if thin { n } else { m };
+        let mut u: Matrix<T> = Matrix::zero(m, u_n);
+        let mut filled: Vec<bool> = vec![false; u_n];
+        let mut vt: Matrix<T> = Matrix::zero(n, n);
+        let mut s: Vec<T> = Vec::with_capacity(n);
+
+        for (k, j) in order.iter().enumerate()
+        {
+            let s_k: T = sigma[*j];
+            s.push(s_k);
+            if s_k > tol
+            {
+                for i in 0..m
+                {
+                    *u.get_mut(i, k) = a[*j * m + i] / s_k;
+                }
+                filled[k] = true;
+            }
+            for i in 0..n
+            {
+                *vt.get_mut(k, i) = v[*j * n + i];
+            }
+        }
+
+        Matrix::complete_orthonormal_basis(&mut u, &filled);
+
+        return Ok(SVDDec::new(u, Vector::new_column(n, s), vt));
+    }
+
+    /// Applies the plane rotation to the columns p and q of the column major
+    /// m x n matrix stored in a
+    fn rotate_columns(a: &mut [T], m: usize, p: usize, q: usize, c: T, s: T)
+    {
+        for i in 0..m
+        {
+            let a_ip: T = a[p * m + i];
+            let a_iq: T = a[q * m + i];
+            a[p * m + i] = c * a_ip - s * a_iq;
+            a[q * m + i] = s * a_ip + c * a_iq;
+        }
+    }
+
+    /// Replaces the columns of u, which are not marked as filled, such that
+    /// the columns of u are orthonormal
+    ///
+    /// The filled columns are factorized by Householder reflections
+    /// Q = H_0 ... H_{r-1}. The trailing columns of Q are orthogonal to the
+    /// filled columns and replace the missing ones.
+    fn complete_orthonormal_basis(u: &mut Matrix<T>, filled: &[bool])
+    {
+        let (m, n): (usize, usize) = u.dim();
+        let basis: Vec<usize> = (0..n).filter(|j| filled[*j]).collect();
+        let r: usize = basis.len();
+
+        let mut a: Vec<T> = Vec::with_capacity(m * r);
+        for j in basis.iter()
+        {
+            for i in 0..m
+            {
+                a.push(*u.get(i, *j));
+            }
+        }
+
+        let mut reflectors: Vec<Vec<T>> = Vec::with_capacity(r);
+        for k in 0..r
+        {
+            let mut w: Vec<T> = vec![T::zero(); m];
+            w[k..m].copy_from_slice(&a[k * m + k..(k + 1) * m]);
+
+            let x_norm: T = w.iter().fold(T::zero(), |acc, w_i| acc + *w_i * *w_i).sqrt();
+            let alpha: T = if w[k] >= T::zero() { -x_norm } else { x_norm };
+            w[k] -= alpha;
+
+            let w_norm: T = w.iter().fold(T::zero(), |acc, w_i| acc + *w_i * *w_i).sqrt();
+            if w_norm > T::zero()
+            {
+                w.iter_mut().for_each(|w_i| *w_i /= w_norm);
+            }
+
+            for j in (k + 1)..r
+            {
+                let mut dot: T = T::zero();
+                for i in k..m
+                {
+                    dot += w[i] * a[j * m + i];
+                }
+                for i in k..m
+                {
+                    a[j * m + i] -= T::from_f64(2.0) * dot * w[i];
+                }
+            }
+            reflectors.push(w);
+        }
+
+        for (l, j) in (0..n).filter(|j| !filled[*j]).enumerate()
+        {
+            // x = Q e_{r + l}
+            let mut x: Vec<T> = vec![T::zero(); m];
+            x[r + l] = T::one();
+            for w in reflectors.iter().rev()
+            {
+                let dot: T = w.iter().zip(x.iter()).fold(T::zero(), |acc, (w_i, x_i)| acc + *w_i * *x_i);
+                for i in 0..m
+                {
+                    x[i] -= T::from_f64(2.0) * dot * w[i];
+                }
+            }
+
+            for i in 0..m
+            {
+                *u.get_mut(i, j) = x[i];
+            }
+        }
+    }
+}
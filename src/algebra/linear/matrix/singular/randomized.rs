@@ -0,0 +1,148 @@
+use crate::{
+    algebra::{
+        abstr::Real,
+        linear::{
+            matrix::{SVDDec, Transpose},
+            Matrix, Vector,
+        },
+    },
+    error::MathError,
+};
+use rand::Rng;
+
+impl<T> Matrix<T> where T: Real
+{
+    /// Randomized range finder
+    ///
+    /// Computes a m x l matrix Q with orthonormal columns, whose range
+    /// approximates the range of A, i.e. A ≈ Q Q^T A (Halko, Martinsson and
+    /// Tropp, algorithm 4.4).
+    ///
+    /// A is multiplied with a random n x l test matrix Ω, whose entries are
+    /// uniformly distributed in [-1, 1), and the product is orthonormalized
+    /// with a QR decomposition. Each power iteration multiplies once more with
+    /// A^T and A, which improves the approximation for slowly decaying
+    /// singular values.
+    ///
+    /// # Arguments
+    ///
+    /// * `l`: number of columns of Q
+    /// * `power_iterations`: number of power iterations, usually 0 to 2
+    /// * `rng`: random number generator for the test matrix
+    ///
+    /// # Panics
+    ///
+    /// if l is zero or greater than min(m, n)
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[macro_use]
+    /// # extern crate mathru;
+    /// # fn main()
+    /// # {
+    /// use mathru::algebra::linear::{matrix::Transpose, Matrix};
+    /// use rand::{rngs::StdRng, SeedableRng};
+    ///
+    /// // matrix of rank 1
+    /// let a: Matrix<f64> = matrix![   1.0, 2.0, 3.0;
+    ///                                 2.0, 4.0, 6.0;
+    ///                                 3.0, 6.0, 9.0;
+    ///                                 4.0, 8.0, 12.0];
+    ///
+    /// let q: Matrix<f64> = a.range_randomized(1, 0, &mut StdRng::seed_from_u64(1)).unwrap();
+    ///
+    /// assert_relative_eq!(a, &q * &(&q.clone().transpose() * &a), epsilon=1.0e-10);
+    /// # }
+    /// ```
+    pub fn range_randomized<R>(self: &Self, l: usize, power_iterations: usize, rng: &mut R) -> Result<Matrix<T>, MathError>
+        where R: Rng + ?Sized
+    {
+        let (m, n): (usize, usize) = self.dim();
+        assert!(l > 0 && l <= m.min(n));
+
+        let omega: Matrix<T> = Matrix::new_random_with_rng(n, l, rng).apply(&|u: &T| T::from_f64(2.0) * *u - T::one());
+        let mut q: Matrix<T> = (self * &omega).dec_qrp()?.q();
+
+        if power_iterations > 0
+        {
+            let a_t: Matrix<T> = self.clone().transpose();
+            for _ in 0..power_iterations
+            {
+                let z: Matrix<T> = (&a_t * &q).dec_qrp()?.q();
+                q = (self * &z).dec_qrp()?.q();
+            }
+        }
+
+        return Ok(q);
+    }
+
+    /// Randomized singular value decomposition
+    ///
+    /// Approximates the k largest singular values and the corresponding
+    /// singular vectors (Halko, Martinsson and Tropp, algorithm 5.1):
+    ///
+    /// 1. Q := range_randomized(A, k + p)
+    /// 2. B := Q^T A
+    /// 3. $`B = \tilde{U} \Sigma V^T`$
+    /// 4. $`U := Q \tilde{U}`$
+    ///
+    /// Only the small (k + p) x n matrix B is decomposed, the costs are
+    /// dominated by the 2 q + 2 products with A. The result has the structure
+    /// of a thin decomposition with a m x k matrix U, a k x k matrix Σ and a
+    /// k x n matrix V^T.
+    ///
+    /// # Arguments
+    ///
+    /// * `k`: number of singular triplets
+    /// * `oversampling`: number p of additional test vectors, usually 5 to 10
+    /// * `power_iterations`: number q of power iterations, usually 0 to 2
+    /// * `rng`: random number generator for the test matrix
+    ///
+    /// # Panics
+    ///
+    /// if k is zero or greater than min(m, n)
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[macro_use]
+    /// # extern crate mathru;
+    /// # fn main()
+    /// # {
+    /// use mathru::algebra::linear::{Matrix, Vector};
+    /// use rand::{rngs::StdRng, SeedableRng};
+    ///
+    /// let a: Matrix<f64> = matrix![   4.0, 1.0, -2.0;
+    ///                                 1.0, 2.0, 0.0;
+    ///                                 0.0, 3.0, -2.0;
+    ///                                 2.0, 1.0, -2.0];
+    ///
+    /// let s: Vector<f64> = a.dec_svd_randomized(2, 1, 1, &mut StdRng::seed_from_u64(1)).unwrap().singular_values();
+    /// let s_ref: Vector<f64> = a.dec_svd().unwrap().singular_values();
+    ///
+    /// assert_relative_eq!(s_ref.get_slice(0, 1), s, epsilon=1.0e-10);
+    /// # }
+    /// ```
+    pub fn dec_svd_randomized<R>(self: &Self, k: usize, oversampling: usize, power_iterations: usize,
+                                 rng: &mut R)
+                                 -> Result<SVDDec<T>, MathError>
+        where R: Rng + ?Sized
+    {
+        let (m, n): (usize, usize) = self.dim();
+        assert!(k > 0 && k <= m.min(n));
+
+        let l: usize = (k + oversampling).min(m.min(n));
+        let q: Matrix<T> = self.range_randomized(l, power_iterations, rng)?;
+
+        let b: Matrix<T> = &q.clone().transpose() * self;
+        let dec_b: SVDDec<T> = b.dec_svd_thin()?;
+        let u_b: Matrix<T> = dec_b.clone().u();
+        let s: Vector<T> = dec_b.clone().singular_values();
+        let vt: Matrix<T> = dec_b.vt();
+
+        let u: Matrix<T> = (&q * &u_b).get_slice(0, m - 1, 0, k - 1);
+
+        return Ok(SVDDec::new(u, s.get_slice(0, k - 1), vt.get_slice(0, k - 1, 0, n - 1)));
+    }
+}
@@ -0,0 +1,295 @@
+use crate::algebra::{
+    abstr::Real,
+    linear::{matrix::Transpose, Matrix, Vector},
+};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::clone::Clone;
+
+/// Singular value decomposition
+///
+/// A = U Σ V^T
+///
+/// For a m x n matrix A with k = min(m, n) the full decomposition consists of
+/// an orthogonal m x m matrix U, a m x n matrix Σ and an orthogonal n x n
+/// matrix V. The thin decomposition keeps only the first k columns of U and
+/// the first k rows of V^T, Σ is k x k in this case.
+///
+/// The singular values are sorted in descending order.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct SVDDec<T>
+{
+    u: Matrix<T>,
+    s: Vector<T>,
+    vt: Matrix<T>,
+}
+
+impl<T> SVDDec<T>
+{
+    pub(super) fn new(u: Matrix<T>, s: Vector<T>, vt: Matrix<T>) -> SVDDec<T>
+    {
+        return SVDDec { u, s, vt };
+    }
+
+    /// Returns the left singular vectors U
+    pub fn u(self: Self) -> Matrix<T>
+    {
+        return self.u;
+    }
+
+    /// Returns the transposed right singular vectors V^T
+    pub fn vt(self: Self) -> Matrix<T>
+    {
+        return self.vt;
+    }
+
+    /// Returns the singular values in descending order
+    pub fn singular_values(self: Self) -> Vector<T>
+    {
+        return self.s;
+    }
+}
+
+impl<T> SVDDec<T> where T: Real
+{
+    /// Returns the matrix Σ
+    ///
+    /// Σ is m x n for the full decomposition and k x k for the thin
+    /// decomposition.
+    pub fn sigma(self: Self) -> Matrix<T>
+    {
+        return self.sigma_matrix();
+    }
+
+    /// Returns the matrices (U, Σ, V^T)
+    pub fn usvt(self: Self) -> (Matrix<T>, Matrix<T>, Matrix<T>)
+    {
+        let sigma: Matrix<T> = self.sigma_matrix();
+        return (self.u, sigma, self.vt);
+    }
+
+    /// Returns the numerical rank, the number of singular values greater than
+    /// tol
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[macro_use]
+    /// # extern crate mathru;
+    /// # fn main()
+    /// # {
+    /// use mathru::algebra::linear::{matrix::SVDDec, Matrix};
+    ///
+    /// let a: Matrix<f64> = matrix![   1.0, 2.0;
+    ///                                 2.0, 4.0;
+    ///                                 3.0, 6.0];
+    ///
+    /// let dec: SVDDec<f64> = a.dec_svd().unwrap();
+    ///
+    /// assert_eq!(1, dec.rank(1.0e-10));
+    /// # }
+    /// ```
+    pub fn rank(self: &Self, tol: T) -> usize
+    {
+        return self.s.iter().filter(|s_i| **s_i > tol).count();
+    }
+
+    /// Returns an orthonormal basis of the null space of A
+    ///
+    /// The basis consists of the right singular vectors belonging to the
+    /// singular values, which are smaller or equal to tol. The matrix has n
+    /// rows and n - rank(tol) columns.
+    ///
+    /// # Panics
+    ///
+    /// If the decomposition is thin and does not contain all right singular
+    /// vectors, i.e. m < n.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[macro_use]
+    /// # extern crate mathru;
+    /// # fn main()
+    /// # {
+    /// use mathru::algebra::linear::{Matrix, Vector};
+    ///
+    /// let a: Matrix<f64> = matrix![   1.0, 2.0;
+    ///                                 2.0, 4.0];
+    ///
+    /// let null: Matrix<f64> = a.dec_svd().unwrap().null_space(1.0e-10);
+    /// let x: Vector<f64> = null.get_column(0);
+    ///
+    /// assert_relative_eq!(Vector::zero(2), &a * &x, epsilon=1.0e-10);
+    /// # }
+    /// ```
+    pub fn null_space(self: &Self, tol: T) -> Matrix<T>
+    {
+        let (vt_m, n): (usize, usize) = self.vt.dim();
+        assert_eq!(vt_m, n, "The thin decomposition does not contain the complete null space");
+
+        let r: usize = self.rank(tol);
+        let mut null: Matrix<T> = Matrix::zero(n, n - r);
+        for j in r..n
+        {
+            for i in 0..n
+            {
+                *null.get_mut(i, j - r) = *self.vt.get(j, i);
+            }
+        }
+
+        return null;
+    }
+
+    /// Returns an orthonormal basis of the range of A
+    ///
+    /// The basis consists of the left singular vectors belonging to the
+    /// singular values, which are greater than tol. The matrix has m rows and
+    /// rank(tol) columns.
+    pub fn range(self: &Self, tol: T) -> Matrix<T>
+    {
+        let (m, _): (usize, usize) = self.u.dim();
+        let r: usize = self.rank(tol);
+        let mut range: Matrix<T> = Matrix::zero(m, r);
+        for j in 0..r
+        {
+            for i in 0..m
+            {
+                *range.get_mut(i, j) = *self.u.get(i, j);
+            }
+        }
+
+        return range;
+    }
+
+    /// Returns the condition number in the 2-norm
+    ///
+    /// cond(A) = σ_max / σ_min
+    ///
+    /// The condition number of a singular matrix is infinite.
+    pub fn cond(self: &Self) -> T
+    {
+        let (k, _): (usize, usize) = self.s.dim();
+        if k == 0
+        {
+            return T::zero();
+        }
+
+        let s_max: T = *self.s.get(0);
+        let s_min: T = *self.s.get(k - 1);
+        if s_min == T::zero()
+        {
+            return T::infinity();
+        }
+
+        return s_max / s_min;
+    }
+
+    /// Returns the best rank k approximation of A
+    ///
+    /// A_k = U_k Σ_k V_k^T
+    ///
+    /// # Panics
+    ///
+    /// If k is greater than min(m, n)
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[macro_use]
+    /// # extern crate mathru;
+    /// # fn main()
+    /// # {
+    /// use mathru::algebra::linear::Matrix;
+    ///
+    /// let a: Matrix<f64> = matrix![   3.0, 0.0;
+    ///                                 0.0, 1.0];
+    ///
+    /// let a_1: Matrix<f64> = a.dec_svd().unwrap().truncate(1);
+    ///
+    /// assert_relative_eq!(matrix![3.0, 0.0; 0.0, 0.0], a_1, epsilon=1.0e-10);
+    /// # }
+    /// ```
+    pub fn truncate(self: &Self, k: usize) -> Matrix<T>
+    {
+        let (s_m, _): (usize, usize) = self.s.dim();
+        assert!(k <= s_m, "k is greater than the number of singular values");
+
+        let (m, _): (usize, usize) = self.u.dim();
+        let (_, n): (usize, usize) = self.vt.dim();
+
+        let mut a_k: Matrix<T> = Matrix::zero(m, n);
+        for l in 0..k
+        {
+            let s_l: T = *self.s.get(l);
+            for j in 0..n
+            {
+                let s_vt_lj: T = s_l * *self.vt.get(l, j);
+                for i in 0..m
+                {
+                    *a_k.get_mut(i, j) += *self.u.get(i, l) * s_vt_lj;
+                }
+            }
+        }
+
+        return a_k;
+    }
+
+    /// Returns the pseudo inverse of A
+    ///
+    /// A^+ = V Σ^+ U^T
+    ///
+    /// Singular values smaller or equal to ε max(m, n) σ_max are treated as
+    /// zero.
+    pub fn pinv(self: &Self) -> Matrix<T>
+    {
+        let (m, _): (usize, usize) = self.u.dim();
+        let (_, n): (usize, usize) = self.vt.dim();
+        let (k, _): (usize, usize) = self.s.dim();
+
+        let s_max: T = if k == 0 { T::zero() } else { *self.s.get(0) };
+        let tol: T = T::default_epsilon() * T::from_f64(m.max(n) as f64) * s_max;
+
+        let mut a_pinv: Matrix<T> = Matrix::zero(n, m);
+        for l in 0..self.rank(tol)
+        {
+            let s_l: T = *self.s.get(l);
+            for j in 0..m
+            {
+                let u_jl: T = *self.u.get(j, l) / s_l;
+                for i in 0..n
+                {
+                    *a_pinv.get_mut(i, j) += *self.vt.get(l, i) * u_jl;
+                }
+            }
+        }
+
+        return a_pinv;
+    }
+
+    /// Decomposition of the transposed matrix
+    ///
+    /// A^T = V Σ^T U^T
+    pub(super) fn transpose(self: Self) -> SVDDec<T>
+    {
+        return SVDDec { u: self.vt.transpose(),
+                        s: self.s,
+                        vt: self.u.transpose() };
+    }
+
+    fn sigma_matrix(self: &Self) -> Matrix<T>
+    {
+        let (_, u_n): (usize, usize) = self.u.dim();
+        let (vt_m, _): (usize, usize) = self.vt.dim();
+        let (k, _): (usize, usize) = self.s.dim();
+
+        let mut sigma: Matrix<T> = Matrix::zero(u_n, vt_m);
+        for i in 0..k
+        {
+            *sigma.get_mut(i, i) = *self.s.get(i);
+        }
+
+        return sigma;
+    }
+}
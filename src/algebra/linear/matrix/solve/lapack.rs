@@ -1,6 +1,9 @@
-use crate::algebra::{
-    abstr::{Field, Scalar, Zero},
-    linear::{Matrix, Vector},
+use crate::{
+    algebra::{
+        abstr::{Field, Scalar, Zero},
+        linear::{Matrix, Vector},
+    },
+    error::MathError,
 };
 
 use super::Solve;
@@ -11,7 +14,7 @@ impl<T> Solve<Vector<T>> for Matrix<T>
 {
     /// Solves Ax = y
     /// where A \in R^{m * n}, x \in R^n, y \in R^m
-    fn solve(self: &Self, rhs: &Vector<T>) -> Result<Vector<T>, ()>
+    fn solve(self: &Self, rhs: &Vector<T>) -> Result<Vector<T>, MathError>
     {
         return self.solve_vector_r(rhs);
     }
@@ -20,7 +23,7 @@ impl<T> Solve<Vector<T>> for Matrix<T>
 impl<T> Solve<Matrix<T>> for Matrix<T>
     where T: Field + Scalar
 {
-    fn solve(self: &Self, rhs: &Matrix<T>) -> Result<Matrix<T>, ()>
+    fn solve(self: &Self, rhs: &Matrix<T>) -> Result<Matrix<T>, MathError>
     {
         return self.solve_matrix_r(rhs);
     }
@@ -29,13 +32,17 @@ impl<T> Solve<Matrix<T>> for Matrix<T>
 impl<T> Matrix<T>
     where T: Field + Scalar
 {
-    fn solve_vector_r(self: &Self, y: &Vector<T>) -> Result<Vector<T>, ()>
+    fn solve_vector_r(self: &Self, y: &Vector<T>) -> Result<Vector<T>, MathError>
     {
         let (m, n): (usize, usize) = self.dim();
         let m_i32: i32 = m as i32;
         let n_i32: i32 = n as i32;
 
         let (y_m, _y_n): (usize, usize) = y.dim();
+        if y_m != m
+        {
+            return Err(MathError::DimensionMismatch);
+        }
         let y_m_i32: i32 = y_m as i32;
 
         let dim_min: i32 = m_i32.min(n_i32);
@@ -53,9 +60,14 @@ impl<T> Matrix<T>
                   ipiv.as_mut_slice(),
                   &mut info);
 
+        if info > 0
+        {
+            return Err(MathError::Singular { pivot: (info - 1) as usize });
+        }
+
         if info != 0
         {
-            return Err(());
+            return Err(MathError::LapackInfo(info));
         }
 
         T::xgetrs(m_i32,
@@ -69,7 +81,7 @@ impl<T> Matrix<T>
 
         if info != 0
         {
-            return Err(());
+            return Err(MathError::LapackInfo(info));
         }
 
         return Ok(Vector::new_column(y_m, y_data));
@@ -79,13 +91,17 @@ impl<T> Matrix<T>
 impl<T> Matrix<T>
     where T: Field + Scalar
 {
-    pub fn solve_matrix_r(self: &Self, y: &Matrix<T>) -> Result<Matrix<T>, ()>
+    pub fn solve_matrix_r(self: &Self, y: &Matrix<T>) -> Result<Matrix<T>, MathError>
     {
         let (m, n): (usize, usize) = self.dim();
         let m_i32: i32 = m as i32;
         let n_i32: i32 = n as i32;
 
         let (y_m, y_n): (usize, usize) = y.dim();
+        if y_m != m
+        {
+            return Err(MathError::DimensionMismatch);
+        }
         let y_n_i32: i32 = y_n as i32;
 
         let dim_min: i32 = m_i32.min(n_i32);
@@ -103,9 +119,14 @@ impl<T> Matrix<T>
                   ipiv.as_mut_slice(),
                   &mut info);
 
+        if info > 0
+        {
+            return Err(MathError::Singular { pivot: (info - 1) as usize });
+        }
+
         if info != 0
         {
-            return Err(());
+            return Err(MathError::LapackInfo(info));
         }
 
         T::xgetrs(n_i32,
@@ -119,7 +140,7 @@ impl<T> Matrix<T>
 
         if info != 0
         {
-            return Err(());
+            return Err(MathError::LapackInfo(info));
         }
 
         return Ok(Matrix::new(y_m, y_n, y_data));
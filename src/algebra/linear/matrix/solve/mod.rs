@@ -3,10 +3,12 @@ pub mod lapack;
 #[cfg(feature = "native")]
 pub mod native;
 
+use crate::error::MathError;
+
 pub trait Solve<T>
 {
     /// A * x = b
-    fn solve(self: &Self, rhs: &T) -> Result<T, ()>;
+    fn solve(self: &Self, rhs: &T) -> Result<T, MathError>;
 }
 // #[cfg(feature = "lapack")]
 // pub use self::lapack::Solve;
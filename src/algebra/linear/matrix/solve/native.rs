@@ -1,6 +1,9 @@
-use crate::algebra::{
-    abstr::{Field, Scalar, AbsDiffEq},
-    linear::{Matrix, Vector},
+use crate::{
+    algebra::{
+        abstr::{Field, Scalar, AbsDiffEq},
+        linear::{Matrix, Vector},
+    },
+    error::MathError,
 };
 
 use super::Solve;
@@ -10,7 +13,7 @@ impl<T> Solve<Vector<T>> for Matrix<T>
 {
     /// Solves Ax = y
     /// where A \in R^{m * n}, x \in R^n, y \in R^m
-    fn solve(self: &Self, rhs: &Vector<T>) -> Result<Vector<T>, ()>
+    fn solve(self: &Self, rhs: &Vector<T>) -> Result<Vector<T>, MathError>
     {
         self.dec_lu()?.solve(rhs)
     }
@@ -19,7 +22,7 @@ impl<T> Solve<Vector<T>> for Matrix<T>
 impl<T> Solve<Matrix<T>> for Matrix<T>
     where T: Field + Scalar + AbsDiffEq
 {
-    fn solve(self: &Self, rhs: &Matrix<T>) -> Result<Matrix<T>, ()>
+    fn solve(self: &Self, rhs: &Matrix<T>) -> Result<Matrix<T>, MathError>
     {
         self.dec_lu()?.solve(rhs)
     }
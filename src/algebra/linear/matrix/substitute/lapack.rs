@@ -1,10 +1,15 @@
-use crate::algebra::linear::{Vector, Matrix};
 use super::Substitute;
-use crate::algebra::abstr::{Field, Scalar};
+use crate::{
+    algebra::{
+        abstr::{Field, Scalar},
+        linear::{Matrix, Vector},
+    },
+    error::MathError,
+};
 
 impl<T> Substitute<Vector<T>> for Matrix<T> where T: Field + Scalar
 {
-    fn substitute_forward(self: &Self, b: Vector<T>) -> Result<Vector<T>, ()>
+    fn substitute_forward(self: &Self, b: Vector<T>) -> Result<Vector<T>, MathError>
     {
         let (b_m, b_n): (usize, usize) = b.dim();
         let mut b_data = b.convert_to_vec();
@@ -23,7 +28,7 @@ impl<T> Substitute<Vector<T>> for Matrix<T> where T: Field + Scalar
         return Ok(Vector::new_column(b_m, b_data));
     }
 
-    fn substitute_backward(self: &Self, b: Vector<T>) -> Result<Vector<T>, ()>
+    fn substitute_backward(self: &Self, b: Vector<T>) -> Result<Vector<T>, MathError>
     {
         let (b_m, b_n): (usize, usize) = b.dim();
         let mut b_data = b.convert_to_vec();
@@ -45,7 +50,7 @@ impl<T> Substitute<Vector<T>> for Matrix<T> where T: Field + Scalar
 
 impl<T> Substitute<Matrix<T>> for Matrix<T> where T: Field + Scalar
 {
-    fn substitute_forward(self: &Self, b: Matrix<T>) -> Result<Matrix<T>, ()>
+    fn substitute_forward(self: &Self, b: Matrix<T>) -> Result<Matrix<T>, MathError>
     {
         let mut c: Matrix<T> = b;
         T::xtrsm('L',
@@ -63,7 +68,7 @@ impl<T> Substitute<Matrix<T>> for Matrix<T> where T: Field + Scalar
         return Ok(c);
     }
 
-    fn substitute_backward(self: &Self, b: Matrix<T>) -> Result<Matrix<T>, ()>
+    fn substitute_backward(self: &Self, b: Matrix<T>) -> Result<Matrix<T>, MathError>
     {
         let mut c: Matrix<T> = b;
         T::xtrsm('L',
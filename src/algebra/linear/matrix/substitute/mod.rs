@@ -3,9 +3,11 @@ pub mod lapack;
 #[cfg(feature = "native")]
 pub mod native;
 
+use crate::error::MathError;
+
 pub trait Substitute<T>
 {
-    fn substitute_forward(self: &Self, b: T) -> Result<T, ()>;
+    fn substitute_forward(self: &Self, b: T) -> Result<T, MathError>;
 
-    fn substitute_backward(self: &Self, b: T) -> Result<T, ()>;
+    fn substitute_backward(self: &Self, b: T) -> Result<T, MathError>;
 }
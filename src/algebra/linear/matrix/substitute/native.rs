@@ -1,10 +1,15 @@
-use crate::algebra::linear::{Vector, Matrix};
 use super::Substitute;
-use crate::algebra::abstr::{Field, Scalar, AbsDiffEq};
+use crate::{
+    algebra::{
+        abstr::{AbsDiffEq, Field, Scalar},
+        linear::{Matrix, Vector},
+    },
+    error::MathError,
+};
 
 impl<T> Substitute<Vector<T>> for Matrix<T> where T: Field + Scalar + AbsDiffEq
 {
-    fn substitute_forward(self: &Self, a: Vector<T>) -> Result<Vector<T>, ()>
+    fn substitute_forward(self: &Self, a: Vector<T>) -> Result<Vector<T>, MathError>
     {
         let mut b: Vector<T> = a;
         for k in 0..self.n
@@ -19,7 +24,7 @@ impl<T> Substitute<Vector<T>> for Matrix<T> where T: Field + Scalar + AbsDiffEq
                 // }
                 // else
                 // {
-                //     return Err(())
+                //     return Err(MathError::Singular { pivot: k })
                 // }
             }
             else
@@ -35,7 +40,7 @@ impl<T> Substitute<Vector<T>> for Matrix<T> where T: Field + Scalar + AbsDiffEq
         return Ok(b);
     }
 
-    fn substitute_backward(self: &Self, c: Vector<T>) -> Result<Vector<T>, ()>
+    fn substitute_backward(self: &Self, c: Vector<T>) -> Result<Vector<T>, MathError>
     {
         let mut b: Vector<T> = c;
 
@@ -50,7 +55,7 @@ impl<T> Substitute<Vector<T>> for Matrix<T> where T: Field + Scalar + AbsDiffEq
                 // }
                 // else
                 // {
-                //     return Err(());
+                //     return Err(MathError::Singular { pivot: k });
                 // }
             }
             else
@@ -69,7 +74,7 @@ impl<T> Substitute<Vector<T>> for Matrix<T> where T: Field + Scalar + AbsDiffEq
 
 impl<T> Substitute<Matrix<T>> for Matrix<T> where T: Field + Scalar + AbsDiffEq
 {
-    fn substitute_forward(self: &Self, a: Matrix<T>) -> Result<Matrix<T>, ()>
+    fn substitute_forward(self: &Self, a: Matrix<T>) -> Result<Matrix<T>, MathError>
     {
         let mut b: Matrix<T> = a;
         let min: usize = std::cmp::min( self.m, self.n);
@@ -84,7 +89,7 @@ impl<T> Substitute<Matrix<T>> for Matrix<T> where T: Field + Scalar + AbsDiffEq
                 // }
                 // else
                 // {
-                //     return Err(());
+                //     return Err(MathError::Singular { pivot: k });
                 // }
             }
             else
@@ -101,7 +106,7 @@ impl<T> Substitute<Matrix<T>> for Matrix<T> where T: Field + Scalar + AbsDiffEq
         return Ok(b);
     }
 
-    fn substitute_backward(self: &Self, a: Matrix<T>) -> Result<Matrix<T>, ()>
+    fn substitute_backward(self: &Self, a: Matrix<T>) -> Result<Matrix<T>, MathError>
     {
         let mut b: Matrix<T> = a;
         let min = std::cmp::min(self.m, self.n);
@@ -120,7 +125,7 @@ impl<T> Substitute<Matrix<T>> for Matrix<T> where T: Field + Scalar + AbsDiffEq
                 // }
                 // else
                 // {
-                //     return Err(())
+                //     return Err(MathError::Singular { pivot: k })
                 // }
             }
             else
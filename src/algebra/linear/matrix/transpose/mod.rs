@@ -6,3 +6,15 @@ pub trait Transpose
     fn transpose(self: Self) -> Self::Output;
 }
 
+/// Conjugate (Hermitian) transpose
+///
+/// ```math
+/// A^H = \overline{A}^T
+/// ```
+///
+/// For real matrices the conjugate transpose is the transpose.
+pub trait ConjugateTranspose
+{
+    type Output;
+    fn conj_transpose(self: Self) -> Self::Output;
+}
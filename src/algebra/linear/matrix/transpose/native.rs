@@ -3,7 +3,7 @@ use crate::algebra::{
     linear::Matrix,
 };
 
-use super::Transpose;
+use super::{ConjugateTranspose, Transpose};
 
 
 impl<T> Matrix<T>
@@ -122,4 +122,31 @@ impl<T> Transpose for Matrix<T>
 
         return self;
     }
-}
\ No newline at end of file
+}
+
+impl<T> ConjugateTranspose for Matrix<T>
+    where T: Field + Scalar
+{
+    type Output = Matrix<T>;
+
+    /// Transposes and conjugates a matrix
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[macro_use]
+    /// # extern crate mathru;
+    /// # fn main()
+    /// # {
+    /// use mathru::algebra::{abstr::Complex, linear::{matrix::ConjugateTranspose, Matrix}};
+    ///
+    /// let a: Matrix<Complex<f64>> = matrix![Complex::new(1.0, 2.0), Complex::new(3.0, -4.0)];
+    ///
+    /// assert_eq!(matrix![Complex::new(1.0, -2.0); Complex::new(3.0, 4.0)], a.conj_transpose());
+    /// # }
+    /// ```
+    fn conj_transpose(self: Self) -> Matrix<T>
+    {
+        return self.transpose().apply_mut(&|x: &T| x.conj());
+    }
+}
@@ -0,0 +1,58 @@
+use super::MatrixView;
+use crate::algebra::{
+    abstr::{Field, Scalar},
+    linear::Matrix,
+};
+use std::ops::{Add, AddAssign};
+
+/// Adds two views
+impl<'a, 'b, T> Add<&'b MatrixView<'b, T>> for &'a MatrixView<'a, T> where T: Field + Scalar
+{
+    type Output = Matrix<T>;
+
+    /// Adds two views, the sum is a new matrix
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[macro_use]
+    /// # extern crate mathru;
+    /// # fn main()
+    /// # {
+    /// use mathru::algebra::linear::{matrix::Transpose, Matrix};
+    ///
+    /// let a: Matrix<f64> = matrix![   1.0, 2.0;
+    ///                                 3.0, 4.0];
+    ///
+    /// assert_eq!(matrix![2.0, 5.0], &a.row(0) + &a.column(0).transpose());
+    /// # }
+    /// ```
+    fn add(self: Self, rhs: &'b MatrixView<'b, T>) -> Matrix<T>
+    {
+        assert_eq!(self.dim(), rhs.dim(), "Matrix dimensions do not match");
+
+        let mut sum: Matrix<T> = Matrix::from(*self);
+        sum.view_mut().add_assign(*rhs);
+        return sum;
+    }
+}
+
+impl<'a, 'b, T> Add<&'b Matrix<T>> for &'a MatrixView<'a, T> where T: Field + Scalar
+{
+    type Output = Matrix<T>;
+
+    fn add(self: Self, rhs: &'b Matrix<T>) -> Matrix<T>
+    {
+        return self + &rhs.view();
+    }
+}
+
+impl<'a, 'b, T> Add<&'b MatrixView<'b, T>> for &'a Matrix<T> where T: Field + Scalar
+{
+    type Output = Matrix<T>;
+
+    fn add(self: Self, rhs: &'b MatrixView<'b, T>) -> Matrix<T>
+    {
+        return &self.view() + rhs;
+    }
+}
@@ -0,0 +1,263 @@
+use crate::algebra::linear::{matrix::Transpose, Matrix};
+use std::clone::Clone;
+
+/// Borrowed, strided view into a matrix
+///
+/// A view references the entries of a matrix without copying them. The
+/// element a_ij of the view is stored at data[i * row_stride + j *
+/// col_stride]. Therewith sub-blocks, single rows and columns and transposed
+/// matrices are represented by the same type.
+///
+/// # Example
+///
+/// ```
+/// # #[macro_use]
+/// # extern crate mathru;
+/// # fn main()
+/// # {
+/// use mathru::algebra::linear::{matrix::MatrixView, Matrix};
+///
+/// let a: Matrix<f64> = matrix![   1.0, 2.0, 3.0;
+///                                 4.0, 5.0, 6.0;
+///                                 7.0, 8.0, 9.0];
+///
+/// let block: MatrixView<f64> = a.slice(1, 2, 0, 1);
+///
+/// assert_eq!((2, 2), block.dim());
+/// assert_eq!(8.0, *block.get(1, 1));
+/// assert_eq!(matrix![4.0, 5.0; 7.0, 8.0], Matrix::from(block));
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct MatrixView<'a, T>
+{
+    pub(super) m: usize,
+    pub(super) n: usize,
+    pub(super) row_stride: usize,
+    pub(super) col_stride: usize,
+    pub(super) data: &'a [T],
+}
+
+impl<'a, T> Clone for MatrixView<'a, T>
+{
+    fn clone(self: &Self) -> MatrixView<'a, T>
+    {
+        return *self;
+    }
+}
+
+impl<'a, T> Copy for MatrixView<'a, T>
+{
+}
+
+/// Number of entries between the first and the last element of a view,
+/// including both
+pub(super) fn span(m: usize, n: usize, row_stride: usize, col_stride: usize) -> usize
+{
+    if m == 0 || n == 0
+    {
+        return 0;
+    }
+
+    return (m - 1) * row_stride + (n - 1) * col_stride + 1;
+}
+
+impl<'a, T> MatrixView<'a, T>
+{
+    pub(crate) fn new(m: usize, n: usize, row_stride: usize, col_stride: usize, data: &'a [T])
+                      -> MatrixView<'a, T>
+    {
+        let len: usize = span(m, n, row_stride, col_stride);
+        return MatrixView { m,
+                            n,
+                            row_stride,
+                            col_stride,
+                            data: &data[..len] };
+    }
+
+    /// Returns the dimension of the view
+    pub fn dim(self: &Self) -> (usize, usize)
+    {
+        return (self.m, self.n);
+    }
+
+    /// Returns the number of rows
+    pub fn nrows(self: &Self) -> usize
+    {
+        return self.m;
+    }
+
+    /// Returns the number of columns
+    pub fn ncols(self: &Self) -> usize
+    {
+        return self.n;
+    }
+
+    /// Returns the element a_ij
+    ///
+    /// # Panics
+    ///
+    /// if the index is out of bounds
+    pub fn get(self: &Self, i: usize, j: usize) -> &'a T
+    {
+        assert!(i < self.m);
+        assert!(j < self.n);
+
+        return &self.data[i * self.row_stride + j * self.col_stride];
+    }
+
+    /// Returns a view of the block from row row_s to row row_e and from column
+    /// column_s to column column_e, the bounds are inclusive like in
+    /// `Matrix::get_slice`
+    ///
+    /// # Panics
+    ///
+    /// if the block exceeds the view
+    pub fn slice(self: &Self, row_s: usize, row_e: usize, column_s: usize, column_e: usize)
+                 -> MatrixView<'a, T>
+    {
+        assert!(row_s <= row_e && row_e < self.m);
+        assert!(column_s <= column_e && column_e < self.n);
+
+        let offset: usize = row_s * self.row_stride + column_s * self.col_stride;
+        return MatrixView::new(row_e - row_s + 1,
+                               column_e - column_s + 1,
+                               self.row_stride,
+                               self.col_stride,
+                               &self.data[offset..]);
+    }
+
+    /// Returns the i-th row as 1 x n view
+    pub fn row(self: &Self, i: usize) -> MatrixView<'a, T>
+    {
+        assert!(i < self.m);
+        return MatrixView::new(1,
+                               self.n,
+                               self.row_stride,
+                               self.col_stride,
+                               &self.data[i * self.row_stride..]);
+    }
+
+    /// Returns the j-th column as m x 1 view
+    pub fn column(self: &Self, j: usize) -> MatrixView<'a, T>
+    {
+        assert!(j < self.n);
+        return MatrixView::new(self.m,
+                               1,
+                               self.row_stride,
+                               self.col_stride,
+                               &self.data[j * self.col_stride..]);
+    }
+}
+
+impl<'a, T> Transpose for MatrixView<'a, T>
+{
+    type Output = MatrixView<'a, T>;
+
+    /// Returns the transposed view, no entries are copied
+    fn transpose(self: Self) -> MatrixView<'a, T>
+    {
+        return MatrixView { m: self.n,
+                            n: self.m,
+                            row_stride: self.col_stride,
+                            col_stride: self.row_stride,
+                            data: self.data };
+    }
+}
+
+impl<'a, 'b, T> PartialEq<MatrixView<'b, T>> for MatrixView<'a, T> where T: PartialEq
+{
+    /// Checks if two views have the same dimension and entries
+    fn eq(self: &Self, other: &MatrixView<'b, T>) -> bool
+    {
+        if self.dim() != other.dim()
+        {
+            return false;
+        }
+
+        for j in 0..self.n
+        {
+            for i in 0..self.m
+            {
+                if self.get(i, j) != other.get(i, j)
+                {
+                    return false;
+                }
+            }
+        }
+
+        return true;
+    }
+}
+
+impl<'a, T> From<MatrixView<'a, T>> for Matrix<T> where T: Clone + Copy
+{
+    /// Copies the entries of the view into a new matrix
+    fn from(view: MatrixView<'a, T>) -> Matrix<T>
+    {
+        let mut data: Vec<T> = Vec::with_capacity(view.m * view.n);
+        for j in 0..view.n
+        {
+            for i in 0..view.m
+            {
+                data.push(*view.get(i, j));
+            }
+        }
+
+        return Matrix::new(view.m, view.n, data);
+    }
+}
+
+impl<T> Matrix<T>
+{
+    /// Returns a view of the whole matrix
+    pub fn view(self: &Self) -> MatrixView<'_, T>
+    {
+        return MatrixView::new(self.m, self.n, 1, self.m, &self.data);
+    }
+
+    /// Returns a view of the block from row row_s to row row_e and from column
+    /// column_s to column column_e
+    ///
+    /// In contrast to `get_slice` no entries are copied, the bounds are
+    /// inclusive.
+    ///
+    /// # Panics
+    ///
+    /// if the block exceeds the matrix
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[macro_use]
+    /// # extern crate mathru;
+    /// # fn main()
+    /// # {
+    /// use mathru::algebra::linear::{matrix::MatrixView, Matrix};
+    ///
+    /// let a: Matrix<f64> = matrix![   1.0, 2.0, 3.0;
+    ///                                 4.0, 5.0, 6.0];
+    ///
+    /// let b: MatrixView<f64> = a.slice(0, 1, 1, 2);
+    ///
+    /// assert_eq!(a.get_slice(0, 1, 1, 2), Matrix::from(b));
+    /// # }
+    /// ```
+    pub fn slice(self: &Self, row_s: usize, row_e: usize, column_s: usize, column_e: usize)
+                 -> MatrixView<'_, T>
+    {
+        return self.view().slice(row_s, row_e, column_s, column_e);
+    }
+
+    /// Returns a view of the i-th row
+    pub fn row(self: &Self, i: usize) -> MatrixView<'_, T>
+    {
+        return self.view().row(i);
+    }
+
+    /// Returns a view of the j-th column
+    pub fn column(self: &Self, j: usize) -> MatrixView<'_, T>
+    {
+        return self.view().column(j);
+    }
+}
@@ -0,0 +1,335 @@
+use super::matrixview::{span, MatrixView};
+use crate::algebra::{
+    abstr::{Field, Scalar},
+    linear::{matrix::Transpose, Matrix},
+};
+use std::ops::{AddAssign, MulAssign, SubAssign};
+
+/// Borrowed, strided and mutable view into a matrix
+///
+/// The mutable counterpart of [`MatrixView`]. Changes of the view are
+/// written directly to the underlying matrix.
+///
+/// # Example
+///
+/// ```
+/// # #[macro_use]
+/// # extern crate mathru;
+/// # fn main()
+/// # {
+/// use mathru::algebra::linear::Matrix;
+///
+/// let mut a: Matrix<f64> = matrix![   1.0, 2.0, 3.0;
+///                                     4.0, 5.0, 6.0];
+///
+/// let b: Matrix<f64> = matrix![-1.0; -2.0];
+///
+/// a.slice_mut(0, 1, 2, 2).assign(b.view());
+/// a.row_mut(0).fill(0.0);
+///
+/// assert_eq!(matrix![0.0, 0.0, 0.0; 4.0, 5.0, -2.0], a);
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct MatrixViewMut<'a, T>
+{
+    pub(super) m: usize,
+    pub(super) n: usize,
+    pub(super) row_stride: usize,
+    pub(super) col_stride: usize,
+    pub(super) data: &'a mut [T],
+}
+
+impl<'a, T> MatrixViewMut<'a, T>
+{
+    pub(crate) fn new(m: usize, n: usize, row_stride: usize, col_stride: usize,
+                      data: &'a mut [T])
+                      -> MatrixViewMut<'a, T>
+    {
+        let len: usize = span(m, n, row_stride, col_stride);
+        return MatrixViewMut { m,
+                               n,
+                               row_stride,
+                               col_stride,
+                               data: &mut data[..len] };
+    }
+
+    /// Returns the dimension of the view
+    pub fn dim(self: &Self) -> (usize, usize)
+    {
+        return (self.m, self.n);
+    }
+
+    /// Returns the number of rows
+    pub fn nrows(self: &Self) -> usize
+    {
+        return self.m;
+    }
+
+    /// Returns the number of columns
+    pub fn ncols(self: &Self) -> usize
+    {
+        return self.n;
+    }
+
+    /// Returns the element a_ij
+    pub fn get(self: &Self, i: usize, j: usize) -> &T
+    {
+        assert!(i < self.m);
+        assert!(j < self.n);
+
+        return &self.data[i * self.row_stride + j * self.col_stride];
+    }
+
+    /// Returns the mutual element a_ij
+    pub fn get_mut(self: &mut Self, i: usize, j: usize) -> &mut T
+    {
+        assert!(i < self.m);
+        assert!(j < self.n);
+
+        return &mut self.data[i * self.row_stride + j * self.col_stride];
+    }
+
+    /// Returns an immutable view of the same entries
+    pub fn view(self: &Self) -> MatrixView<'_, T>
+    {
+        return MatrixView::new(self.m, self.n, self.row_stride, self.col_stride, self.data);
+    }
+
+    /// Returns a mutable view of the block from row row_s to row row_e and from
+    /// column column_s to column column_e, the bounds are inclusive
+    pub fn slice_mut(self: &mut Self, row_s: usize, row_e: usize, column_s: usize,
+                     column_e: usize)
+                     -> MatrixViewMut<'_, T>
+    {
+        assert!(row_s <= row_e && row_e < self.m);
+        assert!(column_s <= column_e && column_e < self.n);
+
+        let offset: usize = row_s * self.row_stride + column_s * self.col_stride;
+        return MatrixViewMut::new(row_e - row_s + 1,
+                                  column_e - column_s + 1,
+                                  self.row_stride,
+                                  self.col_stride,
+                                  &mut self.data[offset..]);
+    }
+
+    /// Returns a mutable view of the i-th row
+    pub fn row_mut(self: &mut Self, i: usize) -> MatrixViewMut<'_, T>
+    {
+        assert!(i < self.m);
+        let offset: usize = i * self.row_stride;
+        return MatrixViewMut::new(1,
+                                  self.n,
+                                  self.row_stride,
+                                  self.col_stride,
+                                  &mut self.data[offset..]);
+    }
+
+    /// Returns a mutable view of the j-th column
+    pub fn column_mut(self: &mut Self, j: usize) -> MatrixViewMut<'_, T>
+    {
+        assert!(j < self.n);
+        let offset: usize = j * self.col_stride;
+        return MatrixViewMut::new(self.m,
+                                  1,
+                                  self.row_stride,
+                                  self.col_stride,
+                                  &mut self.data[offset..]);
+    }
+
+    /// Splits the view into the columns 0..j and j..n
+    ///
+    /// # Panics
+    ///
+    /// if j > n or the columns of the view are interleaved, which is the case
+    /// for transposed views
+    pub fn split_at_column_mut(self: Self, j: usize) -> (MatrixViewMut<'a, T>, MatrixViewMut<'a, T>)
+    {
+        assert!(j <= self.n);
+        assert!(self.n <= 1 || span(self.m, 1, self.row_stride, self.col_stride) <= self.col_stride,
+                "The columns of the view are interleaved");
+
+        let (m, n, row_stride, col_stride): (usize, usize, usize, usize) =
+            (self.m, self.n, self.row_stride, self.col_stride);
+        let mid: usize = (j * col_stride).min(self.data.len());
+        let (left, right): (&'a mut [T], &'a mut [T]) = self.data.split_at_mut(mid);
+
+        return (MatrixViewMut::new(m, j, row_stride, col_stride, left),
+                MatrixViewMut::new(m, n - j, row_stride, col_stride, right));
+    }
+
+    /// Splits the view into the rows 0..i and i..m
+    ///
+    /// # Panics
+    ///
+    /// if i > m or the rows of the view are interleaved, which is the case for
+    /// views, which are not transposed
+    pub fn split_at_row_mut(self: Self, i: usize) -> (MatrixViewMut<'a, T>, MatrixViewMut<'a, T>)
+    {
+        let (top_t, bottom_t): (MatrixViewMut<'a, T>, MatrixViewMut<'a, T>) =
+            self.transpose().split_at_column_mut(i);
+        return (top_t.transpose(), bottom_t.transpose());
+    }
+}
+
+impl<'a, T> MatrixViewMut<'a, T> where T: Clone + Copy
+{
+    /// Sets all entries to value
+    pub fn fill(self: &mut Self, value: T)
+    {
+        for j in 0..self.n
+        {
+            for i in 0..self.m
+            {
+                *self.get_mut(i, j) = value;
+            }
+        }
+    }
+
+    /// Copies the entries of src into the view
+    ///
+    /// # Panics
+    ///
+    /// if the dimensions do not match
+    pub fn assign(self: &mut Self, src: MatrixView<'_, T>)
+    {
+        assert_eq!(self.dim(), src.dim(), "Dimensions do not match");
+
+        for j in 0..self.n
+        {
+            for i in 0..self.m
+            {
+                *self.get_mut(i, j) = *src.get(i, j);
+            }
+        }
+    }
+}
+
+impl<'a, T> Transpose for MatrixViewMut<'a, T>
+{
+    type Output = MatrixViewMut<'a, T>;
+
+    /// Returns the transposed view, no entries are copied
+    fn transpose(self: Self) -> MatrixViewMut<'a, T>
+    {
+        return MatrixViewMut { m: self.n,
+                               n: self.m,
+                               row_stride: self.col_stride,
+                               col_stride: self.row_stride,
+                               data: self.data };
+    }
+}
+
+impl<'a, 'b, T> AddAssign<MatrixView<'b, T>> for MatrixViewMut<'a, T> where T: Field + Scalar
+{
+    fn add_assign(self: &mut Self, rhs: MatrixView<'b, T>)
+    {
+        assert_eq!(self.dim(), rhs.dim(), "Dimensions do not match");
+
+        for j in 0..self.n
+        {
+            for i in 0..self.m
+            {
+                *self.get_mut(i, j) += *rhs.get(i, j);
+            }
+        }
+    }
+}
+
+impl<'a, 'b, T> AddAssign<&'b Matrix<T>> for MatrixViewMut<'a, T> where T: Field + Scalar
+{
+    fn add_assign(self: &mut Self, rhs: &'b Matrix<T>)
+    {
+        *self += rhs.view();
+    }
+}
+
+impl<'a, 'b, T> SubAssign<MatrixView<'b, T>> for MatrixViewMut<'a, T> where T: Field + Scalar
+{
+    fn sub_assign(self: &mut Self, rhs: MatrixView<'b, T>)
+    {
+        assert_eq!(self.dim(), rhs.dim(), "Dimensions do not match");
+
+        for j in 0..self.n
+        {
+            for i in 0..self.m
+            {
+                *self.get_mut(i, j) -= *rhs.get(i, j);
+            }
+        }
+    }
+}
+
+impl<'a, 'b, T> SubAssign<&'b Matrix<T>> for MatrixViewMut<'a, T> where T: Field + Scalar
+{
+    fn sub_assign(self: &mut Self, rhs: &'b Matrix<T>)
+    {
+        *self -= rhs.view();
+    }
+}
+
+impl<'a, T> MulAssign<T> for MatrixViewMut<'a, T> where T: Field + Scalar
+{
+    fn mul_assign(self: &mut Self, rhs: T)
+    {
+        for j in 0..self.n
+        {
+            for i in 0..self.m
+            {
+                *self.get_mut(i, j) *= rhs;
+            }
+        }
+    }
+}
+
+impl<T> Matrix<T>
+{
+    /// Returns a mutable view of the whole matrix
+    pub fn view_mut(self: &mut Self) -> MatrixViewMut<'_, T>
+    {
+        let m: usize = self.m;
+        let n: usize = self.n;
+        return MatrixViewMut::new(m, n, 1, m, &mut self.data);
+    }
+
+    /// Returns a mutable view of the block from row row_s to row row_e and from
+    /// column column_s to column column_e, the bounds are inclusive
+    ///
+    /// In contrast to `set_slice` the entries are changed in place.
+    ///
+    /// # Panics
+    ///
+    /// if the block exceeds the matrix
+    pub fn slice_mut(self: &mut Self, row_s: usize, row_e: usize, column_s: usize,
+                     column_e: usize)
+                     -> MatrixViewMut<'_, T>
+    {
+        assert!(row_s <= row_e && row_e < self.m);
+        assert!(column_s <= column_e && column_e < self.n);
+
+        let m: usize = self.m;
+        let offset: usize = column_s * m + row_s;
+        return MatrixViewMut::new(row_e - row_s + 1,
+                                  column_e - column_s + 1,
+                                  1,
+                                  m,
+                                  &mut self.data[offset..]);
+    }
+
+    /// Returns a mutable view of the i-th row
+    pub fn row_mut(self: &mut Self, i: usize) -> MatrixViewMut<'_, T>
+    {
+        assert!(i < self.m);
+        let m: usize = self.m;
+        let n: usize = self.n;
+        return MatrixViewMut::new(1, n, 1, m, &mut self.data[i..]);
+    }
+
+    /// Returns a mutable view of the j-th column
+    pub fn column_mut(self: &mut Self, j: usize) -> MatrixViewMut<'_, T>
+    {
+        assert!(j < self.n);
+        let m: usize = self.m;
+        return MatrixViewMut::new(m, 1, 1, m, &mut self.data[j * m..]);
+    }
+}
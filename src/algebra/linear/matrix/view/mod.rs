@@ -0,0 +1,8 @@
+mod add;
+mod matrixview;
+mod matrixviewmut;
+mod mul;
+mod solve;
+mod sub;
+
+pub use self::{matrixview::MatrixView, matrixviewmut::MatrixViewMut};
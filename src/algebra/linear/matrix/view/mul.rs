@@ -0,0 +1,114 @@
+use super::MatrixView;
+use crate::algebra::{
+    abstr::{Field, Scalar},
+    linear::{Matrix, Vector},
+};
+use std::ops::Mul;
+
+/// Multiplies two views
+impl<'a, 'b, T> Mul<&'b MatrixView<'b, T>> for &'a MatrixView<'a, T> where T: Field + Scalar
+{
+    type Output = Matrix<T>;
+
+    /// Multiplies two views, the product is a new matrix
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[macro_use]
+    /// # extern crate mathru;
+    /// # fn main()
+    /// # {
+    /// use mathru::algebra::linear::{matrix::Transpose, Matrix};
+    ///
+    /// let a: Matrix<f64> = matrix![   1.0, 2.0, 3.0;
+    ///                                 4.0, 5.0, 6.0];
+    ///
+    /// let a_1: Matrix<f64> = &a.slice(0, 1, 0, 1) * &a.slice(0, 1, 1, 2).transpose();
+    ///
+    /// assert_eq!(matrix![8.0, 17.0; 23.0, 50.0], a_1);
+    /// # }
+    /// ```
+    fn mul(self: Self, rhs: &'b MatrixView<'b, T>) -> Matrix<T>
+    {
+        let (m, k): (usize, usize) = self.dim();
+        let (rhs_m, n): (usize, usize) = rhs.dim();
+        assert_eq!(k, rhs_m, "Matrix dimensions do not match");
+
+        let mut prod: Vec<T> = vec![T::zero(); m * n];
+
+        for j in 0..n
+        {
+            let prod_j: &mut [T] = &mut prod[j * m..(j + 1) * m];
+            for l in 0..k
+            {
+                let b_lj: T = rhs.data[l * rhs.row_stride + j * rhs.col_stride];
+                let a_l: usize = l * self.col_stride;
+                for (i, p_ij) in prod_j.iter_mut().enumerate()
+                {
+                    *p_ij += self.data[i * self.row_stride + a_l] * b_lj;
+                }
+            }
+        }
+
+        return Matrix::new(m, n, prod);
+    }
+}
+
+impl<'a, 'b, T> Mul<&'b Matrix<T>> for &'a MatrixView<'a, T> where T: Field + Scalar
+{
+    type Output = Matrix<T>;
+
+    fn mul(self: Self, rhs: &'b Matrix<T>) -> Matrix<T>
+    {
+        return self * &rhs.view();
+    }
+}
+
+impl<'a, 'b, T> Mul<&'b MatrixView<'b, T>> for &'a Matrix<T> where T: Field + Scalar
+{
+    type Output = Matrix<T>;
+
+    fn mul(self: Self, rhs: &'b MatrixView<'b, T>) -> Matrix<T>
+    {
+        return &self.view() * rhs;
+    }
+}
+
+/// Multiplies a view with a vector
+impl<'a, 'b, T> Mul<&'b Vector<T>> for &'a MatrixView<'a, T> where T: Field + Scalar
+{
+    type Output = Vector<T>;
+
+    fn mul(self: Self, v: &'b Vector<T>) -> Vector<T>
+    {
+        let (m, n): (usize, usize) = self.dim();
+        let (v_m, _v_n): (usize, usize) = v.dim();
+        assert_eq!(n, v_m, "Matrix and Vector dimension do not match");
+
+        let mut prod: Vec<T> = vec![T::zero(); m];
+        for j in 0..n
+        {
+            let v_j: T = *v.get(j);
+            for (i, p_i) in prod.iter_mut().enumerate()
+            {
+                *p_i += *self.get(i, j) * v_j;
+            }
+        }
+
+        return Vector::new_column(m, prod);
+    }
+}
+
+/// Multiplies a view with a scalar
+impl<'a, 'b, T> Mul<&'b T> for &'a MatrixView<'a, T> where T: Field + Scalar
+{
+    type Output = Matrix<T>;
+
+    fn mul(self: Self, rhs: &'b T) -> Matrix<T>
+    {
+        let mut prod: Matrix<T> = Matrix::from(*self);
+        prod.data.iter_mut().for_each(|a_ij| *a_ij *= *rhs);
+        return prod;
+    }
+}
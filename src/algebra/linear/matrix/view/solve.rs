@@ -0,0 +1,137 @@
+use super::MatrixView;
+use crate::{
+    algebra::{
+        abstr::{AbsDiffEq, Complex, Field, Real, Scalar},
+        linear::{
+            matrix::{
+                CholeskyDec, EigenDec, HessenbergDec, Inverse, LUDec, QRDec, QRPDec, SVDDec, Solve,
+            },
+            Matrix, Vector,
+        },
+    },
+    elementary::Power,
+    error::MathError,
+};
+
+// The decompositions work on a copy of the matrix anyway, therefore the view
+// is copied into a contiguous matrix, which is then decomposed in place.
+
+impl<'a, T> Solve<Vector<T>> for MatrixView<'a, T> where T: Field + Scalar + AbsDiffEq
+{
+    /// Solves Ax = y
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[macro_use]
+    /// # extern crate mathru;
+    /// # fn main()
+    /// # {
+    /// use mathru::algebra::linear::{matrix::Solve, Matrix, Vector};
+    ///
+    /// let a: Matrix<f64> = matrix![   6.0, 2.0, 0.0;
+    ///                                 4.0, 3.0, 1.0;
+    ///                                 0.0, 1.0, 1.0];
+    /// let b: Vector<f64> = vector![2.0; 1.0];
+    ///
+    /// let x: Vector<f64> = a.slice(0, 1, 0, 1).solve(&b).unwrap();
+    ///
+    /// assert_relative_eq!(vector![0.4; -0.2], x, epsilon=1.0e-10);
+    /// # }
+    /// ```
+    fn solve(self: &Self, rhs: &Vector<T>) -> Result<Vector<T>, MathError>
+    {
+        return Matrix::from(*self).solve(rhs);
+    }
+}
+
+impl<'a, T> Solve<Matrix<T>> for MatrixView<'a, T> where T: Field + Scalar + AbsDiffEq
+{
+    fn solve(self: &Self, rhs: &Matrix<T>) -> Result<Matrix<T>, MathError>
+    {
+        return Matrix::from(*self).solve(rhs);
+    }
+}
+
+impl<'a, T> Inverse<T> for MatrixView<'a, T> where T: Field + Scalar + AbsDiffEq
+{
+    fn inv(self: &Self) -> Result<Matrix<T>, MathError>
+    {
+        return Matrix::from(*self).inv();
+    }
+}
+
+impl<'a, T> MatrixView<'a, T> where T: Field + Scalar + Power + AbsDiffEq
+{
+    /// LU decomposition of the viewed matrix, see [`Matrix::dec_lu`]
+    pub fn dec_lu(self: &Self) -> Result<LUDec<T>, MathError>
+    {
+        return Matrix::from(*self).dec_lu();
+    }
+
+    /// QR decomposition of the viewed matrix, see [`Matrix::dec_qr`]
+    pub fn dec_qr(self: &Self) -> Result<QRDec<T>, MathError>
+    {
+        return Matrix::from(*self).dec_qr();
+    }
+
+    /// Hessenberg decomposition of the viewed matrix, see
+    /// [`Matrix::dec_hessenberg`]
+    pub fn dec_hessenberg(self: &Self) -> HessenbergDec<T>
+    {
+        return Matrix::from(*self).dec_hessenberg();
+    }
+
+    /// Determinant of the viewed matrix
+    pub fn det(self: &Self) -> T
+    {
+        return Matrix::from(*self).det();
+    }
+}
+
+impl<'a, T> MatrixView<'a, T> where T: Real
+{
+    /// QR decomposition with column pivoting of the viewed matrix, see
+    /// [`Matrix::dec_qrp`]
+    pub fn dec_qrp(self: &Self) -> Result<QRPDec<T>, MathError>
+    {
+        return Matrix::from(*self).dec_qrp();
+    }
+
+    /// Cholesky decomposition of the viewed matrix, see
+    /// [`Matrix::dec_cholesky`]
+    pub fn dec_cholesky(self: &Self) -> Result<CholeskyDec<T>, MathError>
+    {
+        return Matrix::from(*self).dec_cholesky();
+    }
+
+    /// Singular value decomposition of the viewed matrix, see
+    /// [`Matrix::dec_svd`]
+    pub fn dec_svd(self: &Self) -> Result<SVDDec<T>, MathError>
+    {
+        return Matrix::from(*self).dec_svd();
+    }
+
+    /// Thin singular value decomposition of the viewed matrix, see
+    /// [`Matrix::dec_svd_thin`]
+    pub fn dec_svd_thin(self: &Self) -> Result<SVDDec<T>, MathError>
+    {
+        return Matrix::from(*self).dec_svd_thin();
+    }
+
+    /// Eigen decomposition of the viewed symmetric matrix, see
+    /// [`Matrix::dec_eigen_symmetric`]
+    pub fn dec_eigen_symmetric(self: &Self) -> Result<EigenDec<T>, MathError>
+    {
+        return Matrix::from(*self).dec_eigen_symmetric();
+    }
+}
+
+impl<'a, T> MatrixView<'a, T> where T: Real, Complex<T>: Scalar
+{
+    /// Eigen decomposition of the viewed matrix, see [`Matrix::dec_eigen`]
+    pub fn dec_eigen(self: &Self) -> Result<EigenDec<Complex<T>>, MathError>
+    {
+        return Matrix::from(*self).dec_eigen();
+    }
+}
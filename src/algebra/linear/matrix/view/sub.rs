@@ -0,0 +1,58 @@
+use super::MatrixView;
+use crate::algebra::{
+    abstr::{Field, Scalar},
+    linear::Matrix,
+};
+use std::ops::{Sub, SubAssign};
+
+/// Subtracts two views
+impl<'a, 'b, T> Sub<&'b MatrixView<'b, T>> for &'a MatrixView<'a, T> where T: Field + Scalar
+{
+    type Output = Matrix<T>;
+
+    /// Subtracts two views, the difference is a new matrix
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[macro_use]
+    /// # extern crate mathru;
+    /// # fn main()
+    /// # {
+    /// use mathru::algebra::linear::{matrix::Transpose, Matrix};
+    ///
+    /// let a: Matrix<f64> = matrix![   1.0, 2.0;
+    ///                                 3.0, 4.0];
+    ///
+    /// assert_eq!(matrix![0.0, -1.0], &a.row(0) - &a.column(0).transpose());
+    /// # }
+    /// ```
+    fn sub(self: Self, rhs: &'b MatrixView<'b, T>) -> Matrix<T>
+    {
+        assert_eq!(self.dim(), rhs.dim(), "Matrix dimensions do not match");
+
+        let mut diff: Matrix<T> = Matrix::from(*self);
+        diff.view_mut().sub_assign(*rhs);
+        return diff;
+    }
+}
+
+impl<'a, 'b, T> Sub<&'b Matrix<T>> for &'a MatrixView<'a, T> where T: Field + Scalar
+{
+    type Output = Matrix<T>;
+
+    fn sub(self: Self, rhs: &'b Matrix<T>) -> Matrix<T>
+    {
+        return self - &rhs.view();
+    }
+}
+
+impl<'a, 'b, T> Sub<&'b MatrixView<'b, T>> for &'a Matrix<T> where T: Field + Scalar
+{
+    type Output = Matrix<T>;
+
+    fn sub(self: Self, rhs: &'b MatrixView<'b, T>) -> Matrix<T>
+    {
+        return &self.view() - rhs;
+    }
+}
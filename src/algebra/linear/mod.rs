@@ -1,12 +1,22 @@
 //! Linear algebra
 //!
 
-pub use self::{matrix::Matrix, vector::Vector};
+pub use self::{
+    matrix::Matrix,
+    smatrix::{SMatrix, SVector},
+    vector::Vector,
+};
 
 #[macro_use]
 pub mod vector;
 #[macro_use]
 pub mod matrix;
+pub mod smatrix;
+pub mod sparse;
+pub mod banded;
+pub mod solver;
+#[cfg(any(feature = "nalgebra", feature = "ndarray"))]
+mod interop;
 #[cfg(feature = "lapack")]
 pub mod lapack;
 #[cfg(feature = "lapack")]
@@ -0,0 +1,106 @@
+use super::{SMatrix, SVector};
+use crate::{
+    algebra::{abstr::Real, linear::matrix::Solve},
+    error::MathError,
+};
+
+/// Result of a Cholesky decomposition A = L L^T of a fixed size matrix
+#[derive(Debug, Clone, Copy)]
+pub struct SCholeskyDec<T, const N: usize>
+{
+    l: SMatrix<T, N, N>,
+}
+
+impl<T, const N: usize> SCholeskyDec<T, N>
+{
+    /// Return the l matrix
+    pub fn l(self: Self) -> SMatrix<T, N, N>
+    {
+        return self.l;
+    }
+}
+
+impl<T, const N: usize> Solve<SVector<T, N>> for SCholeskyDec<T, N> where T: Real
+{
+    /// Solves Ax = b by forward and backward substitution with L and L^T
+    fn solve(self: &Self, rhs: &SVector<T, N>) -> Result<SVector<T, N>, MathError>
+    {
+        let mut x: SVector<T, N> = *rhs;
+
+        for i in 0..N
+        {
+            for k in 0..i
+            {
+                x[i] = x[i] - *self.l.get(i, k) * x[k];
+            }
+            x[i] /= *self.l.get(i, i);
+        }
+
+        for i in (0..N).rev()
+        {
+            for k in (i + 1)..N
+            {
+                x[i] = x[i] - *self.l.get(k, i) * x[k];
+            }
+            x[i] /= *self.l.get(i, i);
+        }
+
+        return Ok(x);
+    }
+}
+
+impl<T, const N: usize> SMatrix<T, N, N> where T: Real
+{
+    /// Decomposes the symmetric, positive definite matrix A into a lower
+    /// triangular matrix L with A = L L^T
+    ///
+    /// # Return
+    ///
+    /// MathError::NotPositiveDefinite if A is not positive definite
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mathru::algebra::linear::{matrix::Transpose, SMatrix};
+    ///
+    /// let a: SMatrix<f64, 3, 3> = SMatrix::from_rows([[4.0, 2.0, 0.0],
+    ///                                                 [2.0, 5.0, 2.0],
+    ///                                                 [0.0, 2.0, 5.0]]);
+    ///
+    /// let l: SMatrix<f64, 3, 3> = a.dec_cholesky().unwrap().l();
+    ///
+    /// assert_eq!(a, l * l.transpose());
+    /// ```
+    pub fn dec_cholesky(self: &Self) -> Result<SCholeskyDec<T, N>, MathError>
+    {
+        let mut l: SMatrix<T, N, N> = SMatrix::zero();
+
+        for i in 0..N
+        {
+            for j in 0..(i + 1)
+            {
+                let mut sum: T = T::zero();
+                for k in 0..j
+                {
+                    sum += *l.get(i, k) * *l.get(j, k);
+                }
+
+                if i == j
+                {
+                    let d: T = *self.get(i, i) - sum;
+                    if d <= T::zero()
+                    {
+                        return Err(MathError::NotPositiveDefinite);
+                    }
+                    *l.get_mut(i, j) = d.sqrt();
+                }
+                else
+                {
+                    *l.get_mut(i, j) = (*self.get(i, j) - sum) / *l.get(j, j);
+                }
+            }
+        }
+
+        return Ok(SCholeskyDec { l });
+    }
+}
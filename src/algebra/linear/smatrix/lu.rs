@@ -0,0 +1,267 @@
+use super::{SMatrix, SVector};
+use crate::{
+    algebra::{
+        abstr::{Field, Scalar},
+        linear::matrix::Solve,
+    },
+    error::MathError,
+};
+
+/// Result of a LU decomposition PA = LU of a fixed size matrix
+#[derive(Debug, Clone, Copy)]
+pub struct SLUDec<T, const N: usize>
+{
+    l: SMatrix<T, N, N>,
+    u: SMatrix<T, N, N>,
+    p: SMatrix<T, N, N>,
+    /// Determinant of p
+    p_det: T,
+}
+
+impl<T, const N: usize> SLUDec<T, N>
+{
+    /// Return l Matrix of LU decomposition
+    pub fn l(self: Self) -> SMatrix<T, N, N>
+    {
+        return self.l;
+    }
+
+    /// Return u Matrix of LU decomposition
+    pub fn u(self: Self) -> SMatrix<T, N, N>
+    {
+        return self.u;
+    }
+
+    /// Return p Matrix of LU decomposition
+    pub fn p(self: Self) -> SMatrix<T, N, N>
+    {
+        return self.p;
+    }
+
+    /// Return l, u, and p matrix of the LU decomposition
+    pub fn lup(self: Self) -> (SMatrix<T, N, N>, SMatrix<T, N, N>, SMatrix<T, N, N>)
+    {
+        return (self.l, self.u, self.p);
+    }
+}
+
+impl<T, const N: usize> SLUDec<T, N> where T: Field + Scalar
+{
+    /// Solves LUx = Pb by forward and backward substitution
+    fn substitute(self: &Self, b: &SVector<T, N>) -> SVector<T, N>
+    {
+        let mut x: SVector<T, N> = self.p * *b;
+
+        for i in 0..N
+        {
+            for k in 0..i
+            {
+                x[i] = x[i] - *self.l.get(i, k) * x[k];
+            }
+        }
+
+        for i in (0..N).rev()
+        {
+            for k in (i + 1)..N
+            {
+                x[i] = x[i] - *self.u.get(i, k) * x[k];
+            }
+            x[i] /= *self.u.get(i, i);
+        }
+
+        return x;
+    }
+}
+
+impl<T, const N: usize> Solve<SVector<T, N>> for SLUDec<T, N> where T: Field + Scalar
+{
+    /// Solves Ax = b
+    fn solve(self: &Self, rhs: &SVector<T, N>) -> Result<SVector<T, N>, MathError>
+    {
+        return Ok(self.substitute(rhs));
+    }
+}
+
+impl<T, const N: usize> SMatrix<T, N, N> where T: Field + Scalar
+{
+    /// Decomposes the matrix into a lower and an upper triangular matrix
+    ///
+    /// PA = LU
+    ///
+    /// # Return
+    ///
+    /// MathError::Singular if a pivot is zero
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mathru::algebra::linear::SMatrix;
+    ///
+    /// let a: SMatrix<f64, 2, 2> = SMatrix::from_rows([[1.0, -2.0], [3.0, -7.0]]);
+    ///
+    /// let (l, u, p): (SMatrix<f64, 2, 2>, SMatrix<f64, 2, 2>, SMatrix<f64, 2, 2>) = a.dec_lu().unwrap().lup();
+    ///
+    /// assert_eq!(p * a, l * u);
+    /// ```
+    pub fn dec_lu(self: &Self) -> Result<SLUDec<T, N>, MathError>
+    {
+        let mut a: SMatrix<T, N, N> = *self;
+        let mut p: SMatrix<T, N, N> = SMatrix::one();
+        let mut p_det: T = T::one();
+
+        for i in 0..N
+        {
+            //pivoting
+            let mut max: T = T::zero();
+            let mut i_max: usize = i;
+
+            for k in i..N
+            {
+                let p_cand: T = a.get(k, i).abs();
+                if p_cand > max
+                {
+                    max = p_cand;
+                    i_max = k;
+                }
+            }
+
+            if max == T::zero()
+            {
+                return Err(MathError::Singular { pivot: i });
+            }
+
+            if i != i_max
+            {
+                for j in 0..N
+                {
+                    a.data[j].swap(i, i_max);
+                    p.data[j].swap(i, i_max);
+                }
+                p_det = -p_det;
+            }
+
+            for k in (i + 1)..N
+            {
+                let f: T = *a.get(k, i) / *a.get(i, i);
+
+                for j in (i + 1)..N
+                {
+                    *a.get_mut(k, j) = *a.get(k, j) - f * *a.get(i, j);
+                }
+                *a.get_mut(k, i) = f;
+            }
+        }
+
+        let mut l: SMatrix<T, N, N> = SMatrix::one();
+        let mut u: SMatrix<T, N, N> = SMatrix::zero();
+
+        for j in 0..N
+        {
+            for i in 0..N
+            {
+                if i > j
+                {
+                    *l.get_mut(i, j) = *a.get(i, j);
+                }
+                else
+                {
+                    *u.get_mut(i, j) = *a.get(i, j);
+                }
+            }
+        }
+
+        return Ok(SLUDec { l, u, p, p_det });
+    }
+
+    /// Determinant of the matrix
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[macro_use]
+    /// # extern crate mathru;
+    /// # fn main()
+    /// # {
+    /// use mathru::algebra::linear::SMatrix;
+    ///
+    /// let a: SMatrix<f64, 2, 2> = SMatrix::from_rows([[1.0, -2.0], [3.0, -7.0]]);
+    ///
+    /// assert_relative_eq!(-1.0, a.det(), epsilon=1.0e-10);
+    /// # }
+    /// ```
+    pub fn det(self: &Self) -> T
+    {
+        let lu: SLUDec<T, N> = match self.dec_lu()
+        {
+            Ok(lu) => lu,
+            Err(_) => return T::zero(),
+        };
+
+        let mut det: T = lu.p_det;
+        for i in 0..N
+        {
+            det *= *lu.u.get(i, i);
+        }
+
+        return det;
+    }
+
+    /// Inverse of the matrix
+    ///
+    /// # Return
+    ///
+    /// MathError::Singular if the matrix is singular
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[macro_use]
+    /// # extern crate mathru;
+    /// # fn main()
+    /// # {
+    /// use mathru::algebra::linear::SMatrix;
+    ///
+    /// let a: SMatrix<f64, 2, 2> = SMatrix::from_rows([[1.0, 0.0], [3.0, -7.0]]);
+    ///
+    /// let a_inv: SMatrix<f64, 2, 2> = a.inv().unwrap();
+    ///
+    /// assert_relative_eq!(SMatrix::one(), a * a_inv, epsilon=1.0e-10);
+    /// # }
+    /// ```
+    pub fn inv(self: &Self) -> Result<SMatrix<T, N, N>, MathError>
+    {
+        let lu: SLUDec<T, N> = self.dec_lu()?;
+        let mut inv: SMatrix<T, N, N> = SMatrix::zero();
+
+        for j in 0..N
+        {
+            let mut e_j: SVector<T, N> = SVector::zero();
+            e_j[j] = T::one();
+            inv.data[j] = lu.substitute(&e_j).data[0];
+        }
+
+        return Ok(inv);
+    }
+}
+
+impl<T, const N: usize> Solve<SVector<T, N>> for SMatrix<T, N, N> where T: Field + Scalar
+{
+    /// Solves Ax = b
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mathru::algebra::linear::{matrix::Solve, SMatrix, SVector};
+    ///
+    /// let a: SMatrix<f64, 2, 2> = SMatrix::from_rows([[6.0, 2.0], [4.0, 3.0]]);
+    /// let b: SVector<f64, 2> = SVector::new_column([2.0, 1.0]);
+    ///
+    /// let x: SVector<f64, 2> = a.solve(&b).unwrap();
+    ///
+    /// assert_eq!(b, a * x);
+    /// ```
+    fn solve(self: &Self, rhs: &SVector<T, N>) -> Result<SVector<T, N>, MathError>
+    {
+        return self.dec_lu()?.solve(rhs);
+    }
+}
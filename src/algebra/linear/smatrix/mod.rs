@@ -0,0 +1,19 @@
+//! Fixed size matrices and vectors
+//!
+//! The dimensions of [`SMatrix`] and [`SVector`] are const generic parameters.
+//! The entries are stored on the stack and mismatching dimensions of
+//! operands are rejected at compile time.
+mod add;
+mod cholesky;
+mod lu;
+mod mul;
+mod smatrix;
+mod sub;
+mod svector;
+
+pub use self::{
+    cholesky::SCholeskyDec,
+    lu::SLUDec,
+    smatrix::SMatrix,
+    svector::SVector,
+};
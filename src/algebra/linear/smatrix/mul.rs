@@ -0,0 +1,75 @@
+use super::SMatrix;
+use crate::algebra::abstr::{Field, Scalar};
+use std::ops::{Mul, MulAssign};
+
+impl<T, const M: usize, const N: usize, const P: usize> Mul<SMatrix<T, N, P>>
+    for SMatrix<T, M, N> where T: Field + Scalar
+{
+    type Output = SMatrix<T, M, P>;
+
+    /// Multiplies two matrices
+    ///
+    /// The number of columns of the left hand side has to be equal to the
+    /// number of rows of the right hand side, otherwise the product does not
+    /// compile.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mathru::algebra::linear::SMatrix;
+    ///
+    /// let a: SMatrix<f64, 2, 3> = SMatrix::from_rows([[1.0, 2.0, 5.0], [3.0, 4.0, 6.0]]);
+    /// let b: SMatrix<f64, 3, 2> = SMatrix::from_rows([[5.0, 8.0], [6.0, 9.0], [7.0, 10.0]]);
+    ///
+    /// assert_eq!(SMatrix::from_rows([[52.0, 76.0], [81.0, 120.0]]), a * b);
+    /// ```
+    ///
+    /// ```compile_fail
+    /// use mathru::algebra::linear::SMatrix;
+    ///
+    /// let a: SMatrix<f64, 2, 3> = SMatrix::zero();
+    /// let b: SMatrix<f64, 2, 3> = SMatrix::zero();
+    ///
+    /// let c = a * b;
+    /// ```
+    fn mul(self: Self, rhs: SMatrix<T, N, P>) -> SMatrix<T, M, P>
+    {
+        let mut prod: SMatrix<T, M, P> = SMatrix::zero();
+
+        for (c_j, b_j) in prod.data.iter_mut().zip(rhs.data.iter())
+        {
+            for (a_k, b_kj) in self.data.iter().zip(b_j.iter())
+            {
+                for (c_ij, a_ik) in c_j.iter_mut().zip(a_k.iter())
+                {
+                    *c_ij += *a_ik * *b_kj;
+                }
+            }
+        }
+
+        return prod;
+    }
+}
+
+impl<T, const M: usize, const N: usize> Mul<T> for SMatrix<T, M, N> where T: Field + Scalar
+{
+    type Output = SMatrix<T, M, N>;
+
+    /// Multiplies a matrix with a scalar
+    fn mul(mut self: Self, rhs: T) -> SMatrix<T, M, N>
+    {
+        self *= rhs;
+        return self;
+    }
+}
+
+impl<T, const M: usize, const N: usize> MulAssign<T> for SMatrix<T, M, N> where T: Field + Scalar
+{
+    fn mul_assign(self: &mut Self, rhs: T)
+    {
+        for a_ij in self.data.iter_mut().flatten()
+        {
+            *a_ij *= rhs;
+        }
+    }
+}
@@ -0,0 +1,284 @@
+use crate::{
+    algebra::{
+        abstr::{AbsDiffEq, Field, RelativeEq, Scalar},
+        linear::{matrix::Transpose, Matrix},
+    },
+    error::MathError,
+};
+use std::{array, convert::TryFrom};
+
+/// Matrix with M rows and N columns, the dimensions are known at compile
+/// time
+///
+/// The entries are stored column by column in an array, therewith the matrix
+/// lives on the stack and does not need any heap allocation.
+///
+/// # Example
+///
+/// ```
+/// use mathru::algebra::linear::{SMatrix, SVector};
+///
+/// let a: SMatrix<f64, 2, 3> = SMatrix::from_rows([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+/// let x: SVector<f64, 3> = SVector::new_column([1.0, 0.0, -1.0]);
+///
+/// // The product of a 2 x 3 matrix with a 3 x 1 vector is a 2 x 1 vector
+/// let y: SVector<f64, 2> = a * x;
+///
+/// assert_eq!(SVector::new_column([-2.0, -2.0]), y);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SMatrix<T, const M: usize, const N: usize>
+{
+    /// Columns of the matrix
+    pub(super) data: [[T; M]; N],
+}
+
+impl<T, const M: usize, const N: usize> SMatrix<T, M, N>
+{
+    /// Creates a matrix from its columns
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mathru::algebra::linear::SMatrix;
+    ///
+    /// let a: SMatrix<f64, 2, 2> = SMatrix::new([[1.0, 3.0], [2.0, 4.0]]);
+    ///
+    /// assert_eq!(2.0, *a.get(0, 1));
+    /// ```
+    pub fn new(columns: [[T; M]; N]) -> SMatrix<T, M, N>
+    {
+        return SMatrix { data: columns };
+    }
+
+    /// Returns the dimension (M, N) of the matrix
+    pub fn dim(self: &Self) -> (usize, usize)
+    {
+        return (M, N);
+    }
+
+    /// Returns the number of rows
+    pub fn nrows(self: &Self) -> usize
+    {
+        return M;
+    }
+
+    /// Returns the number of columns
+    pub fn ncols(self: &Self) -> usize
+    {
+        return N;
+    }
+
+    /// Returns the element a_ij
+    ///
+    /// # Panics
+    ///
+    /// if the index is out of bounds
+    pub fn get(self: &Self, i: usize, j: usize) -> &T
+    {
+        return &self.data[j][i];
+    }
+
+    /// Returns the mutual element a_ij
+    ///
+    /// # Panics
+    ///
+    /// if the index is out of bounds
+    pub fn get_mut(self: &mut Self, i: usize, j: usize) -> &mut T
+    {
+        return &mut self.data[j][i];
+    }
+}
+
+impl<T, const M: usize, const N: usize> SMatrix<T, M, N> where T: Copy
+{
+    /// Creates a matrix from its rows
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mathru::algebra::linear::SMatrix;
+    ///
+    /// let a: SMatrix<f64, 2, 2> = SMatrix::from_rows([[1.0, 2.0], [3.0, 4.0]]);
+    ///
+    /// assert_eq!(SMatrix::new([[1.0, 3.0], [2.0, 4.0]]), a);
+    /// ```
+    pub fn from_rows(rows: [[T; N]; M]) -> SMatrix<T, M, N>
+    {
+        return SMatrix { data: array::from_fn(|j| array::from_fn(|i| rows[i][j])) };
+    }
+
+    /// Returns the j-th column
+    pub fn get_column(self: &Self, j: usize) -> SMatrix<T, M, 1>
+    {
+        return SMatrix { data: [self.data[j]] };
+    }
+
+    /// Returns the i-th row
+    pub fn get_row(self: &Self, i: usize) -> SMatrix<T, 1, N>
+    {
+        return SMatrix { data: array::from_fn(|j| [self.data[j][i]]) };
+    }
+
+    /// Applies f to all entries
+    pub fn apply(self: &Self, f: &dyn Fn(&T) -> T) -> SMatrix<T, M, N>
+    {
+        return SMatrix { data: array::from_fn(|j| array::from_fn(|i| f(&self.data[j][i]))) };
+    }
+}
+
+impl<T, const M: usize, const N: usize> SMatrix<T, M, N> where T: Field + Scalar
+{
+    /// Returns the zero matrix
+    pub fn zero() -> SMatrix<T, M, N>
+    {
+        return SMatrix { data: [[T::zero(); M]; N] };
+    }
+}
+
+impl<T, const N: usize> SMatrix<T, N, N> where T: Field + Scalar
+{
+    /// Returns the identity matrix
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mathru::algebra::linear::SMatrix;
+    ///
+    /// let a: SMatrix<f64, 2, 2> = SMatrix::one();
+    ///
+    /// assert_eq!(SMatrix::new([[1.0, 0.0], [0.0, 1.0]]), a);
+    /// ```
+    pub fn one() -> SMatrix<T, N, N>
+    {
+        let mut one: SMatrix<T, N, N> = SMatrix::zero();
+        for i in 0..N
+        {
+            one.data[i][i] = T::one();
+        }
+        return one;
+    }
+
+    /// Returns the sum of the diagonal entries
+    pub fn trace(self: &Self) -> T
+    {
+        let mut sum: T = T::zero();
+        for i in 0..N
+        {
+            sum += self.data[i][i];
+        }
+        return sum;
+    }
+}
+
+impl<T, const M: usize, const N: usize> Transpose for SMatrix<T, M, N> where T: Copy
+{
+    type Output = SMatrix<T, N, M>;
+
+    /// Returns the transposed matrix
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mathru::algebra::linear::{matrix::Transpose, SMatrix};
+    ///
+    /// let a: SMatrix<f64, 1, 2> = SMatrix::from_rows([[1.0, 2.0]]);
+    /// let a_t: SMatrix<f64, 2, 1> = a.transpose();
+    ///
+    /// assert_eq!(SMatrix::from_rows([[1.0], [2.0]]), a_t);
+    /// ```
+    fn transpose(self: Self) -> SMatrix<T, N, M>
+    {
+        return SMatrix { data: array::from_fn(|i| array::from_fn(|j| self.data[j][i])) };
+    }
+}
+
+impl<T, const M: usize, const N: usize> From<SMatrix<T, M, N>> for Matrix<T> where T: Copy
+{
+    /// Copies the entries into a dynamically sized matrix
+    fn from(a: SMatrix<T, M, N>) -> Matrix<T>
+    {
+        let data: Vec<T> = a.data.iter().flat_map(|column| column.iter().copied()).collect();
+        return Matrix::new(M, N, data);
+    }
+}
+
+impl<'a, T, const M: usize, const N: usize> TryFrom<&'a Matrix<T>> for SMatrix<T, M, N>
+    where T: Copy
+{
+    type Error = MathError;
+
+    /// Copies the entries of a dynamically sized matrix
+    ///
+    /// # Return
+    ///
+    /// MathError::DimensionMismatch if the matrix is not a M x N matrix
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[macro_use]
+    /// # extern crate mathru;
+    /// # fn main()
+    /// # {
+    /// use mathru::algebra::linear::{Matrix, SMatrix};
+    /// use std::convert::TryFrom;
+    ///
+    /// let a: Matrix<f64> = matrix![   1.0, 2.0;
+    ///                                 3.0, 4.0];
+    ///
+    /// let b: SMatrix<f64, 2, 2> = SMatrix::try_from(&a).unwrap();
+    ///
+    /// assert_eq!(a, Matrix::from(b));
+    /// assert!(SMatrix::<f64, 3, 2>::try_from(&a).is_err());
+    /// # }
+    /// ```
+    fn try_from(a: &'a Matrix<T>) -> Result<SMatrix<T, M, N>, MathError>
+    {
+        if a.dim() != (M, N)
+        {
+            return Err(MathError::DimensionMismatch);
+        }
+
+        return Ok(SMatrix { data: array::from_fn(|j| array::from_fn(|i| *a.get(i, j))) });
+    }
+}
+
+impl<T, const M: usize, const N: usize> AbsDiffEq for SMatrix<T, M, N>
+    where T: Field + Scalar + AbsDiffEq<Epsilon = T>
+{
+    type Epsilon = T;
+
+    fn default_epsilon() -> T
+    {
+        T::default_epsilon()
+    }
+
+    fn abs_diff_eq(&self, other: &SMatrix<T, M, N>, epsilon: T) -> bool
+    {
+        return self.data
+                   .iter()
+                   .flatten()
+                   .zip(other.data.iter().flatten())
+                   .all(|(a, b)| a.abs_diff_eq(b, epsilon));
+    }
+}
+
+impl<T, const M: usize, const N: usize> RelativeEq for SMatrix<T, M, N>
+    where T: Field + Scalar + AbsDiffEq<Epsilon = T> + RelativeEq
+{
+    fn default_max_relative() -> T
+    {
+        T::default_max_relative()
+    }
+
+    /// A test for equality that uses a relative comparison if the values are far apart.
+    fn relative_eq(&self, other: &SMatrix<T, M, N>, epsilon: T, max_relative: T) -> bool
+    {
+        return self.data
+                   .iter()
+                   .flatten()
+                   .zip(other.data.iter().flatten())
+                   .all(|(a, b)| a.relative_eq(b, epsilon, max_relative));
+    }
+}
@@ -0,0 +1,42 @@
+use super::SMatrix;
+use crate::algebra::abstr::{Field, Scalar};
+use std::ops::{Sub, SubAssign};
+
+impl<T, const M: usize, const N: usize> Sub<SMatrix<T, M, N>> for SMatrix<T, M, N>
+    where T: Field + Scalar
+{
+    type Output = SMatrix<T, M, N>;
+
+    /// Subtracts two matrices of the same dimension
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mathru::algebra::linear::SMatrix;
+    ///
+    /// let a: SMatrix<f64, 2, 2> = SMatrix::from_rows([[1.0, 0.0], [3.0, -7.0]]);
+    /// let b: SMatrix<f64, 2, 2> = SMatrix::from_rows([[0.0, 1.0], [2.0, 3.0]]);
+    ///
+    /// assert_eq!(SMatrix::from_rows([[1.0, -1.0], [1.0, -10.0]]), a - b);
+    /// ```
+    fn sub(mut self: Self, rhs: SMatrix<T, M, N>) -> SMatrix<T, M, N>
+    {
+        self -= rhs;
+        return self;
+    }
+}
+
+impl<T, const M: usize, const N: usize> SubAssign<SMatrix<T, M, N>> for SMatrix<T, M, N>
+    where T: Field + Scalar
+{
+    fn sub_assign(self: &mut Self, rhs: SMatrix<T, M, N>)
+    {
+        for (a_j, b_j) in self.data.iter_mut().zip(rhs.data.iter())
+        {
+            for (a_ij, b_ij) in a_j.iter_mut().zip(b_j.iter())
+            {
+                *a_ij -= *b_ij;
+            }
+        }
+    }
+}
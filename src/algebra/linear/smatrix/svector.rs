@@ -0,0 +1,116 @@
+use super::SMatrix;
+use crate::{
+    algebra::{
+        abstr::{Field, Real, Scalar},
+        linear::Vector,
+    },
+    error::MathError,
+};
+use std::{
+    array,
+    convert::TryFrom,
+    ops::{Index, IndexMut},
+};
+
+/// Column vector with N entries, the dimension is known at compile time
+///
+/// A vector is a N x 1 matrix, therefore all operations of [`SMatrix`] are
+/// available.
+///
+/// # Example
+///
+/// ```
+/// use mathru::algebra::linear::SVector;
+///
+/// let x: SVector<f64, 3> = SVector::new_column([1.0, 2.0, 2.0]);
+///
+/// assert_eq!(2.0, x[1]);
+/// assert_eq!(3.0, x.eucl_norm());
+/// ```
+pub type SVector<T, const N: usize> = SMatrix<T, N, 1>;
+
+impl<T, const N: usize> SMatrix<T, N, 1>
+{
+    /// Creates a column vector
+    pub fn new_column(data: [T; N]) -> SVector<T, N>
+    {
+        return SMatrix { data: [data] };
+    }
+
+    /// Returns the entries of the vector
+    pub fn convert_to_array(self: Self) -> [T; N]
+    {
+        let [data] = self.data;
+        return data;
+    }
+}
+
+impl<T, const N: usize> SMatrix<T, N, 1> where T: Field + Scalar
+{
+    /// Dot product of two vectors
+    pub fn dotp(self: &Self, rhs: &SVector<T, N>) -> T
+    {
+        let mut sum: T = T::zero();
+        for i in 0..N
+        {
+            sum += self[i] * rhs[i];
+        }
+        return sum;
+    }
+}
+
+impl<T, const N: usize> SMatrix<T, N, 1> where T: Real
+{
+    /// Euclidean norm
+    pub fn eucl_norm(self: &Self) -> T
+    {
+        return self.dotp(self).sqrt();
+    }
+}
+
+impl<T, const N: usize> Index<usize> for SMatrix<T, N, 1>
+{
+    type Output = T;
+
+    fn index(self: &Self, i: usize) -> &T
+    {
+        return &self.data[0][i];
+    }
+}
+
+impl<T, const N: usize> IndexMut<usize> for SMatrix<T, N, 1>
+{
+    fn index_mut(self: &mut Self, i: usize) -> &mut T
+    {
+        return &mut self.data[0][i];
+    }
+}
+
+impl<T, const N: usize> From<SMatrix<T, N, 1>> for Vector<T> where T: Copy
+{
+    /// Copies the entries into a dynamically sized column vector
+    fn from(x: SVector<T, N>) -> Vector<T>
+    {
+        return Vector::new_column(N, x.data[0].to_vec());
+    }
+}
+
+impl<'a, T, const N: usize> TryFrom<&'a Vector<T>> for SMatrix<T, N, 1> where T: Copy
+{
+    type Error = MathError;
+
+    /// Copies the entries of a dynamically sized column vector
+    ///
+    /// # Return
+    ///
+    /// MathError::DimensionMismatch if x is not a column vector with N entries
+    fn try_from(x: &'a Vector<T>) -> Result<SVector<T, N>, MathError>
+    {
+        if x.dim() != (N, 1)
+        {
+            return Err(MathError::DimensionMismatch);
+        }
+
+        return Ok(SVector::new_column(array::from_fn(|i| *x.get(i))));
+    }
+}
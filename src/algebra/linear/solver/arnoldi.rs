@@ -0,0 +1,234 @@
+use super::{krylovbasis::KrylovBasis, EigenTarget, LinearOperator};
+use crate::{
+    algebra::{
+        abstr::{Complex, Real, Scalar},
+        linear::{matrix::EigenDec, Matrix, Vector},
+    },
+    error::MathError,
+};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Implicitly restarted Arnoldi method
+///
+/// Computes a few eigenpairs of a general real operator A. The Arnoldi process
+/// builds an orthonormal basis V of a Krylov subspace of dimension ncv, the
+/// eigenpairs (θ, y) of the projection $`H = V^{T} A V`$ yield the Ritz pairs
+/// (θ, V y). The method is restarted with an orthonormal basis of the real
+/// and imaginary parts of the wanted Ritz vectors (Krylov-Schur restart),
+/// which is equivalent to an implicit restart with the unwanted Ritz values
+/// as exact shifts. Complex conjugate Ritz values are kept or discarded
+/// together.
+///
+/// A Ritz pair has converged, if its residual satisfies
+///
+/// ```math
+/// \lVert A x - \theta x \rVert_{2} \leq tol \max(\lvert \theta \rvert, \epsilon^{2/3})
+/// ```
+///
+/// For more information: <br>
+/// G. W. Stewart, A Krylov-Schur algorithm for large eigenproblems, SIAM J.
+/// Matrix Anal. Appl., 23(3), 2001
+///
+/// # Example
+///
+/// ```
+/// # #[macro_use]
+/// # extern crate mathru;
+/// # fn main()
+/// # {
+/// use mathru::algebra::{
+///     abstr::Complex,
+///     linear::{
+///         solver::{Arnoldi, EigenTarget},
+///         Matrix, Vector,
+///     },
+/// };
+///
+/// // upwind discretization of a convection diffusion operator
+/// let n: usize = 40;
+/// let a = |x: &Vector<f64>| -> Vector<f64> {
+///     let mut y: Vector<f64> = Vector::zero(n);
+///     for i in 0..n
+///     {
+///         let left: f64 = if i > 0 { *x.get(i - 1) } else { 0.0 };
+///         let right: f64 = if i + 1 < n { *x.get(i + 1) } else { 0.0 };
+///         *y.get_mut(i) = 3.0 * *x.get(i) - 2.0 * left - right;
+///     }
+///     y
+/// };
+///
+/// let arnoldi: Arnoldi<f64> = Arnoldi::new(2, 20, 200, 1.0e-10);
+/// let value: Vector<Complex<f64>> = arnoldi.solve(&a, &Vector::new_column(n, vec![1.0; n]), EigenTarget::LargestReal)
+///                                          .unwrap()
+///                                          .value();
+///
+/// // the eigenvalues are 3 - 2 sqrt(2) cos(k pi / (n + 1))
+/// let h: f64 = std::f64::consts::PI / (n + 1) as f64;
+/// assert_relative_eq!(3.0 + 2.0 * 2.0f64.sqrt() * h.cos(), value.get(0).re, epsilon=1.0e-8);
+/// # }
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Copy, Debug)]
+pub struct Arnoldi<T>
+{
+    nev: usize,
+    ncv: usize,
+    max_restart: usize,
+    tolerance: T,
+}
+
+impl<T> Arnoldi<T>
+{
+    /// Creates an instance of the implicitly restarted Arnoldi method
+    ///
+    /// # Arguments
+    ///
+    /// * 'nev': Number of wanted eigenpairs
+    /// * 'ncv': Dimension of the Krylov subspace, usually 2 nev + 1 or larger
+    /// * 'max_restart': Maximum number of restarts
+    /// * 'tolerance': Tolerance for the relative residual of a Ritz pair
+    ///
+    /// # Panics
+    ///
+    /// if nev is zero or ncv is not greater than nev + 1
+    pub fn new(nev: usize, ncv: usize, max_restart: usize, tolerance: T) -> Arnoldi<T>
+    {
+        assert!(nev > 0 && ncv > nev + 1);
+        return Arnoldi { nev,
+                         ncv,
+                         max_restart,
+                         tolerance };
+    }
+}
+
+impl<T> Arnoldi<T> where T: Real, Complex<T>: Scalar
+{
+    /// Computes the wanted eigenpairs of the operator a
+    ///
+    /// # Arguments
+    ///
+    /// * 'a': Operator, for shift-invert the operator $`(A - \sigma I)^{-1}`$
+    /// * 'v_0': Start vector, its dimension is the dimension of the operator
+    /// * 'target': Wanted eigenvalues
+    ///
+    /// # Return
+    ///
+    /// The eigenvalues sorted by the target, e.g. the largest magnitude
+    /// first, and the eigenvectors of unit length
+    ///
+    /// # Errors
+    ///
+    /// MathError::DimensionMismatch if the operator has less than nev
+    /// dimensions, MathError::Breakdown if the start vector is zero and
+    /// MathError::NoConvergence if the eigenpairs have not converged within
+    /// the maximum number of restarts
+    pub fn solve<A>(self: &Self, a: &A, v_0: &Vector<T>, target: EigenTarget<T>)
+                    -> Result<EigenDec<Complex<T>>, MathError>
+        where A: LinearOperator<T>
+    {
+        let (n, _): (usize, usize) = v_0.dim();
+        if self.nev > n
+        {
+            return Err(MathError::DimensionMismatch);
+        }
+
+        let m: usize = self.ncv.min(n);
+        let eps: T = T::default_epsilon().pow(T::from_f64(2.0 / 3.0));
+        let mut basis: KrylovBasis<T> = KrylovBasis::new(v_0, m)?;
+
+        for restart in 0..(self.max_restart + 1)
+        {
+            basis.expand(a);
+
+            let (theta, y): (Vector<Complex<T>>, Matrix<Complex<T>>) = basis.h().clone().dec_eigen()?.pair();
+            let (y_re, y_im): (Matrix<T>, Matrix<T>) = Arnoldi::split(&y);
+
+            // the stable sort keeps a pair of complex conjugate values next to
+            // each other, the value with the positive imaginary part first
+            let mut order: Vec<usize> = (0..m).collect();
+            order.sort_by(|i, j| {
+                     let theta_i: &Complex<T> = theta.get(*i);
+                     let theta_j: &Complex<T> = theta.get(*j);
+                     target.rating(theta_j.re, theta_j.im)
+                           .partial_cmp(&target.rating(theta_i.re, theta_i.im))
+                           .unwrap_or(std::cmp::Ordering::Equal)
+                 });
+
+            let converged: bool = order[..self.nev].iter().all(|i| {
+                                      let theta_i: &Complex<T> = theta.get(*i);
+                                      let residual_re: T = basis.residual(&y_re.get_column(*i));
+                                      let residual_im: T = basis.residual(&y_im.get_column(*i));
+                                      let magnitude: T = (theta_i.re * theta_i.re + theta_i.im * theta_i.im).sqrt();
+                                      (residual_re * residual_re + residual_im * residual_im).sqrt()
+                                      <= self.tolerance * magnitude.max(eps)
+                                  });
+
+            if converged
+            {
+                let indices: &[usize] = &order[..self.nev];
+                let x_re: Vec<Vector<T>> = basis.vectors(&Arnoldi::columns(&y_re, indices));
+                let x_im: Vec<Vector<T>> = basis.vectors(&Arnoldi::columns(&y_im, indices));
+
+                let value: Vec<Complex<T>> = indices.iter()
+                                                    .map(|i| {
+                                                        let (re, im): (T, T) = target.eigenvalue(theta.get(*i).re, theta.get(*i).im);
+                                                        Complex::new(re, im)
+                                                    })
+                                                    .collect();
+                let vector: Vec<Complex<T>> = x_re.iter()
+                                                  .zip(x_im.iter())
+                                                  .flat_map(|(re, im)| {
+                                                      re.iter().zip(im.iter()).map(|(re_i, im_i)| Complex::new(*re_i, *im_i)).collect::<Vec<Complex<T>>>()
+                                                  })
+                                                  .collect();
+
+                return Ok(EigenDec::new(Vector::new_column(self.nev, value), Matrix::new(n, self.nev, vector)));
+            }
+
+            if restart < self.max_restart
+            {
+                // the real and imaginary part of a complex Ritz vector span
+                // the invariant subspace of the complex conjugate pair
+                let k: usize = self.nev + (m - self.nev) / 2;
+                let mut w: Vec<T> = Vec::with_capacity(m * (k + 1));
+                for i in order[..k].iter()
+                {
+                    let im: T = theta.get(*i).im;
+                    if im >= T::zero()
+                    {
+                        w.extend(y_re.get_column(*i).convert_to_vec());
+                    }
+                    if im > T::zero()
+                    {
+                        w.extend(y_im.get_column(*i).convert_to_vec());
+                    }
+                }
+                let w_n: usize = w.len() / m;
+
+                basis.restart(&Matrix::new(m, w_n, w).dec_qrp()?.q());
+            }
+        }
+
+        return Err(MathError::NoConvergence { iterations: self.max_restart });
+    }
+
+    /// Splits a complex matrix into its real and imaginary part
+    fn split(y: &Matrix<Complex<T>>) -> (Matrix<T>, Matrix<T>)
+    {
+        let (m, n): (usize, usize) = y.dim();
+        let re: Vec<T> = y.iter().map(|y_ij| y_ij.re).collect();
+        let im: Vec<T> = y.iter().map(|y_ij| y_ij.im).collect();
+
+        return (Matrix::new(m, n, re), Matrix::new(m, n, im));
+    }
+
+    /// Returns the columns of y with the given indices
+    fn columns(y: &Matrix<T>, indices: &[usize]) -> Matrix<T>
+    {
+        let (m, _): (usize, usize) = y.dim();
+        let data: Vec<T> = indices.iter().flat_map(|j| y.get_column(*j).convert_to_vec()).collect();
+
+        return Matrix::new(m, indices.len(), data);
+    }
+}
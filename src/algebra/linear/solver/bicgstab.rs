@@ -0,0 +1,169 @@
+use super::{IdentityPreconditioner, IterativeResult, LinearOperator, Preconditioner};
+use crate::{
+    algebra::{abstr::Real, linear::Vector},
+    error::MathError,
+};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::clone::Clone;
+
+/// Biconjugate gradient stabilized method (BiCGSTAB)
+///
+/// Solves Ax = b for general non-symmetric matrices A. The preconditioner is
+/// applied from the right, therefore the monitored residual is the residual of
+/// the original system.
+///
+/// Fore more information: <br>
+/// H. A. van der Vorst, Bi-CGSTAB: A Fast and Smoothly Converging Variant of
+/// Bi-CG for the Solution of Nonsymmetric Linear Systems, SIAM J. Sci. Stat.
+/// Comput., 13(2), 1992
+///
+/// # Example
+///
+/// ```
+/// # #[macro_use]
+/// # extern crate mathru;
+/// # fn main()
+/// # {
+/// use mathru::algebra::linear::{solver::BiCGSTAB, Matrix, Vector};
+///
+/// let a: Matrix<f64> = matrix![   4.0, 1.0;
+///                                 -2.0, 3.0];
+/// let b: Vector<f64> = vector![1.0; 2.0];
+///
+/// let bicgstab: BiCGSTAB<f64> = BiCGSTAB::new(10, 1.0e-12);
+/// let x: Vector<f64> = bicgstab.solve(&a, &b, &Vector::zero(2)).unwrap().x();
+/// # }
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Copy, Debug)]
+pub struct BiCGSTAB<T>
+{
+    max_iter: usize,
+    tolerance: T,
+}
+
+impl<T> BiCGSTAB<T>
+{
+    /// Creates an instance of the BiCGSTAB method
+    ///
+    /// # Arguments
+    ///
+    /// * 'max_iter': Maximum number of iterations
+    /// * 'tolerance': Tolerance for the relative residual norm
+    pub fn new(max_iter: usize, tolerance: T) -> BiCGSTAB<T>
+    {
+        return BiCGSTAB { max_iter, tolerance };
+    }
+}
+
+impl<T> BiCGSTAB<T> where T: Real
+{
+    /// Solves Ax = b without preconditioning
+    ///
+    /// # Errors
+    ///
+    /// if the method breaks down
+    pub fn solve<A>(self: &Self, a: &A, b: &Vector<T>, x_0: &Vector<T>)
+                    -> Result<IterativeResult<T>, MathError>
+        where A: LinearOperator<T>
+    {
+        return self.solve_preconditioned(a, b, x_0, &IdentityPreconditioner);
+    }
+
+    /// Solves Ax = b with the right preconditioner m
+    ///
+    /// # Errors
+    ///
+    /// if the method breaks down
+    pub fn solve_preconditioned<A, P>(self: &Self, a: &A, b: &Vector<T>, x_0: &Vector<T>,
+                                      m: &P)
+                                      -> Result<IterativeResult<T>, MathError>
+        where A: LinearOperator<T>,
+              P: Preconditioner<T>
+    {
+        let b_norm: T = b.dotp(b).sqrt();
+        if b_norm == T::zero()
+        {
+            let (n, _): (usize, usize) = b.dim();
+            return Ok(IterativeResult::new(Vector::zero(n), 0, vec![T::zero()], true));
+        }
+
+        let mut x: Vector<T> = x_0.clone();
+        let mut r: Vector<T> = b - &a.apply(&x);
+        let mut residuals: Vec<T> = vec![r.dotp(&r).sqrt() / b_norm];
+
+        if residuals[0] <= self.tolerance
+        {
+            return Ok(IterativeResult::new(x, 0, residuals, true));
+        }
+
+        let r_hat: Vector<T> = r.clone();
+        let mut rho: T = T::one();
+        let mut alpha: T = T::one();
+        let mut omega: T = T::one();
+        let mut p: Vector<T> = r.clone();
+        let mut v: Vector<T> = Vector::zero(r.dim().0);
+
+        for k in 1..(self.max_iter + 1)
+        {
+            let rho_new: T = r_hat.dotp(&r);
+            if rho_new == T::zero()
+            {
+                return Err(MathError::Breakdown { iterations: k });
+            }
+
+            if k > 1
+            {
+                let beta: T = (rho_new / rho) * (alpha / omega);
+                p = &r + &(&(&p - &(&v * &omega)) * &beta);
+            }
+
+            let p_hat: Vector<T> = m.apply(&p);
+            v = a.apply(&p_hat);
+
+            let r_hat_v: T = r_hat.dotp(&v);
+            if r_hat_v == T::zero()
+            {
+                return Err(MathError::Breakdown { iterations: k });
+            }
+            alpha = rho_new / r_hat_v;
+
+            let s: Vector<T> = &r - &(&v * &alpha);
+            let s_norm: T = s.dotp(&s).sqrt() / b_norm;
+            if s_norm <= self.tolerance
+            {
+                x = &x + &(&p_hat * &alpha);
+                residuals.push(s_norm);
+                return Ok(IterativeResult::new(x, k, residuals, true));
+            }
+
+            let s_hat: Vector<T> = m.apply(&s);
+            let t: Vector<T> = a.apply(&s_hat);
+            let tt: T = t.dotp(&t);
+            if tt == T::zero()
+            {
+                return Err(MathError::Breakdown { iterations: k });
+            }
+            omega = t.dotp(&s) / tt;
+
+            x = &(&x + &(&p_hat * &alpha)) + &(&s_hat * &omega);
+            r = &s - &(&t * &omega);
+
+            let res: T = r.dotp(&r).sqrt() / b_norm;
+            residuals.push(res);
+            if res <= self.tolerance
+            {
+                return Ok(IterativeResult::new(x, k, residuals, true));
+            }
+
+            if omega == T::zero()
+            {
+                return Err(MathError::Breakdown { iterations: k });
+            }
+            rho = rho_new;
+        }
+
+        return Ok(IterativeResult::new(x, self.max_iter, residuals, false));
+    }
+}
@@ -0,0 +1,146 @@
+use super::{IdentityPreconditioner, IterativeResult, LinearOperator, Preconditioner};
+use crate::{
+    algebra::{abstr::Real, linear::Vector},
+    error::MathError,
+};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::clone::Clone;
+
+/// Preconditioned conjugate gradient method
+///
+/// Solves Ax = b, where A is a symmetric and positive definite matrix.
+///
+/// input: $`A \in \mathbb{R}^{n \times n}`$, $`b \in \mathbb{R}^{n}`$, initial
+/// approximation $`x_{0} \in \mathbb{R}^{n}`$ and preconditioner M
+///
+/// 1. $`r_{0} := b - Ax_{0}`$, $`z_{0} := M^{-1}r_{0}`$, $`p_{0} := z_{0}`$
+/// 2. $`\alpha_{k} := \frac{r_{k}^{T}z_{k}}{p_{k}^{T}Ap_{k}}`$ <br>
+///     $`x_{k+1} := x_{k} + \alpha_{k}p_{k}`$ <br>
+///     $`r_{k+1} := r_{k} - \alpha_{k}Ap_{k}`$ <br>
+///     $`z_{k+1} := M^{-1}r_{k+1}`$ <br>
+///     $`\beta_{k} := \frac{r_{k+1}^{T}z_{k+1}}{r_{k}^{T}z_{k}}`$ <br>
+///     $`p_{k+1} := z_{k+1} + \beta_{k}p_{k}`$
+/// 3. Stop if $`\lvert \lvert r_{k+1} \rvert \rvert_{2} \leq \epsilon \lvert
+/// \lvert b \rvert \rvert_{2}`$
+///
+/// # Example
+///
+/// ```
+/// # #[macro_use]
+/// # extern crate mathru;
+/// # fn main()
+/// # {
+/// use mathru::algebra::linear::{solver::CG, Matrix, Vector};
+///
+/// let a: Matrix<f64> = matrix![   4.0, 1.0;
+///                                 1.0, 3.0];
+/// let b: Vector<f64> = vector![1.0; 2.0];
+///
+/// let cg: CG<f64> = CG::new(10, 1.0e-12);
+/// let x: Vector<f64> = cg.solve(&a, &b, &Vector::zero(2)).unwrap().x();
+/// # }
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Copy, Debug)]
+pub struct CG<T>
+{
+    max_iter: usize,
+    tolerance: T,
+}
+
+impl<T> CG<T>
+{
+    /// Creates an instance of the conjugate gradient method
+    ///
+    /// # Arguments
+    ///
+    /// * 'max_iter': Maximum number of iterations
+    /// * 'tolerance': Tolerance for the relative residual norm
+    pub fn new(max_iter: usize, tolerance: T) -> CG<T>
+    {
+        return CG { max_iter, tolerance };
+    }
+}
+
+impl<T> CG<T> where T: Real
+{
+    /// Solves Ax = b without preconditioning
+    ///
+    /// # Arguments
+    ///
+    /// * 'a': Symmetric and positive definite operator
+    /// * 'b': Right hand side
+    /// * 'x_0': Initial guess
+    ///
+    /// # Errors
+    ///
+    /// if the method breaks down, i.e. A is not positive definite
+    pub fn solve<A>(self: &Self, a: &A, b: &Vector<T>, x_0: &Vector<T>)
+                    -> Result<IterativeResult<T>, MathError>
+        where A: LinearOperator<T>
+    {
+        return self.solve_preconditioned(a, b, x_0, &IdentityPreconditioner);
+    }
+
+    /// Solves Ax = b with the symmetric and positive definite preconditioner m
+    ///
+    /// # Errors
+    ///
+    /// if the method breaks down, i.e. A is not positive definite
+    pub fn solve_preconditioned<A, P>(self: &Self, a: &A, b: &Vector<T>, x_0: &Vector<T>,
+                                      m: &P)
+                                      -> Result<IterativeResult<T>, MathError>
+        where A: LinearOperator<T>,
+              P: Preconditioner<T>
+    {
+        let b_norm: T = b.dotp(b).sqrt();
+        if b_norm == T::zero()
+        {
+            let (n, _): (usize, usize) = b.dim();
+            return Ok(IterativeResult::new(Vector::zero(n), 0, vec![T::zero()], true));
+        }
+
+        let mut x: Vector<T> = x_0.clone();
+        let mut r: Vector<T> = b - &a.apply(&x);
+        let mut residuals: Vec<T> = vec![r.dotp(&r).sqrt() / b_norm];
+
+        if residuals[0] <= self.tolerance
+        {
+            return Ok(IterativeResult::new(x, 0, residuals, true));
+        }
+
+        let mut z: Vector<T> = m.apply(&r);
+        let mut p: Vector<T> = z.clone();
+        let mut rz: T = r.dotp(&z);
+
+        for k in 1..(self.max_iter + 1)
+        {
+            let ap: Vector<T> = a.apply(&p);
+            let pap: T = p.dotp(&ap);
+            if pap <= T::zero()
+            {
+                return Err(MathError::NotPositiveDefinite);
+            }
+
+            let alpha: T = rz / pap;
+            x = &x + &(&p * &alpha);
+            r = &r - &(&ap * &alpha);
+
+            let res: T = r.dotp(&r).sqrt() / b_norm;
+            residuals.push(res);
+            if res <= self.tolerance
+            {
+                return Ok(IterativeResult::new(x, k, residuals, true));
+            }
+
+            z = m.apply(&r);
+            let rz_new: T = r.dotp(&z);
+            let beta: T = rz_new / rz;
+            p = &z + &(&p * &beta);
+            rz = rz_new;
+        }
+
+        return Ok(IterativeResult::new(x, self.max_iter, residuals, false));
+    }
+}
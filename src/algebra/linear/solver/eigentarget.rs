@@ -0,0 +1,61 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use crate::algebra::abstr::Real;
+
+/// Eigenvalues, which are computed by the eigensolvers [`Lanczos`] and
+/// [`Arnoldi`]
+///
+/// [`Lanczos`]: super::Lanczos
+/// [`Arnoldi`]: super::Arnoldi
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum EigenTarget<T>
+{
+    /// Eigenvalues with the largest magnitude
+    LargestMagnitude,
+    /// Eigenvalues with the smallest magnitude
+    SmallestMagnitude,
+    /// Eigenvalues with the largest real part
+    LargestReal,
+    /// Eigenvalues with the smallest real part
+    SmallestReal,
+    /// Eigenvalues closest to the shift σ
+    ///
+    /// The operator has to apply $`(A - \sigma I)^{-1}`$, e.g. with a LU
+    /// decomposition of $`A - \sigma I`$. The eigenvalues θ of largest
+    /// magnitude of the operator are transformed back to the eigenvalues
+    /// $`\lambda = \sigma + 1 / \theta`$ of A, the eigenvectors are the same.
+    ShiftInvert(T),
+}
+
+impl<T> EigenTarget<T> where T: Real
+{
+    /// Rating of an eigenvalue θ = re + i im of the operator, wanted
+    /// eigenvalues have a larger rating
+    pub(super) fn rating(self: &Self, re: T, im: T) -> T
+    {
+        let magnitude: T = (re * re + im * im).sqrt();
+        return match self
+        {
+            EigenTarget::LargestMagnitude | EigenTarget::ShiftInvert(_) => magnitude,
+            EigenTarget::SmallestMagnitude => -magnitude,
+            EigenTarget::LargestReal => re,
+            EigenTarget::SmallestReal => -re,
+        };
+    }
+
+    /// Transforms an eigenvalue θ = re + i im of the operator to an
+    /// eigenvalue of A
+    pub(super) fn eigenvalue(self: &Self, re: T, im: T) -> (T, T)
+    {
+        return match self
+        {
+            EigenTarget::ShiftInvert(sigma) =>
+            {
+                let magnitude_sq: T = re * re + im * im;
+                (*sigma + re / magnitude_sq, -im / magnitude_sq)
+            }
+            _ => (re, im),
+        };
+    }
+}
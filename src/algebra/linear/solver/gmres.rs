@@ -0,0 +1,238 @@
+use super::{IdentityPreconditioner, IterativeResult, LinearOperator, Preconditioner};
+use crate::{
+    algebra::{abstr::Real, linear::Vector},
+    error::MathError,
+};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::clone::Clone;
+
+/// Restarted generalized minimal residual method GMRES(m)
+///
+/// Solves Ax = b for general matrices A. In every cycle an orthonormal basis
+/// of the Krylov subspace of dimension m is built with the Arnoldi process
+/// (modified Gram-Schmidt). The least squares problem is solved with Givens
+/// rotations. The preconditioner is applied from the right, therefore the
+/// monitored residual is the residual of the original system.
+///
+/// Fore more information: <br>
+/// Y. Saad, M. H. Schultz, GMRES: A generalized minimal residual algorithm for
+/// solving nonsymmetric linear systems, SIAM J. Sci. Stat. Comput., 7(3), 1986
+///
+/// # Example
+///
+/// ```
+/// # #[macro_use]
+/// # extern crate mathru;
+/// # fn main()
+/// # {
+/// use mathru::algebra::linear::{solver::GMRES, Matrix, Vector};
+///
+/// let a: Matrix<f64> = matrix![   4.0, 1.0;
+///                                 -2.0, 3.0];
+/// let b: Vector<f64> = vector![1.0; 2.0];
+///
+/// let gmres: GMRES<f64> = GMRES::new(2, 10, 1.0e-12);
+/// let x: Vector<f64> = gmres.solve(&a, &b, &Vector::zero(2)).unwrap().x();
+/// # }
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Copy, Debug)]
+pub struct GMRES<T>
+{
+    restart: usize,
+    max_iter: usize,
+    tolerance: T,
+}
+
+impl<T> GMRES<T>
+{
+    /// Creates an instance of the GMRES(m) method
+    ///
+    /// # Arguments
+    ///
+    /// * 'restart': Dimension m of the Krylov subspace after which the method
+    /// is restarted
+    /// * 'max_iter': Maximum number of iterations (matrix vector products)
+    /// * 'tolerance': Tolerance for the relative residual norm
+    ///
+    /// # Panics
+    ///
+    /// if restart is zero
+    pub fn new(restart: usize, max_iter: usize, tolerance: T) -> GMRES<T>
+    {
+        assert!(restart > 0);
+        return GMRES { restart,
+                       max_iter,
+                       tolerance };
+    }
+}
+
+impl<T> GMRES<T> where T: Real
+{
+    /// Solves Ax = b without preconditioning
+    ///
+    /// # Errors
+    ///
+    /// if the least squares problem is singular
+    pub fn solve<A>(self: &Self, a: &A, b: &Vector<T>, x_0: &Vector<T>)
+                    -> Result<IterativeResult<T>, MathError>
+        where A: LinearOperator<T>
+    {
+        return self.solve_preconditioned(a, b, x_0, &IdentityPreconditioner);
+    }
+
+    /// Solves Ax = b with the right preconditioner m
+    ///
+    /// # Errors
+    ///
+    /// if the least squares problem is singular
+    pub fn solve_preconditioned<A, P>(self: &Self, a: &A, b: &Vector<T>, x_0: &Vector<T>,
+                                      m: &P)
+                                      -> Result<IterativeResult<T>, MathError>
+        where A: LinearOperator<T>,
+              P: Preconditioner<T>
+    {
+        let (n, _): (usize, usize) = b.dim();
+        let b_norm: T = b.dotp(b).sqrt();
+        if b_norm == T::zero()
+        {
+            return Ok(IterativeResult::new(Vector::zero(n), 0, vec![T::zero()], true));
+        }
+
+        let mut x: Vector<T> = x_0.clone();
+        let mut r: Vector<T> = b - &a.apply(&x);
+        let mut beta: T = r.dotp(&r).sqrt();
+        let mut residuals: Vec<T> = vec![beta / b_norm];
+        let mut iterations: usize = 0;
+
+        if residuals[0] <= self.tolerance
+        {
+            return Ok(IterativeResult::new(x, 0, residuals, true));
+        }
+
+        let restart: usize = self.restart;
+
+        while iterations < self.max_iter
+        {
+            let mut v: Vec<Vector<T>> = Vec::with_capacity(restart + 1);
+            v.push(&r / &beta);
+
+            // Hessenberg matrix, h[j] is the j-th column
+            let mut h: Vec<Vec<T>> = Vec::with_capacity(restart);
+            let mut cs: Vec<T> = Vec::with_capacity(restart);
+            let mut sn: Vec<T> = Vec::with_capacity(restart);
+            let mut g: Vec<T> = vec![T::zero(); restart + 1];
+            g[0] = beta;
+
+            let mut k: usize = 0;
+            let mut converged: bool = false;
+
+            while k < restart && iterations < self.max_iter
+            {
+                let z: Vector<T> = m.apply(&v[k]);
+                let mut w: Vector<T> = a.apply(&z);
+
+                let mut h_k: Vec<T> = vec![T::zero(); k + 2];
+                for i in 0..(k + 1)
+                {
+                    h_k[i] = w.dotp(&v[i]);
+                    w = &w - &(&v[i] * &h_k[i]);
+                }
+                h_k[k + 1] = w.dotp(&w).sqrt();
+
+                // apply previous rotations to the new column
+                for i in 0..k
+                {
+                    let temp: T = cs[i] * h_k[i] + sn[i] * h_k[i + 1];
+                    h_k[i + 1] = -sn[i] * h_k[i] + cs[i] * h_k[i + 1];
+                    h_k[i] = temp;
+                }
+
+                let (c, s): (T, T) = GMRES::rotation(h_k[k], h_k[k + 1]);
+                let h_kk: T = c * h_k[k] + s * h_k[k + 1];
+                let lucky_breakdown: bool = h_k[k + 1] == T::zero();
+
+                if !lucky_breakdown
+                {
+                    v.push(&w / &h_k[k + 1]);
+                }
+
+                h_k[k] = h_kk;
+                h_k[k + 1] = T::zero();
+                g[k + 1] = -s * g[k];
+                g[k] = c * g[k];
+                cs.push(c);
+                sn.push(s);
+                h.push(h_k);
+
+                k += 1;
+                iterations += 1;
+
+                let res: T = g[k].abs() / b_norm;
+                residuals.push(res);
+
+                if res <= self.tolerance || lucky_breakdown
+                {
+                    converged = res <= self.tolerance;
+                    break;
+                }
+            }
+
+            // solve the upper triangular system H y = g
+            let mut y: Vec<T> = vec![T::zero(); k];
+            for i in (0..k).rev()
+            {
+                let mut sum: T = g[i];
+                for j in (i + 1)..k
+                {
+                    sum -= h[j][i] * y[j];
+                }
+                if h[i][i] == T::zero()
+                {
+                    return Err(MathError::Breakdown { iterations });
+                }
+                y[i] = sum / h[i][i];
+            }
+
+            let mut update: Vector<T> = Vector::zero(n);
+            for i in 0..k
+            {
+                update = &update + &(&v[i] * &y[i]);
+            }
+            x = &x + &m.apply(&update);
+
+            r = b - &a.apply(&x);
+            beta = r.dotp(&r).sqrt();
+
+            if converged || beta / b_norm <= self.tolerance
+            {
+                return Ok(IterativeResult::new(x, iterations, residuals, true));
+            }
+        }
+
+        return Ok(IterativeResult::new(x, iterations, residuals, false));
+    }
+
+    /// Determines the Givens rotation (c, s) with [c s; -s c] [a; b] = [r; 0]
+    fn rotation(a: T, b: T) -> (T, T)
+    {
+        if b == T::zero()
+        {
+            return (T::one(), T::zero());
+        }
+
+        if b.abs() > a.abs()
+        {
+            let tau: T = a / b;
+            let s: T = T::one() / (T::one() + tau * tau).sqrt();
+            return (s * tau, s);
+        }
+        else
+        {
+            let tau: T = b / a;
+            let c: T = T::one() / (T::one() + tau * tau).sqrt();
+            return (c, c * tau);
+        }
+    }
+}
@@ -0,0 +1,143 @@
+use super::Preconditioner;
+use crate::{
+    algebra::{
+        abstr::{Field, Scalar},
+        linear::{sparse::CSRMatrix, Vector},
+    },
+    error::MathError,
+};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::clone::Clone;
+
+/// Incomplete LU factorization without fill-in ILU(0)
+///
+/// A ≈ LU, where L is a unit lower triangular matrix and U is an upper
+/// triangular matrix. L + U has the same sparsity pattern as A, entries
+/// outside of the pattern are dropped during the factorization.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug)]
+pub struct ILU0<T>
+{
+    row_ptr: Vec<usize>,
+    col_idx: Vec<usize>,
+    /// L (without the unit diagonal) and U stored in the pattern of A
+    values: Vec<T>,
+    /// Position of the diagonal entry of every row
+    diag: Vec<usize>,
+}
+
+impl<T> ILU0<T> where T: Field + Scalar
+{
+    /// Computes the incomplete LU factorization of the square matrix a
+    ///
+    /// # Errors
+    ///
+    /// if a diagonal entry is missing in the pattern or a zero pivot occurs
+    ///
+    /// # Panics
+    ///
+    /// if a is not square
+    pub fn new(a: &CSRMatrix<T>) -> Result<ILU0<T>, MathError>
+    {
+        let (m, n): (usize, usize) = a.dim();
+        assert_eq!(m, n);
+
+        let row_ptr: Vec<usize> = a.row_ptr().to_vec();
+        let col_idx: Vec<usize> = a.col_idx().to_vec();
+        let mut values: Vec<T> = a.values().to_vec();
+
+        let mut diag: Vec<usize> = Vec::with_capacity(n);
+        for i in 0..n
+        {
+            match col_idx[row_ptr[i]..row_ptr[i + 1]].binary_search(&i)
+            {
+                Ok(pos) => diag.push(row_ptr[i] + pos),
+                Err(_) => return Err(MathError::Singular { pivot: i }),
+            }
+        }
+
+        // position of the entries of row i in the pattern, usize::MAX if the
+        // column is not part of the pattern
+        let mut position: Vec<usize> = vec![usize::MAX; n];
+
+        for i in 0..n
+        {
+            for k in row_ptr[i]..row_ptr[i + 1]
+            {
+                position[col_idx[k]] = k;
+            }
+
+            for k in row_ptr[i]..diag[i]
+            {
+                let p: usize = col_idx[k];
+                let u_pp: T = values[diag[p]];
+                if u_pp == T::zero()
+                {
+                    return Err(MathError::Singular { pivot: p });
+                }
+                let l_ip: T = values[k] / u_pp;
+                values[k] = l_ip;
+
+                for l in (diag[p] + 1)..row_ptr[p + 1]
+                {
+                    let pos: usize = position[col_idx[l]];
+                    if pos != usize::MAX
+                    {
+                        let u_pl: T = values[l];
+                        values[pos] -= l_ip * u_pl;
+                    }
+                }
+            }
+
+            if values[diag[i]] == T::zero()
+            {
+                return Err(MathError::Singular { pivot: i });
+            }
+
+            for k in row_ptr[i]..row_ptr[i + 1]
+            {
+                position[col_idx[k]] = usize::MAX;
+            }
+        }
+
+        return Ok(ILU0 { row_ptr,
+                         col_idx,
+                         values,
+                         diag });
+    }
+}
+
+impl<T> Preconditioner<T> for ILU0<T> where T: Field + Scalar
+{
+    fn apply(self: &Self, r: &Vector<T>) -> Vector<T>
+    {
+        let n: usize = self.diag.len();
+
+        // L y = r
+        let mut y: Vec<T> = vec![T::zero(); n];
+        for i in 0..n
+        {
+            let mut sum: T = *r.get(i);
+            for k in self.row_ptr[i]..self.diag[i]
+            {
+                sum -= self.values[k] * y[self.col_idx[k]];
+            }
+            y[i] = sum;
+        }
+
+        // U z = y
+        let mut z: Vec<T> = vec![T::zero(); n];
+        for i in (0..n).rev()
+        {
+            let mut sum: T = y[i];
+            for k in (self.diag[i] + 1)..self.row_ptr[i + 1]
+            {
+                sum -= self.values[k] * z[self.col_idx[k]];
+            }
+            z[i] = sum / self.values[self.diag[i]];
+        }
+
+        return Vector::new_column(n, z);
+    }
+}
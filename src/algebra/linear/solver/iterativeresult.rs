@@ -0,0 +1,48 @@
+use crate::algebra::linear::Vector;
+
+/// Result of an iterative solver
+#[derive(Debug, Clone)]
+pub struct IterativeResult<T>
+{
+    x: Vector<T>,
+    iterations: usize,
+    residuals: Vec<T>,
+    converged: bool,
+}
+
+impl<T> IterativeResult<T>
+{
+    pub(super) fn new(x: Vector<T>, iterations: usize, residuals: Vec<T>, converged: bool)
+                      -> IterativeResult<T>
+    {
+        return IterativeResult { x,
+                                 iterations,
+                                 residuals,
+                                 converged };
+    }
+
+    /// Returns the approximated solution
+    pub fn x(self: Self) -> Vector<T>
+    {
+        return self.x;
+    }
+
+    /// Returns the number of performed iterations
+    pub fn iterations(self: &Self) -> usize
+    {
+        return self.iterations;
+    }
+
+    /// Returns the history of the relative residual norm ||b - Ax_k|| / ||b||,
+    /// the first entry belongs to the initial guess.
+    pub fn residuals(self: &Self) -> &[T]
+    {
+        return &self.residuals;
+    }
+
+    /// Returns true, if the requested tolerance has been reached
+    pub fn converged(self: &Self) -> bool
+    {
+        return self.converged;
+    }
+}
@@ -0,0 +1,61 @@
+use super::Preconditioner;
+use crate::{
+    algebra::{
+        abstr::{Field, Scalar},
+        linear::{sparse::CSRMatrix, Vector},
+    },
+    error::MathError,
+};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::clone::Clone;
+
+/// Jacobi (diagonal) preconditioner
+///
+/// M = diag(A)
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug)]
+pub struct Jacobi<T>
+{
+    inv_diag: Vec<T>,
+}
+
+impl<T> Jacobi<T> where T: Field + Scalar
+{
+    /// Creates the Jacobi preconditioner of the square matrix a
+    ///
+    /// # Errors
+    ///
+    /// if a diagonal entry is zero
+    ///
+    /// # Panics
+    ///
+    /// if a is not square
+    pub fn new(a: &CSRMatrix<T>) -> Result<Jacobi<T>, MathError>
+    {
+        let (m, n): (usize, usize) = a.dim();
+        assert_eq!(m, n);
+
+        let mut inv_diag: Vec<T> = Vec::with_capacity(n);
+        for i in 0..n
+        {
+            let a_ii: T = a.get(i, i);
+            if a_ii == T::zero()
+            {
+                return Err(MathError::Singular { pivot: i });
+            }
+            inv_diag.push(T::one() / a_ii);
+        }
+
+        return Ok(Jacobi { inv_diag });
+    }
+}
+
+impl<T> Preconditioner<T> for Jacobi<T> where T: Field + Scalar
+{
+    fn apply(self: &Self, r: &Vector<T>) -> Vector<T>
+    {
+        let z: Vec<T> = r.iter().zip(self.inv_diag.iter()).map(|(r_i, d_i)| *r_i * *d_i).collect();
+        return Vector::new_column(z.len(), z);
+    }
+}
@@ -0,0 +1,187 @@
+use super::LinearOperator;
+use crate::{
+    algebra::{
+        abstr::Real,
+        linear::{matrix::Transpose, Matrix, Vector},
+    },
+    error::MathError,
+};
+
+/// Krylov decomposition
+///
+/// ```math
+/// A V = V H + f b^{T}
+/// ```
+///
+/// V has orthonormal columns, f is a unit vector orthogonal to V. After an
+/// expansion with the Arnoldi process H is a Hessenberg matrix and b is a
+/// multiple of the last unit vector, after a thick restart H and b are full.
+pub(super) struct KrylovBasis<T>
+{
+    v: Vec<Vector<T>>,
+    h: Matrix<T>,
+    f: Vector<T>,
+    b: Vec<T>,
+    m: usize,
+}
+
+impl<T> KrylovBasis<T> where T: Real
+{
+    /// Creates an empty decomposition, which can be expanded to m columns
+    ///
+    /// # Errors
+    ///
+    /// MathError::Breakdown if the start vector is zero
+    pub(super) fn new(v_0: &Vector<T>, m: usize) -> Result<KrylovBasis<T>, MathError>
+    {
+        let norm: T = v_0.dotp(v_0).sqrt();
+        if norm == T::zero()
+        {
+            return Err(MathError::Breakdown { iterations: 0 });
+        }
+
+        return Ok(KrylovBasis { v: Vec::with_capacity(m),
+                                h: Matrix::zero(m, m),
+                                f: v_0 / &norm,
+                                b: Vec::new(),
+                                m });
+    }
+
+    /// Returns the matrix H
+    pub(super) fn h(self: &Self) -> &Matrix<T>
+    {
+        return &self.h;
+    }
+
+    /// Returns the residual norm of the Ritz pair (θ, V y)
+    ///
+    /// ```math
+    /// \lVert A V y - \theta V y \rVert_{2} = \lvert b^{T} y \rvert
+    /// ```
+    pub(super) fn residual(self: &Self, y: &Vector<T>) -> T
+    {
+        return self.b.iter().zip(y.iter()).fold(T::zero(), |sum, (b_i, y_i)| sum + *b_i * *y_i).abs();
+    }
+
+    /// Expands the decomposition with the Arnoldi process to m columns
+    ///
+    /// Every new vector is orthogonalized twice against the basis. If the
+    /// Krylov subspace is invariant, the expansion continues with a unit
+    /// vector orthogonal to the basis.
+    pub(super) fn expand<A>(self: &mut Self, a: &A)
+        where A: LinearOperator<T>
+    {
+        for j in self.v.len()..self.m
+        {
+            for (i, b_i) in self.b.iter().enumerate()
+            {
+                *self.h.get_mut(j, i) = *b_i;
+            }
+            self.v.push(self.f.clone());
+
+            let mut w: Vector<T> = a.apply(&self.v[j]);
+            let w_norm: T = w.dotp(&w).sqrt();
+            let h_j: Vec<T> = self.orthogonalize(&mut w);
+            for (i, h_ij) in h_j.iter().enumerate()
+            {
+                *self.h.get_mut(i, j) = *h_ij;
+            }
+
+            let beta: T = w.dotp(&w).sqrt();
+            self.b = vec![T::zero(); j + 1];
+            if beta > T::default_epsilon() * w_norm
+            {
+                self.f = &w / &beta;
+                self.b[j] = beta;
+            }
+            else
+            {
+                self.f = self.new_direction();
+            }
+        }
+    }
+
+    /// Restarts the decomposition with the basis V W
+    ///
+    /// The columns of the m x k matrix W are orthonormal and span an
+    /// invariant subspace of H, therefore
+    ///
+    /// ```math
+    /// A V W = V W (W^{T} H W) + f (b^{T} W)
+    /// ```
+    pub(super) fn restart(self: &mut Self, w: &Matrix<T>)
+    {
+        let (m, k): (usize, usize) = w.dim();
+
+        let v: Vec<Vector<T>> = self.vectors(w);
+        let w_t: Matrix<T> = w.clone().transpose();
+        let h_k: Matrix<T> = &(&w_t * &self.h) * w;
+
+        let mut h: Matrix<T> = Matrix::zero(m, m);
+        for j in 0..k
+        {
+            for i in 0..k
+            {
+                *h.get_mut(i, j) = *h_k.get(i, j);
+            }
+        }
+
+        self.b = (0..k).map(|j| (0..m).fold(T::zero(), |sum, i| sum + self.b[i] * *w.get(i, j))).collect();
+        self.v = v;
+        self.h = h;
+    }
+
+    /// Returns the vectors V y for the columns y of Y
+    pub(super) fn vectors(self: &Self, y: &Matrix<T>) -> Vec<Vector<T>>
+    {
+        let (m, k): (usize, usize) = y.dim();
+        let (n, _): (usize, usize) = self.f.dim();
+
+        return (0..k).map(|j| {
+                         let mut x: Vector<T> = Vector::zero(n);
+                         for i in 0..m
+                         {
+                             x = x + &self.v[i] * y.get(i, j);
+                         }
+                         x
+                     })
+                     .collect();
+    }
+
+    /// Orthogonalizes w against the basis and returns the coefficients
+    fn orthogonalize(self: &Self, w: &mut Vector<T>) -> Vec<T>
+    {
+        let mut h: Vec<T> = vec![T::zero(); self.v.len()];
+        for _ in 0..2
+        {
+            for (v_i, h_i) in self.v.iter().zip(h.iter_mut())
+            {
+                let c: T = v_i.dotp(w);
+                *w = &*w - &(v_i * &c);
+                *h_i += c;
+            }
+        }
+        return h;
+    }
+
+    /// Returns a unit vector orthogonal to the basis, which is obtained from
+    /// the first suitable unit vector
+    fn new_direction(self: &Self) -> Vector<T>
+    {
+        let (n, _): (usize, usize) = self.f.dim();
+        for i in 0..n
+        {
+            let mut e_i: Vector<T> = Vector::zero(n);
+            *e_i.get_mut(i) = T::one();
+            self.orthogonalize(&mut e_i);
+
+            let norm: T = e_i.dotp(&e_i).sqrt();
+            if norm > T::from_f64(0.5)
+            {
+                return &e_i / &norm;
+            }
+        }
+
+        return Vector::zero(n);
+    }
+}
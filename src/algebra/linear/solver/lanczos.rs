@@ -0,0 +1,185 @@
+use super::{krylovbasis::KrylovBasis, EigenTarget, LinearOperator};
+use crate::{
+    algebra::{
+        abstr::Real,
+        linear::{matrix::EigenDec, Matrix, Vector},
+    },
+    error::MathError,
+};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Implicitly restarted Lanczos method
+///
+/// Computes a few eigenpairs of a symmetric operator A. The Lanczos process
+/// builds an orthonormal basis V of a Krylov subspace of dimension ncv, the
+/// eigenpairs (θ, y) of the projection $`H = V^{T} A V`$ yield the Ritz pairs
+/// (θ, V y). The method is restarted with the wanted Ritz vectors (thick
+/// restart), which is equivalent to an implicit restart with the unwanted
+/// Ritz values as exact shifts. The basis is fully reorthogonalized.
+///
+/// A Ritz pair has converged, if its residual satisfies
+///
+/// ```math
+/// \lVert A x - \theta x \rVert_{2} \leq tol \max(\lvert \theta \rvert, \epsilon^{2/3})
+/// ```
+///
+/// For more information: <br>
+/// K. Wu, H. Simon, Thick-restart Lanczos method for large symmetric
+/// eigenvalue problems, SIAM J. Matrix Anal. Appl., 22(2), 2000
+///
+/// # Example
+///
+/// ```
+/// # #[macro_use]
+/// # extern crate mathru;
+/// # fn main()
+/// # {
+/// use mathru::algebra::linear::{
+///     matrix::{EigenDec, LUDec, Solve},
+///     solver::{EigenTarget, Lanczos},
+///     Matrix, Vector,
+/// };
+///
+/// // 1D Laplacian
+/// let n: usize = 50;
+/// let mut a: Matrix<f64> = Matrix::zero(n, n);
+/// for i in 0..n
+/// {
+///     *a.get_mut(i, i) = 2.0;
+///     if i + 1 < n
+///     {
+///         *a.get_mut(i, i + 1) = -1.0;
+///         *a.get_mut(i + 1, i) = -1.0;
+///     }
+/// }
+///
+/// // the three smallest eigenvalues with shift-invert around zero
+/// let lu: LUDec<f64> = a.dec_lu().unwrap();
+/// let a_inv = |x: &Vector<f64>| lu.solve(x).unwrap();
+///
+/// let lanczos: Lanczos<f64> = Lanczos::new(3, 10, 100, 1.0e-12);
+/// let value: Vector<f64> = lanczos.solve(&a_inv, &Vector::new_column(n, vec![1.0; n]), EigenTarget::ShiftInvert(0.0))
+///                                 .unwrap()
+///                                 .value();
+///
+/// let h: f64 = std::f64::consts::PI / (n + 1) as f64;
+/// for k in 0..3
+/// {
+///     assert_relative_eq!(2.0 - 2.0 * (h * (k + 1) as f64).cos(), *value.get(k), epsilon=1.0e-10);
+/// }
+/// # }
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Copy, Debug)]
+pub struct Lanczos<T>
+{
+    nev: usize,
+    ncv: usize,
+    max_restart: usize,
+    tolerance: T,
+}
+
+impl<T> Lanczos<T>
+{
+    /// Creates an instance of the implicitly restarted Lanczos method
+    ///
+    /// # Arguments
+    ///
+    /// * 'nev': Number of wanted eigenpairs
+    /// * 'ncv': Dimension of the Krylov subspace, usually 2 nev or larger
+    /// * 'max_restart': Maximum number of restarts
+    /// * 'tolerance': Tolerance for the relative residual of a Ritz pair
+    ///
+    /// # Panics
+    ///
+    /// if nev is zero or ncv is not greater than nev
+    pub fn new(nev: usize, ncv: usize, max_restart: usize, tolerance: T) -> Lanczos<T>
+    {
+        assert!(nev > 0 && ncv > nev);
+        return Lanczos { nev,
+                         ncv,
+                         max_restart,
+                         tolerance };
+    }
+}
+
+impl<T> Lanczos<T> where T: Real
+{
+    /// Computes the wanted eigenpairs of the symmetric operator a
+    ///
+    /// # Arguments
+    ///
+    /// * 'a': Symmetric operator, for shift-invert the operator $`(A - \sigma I)^{-1}`$
+    /// * 'v_0': Start vector, its dimension is the dimension of the operator
+    /// * 'target': Wanted eigenvalues
+    ///
+    /// # Return
+    ///
+    /// The eigenvalues sorted by the target, e.g. the largest magnitude
+    /// first, and the eigenvectors of unit length
+    ///
+    /// # Errors
+    ///
+    /// MathError::DimensionMismatch if the operator has less than nev
+    /// dimensions, MathError::Breakdown if the start vector is zero and
+    /// MathError::NoConvergence if the eigenpairs have not converged within
+    /// the maximum number of restarts
+    pub fn solve<A>(self: &Self, a: &A, v_0: &Vector<T>, target: EigenTarget<T>) -> Result<EigenDec<T>, MathError>
+        where A: LinearOperator<T>
+    {
+        let (n, _): (usize, usize) = v_0.dim();
+        if self.nev > n
+        {
+            return Err(MathError::DimensionMismatch);
+        }
+
+        let m: usize = self.ncv.min(n);
+        let eps: T = T::default_epsilon().pow(T::from_f64(2.0 / 3.0));
+        let mut basis: KrylovBasis<T> = KrylovBasis::new(v_0, m)?;
+
+        for restart in 0..(self.max_restart + 1)
+        {
+            basis.expand(a);
+
+            let (theta, y): (Vector<T>, Matrix<T>) = basis.h().dec_eigen_symmetric()?.pair();
+
+            let mut order: Vec<usize> = (0..m).collect();
+            order.sort_by(|i, j| {
+                     target.rating(*theta.get(*j), T::zero())
+                           .partial_cmp(&target.rating(*theta.get(*i), T::zero()))
+                           .unwrap_or(std::cmp::Ordering::Equal)
+                 });
+
+            let converged: bool = order[..self.nev].iter().all(|i| {
+                                      basis.residual(&y.get_column(*i)) <= self.tolerance * theta.get(*i).abs().max(eps)
+                                  });
+
+            if converged
+            {
+                let y_nev: Matrix<T> = Lanczos::columns(&y, &order[..self.nev]);
+                let value: Vec<T> = order[..self.nev].iter().map(|i| target.eigenvalue(*theta.get(*i), T::zero()).0).collect();
+                let vector: Vec<T> = basis.vectors(&y_nev).into_iter().flat_map(|x| x.convert_to_vec()).collect();
+
+                return Ok(EigenDec::new(Vector::new_column(self.nev, value), Matrix::new(n, self.nev, vector)));
+            }
+
+            if restart < self.max_restart
+            {
+                let k: usize = self.nev + (m - self.nev) / 2;
+                basis.restart(&Lanczos::columns(&y, &order[..k]));
+            }
+        }
+
+        return Err(MathError::NoConvergence { iterations: self.max_restart });
+    }
+
+    /// Returns the columns of y with the given indices
+    fn columns(y: &Matrix<T>, indices: &[usize]) -> Matrix<T>
+    {
+        let (m, _): (usize, usize) = y.dim();
+        let data: Vec<T> = indices.iter().flat_map(|j| y.get_column(*j).convert_to_vec()).collect();
+
+        return Matrix::new(m, indices.len(), data);
+    }
+}
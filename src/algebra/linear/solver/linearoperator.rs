@@ -0,0 +1,79 @@
+use crate::algebra::{
+    abstr::{Field, Scalar},
+    linear::{
+        sparse::{CSCMatrix, CSRMatrix},
+        Matrix, Vector,
+    },
+};
+
+/// Linear operator
+///
+/// Iterative solvers only access the system matrix A through the product Ax.
+/// Implementing this trait allows to solve systems with matrix free operators,
+/// i.e. operators which are never assembled.
+///
+/// Closures `Fn(&Vector<T>) -> Vector<T>` implement this trait as well.
+///
+/// # Example
+///
+/// ```
+/// use mathru::algebra::linear::{solver::LinearOperator, Vector};
+///
+/// // 1D Laplacian
+/// struct Laplacian
+/// {
+///     n: usize,
+/// }
+///
+/// impl LinearOperator<f64> for Laplacian
+/// {
+///     fn apply(self: &Self, x: &Vector<f64>) -> Vector<f64>
+///     {
+///         let mut y: Vector<f64> = Vector::zero(self.n);
+///         for i in 0..self.n
+///         {
+///             let left: f64 = if i > 0 { *x.get(i - 1) } else { 0.0 };
+///             let right: f64 = if i + 1 < self.n { *x.get(i + 1) } else { 0.0 };
+///             *y.get_mut(i) = 2.0 * *x.get(i) - left - right;
+///         }
+///         y
+///     }
+/// }
+/// ```
+pub trait LinearOperator<T>
+{
+    /// Computes the matrix vector product Ax
+    fn apply(self: &Self, x: &Vector<T>) -> Vector<T>;
+}
+
+impl<T> LinearOperator<T> for Matrix<T> where T: Field + Scalar
+{
+    fn apply(self: &Self, x: &Vector<T>) -> Vector<T>
+    {
+        return self * x;
+    }
+}
+
+impl<T> LinearOperator<T> for CSRMatrix<T> where T: Field + Scalar
+{
+    fn apply(self: &Self, x: &Vector<T>) -> Vector<T>
+    {
+        return self * x;
+    }
+}
+
+impl<T> LinearOperator<T> for CSCMatrix<T> where T: Field + Scalar
+{
+    fn apply(self: &Self, x: &Vector<T>) -> Vector<T>
+    {
+        return self * x;
+    }
+}
+
+impl<T, F> LinearOperator<T> for F where F: Fn(&Vector<T>) -> Vector<T>
+{
+    fn apply(self: &Self, x: &Vector<T>) -> Vector<T>
+    {
+        return self(x);
+    }
+}
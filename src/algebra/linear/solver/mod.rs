@@ -0,0 +1,83 @@
+//! Iterative solvers for linear systems and eigenvalue problems
+//!
+//! Krylov subspace methods solve Ax = b by only evaluating matrix vector
+//! products with A. Therefore they work with dense and sparse matrices as
+//! well as with matrix free operators implementing [`LinearOperator`].
+//!
+//! * [`CG`] Preconditioned conjugate gradient method for symmetric and
+//! positive definite matrices
+//! * [`GMRES`] Restarted generalized minimal residual method GMRES(m)
+//! * [`BiCGSTAB`] Biconjugate gradient stabilized method
+//! * [`IterativeRefinement`] Mixed precision iterative refinement of a solution
+//! computed with a LU decomposition
+//!
+//! The convergence can be accelerated with a [`Preconditioner`], available are
+//! [`Jacobi`], [`SSOR`] and [`ILU0`].
+//!
+//! A few eigenpairs of large operators are computed with Krylov subspace
+//! methods as well:
+//!
+//! * [`Lanczos`] Implicitly restarted Lanczos method for symmetric operators
+//! * [`Arnoldi`] Implicitly restarted Arnoldi method for general operators
+//!
+//! # Example
+//!
+//! ```
+//! # #[macro_use]
+//! # extern crate mathru;
+//! # fn main()
+//! # {
+//! use mathru::algebra::linear::{
+//!     solver::{IterativeResult, Jacobi, CG},
+//!     sparse::CSRMatrix,
+//!     Matrix, Vector,
+//! };
+//!
+//! let a: Matrix<f64> = matrix![   4.0, -1.0, 0.0;
+//!                                -1.0, 4.0, -1.0;
+//!                                 0.0, -1.0, 4.0];
+//! let b: Vector<f64> = vector![2.0; 4.0; 10.0];
+//! let x_0: Vector<f64> = Vector::zero(3);
+//!
+//! let a_csr: CSRMatrix<f64> = CSRMatrix::from(&a);
+//! let jacobi: Jacobi<f64> = Jacobi::new(&a_csr).unwrap();
+//!
+//! let cg: CG<f64> = CG::new(100, 1.0e-10);
+//! let result: IterativeResult<f64> = cg.solve_preconditioned(&a_csr, &b, &x_0, &jacobi).unwrap();
+//!
+//! assert!(result.converged());
+//! # }
+//! ```
+
+mod arnoldi;
+mod bicgstab;
+mod cg;
+mod eigentarget;
+mod gmres;
+mod ilu0;
+mod iterativeresult;
+mod jacobi;
+mod krylovbasis;
+mod lanczos;
+mod linearoperator;
+mod preconditioner;
+mod refinement;
+mod refinementresult;
+mod ssor;
+
+pub use self::{
+    arnoldi::Arnoldi,
+    bicgstab::BiCGSTAB,
+    cg::CG,
+    eigentarget::EigenTarget,
+    gmres::GMRES,
+    ilu0::ILU0,
+    iterativeresult::IterativeResult,
+    jacobi::Jacobi,
+    lanczos::Lanczos,
+    linearoperator::LinearOperator,
+    preconditioner::{IdentityPreconditioner, Preconditioner},
+    refinement::IterativeRefinement,
+    refinementresult::RefinementResult,
+    ssor::SSOR,
+};
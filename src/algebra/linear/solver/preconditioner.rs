@@ -0,0 +1,23 @@
+use crate::algebra::linear::Vector;
+
+/// Preconditioner
+///
+/// A preconditioner M approximates the system matrix A, such that
+/// M^{-1}A is better conditioned than A and systems Mz = r are cheap to solve.
+pub trait Preconditioner<T>
+{
+    /// Solves Mz = r
+    fn apply(self: &Self, r: &Vector<T>) -> Vector<T>;
+}
+
+/// Preconditioner which does nothing, M = I
+#[derive(Clone, Copy, Debug, Default)]
+pub struct IdentityPreconditioner;
+
+impl<T> Preconditioner<T> for IdentityPreconditioner where T: Clone
+{
+    fn apply(self: &Self, r: &Vector<T>) -> Vector<T>
+    {
+        return r.clone();
+    }
+}
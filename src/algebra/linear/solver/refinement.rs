@@ -0,0 +1,193 @@
+use super::RefinementResult;
+use crate::{
+    algebra::{
+        abstr::Real,
+        linear::{
+            matrix::{LUDec, Solve},
+            Matrix, Vector,
+        },
+    },
+    error::MathError,
+};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+
+/// Mixed precision iterative refinement
+///
+/// Solves Ax = b with an LU decomposition in a lower precision L, e.g. f32,
+/// and refines the solution with residuals in the working precision T, e.g.
+/// f64. As long as the condition number of A is small compared to the inverse
+/// of the machine epsilon of L, the refined solution has the accuracy of a
+/// solve in the working precision, while the costly decomposition is done in
+/// the lower precision.
+///
+/// 1. $`x_{0} := (LU)^{-1}Pb`$
+/// 2. $`r_{k} := b - Ax_{k}`$ in the working precision <br>
+///     Stop if the backward error of $`x_{k}`$ is below the tolerance <br>
+///     $`d_{k} := (LU)^{-1}Pr_{k}`$ in the lower precision <br>
+///     $`x_{k+1} := x_{k} + d_{k}`$
+///
+/// The refinement stops without convergence, if a correction does not
+/// decrease to at least half of the previous one.
+///
+/// # Example
+///
+/// ```
+/// # #[macro_use]
+/// # extern crate mathru;
+/// # fn main()
+/// # {
+/// use mathru::algebra::linear::{
+///     solver::{IterativeRefinement, RefinementResult},
+///     Matrix, Vector,
+/// };
+///
+/// let a: Matrix<f64> = matrix![   4.0, 1.0, 0.5;
+///                                 1.0, 3.0, 0.1;
+///                                 0.3, 0.2, 5.0];
+/// let b: Vector<f64> = vector![1.0; 2.0; 3.0];
+///
+/// let refinement: IterativeRefinement<f64> = IterativeRefinement::new(10, 1.0e-15);
+/// let result: RefinementResult<f64> = refinement.solve(&a, &b).unwrap();
+///
+/// assert!(result.converged());
+/// assert!(result.backward_error() <= 1.0e-15);
+/// # }
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Copy, Debug)]
+pub struct IterativeRefinement<T>
+{
+    max_iter: usize,
+    tolerance: T,
+}
+
+impl<T> IterativeRefinement<T>
+{
+    /// Creates an instance of the iterative refinement
+    ///
+    /// # Arguments
+    ///
+    /// * 'max_iter': Maximum number of refinement steps
+    /// * 'tolerance': Tolerance for the normwise backward error
+    pub fn new(max_iter: usize, tolerance: T) -> IterativeRefinement<T>
+    {
+        return IterativeRefinement { max_iter, tolerance };
+    }
+}
+
+impl<T> IterativeRefinement<T> where T: Real
+{
+    /// Solves Ax = b with an LU decomposition of A in single precision
+    ///
+    /// # Arguments
+    ///
+    /// * 'a': Square matrix
+    /// * 'b': Right hand side
+    ///
+    /// # Errors
+    ///
+    /// MathError::DimensionMismatch if A is not square or the dimensions of A
+    /// and b do not match, MathError::Singular if A is singular in single
+    /// precision
+    pub fn solve(self: &Self, a: &Matrix<T>, b: &Vector<T>) -> Result<RefinementResult<T>, MathError>
+    {
+        let (m, n): (usize, usize) = a.dim();
+        if m != n
+        {
+            return Err(MathError::DimensionMismatch);
+        }
+
+        let a_single: Matrix<f32> = Matrix::new(m, n, a.iter().map(|a_ij: &T| a_ij.to_f32()).collect());
+        let lu: LUDec<f32> = a_single.dec_lu()?;
+
+        return self.solve_lu(a, &lu, b);
+    }
+
+    /// Solves Ax = b with a given LU decomposition of A
+    ///
+    /// The decomposition can be computed in any precision, e.g. an existing
+    /// decomposition in the working precision is reused to improve the
+    /// accuracy of its solution.
+    ///
+    /// # Arguments
+    ///
+    /// * 'a': Square matrix in the working precision
+    /// * 'lu': LU decomposition of A
+    /// * 'b': Right hand side
+    ///
+    /// # Errors
+    ///
+    /// MathError::DimensionMismatch if the dimensions of A, the decomposition
+    /// and b do not match
+    pub fn solve_lu<L>(self: &Self, a: &Matrix<T>, lu: &LUDec<L>, b: &Vector<T>)
+                       -> Result<RefinementResult<T>, MathError>
+        where L: Real
+    {
+        let (m, n): (usize, usize) = a.dim();
+        if m != n || b.dim() != (n, 1)
+        {
+            return Err(MathError::DimensionMismatch);
+        }
+
+        let a_norm: T = a.norm_inf();
+        let b_norm: T = IterativeRefinement::norm_inf(b);
+
+        let mut x: Vector<T> = IterativeRefinement::correction(lu, b)?;
+        let mut d_norm_prev: T = T::infinity();
+        let mut k: usize = 0;
+
+        loop
+        {
+            let r: Vector<T> = b - &(a * &x);
+            let x_norm: T = IterativeRefinement::norm_inf(&x);
+            let denominator: T = a_norm * x_norm + b_norm;
+            let backward_error: T = if denominator == T::zero()
+            {
+                T::zero()
+            }
+            else
+            {
+                IterativeRefinement::norm_inf(&r) / denominator
+            };
+
+            let d: Vector<T> = IterativeRefinement::correction(lu, &r)?;
+            let d_norm: T = IterativeRefinement::norm_inf(&d);
+            let forward_error: T = if x_norm == T::zero() { d_norm } else { d_norm / x_norm };
+
+            if backward_error <= self.tolerance
+            {
+                return Ok(RefinementResult::new(x, k, backward_error, forward_error, true));
+            }
+
+            // a NaN correction stagnates as well
+            let stagnates: bool = !matches!(d_norm.partial_cmp(&(d_norm_prev / T::from_f64(2.0))),
+                                            Some(Ordering::Less) | Some(Ordering::Equal));
+            if k == self.max_iter || stagnates
+            {
+                return Ok(RefinementResult::new(x, k, backward_error, forward_error, false));
+            }
+
+            x = x + d;
+            d_norm_prev = d_norm;
+            k += 1;
+        }
+    }
+
+    /// Solves LU d = P r in the precision of the decomposition
+    fn correction<L>(lu: &LUDec<L>, r: &Vector<T>) -> Result<Vector<T>, MathError>
+        where L: Real
+    {
+        let (n, _): (usize, usize) = r.dim();
+        let r_low: Vector<L> = Vector::new_column(n, r.iter().map(|r_i: &T| L::from_f64(r_i.to_f64())).collect());
+        let d: Vec<T> = lu.solve(&r_low)?.iter().map(|d_i: &L| T::from_f64(d_i.to_f64())).collect();
+
+        return Ok(Vector::new_column(n, d));
+    }
+
+    fn norm_inf(x: &Vector<T>) -> T
+    {
+        return x.iter().fold(T::zero(), |norm, x_i| norm.max(x_i.abs()));
+    }
+}
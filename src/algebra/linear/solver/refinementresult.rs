@@ -0,0 +1,67 @@
+use crate::algebra::linear::Vector;
+
+/// Result of the iterative refinement
+#[derive(Debug, Clone)]
+pub struct RefinementResult<T>
+{
+    x: Vector<T>,
+    iterations: usize,
+    backward_error: T,
+    forward_error: T,
+    converged: bool,
+}
+
+impl<T> RefinementResult<T>
+{
+    pub(super) fn new(x: Vector<T>, iterations: usize, backward_error: T, forward_error: T,
+                      converged: bool)
+                      -> RefinementResult<T>
+    {
+        return RefinementResult { x,
+                                  iterations,
+                                  backward_error,
+                                  forward_error,
+                                  converged };
+    }
+
+    /// Returns the refined solution
+    pub fn x(self: Self) -> Vector<T>
+    {
+        return self.x;
+    }
+
+    /// Returns the number of performed refinement steps
+    pub fn iterations(self: &Self) -> usize
+    {
+        return self.iterations;
+    }
+}
+
+impl<T> RefinementResult<T> where T: Copy
+{
+    /// Returns the normwise backward error of the solution
+    ///
+    /// ```math
+    /// \eta = \frac{\lVert b - Ax \rVert_{\infty}}{\lVert A \rVert_{\infty} \lVert x \rVert_{\infty} + \lVert b \rVert_{\infty}}
+    /// ```
+    pub fn backward_error(self: &Self) -> T
+    {
+        return self.backward_error;
+    }
+
+    /// Returns an estimate of the relative forward error of the solution
+    ///
+    /// The estimate is the size of the next correction
+    /// $`\lVert d \rVert_{\infty} / \lVert x \rVert_{\infty}`$, which is
+    /// reliable as long as the refinement converges.
+    pub fn forward_error(self: &Self) -> T
+    {
+        return self.forward_error;
+    }
+
+    /// Returns true, if the requested backward error has been reached
+    pub fn converged(self: &Self) -> bool
+    {
+        return self.converged;
+    }
+}
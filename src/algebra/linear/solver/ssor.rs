@@ -0,0 +1,116 @@
+use super::Preconditioner;
+use crate::{
+    algebra::{
+        abstr::{Field, Scalar},
+        linear::{sparse::CSRMatrix, Vector},
+    },
+    error::MathError,
+};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::clone::Clone;
+
+/// Symmetric successive over-relaxation (SSOR) preconditioner
+///
+/// A = L + D + U is split into its strictly lower triangular part L, its
+/// diagonal D and its strictly upper triangular part U.
+///
+/// $`M = \frac{1}{\omega (2 - \omega)} (D + \omega L) D^{-1} (D + \omega
+/// U)`$
+///
+/// with the relaxation parameter $`0 < \omega < 2`$. For symmetric positive
+/// definite matrices M is symmetric positive definite as well.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug)]
+pub struct SSOR<T>
+{
+    a: CSRMatrix<T>,
+    diag: Vec<T>,
+    omega: T,
+}
+
+impl<T> SSOR<T> where T: Field + Scalar
+{
+    /// Creates the SSOR preconditioner of the square matrix a
+    ///
+    /// # Arguments
+    ///
+    /// * 'a': Square matrix
+    /// * 'omega': Relaxation parameter, 0 < omega < 2
+    ///
+    /// # Errors
+    ///
+    /// if a diagonal entry is zero
+    ///
+    /// # Panics
+    ///
+    /// if a is not square or omega is not in (0, 2)
+    pub fn new(a: &CSRMatrix<T>, omega: T) -> Result<SSOR<T>, MathError>
+    {
+        let (m, n): (usize, usize) = a.dim();
+        assert_eq!(m, n);
+        assert!(omega > T::zero() && omega < T::from_f64(2.0));
+
+        let mut diag: Vec<T> = Vec::with_capacity(n);
+        for i in 0..n
+        {
+            let a_ii: T = a.get(i, i);
+            if a_ii == T::zero()
+            {
+                return Err(MathError::Singular { pivot: i });
+            }
+            diag.push(a_ii);
+        }
+
+        return Ok(SSOR { a: a.clone(),
+                         diag,
+                         omega });
+    }
+}
+
+impl<T> Preconditioner<T> for SSOR<T> where T: Field + Scalar
+{
+    fn apply(self: &Self, r: &Vector<T>) -> Vector<T>
+    {
+        let n: usize = self.diag.len();
+        let row_ptr: &[usize] = self.a.row_ptr();
+        let col_idx: &[usize] = self.a.col_idx();
+        let values: &[T] = self.a.values();
+
+        // (D + omega L) y = r
+        let mut y: Vec<T> = vec![T::zero(); n];
+        for i in 0..n
+        {
+            let mut sum: T = *r.get(i);
+            for k in row_ptr[i]..row_ptr[i + 1]
+            {
+                let j: usize = col_idx[k];
+                if j < i
+                {
+                    sum -= self.omega * values[k] * y[j];
+                }
+            }
+            y[i] = sum / self.diag[i];
+        }
+
+        // (D + omega U) z = D y
+        let mut z: Vec<T> = vec![T::zero(); n];
+        for i in (0..n).rev()
+        {
+            let mut sum: T = self.diag[i] * y[i];
+            for k in row_ptr[i]..row_ptr[i + 1]
+            {
+                let j: usize = col_idx[k];
+                if j > i
+                {
+                    sum -= self.omega * values[k] * z[j];
+                }
+            }
+            z[i] = sum / self.diag[i];
+        }
+
+        let scale: T = self.omega * (T::from_f64(2.0) - self.omega);
+        let z: Vec<T> = z.iter().map(|z_i| *z_i * scale).collect();
+        return Vector::new_column(n, z);
+    }
+}
@@ -0,0 +1,289 @@
+//! Kernels shared by the compressed row and compressed column formats
+//!
+//! A CSR matrix and a CSC matrix store their data in the same way, only the
+//! meaning of the outer and inner index is swapped. The functions in this
+//! module operate on the raw (pointer, index, value) arrays and are therefore
+//! used by both formats.
+use crate::algebra::abstr::{Field, Scalar};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Compressed storage of a sparse matrix
+///
+/// outer: number of rows (CSR) or columns (CSC) \
+/// inner: number of columns (CSR) or rows (CSC)
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub(super) struct Compressed<T>
+{
+    pub(super) outer: usize,
+    pub(super) inner: usize,
+    pub(super) ptr: Vec<usize>,
+    pub(super) idx: Vec<usize>,
+    pub(super) values: Vec<T>,
+}
+
+/// Builds the compressed storage from unsorted triplets, duplicate entries are
+/// summed up.
+pub(super) fn from_triplets<T>(outer: usize, inner: usize, outer_idx: &[usize],
+                               inner_idx: &[usize], values: &[T])
+                               -> Compressed<T>
+    where T: Field + Scalar
+{
+    let nnz: usize = values.len();
+
+    let mut ptr: Vec<usize> = vec![0; outer + 1];
+    for &o in outer_idx.iter()
+    {
+        ptr[o + 1] += 1;
+    }
+    for o in 0..outer
+    {
+        ptr[o + 1] += ptr[o];
+    }
+
+    let mut next: Vec<usize> = ptr.clone();
+    let mut idx: Vec<usize> = vec![0; nnz];
+    let mut val: Vec<T> = vec![T::zero(); nnz];
+
+    for k in 0..nnz
+    {
+        let o: usize = outer_idx[k];
+        let dest: usize = next[o];
+        idx[dest] = inner_idx[k];
+        val[dest] = values[k];
+        next[o] += 1;
+    }
+
+    let mut compressed: Compressed<T> = Compressed { outer,
+                                                     inner,
+                                                     ptr,
+                                                     idx,
+                                                     values: val };
+    compressed.sort_and_sum();
+    return compressed;
+}
+
+impl<T> Compressed<T>
+{
+    /// Creates the compressed storage from its raw arrays
+    ///
+    /// # Panics
+    ///
+    /// if the pointer array does not have outer + 1 monotonically increasing
+    /// entries, if an index is out of bounds or if the indices of an outer
+    /// slice are not strictly increasing.
+    pub(super) fn new(outer: usize, inner: usize, ptr: Vec<usize>, idx: Vec<usize>,
+                      values: Vec<T>)
+                      -> Compressed<T>
+    {
+        assert_eq!(ptr.len(), outer + 1, "Pointer array has wrong length");
+        assert_eq!(ptr[0], 0, "Pointer array has to start with zero");
+        assert_eq!(ptr[outer], idx.len(), "Pointer array and index array do not match");
+        assert_eq!(idx.len(), values.len(), "Index array and value array do not match");
+
+        for o in 0..outer
+        {
+            assert!(ptr[o] <= ptr[o + 1], "Pointer array is not monotonically increasing");
+            for k in ptr[o]..ptr[o + 1]
+            {
+                assert!(idx[k] < inner, "Index out of bounds");
+                if k > ptr[o]
+                {
+                    assert!(idx[k - 1] < idx[k], "Indices are not strictly increasing");
+                }
+            }
+        }
+
+        return Compressed { outer,
+                            inner,
+                            ptr,
+                            idx,
+                            values };
+    }
+
+    /// Number of stored entries
+    pub(super) fn nnz(self: &Self) -> usize
+    {
+        return self.values.len();
+    }
+}
+
+impl<T> Compressed<T> where T: Field + Scalar
+{
+    /// Builds the compressed storage from a function returning the entry at
+    /// (outer, inner), zeros are not stored.
+    pub(super) fn from_fn<F>(outer: usize, inner: usize, f: F) -> Compressed<T>
+        where F: Fn(usize, usize) -> T
+    {
+        let mut ptr: Vec<usize> = Vec::with_capacity(outer + 1);
+        let mut idx: Vec<usize> = Vec::new();
+        let mut values: Vec<T> = Vec::new();
+        ptr.push(0);
+
+        for o in 0..outer
+        {
+            for i in 0..inner
+            {
+                let value: T = f(o, i);
+                if value != T::zero()
+                {
+                    idx.push(i);
+                    values.push(value);
+                }
+            }
+            ptr.push(idx.len());
+        }
+
+        return Compressed { outer,
+                            inner,
+                            ptr,
+                            idx,
+                            values };
+    }
+
+    /// Sorts the inner indices of every outer slice and sums up duplicates
+    fn sort_and_sum(self: &mut Self)
+    {
+        let mut ptr: Vec<usize> = Vec::with_capacity(self.outer + 1);
+        let mut idx: Vec<usize> = Vec::with_capacity(self.idx.len());
+        let mut values: Vec<T> = Vec::with_capacity(self.values.len());
+        ptr.push(0);
+
+        let mut slice: Vec<(usize, T)> = Vec::new();
+        for o in 0..self.outer
+        {
+            slice.clear();
+            for k in self.ptr[o]..self.ptr[o + 1]
+            {
+                slice.push((self.idx[k], self.values[k]));
+            }
+            slice.sort_by_key(|&(i, _)| i);
+
+            for &(i, v) in slice.iter()
+            {
+                if idx.len() > ptr[o] && *idx.last().unwrap() == i
+                {
+                    let last: usize = values.len() - 1;
+                    values[last] += v;
+                }
+                else
+                {
+                    idx.push(i);
+                    values.push(v);
+                }
+            }
+            ptr.push(idx.len());
+        }
+
+        self.ptr = ptr;
+        self.idx = idx;
+        self.values = values;
+    }
+
+    /// Returns the entry at (outer, inner)
+    pub(super) fn get(self: &Self, o: usize, i: usize) -> T
+    {
+        let slice: &[usize] = &self.idx[self.ptr[o]..self.ptr[o + 1]];
+        return match slice.binary_search(&i)
+        {
+            Ok(pos) => self.values[self.ptr[o] + pos],
+            Err(_) => T::zero(),
+        };
+    }
+
+    /// Swaps the role of the outer and the inner index
+    ///
+    /// For a CSR matrix this results in the CSR representation of the
+    /// transposed matrix, which is equal to the CSC representation of the
+    /// matrix itself.
+    pub(super) fn transpose(self: &Self) -> Compressed<T>
+    {
+        let nnz: usize = self.values.len();
+        let mut ptr: Vec<usize> = vec![0; self.inner + 1];
+        for &i in self.idx.iter()
+        {
+            ptr[i + 1] += 1;
+        }
+        for i in 0..self.inner
+        {
+            ptr[i + 1] += ptr[i];
+        }
+
+        let mut next: Vec<usize> = ptr.clone();
+        let mut idx: Vec<usize> = vec![0; nnz];
+        let mut values: Vec<T> = vec![T::zero(); nnz];
+
+        // Iterating over the outer index in ascending order keeps the new inner
+        // indices sorted
+        for o in 0..self.outer
+        {
+            for k in self.ptr[o]..self.ptr[o + 1]
+            {
+                let i: usize = self.idx[k];
+                let dest: usize = next[i];
+                idx[dest] = o;
+                values[dest] = self.values[k];
+                next[i] += 1;
+            }
+        }
+
+        return Compressed { outer: self.inner,
+                            inner: self.outer,
+                            ptr,
+                            idx,
+                            values };
+    }
+
+    /// Product of two compressed matrices (Gustavson's algorithm)
+    ///
+    /// For CSR the result is self * rhs, for CSC the roles of the operands
+    /// are swapped: rhs * self
+    pub(super) fn mul(self: &Self, rhs: &Compressed<T>) -> Compressed<T>
+    {
+        assert_eq!(self.inner, rhs.outer);
+
+        let mut ptr: Vec<usize> = Vec::with_capacity(self.outer + 1);
+        let mut idx: Vec<usize> = Vec::new();
+        let mut values: Vec<T> = Vec::new();
+        ptr.push(0);
+
+        let mut accumulator: Vec<T> = vec![T::zero(); rhs.inner];
+        let mut marker: Vec<usize> = vec![usize::MAX; rhs.inner];
+        let mut pattern: Vec<usize> = Vec::new();
+
+        for o in 0..self.outer
+        {
+            pattern.clear();
+            for k in self.ptr[o]..self.ptr[o + 1]
+            {
+                let a_ok: T = self.values[k];
+                let r: usize = self.idx[k];
+                for l in rhs.ptr[r]..rhs.ptr[r + 1]
+                {
+                    let i: usize = rhs.idx[l];
+                    if marker[i] != o
+                    {
+                        marker[i] = o;
+                        accumulator[i] = T::zero();
+                        pattern.push(i);
+                    }
+                    accumulator[i] += a_ok * rhs.values[l];
+                }
+            }
+            pattern.sort_unstable();
+            for &i in pattern.iter()
+            {
+                idx.push(i);
+                values.push(accumulator[i]);
+            }
+            ptr.push(idx.len());
+        }
+
+        return Compressed { outer: self.outer,
+                            inner: rhs.inner,
+                            ptr,
+                            idx,
+                            values };
+    }
+}
@@ -0,0 +1,263 @@
+//! Coordinate format sparse matrix
+use super::{CSCMatrix, CSRMatrix};
+use crate::algebra::{
+    abstr::{Field, Scalar},
+    linear::{matrix::Transpose, Matrix, Vector},
+};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::{clone::Clone, convert::From};
+
+/// Sparse matrix in coordinate (COO) format
+///
+/// Every entry is stored as a triplet (row, column, value). The format is
+/// meant for the assembly of a matrix, the entries are unordered and duplicate
+/// entries are allowed. They are summed up, when the matrix is converted.
+/// For computations the matrix should be converted to [`CSRMatrix`] or
+/// [`CSCMatrix`].
+///
+/// # Example
+///
+/// ```
+/// use mathru::algebra::linear::sparse::{COOMatrix, CSRMatrix};
+///
+/// let mut a: COOMatrix<f64> = COOMatrix::new(2, 2);
+/// a.push(0, 0, 1.0);
+/// a.push(1, 0, 2.0);
+/// a.push(0, 0, 3.0);
+///
+/// let a_csr: CSRMatrix<f64> = CSRMatrix::from(a);
+///
+/// assert_eq!(4.0, a_csr.get(0, 0));
+/// assert_eq!(2, a_csr.nnz());
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct COOMatrix<T>
+{
+    m: usize,
+    n: usize,
+    row_idx: Vec<usize>,
+    col_idx: Vec<usize>,
+    values: Vec<T>,
+}
+
+impl<T> COOMatrix<T>
+{
+    /// Creates an empty m x n matrix
+    pub fn new(m: usize, n: usize) -> COOMatrix<T>
+    {
+        return COOMatrix { m,
+                           n,
+                           row_idx: Vec::new(),
+                           col_idx: Vec::new(),
+                           values: Vec::new() };
+    }
+
+    /// Creates a matrix from a list of triplets
+    ///
+    /// # Panics
+    ///
+    /// if the length of the arrays differs or an index is out of bounds
+    pub fn from_triplets(m: usize, n: usize, row_idx: Vec<usize>, col_idx: Vec<usize>,
+                         values: Vec<T>)
+                         -> COOMatrix<T>
+    {
+        assert_eq!(row_idx.len(), values.len());
+        assert_eq!(col_idx.len(), values.len());
+        assert!(row_idx.iter().all(|&i| i < m), "Row index out of bounds");
+        assert!(col_idx.iter().all(|&j| j < n), "Column index out of bounds");
+
+        return COOMatrix { m,
+                           n,
+                           row_idx,
+                           col_idx,
+                           values };
+    }
+
+    /// Adds the entry a_ij, if the entry already exists, the values are summed
+    /// up on conversion
+    ///
+    /// # Panics
+    ///
+    /// if the index is out of bounds
+    pub fn push(self: &mut Self, i: usize, j: usize, value: T)
+    {
+        assert!(i < self.m);
+        assert!(j < self.n);
+
+        self.row_idx.push(i);
+        self.col_idx.push(j);
+        self.values.push(value);
+    }
+
+    /// Returns the matrix dimension
+    pub fn dim(self: &Self) -> (usize, usize)
+    {
+        return (self.m, self.n);
+    }
+
+    /// Returns the number of stored entries including duplicates
+    pub fn nnz(self: &Self) -> usize
+    {
+        return self.values.len();
+    }
+
+    /// Returns the row indices of the stored entries
+    pub fn row_idx(self: &Self) -> &[usize]
+    {
+        return &self.row_idx;
+    }
+
+    /// Returns the column indices of the stored entries
+    pub fn col_idx(self: &Self) -> &[usize]
+    {
+        return &self.col_idx;
+    }
+
+    /// Returns the stored entries
+    pub fn values(self: &Self) -> &[T]
+    {
+        return &self.values;
+    }
+
+    /// Returns the triplets (row indices, column indices, values)
+    pub fn triplets(self: Self) -> (Vec<usize>, Vec<usize>, Vec<T>)
+    {
+        return (self.row_idx, self.col_idx, self.values);
+    }
+}
+
+impl<T> Transpose for COOMatrix<T>
+{
+    type Output = COOMatrix<T>;
+
+    fn transpose(self: Self) -> COOMatrix<T>
+    {
+        return COOMatrix { m: self.n,
+                           n: self.m,
+                           row_idx: self.col_idx,
+                           col_idx: self.row_idx,
+                           values: self.values };
+    }
+}
+
+impl<'a, T> From<&'a Matrix<T>> for COOMatrix<T> where T: Field + Scalar
+{
+    /// Converts a dense matrix, zero entries are not stored
+    fn from(a: &'a Matrix<T>) -> COOMatrix<T>
+    {
+        let (m, n): (usize, usize) = a.dim();
+        let mut coo: COOMatrix<T> = COOMatrix::new(m, n);
+
+        for j in 0..n
+        {
+            for i in 0..m
+            {
+                let a_ij: T = *a.get(i, j);
+                if a_ij != T::zero()
+                {
+                    coo.push(i, j, a_ij);
+                }
+            }
+        }
+
+        return coo;
+    }
+}
+
+impl<'a, T> From<&'a Vector<T>> for COOMatrix<T> where T: Field + Scalar
+{
+    /// Converts a column vector to a m x 1 matrix and a row vector to a 1 x n
+    /// matrix
+    fn from(v: &'a Vector<T>) -> COOMatrix<T>
+    {
+        let (m, n): (usize, usize) = v.dim();
+        let mut coo: COOMatrix<T> = COOMatrix::new(m, n);
+
+        for (k, v_k) in v.iter().enumerate()
+        {
+            if *v_k != T::zero()
+            {
+                if n == 1
+                {
+                    coo.push(k, 0, *v_k);
+                }
+                else
+                {
+                    coo.push(0, k, *v_k);
+                }
+            }
+        }
+
+        return coo;
+    }
+}
+
+impl<'a, T> From<&'a COOMatrix<T>> for Matrix<T> where T: Field + Scalar
+{
+    /// Converts to a dense matrix, duplicate entries are summed up
+    fn from(a: &'a COOMatrix<T>) -> Matrix<T>
+    {
+        let mut dense: Matrix<T> = Matrix::zero(a.m, a.n);
+
+        for k in 0..a.values.len()
+        {
+            *dense.get_mut(a.row_idx[k], a.col_idx[k]) += a.values[k];
+        }
+
+        return dense;
+    }
+}
+
+impl<T> From<COOMatrix<T>> for Matrix<T> where T: Field + Scalar
+{
+    fn from(a: COOMatrix<T>) -> Matrix<T>
+    {
+        return Matrix::from(&a);
+    }
+}
+
+impl<T> From<CSRMatrix<T>> for COOMatrix<T> where T: Field + Scalar
+{
+    fn from(a: CSRMatrix<T>) -> COOMatrix<T>
+    {
+        let (m, n): (usize, usize) = a.dim();
+        let mut row_idx: Vec<usize> = Vec::with_capacity(a.nnz());
+        for i in 0..m
+        {
+            for _k in a.row_ptr()[i]..a.row_ptr()[i + 1]
+            {
+                row_idx.push(i);
+            }
+        }
+
+        return COOMatrix { m,
+                           n,
+                           row_idx,
+                           col_idx: a.data.idx,
+                           values: a.data.values };
+    }
+}
+
+impl<T> From<CSCMatrix<T>> for COOMatrix<T> where T: Field + Scalar
+{
+    fn from(a: CSCMatrix<T>) -> COOMatrix<T>
+    {
+        let (m, n): (usize, usize) = a.dim();
+        let mut col_idx: Vec<usize> = Vec::with_capacity(a.nnz());
+        for j in 0..n
+        {
+            for _k in a.col_ptr()[j]..a.col_ptr()[j + 1]
+            {
+                col_idx.push(j);
+            }
+        }
+
+        return COOMatrix { m,
+                           n,
+                           row_idx: a.data.idx,
+                           col_idx,
+                           values: a.data.values };
+    }
+}
@@ -0,0 +1,318 @@
+//! Compressed sparse column matrix
+use super::{
+    compressed::{self, Compressed},
+    COOMatrix, CSRMatrix,
+};
+use crate::algebra::{
+    abstr::{Field, Scalar},
+    linear::{matrix::Transpose, Matrix, Vector},
+};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::{
+    clone::Clone,
+    convert::From,
+    ops::Mul,
+};
+
+/// Sparse matrix in compressed sparse column (CSC) format
+///
+/// The row indices and values of column j are stored in
+/// row_idx[col_ptr[j]..col_ptr[j + 1]] and values[col_ptr[j]..col_ptr[j + 1]].
+/// The row indices of every column are sorted in ascending order.
+///
+/// # Example
+///
+/// ```
+/// use mathru::algebra::linear::{sparse::CSCMatrix, Vector};
+///
+/// // 2 0 0
+/// // 0 0 3
+/// let a: CSCMatrix<f64> = CSCMatrix::new(2, 3, vec![0, 1, 1, 2], vec![0, 1], vec![2.0, 3.0]);
+/// let x: Vector<f64> = Vector::new_column(3, vec![1.0, 2.0, 3.0]);
+///
+/// assert_eq!(Vector::new_column(2, vec![2.0, 9.0]), &a * &x);
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct CSCMatrix<T>
+{
+    pub(super) data: Compressed<T>,
+}
+
+impl<T> CSCMatrix<T>
+{
+    /// Creates a new CSC matrix from its raw arrays
+    ///
+    /// # Arguments
+    ///
+    /// * 'm': Number of rows
+    /// * 'n': Number of columns
+    /// * 'col_ptr': Start of every column in row_idx and values, has n + 1
+    /// entries
+    /// * 'row_idx': Row index of every entry
+    /// * 'values': Entries
+    ///
+    /// # Panics
+    ///
+    /// if the arrays do not describe a valid CSC matrix
+    pub fn new(m: usize, n: usize, col_ptr: Vec<usize>, row_idx: Vec<usize>, values: Vec<T>)
+               -> CSCMatrix<T>
+    {
+        return CSCMatrix { data: Compressed::new(n, m, col_ptr, row_idx, values) };
+    }
+
+    /// Returns the zero matrix
+    pub fn zero(m: usize, n: usize) -> CSCMatrix<T>
+    {
+        return CSCMatrix::new(m, n, vec![0; n + 1], Vec::new(), Vec::new());
+    }
+
+    /// Returns the matrix dimension
+    pub fn dim(self: &Self) -> (usize, usize)
+    {
+        return (self.data.inner, self.data.outer);
+    }
+
+    /// Returns the number of rows
+    pub fn nrows(self: &Self) -> usize
+    {
+        return self.data.inner;
+    }
+
+    /// Returns the number of columns
+    pub fn ncols(self: &Self) -> usize
+    {
+        return self.data.outer;
+    }
+
+    /// Returns the number of stored entries
+    pub fn nnz(self: &Self) -> usize
+    {
+        return self.data.nnz();
+    }
+
+    /// Returns the column pointer array
+    pub fn col_ptr(self: &Self) -> &[usize]
+    {
+        return &self.data.ptr;
+    }
+
+    /// Returns the row indices of the stored entries
+    pub fn row_idx(self: &Self) -> &[usize]
+    {
+        return &self.data.idx;
+    }
+
+    /// Returns the stored entries
+    pub fn values(self: &Self) -> &[T]
+    {
+        return &self.data.values;
+    }
+}
+
+impl<T> CSCMatrix<T> where T: Field + Scalar
+{
+    /// Returns the element a_ij
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mathru::algebra::linear::sparse::CSCMatrix;
+    ///
+    /// let a: CSCMatrix<f64> = CSCMatrix::new(2, 3, vec![0, 1, 1, 2], vec![0, 1], vec![2.0, 3.0]);
+    ///
+    /// assert_eq!(3.0, a.get(1, 2));
+    /// assert_eq!(0.0, a.get(1, 1));
+    /// ```
+    pub fn get(self: &Self, i: usize, j: usize) -> T
+    {
+        assert!(i < self.data.inner);
+        assert!(j < self.data.outer);
+
+        return self.data.get(j, i);
+    }
+}
+
+impl<T> Transpose for CSCMatrix<T> where T: Field + Scalar
+{
+    type Output = CSCMatrix<T>;
+
+    /// Transposes the matrix
+    fn transpose(self: Self) -> CSCMatrix<T>
+    {
+        return CSCMatrix { data: self.data.transpose() };
+    }
+}
+
+/// Multiplies a sparse matrix with a vector
+impl<'a, 'b, T> Mul<&'b Vector<T>> for &'a CSCMatrix<T> where T: Field + Scalar
+{
+    type Output = Vector<T>;
+
+    fn mul(self: Self, v: &'b Vector<T>) -> Vector<T>
+    {
+        let (v_m, _v_n): (usize, usize) = v.dim();
+        if self.data.outer != v_m
+        {
+            panic!("Matrix and Vector dimension do not match");
+        }
+
+        let mut prod_data: Vec<T> = vec![T::zero(); self.data.inner];
+
+        for j in 0..self.data.outer
+        {
+            let v_j: T = *v.get(j);
+            for k in self.data.ptr[j]..self.data.ptr[j + 1]
+            {
+                prod_data[self.data.idx[k]] += self.data.values[k] * v_j;
+            }
+        }
+
+        return Vector::new_column(self.data.inner, prod_data);
+    }
+}
+
+impl<T> Mul<Vector<T>> for CSCMatrix<T> where T: Field + Scalar
+{
+    type Output = Vector<T>;
+
+    fn mul(self: Self, v: Vector<T>) -> Vector<T>
+    {
+        return &self * &v;
+    }
+}
+
+/// Multiplies a sparse matrix with a dense matrix
+impl<'a, 'b, T> Mul<&'b Matrix<T>> for &'a CSCMatrix<T> where T: Field + Scalar
+{
+    type Output = Matrix<T>;
+
+    fn mul(self: Self, rhs: &'b Matrix<T>) -> Matrix<T>
+    {
+        let (rhs_m, rhs_n): (usize, usize) = rhs.dim();
+        assert_eq!(self.data.outer, rhs_m);
+
+        let mut prod: Matrix<T> = Matrix::zero(self.data.inner, rhs_n);
+
+        for c in 0..rhs_n
+        {
+            for j in 0..self.data.outer
+            {
+                let b_jc: T = *rhs.get(j, c);
+                for k in self.data.ptr[j]..self.data.ptr[j + 1]
+                {
+                    *prod.get_mut(self.data.idx[k], c) += self.data.values[k] * b_jc;
+                }
+            }
+        }
+
+        return prod;
+    }
+}
+
+impl<'a, 'b, T> Mul<&'b CSCMatrix<T>> for &'a CSCMatrix<T> where T: Field + Scalar
+{
+    type Output = CSCMatrix<T>;
+
+    /// Multiplies two sparse matrices
+    fn mul(self: Self, rhs: &'b CSCMatrix<T>) -> CSCMatrix<T>
+    {
+        if self.data.outer != rhs.data.inner
+        {
+            panic!("Matrix dimensions do not match");
+        }
+
+        // (AB)^T = B^T A^T and the CSC arrays of a matrix are the CSR arrays of
+        // its transpose
+        return CSCMatrix { data: rhs.data.mul(&self.data) };
+    }
+}
+
+impl<T> Mul<CSCMatrix<T>> for CSCMatrix<T> where T: Field + Scalar
+{
+    type Output = CSCMatrix<T>;
+
+    fn mul(self: Self, rhs: CSCMatrix<T>) -> CSCMatrix<T>
+    {
+        return &self * &rhs;
+    }
+}
+
+impl<'a, T> From<&'a Matrix<T>> for CSCMatrix<T> where T: Field + Scalar
+{
+    /// Converts a dense matrix, zero entries are not stored
+    fn from(a: &'a Matrix<T>) -> CSCMatrix<T>
+    {
+        let (m, n): (usize, usize) = a.dim();
+        return CSCMatrix { data: Compressed::from_fn(n, m, |j, i| *a.get(i, j)) };
+    }
+}
+
+impl<T> From<Matrix<T>> for CSCMatrix<T> where T: Field + Scalar
+{
+    fn from(a: Matrix<T>) -> CSCMatrix<T>
+    {
+        return CSCMatrix::from(&a);
+    }
+}
+
+impl<'a, T> From<&'a Vector<T>> for CSCMatrix<T> where T: Field + Scalar
+{
+    /// Converts a column vector to a m x 1 matrix and a row vector to a 1 x n
+    /// matrix
+    fn from(v: &'a Vector<T>) -> CSCMatrix<T>
+    {
+        let (m, n): (usize, usize) = v.dim();
+        return CSCMatrix { data: Compressed::from_fn(n, m, |j, i| *v.get(i.max(j))) };
+    }
+}
+
+impl<'a, T> From<&'a CSCMatrix<T>> for Matrix<T> where T: Field + Scalar
+{
+    fn from(a: &'a CSCMatrix<T>) -> Matrix<T>
+    {
+        let (m, n): (usize, usize) = a.dim();
+        let mut dense: Vec<T> = vec![T::zero(); m * n];
+
+        for j in 0..n
+        {
+            for k in a.data.ptr[j]..a.data.ptr[j + 1]
+            {
+                dense[j * m + a.data.idx[k]] = a.data.values[k];
+            }
+        }
+
+        return Matrix::new(m, n, dense);
+    }
+}
+
+impl<T> From<CSCMatrix<T>> for Matrix<T> where T: Field + Scalar
+{
+    fn from(a: CSCMatrix<T>) -> Matrix<T>
+    {
+        return Matrix::from(&a);
+    }
+}
+
+impl<T> From<COOMatrix<T>> for CSCMatrix<T> where T: Field + Scalar
+{
+    /// Converts the coordinate format, duplicate entries are summed up
+    fn from(a: COOMatrix<T>) -> CSCMatrix<T>
+    {
+        let (m, n): (usize, usize) = a.dim();
+        return CSCMatrix { data: compressed::from_triplets(n,
+                                                           m,
+                                                           a.col_idx(),
+                                                           a.row_idx(),
+                                                           a.values()) };
+    }
+}
+
+impl<T> From<CSRMatrix<T>> for CSCMatrix<T> where T: Field + Scalar
+{
+    fn from(a: CSRMatrix<T>) -> CSCMatrix<T>
+    {
+        return CSCMatrix { data: a.data.transpose() };
+    }
+}
@@ -0,0 +1,344 @@
+//! Compressed sparse row matrix
+use super::{
+    compressed::{self, Compressed},
+    COOMatrix, CSCMatrix,
+};
+use crate::algebra::{
+    abstr::{Field, Scalar},
+    linear::{matrix::Transpose, Matrix, Vector},
+};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::{
+    clone::Clone,
+    convert::From,
+    ops::Mul,
+};
+
+/// Sparse matrix in compressed sparse row (CSR) format
+///
+/// The column indices and values of row i are stored in
+/// col_idx[row_ptr[i]..row_ptr[i + 1]] and values[row_ptr[i]..row_ptr[i + 1]].
+/// The column indices of every row are sorted in ascending order.
+///
+/// # Example
+///
+/// ```
+/// use mathru::algebra::linear::{sparse::CSRMatrix, Vector};
+///
+/// // 2 0 0
+/// // 0 0 3
+/// let a: CSRMatrix<f64> = CSRMatrix::new(2, 3, vec![0, 1, 2], vec![0, 2], vec![2.0, 3.0]);
+/// let x: Vector<f64> = Vector::new_column(3, vec![1.0, 2.0, 3.0]);
+///
+/// assert_eq!(Vector::new_column(2, vec![2.0, 9.0]), &a * &x);
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct CSRMatrix<T>
+{
+    pub(super) data: Compressed<T>,
+}
+
+impl<T> CSRMatrix<T>
+{
+    /// Creates a new CSR matrix from its raw arrays
+    ///
+    /// # Arguments
+    ///
+    /// * 'm': Number of rows
+    /// * 'n': Number of columns
+    /// * 'row_ptr': Start of every row in col_idx and values, has m + 1
+    /// entries
+    /// * 'col_idx': Column index of every entry
+    /// * 'values': Entries
+    ///
+    /// # Panics
+    ///
+    /// if the arrays do not describe a valid CSR matrix
+    pub fn new(m: usize, n: usize, row_ptr: Vec<usize>, col_idx: Vec<usize>, values: Vec<T>)
+               -> CSRMatrix<T>
+    {
+        return CSRMatrix { data: Compressed::new(m, n, row_ptr, col_idx, values) };
+    }
+
+    /// Returns the zero matrix
+    pub fn zero(m: usize, n: usize) -> CSRMatrix<T>
+    {
+        return CSRMatrix::new(m, n, vec![0; m + 1], Vec::new(), Vec::new());
+    }
+
+    /// Returns the matrix dimension
+    pub fn dim(self: &Self) -> (usize, usize)
+    {
+        return (self.data.outer, self.data.inner);
+    }
+
+    /// Returns the number of rows
+    pub fn nrows(self: &Self) -> usize
+    {
+        return self.data.outer;
+    }
+
+    /// Returns the number of columns
+    pub fn ncols(self: &Self) -> usize
+    {
+        return self.data.inner;
+    }
+
+    /// Returns the number of stored entries
+    pub fn nnz(self: &Self) -> usize
+    {
+        return self.data.nnz();
+    }
+
+    /// Returns the row pointer array
+    pub fn row_ptr(self: &Self) -> &[usize]
+    {
+        return &self.data.ptr;
+    }
+
+    /// Returns the column indices of the stored entries
+    pub fn col_idx(self: &Self) -> &[usize]
+    {
+        return &self.data.idx;
+    }
+
+    /// Returns the stored entries
+    pub fn values(self: &Self) -> &[T]
+    {
+        return &self.data.values;
+    }
+}
+
+impl<T> CSRMatrix<T> where T: Field + Scalar
+{
+    /// Returns the element a_ij
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mathru::algebra::linear::sparse::CSRMatrix;
+    ///
+    /// let a: CSRMatrix<f64> = CSRMatrix::new(2, 3, vec![0, 1, 2], vec![0, 2], vec![2.0, 3.0]);
+    ///
+    /// assert_eq!(3.0, a.get(1, 2));
+    /// assert_eq!(0.0, a.get(1, 1));
+    /// ```
+    pub fn get(self: &Self, i: usize, j: usize) -> T
+    {
+        assert!(i < self.data.outer);
+        assert!(j < self.data.inner);
+
+        return self.data.get(i, j);
+    }
+}
+
+impl<T> Transpose for CSRMatrix<T> where T: Field + Scalar
+{
+    type Output = CSRMatrix<T>;
+
+    /// Transposes the matrix
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mathru::algebra::linear::{matrix::Transpose, sparse::CSRMatrix};
+    ///
+    /// let a: CSRMatrix<f64> = CSRMatrix::new(2, 3, vec![0, 1, 2], vec![0, 2], vec![2.0, 3.0]);
+    /// let a_t: CSRMatrix<f64> = a.transpose();
+    ///
+    /// assert_eq!((3, 2), a_t.dim());
+    /// assert_eq!(3.0, a_t.get(2, 1));
+    /// ```
+    fn transpose(self: Self) -> CSRMatrix<T>
+    {
+        return CSRMatrix { data: self.data.transpose() };
+    }
+}
+
+/// Multiplies a sparse matrix with a vector
+impl<'a, 'b, T> Mul<&'b Vector<T>> for &'a CSRMatrix<T> where T: Field + Scalar
+{
+    type Output = Vector<T>;
+
+    fn mul(self: Self, v: &'b Vector<T>) -> Vector<T>
+    {
+        let (v_m, _v_n): (usize, usize) = v.dim();
+        if self.data.inner != v_m
+        {
+            panic!("Matrix and Vector dimension do not match");
+        }
+
+        let mut prod_data: Vec<T> = Vec::with_capacity(self.data.outer);
+
+        for i in 0..self.data.outer
+        {
+            let mut sum: T = T::zero();
+            for k in self.data.ptr[i]..self.data.ptr[i + 1]
+            {
+                sum += self.data.values[k] * *v.get(self.data.idx[k]);
+            }
+            prod_data.push(sum);
+        }
+
+        return Vector::new_column(self.data.outer, prod_data);
+    }
+}
+
+impl<T> Mul<Vector<T>> for CSRMatrix<T> where T: Field + Scalar
+{
+    type Output = Vector<T>;
+
+    fn mul(self: Self, v: Vector<T>) -> Vector<T>
+    {
+        return &self * &v;
+    }
+}
+
+/// Multiplies a sparse matrix with a dense matrix
+impl<'a, 'b, T> Mul<&'b Matrix<T>> for &'a CSRMatrix<T> where T: Field + Scalar
+{
+    type Output = Matrix<T>;
+
+    fn mul(self: Self, rhs: &'b Matrix<T>) -> Matrix<T>
+    {
+        let (rhs_m, rhs_n): (usize, usize) = rhs.dim();
+        assert_eq!(self.data.inner, rhs_m);
+
+        let m: usize = self.data.outer;
+        let mut prod: Matrix<T> = Matrix::zero(m, rhs_n);
+
+        for j in 0..rhs_n
+        {
+            for i in 0..m
+            {
+                let mut sum: T = T::zero();
+                for k in self.data.ptr[i]..self.data.ptr[i + 1]
+                {
+                    sum += self.data.values[k] * *rhs.get(self.data.idx[k], j);
+                }
+                *prod.get_mut(i, j) = sum;
+            }
+        }
+
+        return prod;
+    }
+}
+
+impl<'a, 'b, T> Mul<&'b CSRMatrix<T>> for &'a CSRMatrix<T> where T: Field + Scalar
+{
+    type Output = CSRMatrix<T>;
+
+    /// Multiplies two sparse matrices
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mathru::algebra::linear::sparse::CSRMatrix;
+    ///
+    /// let a: CSRMatrix<f64> = CSRMatrix::new(2, 2, vec![0, 1, 2], vec![1, 0], vec![2.0, 3.0]);
+    /// let b: CSRMatrix<f64> = &a * &a;
+    ///
+    /// assert_eq!(6.0, b.get(0, 0));
+    /// assert_eq!(6.0, b.get(1, 1));
+    /// assert_eq!(2, b.nnz());
+    /// ```
+    fn mul(self: Self, rhs: &'b CSRMatrix<T>) -> CSRMatrix<T>
+    {
+        if self.data.inner != rhs.data.outer
+        {
+            panic!("Matrix dimensions do not match");
+        }
+
+        return CSRMatrix { data: self.data.mul(&rhs.data) };
+    }
+}
+
+impl<T> Mul<CSRMatrix<T>> for CSRMatrix<T> where T: Field + Scalar
+{
+    type Output = CSRMatrix<T>;
+
+    fn mul(self: Self, rhs: CSRMatrix<T>) -> CSRMatrix<T>
+    {
+        return &self * &rhs;
+    }
+}
+
+impl<'a, T> From<&'a Matrix<T>> for CSRMatrix<T> where T: Field + Scalar
+{
+    /// Converts a dense matrix, zero entries are not stored
+    fn from(a: &'a Matrix<T>) -> CSRMatrix<T>
+    {
+        let (m, n): (usize, usize) = a.dim();
+        return CSRMatrix { data: Compressed::from_fn(m, n, |i, j| *a.get(i, j)) };
+    }
+}
+
+impl<T> From<Matrix<T>> for CSRMatrix<T> where T: Field + Scalar
+{
+    fn from(a: Matrix<T>) -> CSRMatrix<T>
+    {
+        return CSRMatrix::from(&a);
+    }
+}
+
+impl<'a, T> From<&'a Vector<T>> for CSRMatrix<T> where T: Field + Scalar
+{
+    /// Converts a column vector to a m x 1 matrix and a row vector to a 1 x n
+    /// matrix
+    fn from(v: &'a Vector<T>) -> CSRMatrix<T>
+    {
+        let (m, n): (usize, usize) = v.dim();
+        return CSRMatrix { data: Compressed::from_fn(m, n, |i, j| *v.get(i.max(j))) };
+    }
+}
+
+impl<'a, T> From<&'a CSRMatrix<T>> for Matrix<T> where T: Field + Scalar
+{
+    fn from(a: &'a CSRMatrix<T>) -> Matrix<T>
+    {
+        let (m, n): (usize, usize) = a.dim();
+        let mut dense: Vec<T> = vec![T::zero(); m * n];
+
+        for i in 0..m
+        {
+            for k in a.data.ptr[i]..a.data.ptr[i + 1]
+            {
+                dense[a.data.idx[k] * m + i] = a.data.values[k];
+            }
+        }
+
+        return Matrix::new(m, n, dense);
+    }
+}
+
+impl<T> From<CSRMatrix<T>> for Matrix<T> where T: Field + Scalar
+{
+    fn from(a: CSRMatrix<T>) -> Matrix<T>
+    {
+        return Matrix::from(&a);
+    }
+}
+
+impl<T> From<COOMatrix<T>> for CSRMatrix<T> where T: Field + Scalar
+{
+    /// Converts the coordinate format, duplicate entries are summed up
+    fn from(a: COOMatrix<T>) -> CSRMatrix<T>
+    {
+        let (m, n): (usize, usize) = a.dim();
+        return CSRMatrix { data: compressed::from_triplets(m,
+                                                           n,
+                                                           a.row_idx(),
+                                                           a.col_idx(),
+                                                           a.values()) };
+    }
+}
+
+impl<T> From<CSCMatrix<T>> for CSRMatrix<T> where T: Field + Scalar
+{
+    fn from(a: CSCMatrix<T>) -> CSRMatrix<T>
+    {
+        return CSRMatrix { data: a.data.transpose() };
+    }
+}
@@ -0,0 +1,17 @@
+//! Sparse matrices
+//!
+//! Storage formats for matrices where most of the entries are zero.
+//!
+//! * [`COOMatrix`] coordinate format, used to assemble a matrix entry by entry
+//! * [`CSRMatrix`] compressed sparse row format
+//! * [`CSCMatrix`] compressed sparse column format
+//!
+//! Fore more information: <br>
+//! <a href="https://en.wikipedia.org/wiki/Sparse_matrix">https://en.wikipedia.org/wiki/Sparse_matrix</a>
+
+mod compressed;
+mod coo;
+mod csc;
+mod csr;
+
+pub use self::{coo::COOMatrix, csc::CSCMatrix, csr::CSRMatrix};
@@ -5,7 +5,7 @@ use crate::{
     algebra::{
         abstr::{Field, Scalar, Sign},
         linear::Matrix,
-        linear::matrix::Transpose,
+        linear::matrix::{ConjugateTranspose, Transpose},
         abstr::{AbsDiffEq, RelativeEq},
     },
     elementary::{Exponential, Power},
@@ -282,6 +282,25 @@ impl<T> Vector<T> where T: Field + Scalar
 
         return self;
     }
+
+    /// Returns the conjugate transposed vector
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mathru::algebra::{abstr::Complex, linear::Vector};
+    ///
+    /// let a: Vector<Complex<f64>> = Vector::new_column(2, vec![Complex::new(1.0, 2.0), Complex::new(0.0, -1.0)]);
+    /// let b: Vector<Complex<f64>> = a.conj_transpose();
+    ///
+    /// assert_eq!(Vector::new_row(2, vec![Complex::new(1.0, -2.0), Complex::new(0.0, 1.0)]), b);
+    /// ```
+    pub fn conj_transpose(mut self: Self) -> Self
+    {
+        self.data = self.data.conj_transpose();
+
+        return self;
+    }
 }
 
 impl<T> Vector<T>
@@ -2,6 +2,7 @@
 use crate::{
     algebra::{abstr::Real, linear::vector::vector::Vector},
     analysis::differential_equation::ordinary::explicit_ode::ExplicitODE,
+    error::MathError,
 };
 
 #[cfg(feature = "serde")]
@@ -126,7 +127,7 @@ impl<T> AdamsBashforth<T> where T: Real
     /// # Panic
     ///
     /// if t_span.0 > t_span.1
-    pub fn solve<F>(self: &Self, prob: &F) -> Result<(Vec<T>, Vec<Vector<T>>), ()>
+    pub fn solve<F>(self: &Self, prob: &F) -> Result<(Vec<T>, Vec<Vector<T>>), MathError>
         where F: ExplicitODE<T>
     {
         let t_span: (T, T) = prob.time_span();
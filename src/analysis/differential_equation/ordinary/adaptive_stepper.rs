@@ -1,7 +1,10 @@
 //! Adaptive step size stepper
 
 use super::{explicit_method::ExplicitEmbeddedMethod, ExplicitODE};
-use crate::algebra::{abstr::Real, linear::Vector};
+use crate::{
+    algebra::{abstr::Real, linear::Vector},
+    error::MathError,
+};
 use std::default::Default;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
@@ -136,7 +139,7 @@ impl<T> ProportionalControl<T> where T: Real
     pub fn solve<F, M>(self: &Self,
                        prob: &F,
                        method: &M)
-                       -> Result<(Vec<T>, Vec<Vector<T>>), &'static str>
+                       -> Result<(Vec<T>, Vec<Vector<T>>), MathError>
         where F: ExplicitODE<T>,
               M: ExplicitEmbeddedMethod<T>
     {
@@ -198,7 +201,7 @@ impl<T> ProportionalControl<T> where T: Real
         }
         if t_n < t_stop
         {
-            return Err("Maximum number of iterations reached");
+            return Err(MathError::NoConvergence { iterations: n as usize });
         }
         return Ok((t_vec, res_vec));
     }
@@ -2,6 +2,7 @@
 use crate::{
     algebra::{abstr::Real, linear::vector::vector::Vector},
     analysis::differential_equation::ordinary::ImplicitODE,
+    error::MathError,
 };
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
@@ -125,7 +126,7 @@ impl<T> BDF<T> where T: Real
     /// # Panic
     ///
     /// if t_span.0 > t_span.1
-    pub fn solve<F>(self: &Self, prob: &F) -> Result<(Vec<T>, Vec<Vector<T>>), ()>
+    pub fn solve<F>(self: &Self, prob: &F) -> Result<(Vec<T>, Vec<Vector<T>>), MathError>
         where F: ImplicitODE<T>
     {
         let t_span: (T, T) = prob.time_span();
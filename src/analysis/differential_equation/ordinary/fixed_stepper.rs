@@ -3,7 +3,10 @@ use super::{
     explicit_method::ExplicitMethod, implicit_method::ImplicitFixedStepSizeMethod,
     ExplicitODE, ImplicitODE,
 };
-use crate::algebra::{abstr::Real, linear::Vector};
+use crate::{
+    algebra::{abstr::Real, linear::Vector},
+    error::MathError,
+};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 use std::clone::Clone;
@@ -37,7 +40,7 @@ impl<T> FixedStepper<T> where T: Real
         return FixedStepper { step_size };
     }
 
-    pub fn solve<F, M>(self: &Self, prob: &F, method: &M) -> Result<(Vec<T>, Vec<Vector<T>>), ()>
+    pub fn solve<F, M>(self: &Self, prob: &F, method: &M) -> Result<(Vec<T>, Vec<Vector<T>>), MathError>
         where F: ExplicitODE<T>,
               M: ExplicitMethod<T>
     {
@@ -116,7 +119,7 @@ impl<T> ImplicitFixedStepper<T> where T: Real
         return ImplicitFixedStepper { step_size };
     }
 
-    pub fn solve<F, M>(self: &Self, prob: &F, method: &M) -> Result<(Vec<T>, Vec<Vector<T>>), ()>
+    pub fn solve<F, M>(self: &Self, prob: &F, method: &M) -> Result<(Vec<T>, Vec<Vector<T>>), MathError>
         where F: ImplicitODE<T>,
               M: ImplicitFixedStepSizeMethod<T>
     {
@@ -148,7 +151,7 @@ impl<T> ImplicitFixedStepper<T> where T: Real
             t_vec.push(t_n);
             res_vec.push(x_n.clone());
 
-            x_n = method.do_step(prob, &t_n, &x_n, &h);
+            x_n = method.do_step(prob, &t_n, &x_n, &h)?;
 
             t_n = t_n + h;
         }
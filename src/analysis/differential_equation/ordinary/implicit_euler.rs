@@ -9,6 +9,7 @@ use crate::{
         differential_equation::ordinary::fixed_stepper::ImplicitFixedStepper, Function, Jacobian,
         NewtonRaphson,
     },
+    error::MathError,
 };
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
@@ -110,7 +111,7 @@ impl<T> ImplicitEuler<T> where T: Real
                                root_finder: NewtonRaphson::new(100, T::from_f64(0.00000001)) };
     }
 
-    pub fn solve<F>(self: &Self, prob: &F) -> Result<(Vec<T>, Vec<Vector<T>>), ()>
+    pub fn solve<F>(self: &Self, prob: &F) -> Result<(Vec<T>, Vec<Vector<T>>), MathError>
         where F: ImplicitODE<T>
     {
         return self.stepper.solve(prob, self);
@@ -129,14 +130,15 @@ impl<T> ImplicitEuler<T> where T: Real
 
 impl<T> ImplicitFixedStepSizeMethod<T> for ImplicitEuler<T> where T: Real
 {
-    fn do_step<F>(self: &Self, prob: &F, t_n: &T, x_n: &Vector<T>, h: &T) -> Vector<T>
+    fn do_step<F>(self: &Self, prob: &F, t_n: &T, x_n: &Vector<T>, h: &T)
+                  -> Result<Vector<T>, MathError>
         where F: ImplicitODE<T>
     {
         let t: T = *t_n + *h;
         let ie_helper = ImplicitEulerHelper::new(prob, &t, x_n, h);
-        let x_n = self.root_finder.find_root(&ie_helper, x_n).unwrap();
+        let x_n = self.root_finder.find_root(&ie_helper, x_n)?;
 
-        return x_n;
+        return Ok(x_n);
     }
 
     /// Euler's method is a first order method
@@ -1,10 +1,14 @@
 use super::ImplicitODE;
-use crate::algebra::{abstr::Real, linear::Vector};
+use crate::{
+    algebra::{abstr::Real, linear::Vector},
+    error::MathError,
+};
 
 pub trait ImplicitFixedStepSizeMethod<T>
     where T: Real
 {
-    fn do_step<F>(self: &Self, prob: &F, t_n: &T, x_n: &Vector<T>, h: &T) -> Vector<T>
+    fn do_step<F>(self: &Self, prob: &F, t_n: &T, x_n: &Vector<T>, h: &T)
+                  -> Result<Vector<T>, MathError>
         where F: ImplicitODE<T>;
 
     fn order(self: &Self) -> u8;
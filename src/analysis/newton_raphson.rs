@@ -5,6 +5,7 @@ use crate::{
         linear::{matrix::Solve, Matrix, Vector},
     },
     analysis::{Function, Jacobian},
+    error::MathError,
 };
 use std::default::Default;
 #[cfg(feature = "serde")]
@@ -44,7 +45,7 @@ impl<T> Default for NewtonRaphson<T> where T: Real
 
 impl<T> NewtonRaphson<T> where T: Real
 {
-    pub fn find_root<F>(self: &Self, func: &F, x_0: &Vector<T>) -> Result<Vector<T>, &'static str>
+    pub fn find_root<F>(self: &Self, func: &F, x_0: &Vector<T>) -> Result<Vector<T>, MathError>
         where F: Function<Vector<T>, Codomain = Vector<T>> + Jacobian<T>
     {
         let mut x = x_0.clone();
@@ -55,7 +56,7 @@ impl<T> NewtonRaphson<T> where T: Real
 
             let jacobian_x: Matrix<T> = func.jacobian(&x);
 
-            let b: Vector<T> = jacobian_x.solve(&func_x).unwrap();
+            let b: Vector<T> = jacobian_x.solve(&func_x)?;
 
             let x_current: Vector<T> = &x - &b;
 
@@ -66,6 +67,6 @@ impl<T> NewtonRaphson<T> where T: Real
             x = x_current;
         }
 
-        return Err("Maximum number of iterations reached");
+        return Err(MathError::NoConvergence { iterations: self.iters as usize });
     }
 }
@@ -0,0 +1,66 @@
+//! Error type
+//!
+//! All fallible decompositions, solvers and integrators return a
+//! [`MathError`], which describes, why the computation failed.
+use std::{error::Error, fmt};
+
+/// Error of a decomposition, a solver or an integrator
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MathError
+{
+    /// The matrix is singular, the pivot with the given index is zero
+    Singular
+    {
+        pivot: usize
+    },
+    /// The matrix is not positive definite
+    NotPositiveDefinite,
+    /// The dimensions of the operands do not match
+    DimensionMismatch,
+    /// The iteration did not converge within the given number of iterations
+    NoConvergence
+    {
+        iterations: usize
+    },
+    /// An iterative method broke down after the given number of iterations,
+    /// because a division by zero would occur
+    Breakdown
+    {
+        iterations: usize
+    },
+    /// The result is not real, e.g. the square root of a matrix with negative
+    /// eigenvalues
+    NotReal,
+    /// An entry of the input is infinite or NaN
+    NotFinite,
+    /// A LAPACK routine returned the info value
+    LapackInfo(i32),
+}
+
+impl fmt::Display for MathError
+{
+    fn fmt(self: &Self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        match self
+        {
+            MathError::Singular { pivot } => write!(f, "matrix is singular, pivot {} is zero", pivot),
+            MathError::NotPositiveDefinite => write!(f, "matrix is not positive definite"),
+            MathError::DimensionMismatch => write!(f, "dimensions do not match"),
+            MathError::NoConvergence { iterations } =>
+            {
+                write!(f, "no convergence after {} iterations", iterations)
+            }
+            MathError::Breakdown { iterations } =>
+            {
+                write!(f, "breakdown after {} iterations", iterations)
+            }
+            MathError::NotReal => write!(f, "result is not real"),
+            MathError::NotFinite => write!(f, "input is not finite"),
+            MathError::LapackInfo(info) => write!(f, "LAPACK routine returned info = {}", info),
+        }
+    }
+}
+
+impl Error for MathError
+{
+}
@@ -0,0 +1,114 @@
+//! Comma separated values
+//!
+//! Every line of a CSV file is a row of the matrix, the fields of a line are
+//! separated by a delimiter. Fields can be enclosed in double quotes, a double
+//! quote within a quoted field is escaped by another double quote. Quoted
+//! fields spanning several lines are not supported.
+//!
+//! An empty field or a field `NA` is a missing value, the handling is set
+//! with [`MissingValue`].
+//!
+//! # Example
+//!
+//! ```
+//! use mathru::{algebra::linear::Matrix, io::csv::{self, CsvOptions}};
+//!
+//! let table: &str = "time,temperature\n# first day\n0.0,20.5\n1.0,21.25\n";
+//!
+//! let mut options: CsvOptions = CsvOptions::new(',', true);
+//! options.set_comment(Some('#'));
+//!
+//! let (names, a): (Vec<String>, Matrix<f64>) = csv::read_matrix(table.as_bytes(), &options).unwrap();
+//!
+//! assert_eq!(vec!["time", "temperature"], names);
+//! assert_eq!(Matrix::new(2, 2, vec![0.0, 1.0, 20.5, 21.25]), a);
+//! ```
+
+mod read;
+mod write;
+
+pub use self::{
+    read::{load_matrix, load_vector, read_matrix, read_vector},
+    write::{save_matrix, save_vector, write_matrix, write_vector},
+};
+
+/// Handling of missing values
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MissingValue
+{
+    /// A missing value is a parse error
+    Error,
+    /// A missing value is read as NaN
+    NaN,
+    /// Rows with missing values are skipped
+    Skip,
+}
+
+/// Options for reading and writing CSV files
+///
+/// The default options are a comma as delimiter, no header row, no comment
+/// lines, missing values are an error and floating point numbers are written
+/// with the shortest representation, which is read back exactly.
+#[derive(Debug, Clone)]
+pub struct CsvOptions
+{
+    delimiter: char,
+    header: bool,
+    comment: Option<char>,
+    missing: MissingValue,
+    precision: Option<usize>,
+}
+
+impl Default for CsvOptions
+{
+    fn default() -> CsvOptions
+    {
+        return CsvOptions::new(',', false);
+    }
+}
+
+impl CsvOptions
+{
+    /// Creates options with the given delimiter
+    ///
+    /// # Arguments
+    ///
+    /// * `delimiter`: separator of the fields, e.g. ',', ';' or '\t'
+    /// * `header`: true, if the first row contains the column names
+    ///
+    /// # Panics
+    ///
+    /// if the delimiter is a double quote or a line break
+    pub fn new(delimiter: char, header: bool) -> CsvOptions
+    {
+        assert!(delimiter != '"' && delimiter != '\n' && delimiter != '\r');
+
+        return CsvOptions { delimiter,
+                            header,
+                            comment: None,
+                            missing: MissingValue::Error,
+                            precision: None };
+    }
+
+    /// Lines starting with the comment character are ignored when reading
+    pub fn set_comment(self: &mut Self, comment: Option<char>)
+    {
+        self.comment = comment;
+    }
+
+    /// Sets the handling of missing values when reading
+    pub fn set_missing(self: &mut Self, missing: MissingValue)
+    {
+        self.missing = missing;
+    }
+
+    /// Sets the number of digits after the decimal point, with which numbers
+    /// are written in scientific notation
+    ///
+    /// With `None` the shortest representation is written, which is read
+    /// back exactly.
+    pub fn set_precision(self: &mut Self, precision: Option<usize>)
+    {
+        self.precision = precision;
+    }
+}
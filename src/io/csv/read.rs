@@ -0,0 +1,215 @@
+use super::{CsvOptions, MissingValue};
+use crate::{
+    algebra::{
+        abstr::Real,
+        linear::{Matrix, Vector},
+    },
+    io::IoError,
+};
+use std::{
+    fs::File,
+    io::{BufRead, BufReader, Read},
+    path::Path,
+    str::FromStr,
+};
+
+/// Reads a matrix from a CSV file
+///
+/// # Return
+///
+/// The column names, which are empty if the options do not contain a header
+/// row, and the matrix
+///
+/// IoError::Parse with the line number, if a field is not a number, a value
+/// is missing and missing values are an error or the rows do not have the
+/// same number of fields
+///
+/// # Example
+///
+/// ```
+/// use mathru::{algebra::linear::Matrix, io::csv::{self, CsvOptions, MissingValue}};
+///
+/// let table: &str = "1.0;2.0\n3.0;NA\n5.0;6.0\n";
+///
+/// let mut options: CsvOptions = CsvOptions::new(';', false);
+/// options.set_missing(MissingValue::Skip);
+///
+/// let (_, a): (Vec<String>, Matrix<f64>) = csv::read_matrix(table.as_bytes(), &options).unwrap();
+///
+/// assert_eq!(Matrix::new(2, 2, vec![1.0, 5.0, 2.0, 6.0]), a);
+/// ```
+pub fn read_matrix<T, R>(reader: R, options: &CsvOptions) -> Result<(Vec<String>, Matrix<T>), IoError>
+    where T: Real + FromStr,
+          R: Read
+{
+    let mut names: Vec<String> = Vec::new();
+    let mut rows: Vec<Vec<T>> = Vec::new();
+    let mut n: Option<usize> = None;
+
+    for (k, line) in BufReader::new(reader).lines().enumerate()
+    {
+        let line: String = line?;
+        let number: usize = k + 1;
+        let trimmed: &str = line.trim();
+        if trimmed.is_empty() || matches!(options.comment, Some(comment) if trimmed.starts_with(comment))
+        {
+            continue;
+        }
+
+        let fields: Vec<String> = split(&line, options.delimiter).map_err(|message| IoError::parse(number, message))?;
+        match n
+        {
+            Some(n) if n != fields.len() =>
+            {
+                return Err(IoError::parse(number, format!("expected {} fields, found {}", n, fields.len())));
+            }
+            _ => n = Some(fields.len()),
+        }
+
+        if options.header && names.is_empty() && rows.is_empty()
+        {
+            names = fields;
+            continue;
+        }
+
+        let mut row: Vec<T> = Vec::with_capacity(fields.len());
+        for field in fields.iter().map(|field| field.trim())
+        {
+            if field.is_empty() || field == "NA"
+            {
+                match options.missing
+                {
+                    MissingValue::Error => return Err(IoError::parse(number, "missing value")),
+                    MissingValue::NaN => row.push(T::from_f64(f64::NAN)),
+                    MissingValue::Skip => break,
+                }
+            }
+            else
+            {
+                match field.parse::<T>()
+                {
+                    Ok(value) => row.push(value),
+                    Err(_) => return Err(IoError::parse(number, format!("invalid number '{}'", field))),
+                }
+            }
+        }
+
+        if row.len() == fields.len()
+        {
+            rows.push(row);
+        }
+    }
+
+    let m: usize = rows.len();
+    let n: usize = n.unwrap_or(0);
+    let mut data: Vec<T> = Vec::with_capacity(m * n);
+    for j in 0..n
+    {
+        data.extend(rows.iter().map(|row| row[j]));
+    }
+
+    return Ok((names, Matrix::new(m, n, data)));
+}
+
+/// Reads a vector from a CSV file
+///
+/// A single column is read as column vector, a single row as row vector.
+///
+/// # Return
+///
+/// The column names, which are empty if the options do not contain a header
+/// row, and the vector
+///
+/// IoError::Parse if the file can not be read as matrix, see
+/// [`read_matrix`], or contains more than one row and column
+pub fn read_vector<T, R>(reader: R, options: &CsvOptions) -> Result<(Vec<String>, Vector<T>), IoError>
+    where T: Real + FromStr,
+          R: Read
+{
+    let (names, a): (Vec<String>, Matrix<T>) = read_matrix(reader, options)?;
+    let (m, n): (usize, usize) = a.dim();
+
+    if n == 1
+    {
+        return Ok((names, Vector::new_column(m, a.convert_to_vec())));
+    }
+    if m == 1
+    {
+        return Ok((names, Vector::new_row(n, a.convert_to_vec())));
+    }
+
+    return Err(IoError::parse(1, format!("a {} x {} table is not a vector", m, n)));
+}
+
+/// Reads a matrix from the CSV file at the given path
+///
+/// See [`read_matrix`]
+pub fn load_matrix<T, P>(path: P, options: &CsvOptions) -> Result<(Vec<String>, Matrix<T>), IoError>
+    where T: Real + FromStr,
+          P: AsRef<Path>
+{
+    return read_matrix(File::open(path)?, options);
+}
+
+/// Reads a vector from the CSV file at the given path
+///
+/// See [`read_vector`]
+pub fn load_vector<T, P>(path: P, options: &CsvOptions) -> Result<(Vec<String>, Vector<T>), IoError>
+    where T: Real + FromStr,
+          P: AsRef<Path>
+{
+    return read_vector(File::open(path)?, options);
+}
+
+/// Splits a line into fields and removes the quotes of quoted fields
+fn split(line: &str, delimiter: char) -> Result<Vec<String>, String>
+{
+    let mut fields: Vec<String> = Vec::new();
+    let mut field: String = String::new();
+    let mut chars = line.chars().peekable();
+    let mut quoted: bool = false;
+
+    while let Some(c) = chars.next()
+    {
+        if quoted
+        {
+            if c == '"'
+            {
+                if chars.peek() == Some(&'"')
+                {
+                    field.push('"');
+                    chars.next();
+                }
+                else
+                {
+                    quoted = false;
+                }
+            }
+            else
+            {
+                field.push(c);
+            }
+        }
+        else if c == '"' && field.trim().is_empty()
+        {
+            field.clear();
+            quoted = true;
+        }
+        else if c == delimiter
+        {
+            fields.push(std::mem::take(&mut field));
+        }
+        else
+        {
+            field.push(c);
+        }
+    }
+
+    if quoted
+    {
+        return Err("unterminated quoted field".to_string());
+    }
+    fields.push(field);
+
+    return Ok(fields);
+}
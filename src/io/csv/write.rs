@@ -0,0 +1,168 @@
+use super::CsvOptions;
+use crate::{
+    algebra::{
+        abstr::Real,
+        linear::{Matrix, Vector},
+    },
+    io::IoError,
+};
+use std::{
+    fmt::{Display, LowerExp},
+    fs::File,
+    io::{BufWriter, Write},
+    path::Path,
+};
+
+/// Writes a matrix to a CSV file
+///
+/// The column names are written as header row, if they are not empty. Without
+/// a precision in the options, the numbers are written with the shortest
+/// representation, which is read back exactly.
+///
+/// # Arguments
+///
+/// * `names`: column names, empty for no header row
+///
+/// # Return
+///
+/// IoError::Format if names are given and their number is not equal to the
+/// number of columns
+///
+/// # Example
+///
+/// ```
+/// # #[macro_use]
+/// # extern crate mathru;
+/// # fn main()
+/// # {
+/// use mathru::{algebra::linear::Matrix, io::csv::{self, CsvOptions}};
+///
+/// let a: Matrix<f64> = matrix![0.1, 2.0; 3.0e-8, -4.5];
+///
+/// let mut file: Vec<u8> = Vec::new();
+/// csv::write_matrix(&mut file, &a, &["x", "y"], &CsvOptions::default()).unwrap();
+///
+/// assert_eq!("x,y\n0.1,2\n3e-8,-4.5\n", String::from_utf8(file).unwrap());
+/// # }
+/// ```
+pub fn write_matrix<T, W>(writer: W, a: &Matrix<T>, names: &[&str], options: &CsvOptions) -> Result<(), IoError>
+    where T: Real + Display + LowerExp,
+          W: Write
+{
+    let (m, n): (usize, usize) = a.dim();
+    if !names.is_empty() && names.len() != n
+    {
+        return Err(IoError::Format(format!("expected {} column names, found {}", n, names.len())));
+    }
+
+    let mut writer: BufWriter<W> = BufWriter::new(writer);
+
+    if !names.is_empty()
+    {
+        let header: Vec<String> = names.iter().map(|name| quote(name, options.delimiter)).collect();
+        writeln!(writer, "{}", header.join(&options.delimiter.to_string()))?;
+    }
+
+    for i in 0..m
+    {
+        for j in 0..n
+        {
+            if j != 0
+            {
+                write!(writer, "{}", options.delimiter)?;
+            }
+            write_value(&mut writer, *a.get(i, j), options.precision)?;
+        }
+        writeln!(writer)?;
+    }
+
+    writer.flush()?;
+    return Ok(());
+}
+
+/// Writes a vector to a CSV file
+///
+/// A column vector is written as a single column, a row vector as a single
+/// row.
+///
+/// # Arguments
+///
+/// * `names`: column names, empty for no header row
+///
+/// # Return
+///
+/// IoError::Format if names are given and their number is not equal to the
+/// number of columns
+pub fn write_vector<T, W>(writer: W, v: &Vector<T>, names: &[&str], options: &CsvOptions) -> Result<(), IoError>
+    where T: Real + Display + LowerExp,
+          W: Write
+{
+    let (m, n): (usize, usize) = v.dim();
+    let a: Matrix<T> = Matrix::new(m, n, v.clone().convert_to_vec());
+
+    return write_matrix(writer, &a, names, options);
+}
+
+/// Writes a matrix to the CSV file at the given path
+///
+/// See [`write_matrix`]
+pub fn save_matrix<T, P>(path: P, a: &Matrix<T>, names: &[&str], options: &CsvOptions) -> Result<(), IoError>
+    where T: Real + Display + LowerExp,
+          P: AsRef<Path>
+{
+    return write_matrix(File::create(path)?, a, names, options);
+}
+
+/// Writes a vector to the CSV file at the given path
+///
+/// See [`write_vector`]
+pub fn save_vector<T, P>(path: P, v: &Vector<T>, names: &[&str], options: &CsvOptions) -> Result<(), IoError>
+    where T: Real + Display + LowerExp,
+          P: AsRef<Path>
+{
+    return write_vector(File::create(path)?, v, names, options);
+}
+
+/// Writes a number either with the given precision in scientific notation or
+/// with the shortest representation
+///
+/// Rust prints the shortest representation, which parses to the same number,
+/// in decimal as well as in scientific notation. Very large and very small
+/// numbers are written in scientific notation to avoid long rows of zeros.
+fn write_value<T, W>(writer: &mut W, value: T, precision: Option<usize>) -> Result<(), IoError>
+    where T: Real + Display + LowerExp,
+          W: Write
+{
+    match precision
+    {
+        Some(precision) => write!(writer, "{:.*e}", precision, value)?,
+        None =>
+        {
+            let abs: T = value.abs();
+            let large: bool = abs >= T::from_f64(1.0e16) && abs != T::infinity();
+            let small: bool = abs < T::from_f64(1.0e-4) && abs != T::zero();
+            if !large && !small
+            {
+                write!(writer, "{}", value)?;
+            }
+            else
+            {
+                write!(writer, "{:e}", value)?;
+            }
+        }
+    }
+
+    return Ok(());
+}
+
+/// Encloses a column name in double quotes, if it contains the delimiter, a
+/// quote or leading or trailing spaces
+fn quote(name: &str, delimiter: char) -> String
+{
+    if name.contains(delimiter) || name.contains('"') || name.trim() != name
+    {
+        return format!("\"{}\"", name.replace('"', "\"\""));
+    }
+
+    return name.to_string();
+}
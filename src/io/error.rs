@@ -0,0 +1,59 @@
+use std::{error::Error, fmt, io};
+
+/// Error of reading or writing a matrix
+#[derive(Debug)]
+pub enum IoError
+{
+    /// The underlying reader or writer failed
+    Io(io::Error),
+    /// The content is malformed, the line number starts at 1
+    Parse
+    {
+        line: usize,
+        message: String,
+    },
+    /// The content is not valid in the file format
+    Format(String),
+}
+
+impl IoError
+{
+    pub(crate) fn parse(line: usize, message: impl Into<String>) -> IoError
+    {
+        return IoError::Parse { line,
+                                message: message.into() };
+    }
+}
+
+impl fmt::Display for IoError
+{
+    fn fmt(self: &Self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        match self
+        {
+            IoError::Io(err) => write!(f, "{}", err),
+            IoError::Parse { line, message } => write!(f, "line {}: {}", line, message),
+            IoError::Format(message) => write!(f, "{}", message),
+        }
+    }
+}
+
+impl Error for IoError
+{
+    fn source(self: &Self) -> Option<&(dyn Error + 'static)>
+    {
+        match self
+        {
+            IoError::Io(err) => Some(err),
+            IoError::Parse { .. } | IoError::Format(_) => None,
+        }
+    }
+}
+
+impl From<io::Error> for IoError
+{
+    fn from(err: io::Error) -> IoError
+    {
+        return IoError::Io(err);
+    }
+}
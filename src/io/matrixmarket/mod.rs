@@ -0,0 +1,174 @@
+//! Matrix Market exchange format
+//!
+//! The Matrix Market format is a text format for dense and sparse matrices
+//! used by the SuiteSparse matrix collection and MATLAB. A file starts with a
+//! header line
+//!
+//! ```text
+//! %%MatrixMarket matrix <format> <field> <symmetry>
+//! ```
+//!
+//! * format: `coordinate` (sparse) or `array` (dense)
+//! * field: `real`, `integer`, `complex` or `pattern`
+//! * symmetry: `general`, `symmetric`, `skew-symmetric` or `hermitian`
+//!
+//! followed by comment lines starting with `%`, the size line and the
+//! entries. All combinations can be read into a [`Matrix`] or a
+//! [`COOMatrix`]. Matrices are written in the `real` or `complex` field
+//! depending on the scalar type.
+//!
+//! Fore more information: <br>
+//! <a href="https://math.nist.gov/MatrixMarket/formats.html">https://math.nist.gov/MatrixMarket/formats.html</a>
+//!
+//! # Example
+//!
+//! ```
+//! use mathru::{algebra::linear::Matrix, io::matrixmarket};
+//!
+//! let mtx: &str = "%%MatrixMarket matrix coordinate real symmetric
+//! % lower triangle of a 3 x 3 matrix
+//! 3 3 4
+//! 1 1 2.0
+//! 2 1 -1.0
+//! 2 2 2.0
+//! 3 3 2.0
+//! ";
+//!
+//! let a: Matrix<f64> = matrixmarket::read_matrix(mtx.as_bytes()).unwrap();
+//!
+//! assert_eq!(-1.0, *a.get(0, 1));
+//! ```
+//!
+//! [`Matrix`]: crate::algebra::linear::Matrix
+//! [`COOMatrix`]: crate::algebra::linear::sparse::COOMatrix
+
+mod read;
+mod write;
+
+pub use self::{
+    read::{load_coo, load_matrix, read_coo, read_matrix},
+    write::{save_coo, save_matrix, write_coo, write_matrix},
+};
+
+use crate::algebra::abstr::{Complex, Field, Scalar};
+use std::{fmt, io, io::Write};
+
+/// Symmetry of a matrix in a Matrix Market file
+///
+/// For all symmetries except `General` only the entries on and below the
+/// diagonal are stored, for `SkewSymmetric` only the entries below the
+/// diagonal.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Symmetry
+{
+    /// All entries are stored
+    General,
+    /// a_ji = a_ij
+    Symmetric,
+    /// a_ji = -a_ij
+    SkewSymmetric,
+    /// a_ji = conj(a_ij)
+    Hermitian,
+}
+
+impl Symmetry
+{
+    /// Returns true if the entry a_ij is stored in a file with this symmetry
+    fn stores(self: &Self, i: usize, j: usize) -> bool
+    {
+        return match self
+        {
+            Symmetry::General => true,
+            Symmetry::Symmetric | Symmetry::Hermitian => i >= j,
+            Symmetry::SkewSymmetric => i > j,
+        };
+    }
+
+    /// Returns the number of entries of a m x n matrix stored in the array
+    /// format, None on overflow
+    fn count(self: &Self, m: usize, n: usize) -> Option<usize>
+    {
+        return match self
+        {
+            Symmetry::General => m.checked_mul(n),
+            Symmetry::Symmetric | Symmetry::Hermitian => n.checked_add(1)?.checked_mul(n).map(|c| c / 2),
+            Symmetry::SkewSymmetric => n.saturating_sub(1).checked_mul(n).map(|c| c / 2),
+        };
+    }
+}
+
+impl fmt::Display for Symmetry
+{
+    fn fmt(self: &Self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        let name: &str = match self
+        {
+            Symmetry::General => "general",
+            Symmetry::Symmetric => "symmetric",
+            Symmetry::SkewSymmetric => "skew-symmetric",
+            Symmetry::Hermitian => "hermitian",
+        };
+
+        return write!(f, "{}", name);
+    }
+}
+
+/// Scalar, which can be read from and written to a Matrix Market file
+pub trait MatrixMarketScalar: Field + Scalar
+{
+    /// Returns true for complex scalars
+    fn is_complex() -> bool;
+
+    /// Creates the scalar from the real and the imaginary part, the imaginary
+    /// part is zero for real scalars
+    fn from_parts(re: f64, im: f64) -> Self;
+
+    /// Writes the value as one or two numbers separated by a space
+    fn write_value(self: &Self, w: &mut dyn Write) -> io::Result<()>;
+}
+
+macro_rules! impl_matrixmarketscalar
+{
+    ($($t: ty);*) =>
+    {
+        $(
+        impl MatrixMarketScalar for $t
+        {
+            fn is_complex() -> bool
+            {
+                return false;
+            }
+
+            fn from_parts(re: f64, _im: f64) -> Self
+            {
+                return re as $t;
+            }
+
+            fn write_value(self: &Self, w: &mut dyn Write) -> io::Result<()>
+            {
+                return write!(w, "{:e}", self);
+            }
+        }
+
+        impl MatrixMarketScalar for Complex<$t>
+        {
+            fn is_complex() -> bool
+            {
+                return true;
+            }
+
+            fn from_parts(re: f64, im: f64) -> Self
+            {
+                return Complex::new(re as $t, im as $t);
+            }
+
+            fn write_value(self: &Self, w: &mut dyn Write) -> io::Result<()>
+            {
+                return write!(w, "{:e} {:e}", self.re, self.im);
+            }
+        }
+        )*
+    }
+}
+
+impl_matrixmarketscalar!(f32; f64);
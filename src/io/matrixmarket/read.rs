@@ -0,0 +1,395 @@
+use super::{MatrixMarketScalar, Symmetry};
+use crate::{
+    algebra::linear::{sparse::COOMatrix, Matrix},
+    io::IoError,
+};
+use std::{
+    fs::File,
+    io::{BufRead, BufReader, Lines, Read},
+    path::Path,
+};
+
+/// Dimension and triplets of a matrix
+type Entries<T> = (usize, usize, Vec<(usize, usize, T)>);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Format
+{
+    Coordinate,
+    Array,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Field
+{
+    Real,
+    Integer,
+    Complex,
+    Pattern,
+}
+
+/// Reads a matrix in Matrix Market format into a dense matrix
+///
+/// Both the coordinate and the array format are accepted. Entries, which are
+/// only stored once due to the symmetry of the matrix, are mirrored.
+/// Duplicate entries in the coordinate format are summed up.
+///
+/// # Return
+///
+/// IoError::Parse with the line number, if the content is malformed or a
+/// complex matrix is read into a real matrix
+///
+/// IoError::Format if the number of entries of the matrix overflows usize
+///
+/// # Example
+///
+/// ```
+/// use mathru::{algebra::linear::Matrix, io::matrixmarket};
+///
+/// let mtx: &str = "%%MatrixMarket matrix array real general
+/// 2 2
+/// 1.0
+/// 3.0
+/// 2.0
+/// 4.0
+/// ";
+///
+/// let a: Matrix<f64> = matrixmarket::read_matrix(mtx.as_bytes()).unwrap();
+///
+/// assert_eq!(Matrix::new(2, 2, vec![1.0, 3.0, 2.0, 4.0]), a);
+/// ```
+pub fn read_matrix<T, R>(reader: R) -> Result<Matrix<T>, IoError>
+    where T: MatrixMarketScalar,
+          R: Read
+{
+    let (m, n, entries): Entries<T> = read_entries(reader)?;
+    if m.checked_mul(n).is_none()
+    {
+        return Err(IoError::Format(format!("{} x {} matrix is too large", m, n)));
+    }
+
+    let mut a: Matrix<T> = Matrix::zero(m, n);
+    for (i, j, a_ij) in entries
+    {
+        *a.get_mut(i, j) += a_ij;
+    }
+
+    return Ok(a);
+}
+
+/// Reads a matrix in Matrix Market format into a sparse matrix in coordinate
+/// format
+///
+/// Both the coordinate and the array format are accepted, zero entries of
+/// the array format are not stored. Entries, which are only stored once due to
+/// the symmetry of the matrix, are mirrored.
+///
+/// # Return
+///
+/// IoError::Parse with the line number, if the content is malformed or a
+/// complex matrix is read into a real matrix
+///
+/// IoError::Format if the number of entries of a matrix in the array format
+/// overflows usize
+///
+/// # Example
+///
+/// ```
+/// use mathru::{algebra::linear::sparse::COOMatrix, io::matrixmarket};
+///
+/// let mtx: &str = "%%MatrixMarket matrix coordinate pattern general
+/// 3 3 2
+/// 1 2
+/// 3 1
+/// ";
+///
+/// let a: COOMatrix<f64> = matrixmarket::read_coo(mtx.as_bytes()).unwrap();
+///
+/// assert_eq!(&[0, 2], a.row_idx());
+/// assert_eq!(&[1, 0], a.col_idx());
+/// assert_eq!(&[1.0, 1.0], a.values());
+/// ```
+pub fn read_coo<T, R>(reader: R) -> Result<COOMatrix<T>, IoError>
+    where T: MatrixMarketScalar,
+          R: Read
+{
+    let (m, n, entries): Entries<T> = read_entries(reader)?;
+
+    let mut a: COOMatrix<T> = COOMatrix::new(m, n);
+    for (i, j, a_ij) in entries
+    {
+        a.push(i, j, a_ij);
+    }
+
+    return Ok(a);
+}
+
+/// Reads the Matrix Market file at the given path into a dense matrix
+///
+/// See [`read_matrix`]
+pub fn load_matrix<T, P>(path: P) -> Result<Matrix<T>, IoError>
+    where T: MatrixMarketScalar,
+          P: AsRef<Path>
+{
+    return read_matrix(File::open(path)?);
+}
+
+/// Reads the Matrix Market file at the given path into a sparse matrix in
+/// coordinate format
+///
+/// See [`read_coo`]
+pub fn load_coo<T, P>(path: P) -> Result<COOMatrix<T>, IoError>
+    where T: MatrixMarketScalar,
+          P: AsRef<Path>
+{
+    return read_coo(File::open(path)?);
+}
+
+/// Line reader, which counts the lines and skips comments and empty lines
+struct DataLines<R>
+{
+    lines: Lines<BufReader<R>>,
+    line: usize,
+}
+
+impl<R> DataLines<R> where R: Read
+{
+    fn new(reader: R) -> DataLines<R>
+    {
+        return DataLines { lines: BufReader::new(reader).lines(),
+                           line: 0 };
+    }
+
+    fn next_line(self: &mut Self) -> Result<Option<String>, IoError>
+    {
+        return match self.lines.next()
+        {
+            Some(line) =>
+            {
+                self.line += 1;
+                Ok(Some(line?))
+            }
+            None => Ok(None),
+        };
+    }
+
+    fn next_data(self: &mut Self) -> Result<Option<String>, IoError>
+    {
+        while let Some(line) = self.next_line()?
+        {
+            let trimmed: &str = line.trim();
+            if !trimmed.is_empty() && !trimmed.starts_with('%')
+            {
+                return Ok(Some(line));
+            }
+        }
+
+        return Ok(None);
+    }
+
+    fn error(self: &Self, message: impl Into<String>) -> IoError
+    {
+        return IoError::parse(self.line, message);
+    }
+}
+
+fn read_entries<T, R>(reader: R) -> Result<Entries<T>, IoError>
+    where T: MatrixMarketScalar,
+          R: Read
+{
+    let mut lines: DataLines<R> = DataLines::new(reader);
+
+    let (format, field, symmetry): (Format, Field, Symmetry) = read_header(&mut lines)?;
+    if field == Field::Complex && !T::is_complex()
+    {
+        return Err(lines.error("complex matrix can not be read into a real matrix"));
+    }
+
+    let size_line: String = match lines.next_data()?
+    {
+        Some(line) => line,
+        None => return Err(lines.error("missing size line")),
+    };
+    let size: Vec<usize> = size_line.split_whitespace()
+                                    .map(|token| token.parse::<usize>())
+                                    .collect::<Result<Vec<usize>, _>>()
+                                    .map_err(|_| lines.error(format!("invalid size line '{}'", size_line.trim())))?;
+
+    let size_len: usize = if format == Format::Coordinate { 3 } else { 2 };
+    if size.len() != size_len
+    {
+        return Err(lines.error(format!("expected {} numbers in the size line, found {}", size_len, size.len())));
+    }
+    let (m, n): (usize, usize) = (size[0], size[1]);
+    if symmetry != Symmetry::General && m != n
+    {
+        return Err(lines.error(format!("{} matrix is not square", symmetry)));
+    }
+
+    let mut entries: Vec<(usize, usize, T)> = Vec::new();
+    let mut push = |i: usize, j: usize, a_ij: T| {
+        entries.push((i, j, a_ij));
+        if i != j
+        {
+            match symmetry
+            {
+                Symmetry::General => (),
+                Symmetry::Symmetric => entries.push((j, i, a_ij)),
+                Symmetry::SkewSymmetric => entries.push((j, i, -a_ij)),
+                Symmetry::Hermitian => entries.push((j, i, a_ij.conj())),
+            }
+        }
+    };
+
+    match format
+    {
+        Format::Coordinate =>
+        {
+            let nnz: usize = size[2];
+            for k in 0..nnz
+            {
+                let line: String = match lines.next_data()?
+                {
+                    Some(line) => line,
+                    None => return Err(lines.error(format!("expected {} entries, found {}", nnz, k))),
+                };
+                let tokens: Vec<&str> = line.split_whitespace().collect();
+                if tokens.len() < 2
+                {
+                    return Err(lines.error("missing index"));
+                }
+
+                let i: usize = parse_index(&lines, tokens[0], m)?;
+                let j: usize = parse_index(&lines, tokens[1], n)?;
+                let a_ij: T = parse_value(&lines, field, &tokens[2..])?;
+                push(i, j, a_ij);
+            }
+        }
+        Format::Array =>
+        {
+            let count: usize = match symmetry.count(m, n)
+            {
+                Some(count) => count,
+                None => return Err(IoError::Format(format!("{} x {} matrix is too large", m, n))),
+            };
+            let mut k: usize = 0;
+            for j in 0..n
+            {
+                for i in (0..m).filter(|i| symmetry.stores(*i, j))
+                {
+                    let line: String = match lines.next_data()?
+                    {
+                        Some(line) => line,
+                        None => return Err(lines.error(format!("expected {} entries, found {}", count, k))),
+                    };
+                    let tokens: Vec<&str> = line.split_whitespace().collect();
+
+                    let a_ij: T = parse_value(&lines, field, &tokens)?;
+                    if a_ij != T::zero()
+                    {
+                        push(i, j, a_ij);
+                    }
+                    k += 1;
+                }
+            }
+        }
+    }
+
+    if lines.next_data()?.is_some()
+    {
+        return Err(lines.error("unexpected entry after the last entry"));
+    }
+
+    return Ok((m, n, entries));
+}
+
+fn read_header<R>(lines: &mut DataLines<R>) -> Result<(Format, Field, Symmetry), IoError> where R: Read
+{
+    let header: String = match lines.next_line()?
+    {
+        Some(line) => line.to_lowercase(),
+        None => return Err(lines.error("missing header")),
+    };
+    let tokens: Vec<&str> = header.split_whitespace().collect();
+
+    if tokens.len() != 5 || tokens[0] != "%%matrixmarket" || tokens[1] != "matrix"
+    {
+        return Err(lines.error("expected header '%%MatrixMarket matrix <format> <field> <symmetry>'"));
+    }
+
+    let format: Format = match tokens[2]
+    {
+        "coordinate" => Format::Coordinate,
+        "array" => Format::Array,
+        other => return Err(lines.error(format!("unknown format '{}'", other))),
+    };
+
+    let field: Field = match tokens[3]
+    {
+        "real" | "double" => Field::Real,
+        "integer" => Field::Integer,
+        "complex" => Field::Complex,
+        "pattern" => Field::Pattern,
+        other => return Err(lines.error(format!("unknown field '{}'", other))),
+    };
+
+    let symmetry: Symmetry = match tokens[4]
+    {
+        "general" => Symmetry::General,
+        "symmetric" => Symmetry::Symmetric,
+        "skew-symmetric" => Symmetry::SkewSymmetric,
+        "hermitian" => Symmetry::Hermitian,
+        other => return Err(lines.error(format!("unknown symmetry '{}'", other))),
+    };
+
+    if format == Format::Array && field == Field::Pattern
+    {
+        return Err(lines.error("pattern field is not allowed in the array format"));
+    }
+
+    return Ok((format, field, symmetry));
+}
+
+/// Parses an index starting at 1 and converts it to an index starting at 0
+fn parse_index<R>(lines: &DataLines<R>, token: &str, bound: usize) -> Result<usize, IoError> where R: Read
+{
+    return match token.parse::<usize>()
+    {
+        Ok(index) if 1 <= index && index <= bound => Ok(index - 1),
+        Ok(index) => Err(lines.error(format!("index {} is out of bounds 1..{}", index, bound))),
+        Err(_) => Err(lines.error(format!("invalid index '{}'", token))),
+    };
+}
+
+fn parse_value<T, R>(lines: &DataLines<R>, field: Field, tokens: &[&str]) -> Result<T, IoError>
+    where T: MatrixMarketScalar,
+          R: Read
+{
+    let len: usize = match field
+    {
+        Field::Pattern => 0,
+        Field::Real | Field::Integer => 1,
+        Field::Complex => 2,
+    };
+    if tokens.len() != len
+    {
+        return Err(lines.error(format!("expected {} values, found {}", len, tokens.len())));
+    }
+
+    let real = |token: &str| {
+        token.parse::<f64>()
+             .map_err(|_| lines.error(format!("invalid real number '{}'", token)))
+    };
+
+    return match field
+    {
+        Field::Pattern => Ok(T::one()),
+        Field::Real => Ok(T::from_parts(real(tokens[0])?, 0.0)),
+        Field::Integer => match tokens[0].parse::<i64>()
+        {
+            Ok(value) => Ok(T::from_parts(value as f64, 0.0)),
+            Err(_) => Err(lines.error(format!("invalid integer '{}'", tokens[0]))),
+        },
+        Field::Complex => Ok(T::from_parts(real(tokens[0])?, real(tokens[1])?)),
+    };
+}
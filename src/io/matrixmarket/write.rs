@@ -0,0 +1,157 @@
+use super::{MatrixMarketScalar, Symmetry};
+use crate::{
+    algebra::linear::{sparse::COOMatrix, Matrix},
+    io::IoError,
+};
+use std::{
+    fs::File,
+    io::{BufWriter, Write},
+    path::Path,
+};
+
+/// Writes a dense matrix in the Matrix Market array format
+///
+/// If the symmetry is not `General`, only the entries on and below the
+/// diagonal are written, for `SkewSymmetric` only the entries below the
+/// diagonal. The symmetry of the matrix is not checked.
+///
+/// # Return
+///
+/// IoError::Format if the symmetry is not `General` and the matrix is not
+/// square, or the symmetry is `Hermitian` and the matrix is real
+///
+/// # Example
+///
+/// ```
+/// # #[macro_use]
+/// # extern crate mathru;
+/// # fn main()
+/// # {
+/// use mathru::{algebra::linear::Matrix, io::matrixmarket::{self, Symmetry}};
+///
+/// let a: Matrix<f64> = matrix![   2.0, -1.0;
+///                                 -1.0, 2.0];
+/// let mut mtx: Vec<u8> = Vec::new();
+///
+/// matrixmarket::write_matrix(&mut mtx, &a, Symmetry::Symmetric).unwrap();
+///
+/// assert_eq!("%%MatrixMarket matrix array real symmetric\n2 2\n2e0\n-1e0\n2e0\n",
+///            String::from_utf8(mtx).unwrap());
+/// # }
+/// ```
+pub fn write_matrix<T, W>(writer: W, a: &Matrix<T>, symmetry: Symmetry) -> Result<(), IoError>
+    where T: MatrixMarketScalar,
+          W: Write
+{
+    let (m, n): (usize, usize) = a.dim();
+    check_symmetry::<T>(m, n, symmetry)?;
+
+    let mut writer: BufWriter<W> = BufWriter::new(writer);
+    write_header::<T>(&mut writer, "array", symmetry)?;
+    writeln!(writer, "{} {}", m, n)?;
+
+    for j in 0..n
+    {
+        for i in (0..m).filter(|i| symmetry.stores(*i, j))
+        {
+            a.get(i, j).write_value(&mut writer)?;
+            writeln!(writer)?;
+        }
+    }
+
+    writer.flush()?;
+    return Ok(());
+}
+
+/// Writes a sparse matrix in the Matrix Market coordinate format
+///
+/// If the symmetry is not `General`, only the entries on and below the
+/// diagonal are written, for `SkewSymmetric` only the entries below the
+/// diagonal. The symmetry of the matrix is not checked.
+///
+/// # Return
+///
+/// IoError::Format if the symmetry is not `General` and the matrix is not
+/// square, or the symmetry is `Hermitian` and the matrix is real
+///
+/// # Example
+///
+/// ```
+/// use mathru::{algebra::linear::sparse::COOMatrix, io::matrixmarket::{self, Symmetry}};
+///
+/// let a: COOMatrix<f64> = COOMatrix::from_triplets(2, 3, vec![0, 1], vec![2, 0], vec![1.5, -2.0]);
+/// let mut mtx: Vec<u8> = Vec::new();
+///
+/// matrixmarket::write_coo(&mut mtx, &a, Symmetry::General).unwrap();
+///
+/// assert_eq!("%%MatrixMarket matrix coordinate real general\n2 3 2\n1 3 1.5e0\n2 1 -2e0\n",
+///            String::from_utf8(mtx).unwrap());
+/// ```
+pub fn write_coo<T, W>(writer: W, a: &COOMatrix<T>, symmetry: Symmetry) -> Result<(), IoError>
+    where T: MatrixMarketScalar,
+          W: Write
+{
+    let (m, n): (usize, usize) = a.dim();
+    check_symmetry::<T>(m, n, symmetry)?;
+
+    let stored: Vec<usize> = (0..a.nnz()).filter(|k| symmetry.stores(a.row_idx()[*k], a.col_idx()[*k]))
+                                         .collect();
+
+    let mut writer: BufWriter<W> = BufWriter::new(writer);
+    write_header::<T>(&mut writer, "coordinate", symmetry)?;
+    writeln!(writer, "{} {} {}", m, n, stored.len())?;
+
+    for k in stored
+    {
+        write!(writer, "{} {} ", a.row_idx()[k] + 1, a.col_idx()[k] + 1)?;
+        a.values()[k].write_value(&mut writer)?;
+        writeln!(writer)?;
+    }
+
+    writer.flush()?;
+    return Ok(());
+}
+
+/// Writes a dense matrix to a Matrix Market file at the given path
+///
+/// See [`write_matrix`]
+pub fn save_matrix<T, P>(path: P, a: &Matrix<T>, symmetry: Symmetry) -> Result<(), IoError>
+    where T: MatrixMarketScalar,
+          P: AsRef<Path>
+{
+    return write_matrix(File::create(path)?, a, symmetry);
+}
+
+/// Writes a sparse matrix to a Matrix Market file at the given path
+///
+/// See [`write_coo`]
+pub fn save_coo<T, P>(path: P, a: &COOMatrix<T>, symmetry: Symmetry) -> Result<(), IoError>
+    where T: MatrixMarketScalar,
+          P: AsRef<Path>
+{
+    return write_coo(File::create(path)?, a, symmetry);
+}
+
+fn check_symmetry<T>(m: usize, n: usize, symmetry: Symmetry) -> Result<(), IoError>
+    where T: MatrixMarketScalar
+{
+    if symmetry != Symmetry::General && m != n
+    {
+        return Err(IoError::Format(format!("{} matrix is not square", symmetry)));
+    }
+    if symmetry == Symmetry::Hermitian && !T::is_complex()
+    {
+        return Err(IoError::Format("hermitian symmetry requires a complex matrix".to_string()));
+    }
+
+    return Ok(());
+}
+
+fn write_header<T>(writer: &mut dyn Write, format: &str, symmetry: Symmetry) -> Result<(), IoError>
+    where T: MatrixMarketScalar
+{
+    let field: &str = if T::is_complex() { "complex" } else { "real" };
+    writeln!(writer, "%%MatrixMarket matrix {} {} {}", format, field, symmetry)?;
+
+    return Ok(());
+}
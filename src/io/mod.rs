@@ -0,0 +1,14 @@
+//! Input and output
+//!
+//! Reading and writing of matrices from and to files.
+//!
+//! * [`csv`] Comma separated values (.csv)
+//! * [`matrixmarket`] Matrix Market exchange format (.mtx)
+//! * [`npy`] NumPy array files (.npy) and archives (.npz)
+
+pub mod csv;
+mod error;
+pub mod matrixmarket;
+pub mod npy;
+
+pub use self::error::IoError;
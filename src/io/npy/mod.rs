@@ -0,0 +1,130 @@
+//! NumPy array files
+//!
+//! A .npy file stores a single array in binary form. It starts with the magic
+//! string `\x93NUMPY`, the format version and a header, which describes the
+//! element type, the memory order and the shape of the array, e.g.
+//!
+//! ```text
+//! {'descr': '<f8', 'fortran_order': True, 'shape': (3, 4), }
+//! ```
+//!
+//! Arrays with the element types f32, f64, i32, i64, complex64 and
+//! complex128 in little or big endian byte order and in C (row-major) or
+//! Fortran (column-major) order can be read. Integer arrays are converted to
+//! floating point numbers. Matrices are written in Fortran order, which
+//! matches the column-major layout of [`Matrix`], therefore the data is
+//! written without reordering.
+//!
+//! A .npz file is a zip archive of several named .npy files, as written by
+//! `numpy.savez`. Compressed archives written by `numpy.savez_compressed`
+//! are not supported.
+//!
+//! Fore more information: <br>
+//! <a href="https://numpy.org/doc/stable/reference/generated/numpy.lib.format.html">https://numpy.org/doc/stable/reference/generated/numpy.lib.format.html</a>
+//!
+//! # Example
+//!
+//! ```
+//! # #[macro_use]
+//! # extern crate mathru;
+//! # fn main()
+//! # {
+//! use mathru::{algebra::linear::Matrix, io::npy};
+//!
+//! let a: Matrix<f64> = matrix![   1.0, 2.0, 3.0;
+//!                                 4.0, 5.0, 6.0];
+//! let mut bytes: Vec<u8> = Vec::new();
+//!
+//! npy::write_matrix(&mut bytes, &a).unwrap();
+//! let b: Matrix<f64> = npy::read_matrix(&bytes[..]).unwrap();
+//!
+//! assert_eq!(a, b);
+//! # }
+//! ```
+//!
+//! [`Matrix`]: crate::algebra::linear::Matrix
+
+mod read;
+mod write;
+mod zip;
+
+pub use self::{
+    read::{load_matrix, load_npz, load_vector, read_matrix, read_npz, read_vector},
+    write::{save_matrix, save_npz, save_vector, write_matrix, write_npz, write_vector},
+};
+
+use crate::algebra::abstr::{Complex, Field, Scalar};
+
+/// Scalar, which can be read from and written to a .npy file
+pub trait NpyScalar: Field + Scalar
+{
+    /// Type description of the element in the header, e.g. `<f8`
+    fn descr() -> &'static str;
+
+    /// Returns true for complex scalars
+    fn is_complex() -> bool;
+
+    /// Creates the scalar from the real and the imaginary part, the imaginary
+    /// part is zero for real scalars
+    fn from_parts(re: f64, im: f64) -> Self;
+
+    /// Appends the little endian representation
+    fn write_le(self: &Self, bytes: &mut Vec<u8>);
+}
+
+macro_rules! impl_npyscalar
+{
+    ($($t: ty, $descr: expr, $descr_complex: expr);*) =>
+    {
+        $(
+        impl NpyScalar for $t
+        {
+            fn descr() -> &'static str
+            {
+                return $descr;
+            }
+
+            fn is_complex() -> bool
+            {
+                return false;
+            }
+
+            fn from_parts(re: f64, _im: f64) -> Self
+            {
+                return re as $t;
+            }
+
+            fn write_le(self: &Self, bytes: &mut Vec<u8>)
+            {
+                bytes.extend_from_slice(&self.to_le_bytes());
+            }
+        }
+
+        impl NpyScalar for Complex<$t>
+        {
+            fn descr() -> &'static str
+            {
+                return $descr_complex;
+            }
+
+            fn is_complex() -> bool
+            {
+                return true;
+            }
+
+            fn from_parts(re: f64, im: f64) -> Self
+            {
+                return Complex::new(re as $t, im as $t);
+            }
+
+            fn write_le(self: &Self, bytes: &mut Vec<u8>)
+            {
+                bytes.extend_from_slice(&self.re.to_le_bytes());
+                bytes.extend_from_slice(&self.im.to_le_bytes());
+            }
+        }
+        )*
+    }
+}
+
+impl_npyscalar!(f32, "<f4", "<c8"; f64, "<f8", "<c16");
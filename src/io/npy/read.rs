@@ -0,0 +1,315 @@
+use super::{zip, NpyScalar};
+use crate::{
+    algebra::linear::{Matrix, Vector},
+    io::IoError,
+};
+use std::{fs::File, io::Read, path::Path};
+
+const MAGIC: &[u8] = b"\x93NUMPY";
+
+/// Reads a matrix from a .npy file
+///
+/// One dimensional arrays are read as column matrices.
+///
+/// # Return
+///
+/// IoError::Format if the file is malformed, the array has more than two
+/// dimensions or a complex array is read into a real matrix
+///
+/// # Example
+///
+/// ```
+/// use mathru::{algebra::linear::Matrix, io::npy};
+///
+/// // numpy.save(f, numpy.array([[1, 2, 3], [4, 5, 6]], dtype='<i4'))
+/// let mut bytes: Vec<u8> = b"\x93NUMPY\x01\x00\x76\x00".to_vec();
+/// let header: &str = "{'descr': '<i4', 'fortran_order': False, 'shape': (2, 3), }";
+/// bytes.extend_from_slice(format!("{:<117}\n", header).as_bytes());
+/// for a_ij in &[1, 2, 3, 4, 5, 6]
+/// {
+///     bytes.extend_from_slice(&i32::to_le_bytes(*a_ij));
+/// }
+///
+/// let a: Matrix<f64> = npy::read_matrix(&bytes[..]).unwrap();
+///
+/// assert_eq!(Matrix::new(2, 3, vec![1.0, 4.0, 2.0, 5.0, 3.0, 6.0]), a);
+/// ```
+pub fn read_matrix<T, R>(mut reader: R) -> Result<Matrix<T>, IoError>
+    where T: NpyScalar,
+          R: Read
+{
+    let mut bytes: Vec<u8> = Vec::new();
+    reader.read_to_end(&mut bytes)?;
+
+    return parse_matrix(&bytes);
+}
+
+/// Reads a vector from a .npy file
+///
+/// One dimensional arrays and two dimensional arrays with one column are read
+/// as column vectors, two dimensional arrays with one row as row vectors.
+///
+/// # Return
+///
+/// IoError::Format if the file is malformed, the array is not a vector or a
+/// complex array is read into a real vector
+pub fn read_vector<T, R>(mut reader: R) -> Result<Vector<T>, IoError>
+    where T: NpyScalar,
+          R: Read
+{
+    let mut bytes: Vec<u8> = Vec::new();
+    reader.read_to_end(&mut bytes)?;
+
+    let (shape, data): (Vec<usize>, Vec<T>) = parse_array(&bytes)?;
+    return match shape.as_slice()
+    {
+        [m] | [m, 1] => Ok(Vector::new_column(*m, data)),
+        [1, n] => Ok(Vector::new_row(*n, data)),
+        _ => Err(IoError::Format(format!("array of shape {:?} is not a vector", shape))),
+    };
+}
+
+/// Reads all arrays of a .npz archive as matrices
+///
+/// The names of the arrays are returned without the extension `.npy`. One
+/// dimensional arrays are read as column matrices.
+///
+/// # Return
+///
+/// IoError::Format if the archive or an array is malformed, the archive is
+/// compressed, an array has more than two dimensions or a complex array is
+/// read into a real matrix
+pub fn read_npz<T, R>(mut reader: R) -> Result<Vec<(String, Matrix<T>)>, IoError>
+    where T: NpyScalar,
+          R: Read
+{
+    let mut bytes: Vec<u8> = Vec::new();
+    reader.read_to_end(&mut bytes)?;
+
+    let mut arrays: Vec<(String, Matrix<T>)> = Vec::new();
+    for (name, content) in zip::read_archive(&bytes)?
+    {
+        let matrix: Matrix<T> = parse_matrix(content).map_err(|err| match err
+                                                      {
+                                                          IoError::Format(message) => IoError::Format(format!("{}: {}", name, message)),
+                                                          err => err,
+                                                      })?;
+        let name: String = name.strip_suffix(".npy").unwrap_or(&name).to_string();
+        arrays.push((name, matrix));
+    }
+
+    return Ok(arrays);
+}
+
+/// Reads a matrix from the .npy file at the given path
+///
+/// See [`read_matrix`]
+pub fn load_matrix<T, P>(path: P) -> Result<Matrix<T>, IoError>
+    where T: NpyScalar,
+          P: AsRef<Path>
+{
+    return read_matrix(File::open(path)?);
+}
+
+/// Reads a vector from the .npy file at the given path
+///
+/// See [`read_vector`]
+pub fn load_vector<T, P>(path: P) -> Result<Vector<T>, IoError>
+    where T: NpyScalar,
+          P: AsRef<Path>
+{
+    return read_vector(File::open(path)?);
+}
+
+/// Reads all arrays of the .npz archive at the given path
+///
+/// See [`read_npz`]
+pub fn load_npz<T, P>(path: P) -> Result<Vec<(String, Matrix<T>)>, IoError>
+    where T: NpyScalar,
+          P: AsRef<Path>
+{
+    return read_npz(File::open(path)?);
+}
+
+fn parse_matrix<T>(bytes: &[u8]) -> Result<Matrix<T>, IoError> where T: NpyScalar
+{
+    let (shape, data): (Vec<usize>, Vec<T>) = parse_array(bytes)?;
+    return match shape.as_slice()
+    {
+        [] => Ok(Matrix::new(1, 1, data)),
+        [m] => Ok(Matrix::new(*m, 1, data)),
+        [m, n] => Ok(Matrix::new(*m, *n, data)),
+        _ => Err(IoError::Format(format!("array of shape {:?} has more than two dimensions", shape))),
+    };
+}
+
+/// Parses a .npy file and returns the shape and the data in column-major
+/// order
+fn parse_array<T>(bytes: &[u8]) -> Result<(Vec<usize>, Vec<T>), IoError> where T: NpyScalar
+{
+    if bytes.len() < 10 || &bytes[0..6] != MAGIC
+    {
+        return Err(IoError::Format("not a .npy file".to_string()));
+    }
+
+    // version 1.0 has a 2 byte header length, version 2.0 and 3.0 a 4 byte
+    // header length
+    let (header_start, header_len): (usize, usize) = match bytes[6]
+    {
+        1 => (10, u16::from_le_bytes([bytes[8], bytes[9]]) as usize),
+        2 | 3 if bytes.len() >= 12 =>
+        {
+            (12, u32::from_le_bytes([bytes[8], bytes[9], bytes[10], bytes[11]]) as usize)
+        }
+        version => return Err(IoError::Format(format!("unsupported .npy version {}", version))),
+    };
+
+    let header: &str = bytes.get(header_start..header_start + header_len)
+                            .and_then(|header| std::str::from_utf8(header).ok())
+                            .ok_or_else(|| IoError::Format("invalid .npy header".to_string()))?;
+
+    let descr: &str = header_value(header, "descr")?.trim_matches(['\'', '"']);
+    let fortran_order: bool = match header_value(header, "fortran_order")?
+    {
+        "True" => true,
+        "False" => false,
+        other => return Err(IoError::Format(format!("invalid fortran_order '{}'", other))),
+    };
+    let shape: Vec<usize> = parse_shape(header_value(header, "shape")?)?;
+
+    let (little_endian, kind, size): (bool, char, usize) = parse_descr(descr)?;
+    if kind == 'c' && !T::is_complex()
+    {
+        return Err(IoError::Format("complex array can not be read into a real matrix".to_string()));
+    }
+
+    let overflow = || IoError::Format(format!("array of shape {:?} is too large", shape));
+    let len: usize = shape.iter().try_fold(1usize, |len, d| len.checked_mul(*d)).ok_or_else(overflow)?;
+    let byte_len: usize = len.checked_mul(size).ok_or_else(overflow)?;
+    let data: &[u8] = &bytes[header_start + header_len..];
+    if data.len() < byte_len
+    {
+        return Err(IoError::Format(format!("expected {} bytes of data, found {}", byte_len, data.len())));
+    }
+
+    let values: Vec<T> = data.chunks_exact(size)
+                             .take(len)
+                             .map(|element| decode(element, little_endian, kind))
+                             .collect();
+
+    // C order of a matrix is the column-major order of its transpose
+    let values: Vec<T> = match shape.as_slice()
+    {
+        [m, n] if !fortran_order =>
+        {
+            let (m, n): (usize, usize) = (*m, *n);
+            (0..m * n).map(|k| values[(k % m) * n + k / m]).collect()
+        }
+        _ => values,
+    };
+
+    return Ok((shape, values));
+}
+
+/// Returns the value of the key in the header dictionary
+fn header_value<'a>(header: &'a str, key: &str) -> Result<&'a str, IoError>
+{
+    let missing = || IoError::Format(format!("missing key '{}' in the .npy header", key));
+
+    let key_start: usize = header.find(&format!("'{}'", key))
+                                 .or_else(|| header.find(&format!("\"{}\"", key)))
+                                 .ok_or_else(missing)?;
+    let rest: &str = &header[key_start + key.len() + 2..];
+    let rest: &str = rest.trim_start().strip_prefix(':').ok_or_else(missing)?.trim_start();
+
+    let end: usize = if rest.starts_with('(')
+    {
+        rest.find(')').map(|end| end + 1)
+    }
+    else
+    {
+        rest.find([',', '}'])
+    }.ok_or_else(missing)?;
+
+    return Ok(rest[..end].trim());
+}
+
+fn parse_shape(shape: &str) -> Result<Vec<usize>, IoError>
+{
+    return shape.trim_start_matches('(')
+                .trim_end_matches(')')
+                .split(',')
+                .map(|dim| dim.trim().trim_end_matches('L'))
+                .filter(|dim| !dim.is_empty())
+                .map(|dim| {
+                    dim.parse::<usize>()
+                       .map_err(|_| IoError::Format(format!("invalid shape '{}'", shape)))
+                })
+                .collect();
+}
+
+/// Returns byte order, kind and size in bytes of the element type
+fn parse_descr(descr: &str) -> Result<(bool, char, usize), IoError>
+{
+    let unsupported = || IoError::Format(format!("unsupported element type '{}'", descr));
+
+    let mut chars = descr.chars();
+    let little_endian: bool = match chars.next()
+    {
+        Some('<') => true,
+        Some('>') => false,
+        Some('=') => cfg!(target_endian = "little"),
+        _ => return Err(unsupported()),
+    };
+    let kind: char = chars.next().ok_or_else(unsupported)?;
+    let size: usize = chars.as_str().parse::<usize>().map_err(|_| unsupported())?;
+
+    return match (kind, size)
+    {
+        ('f', 4) | ('f', 8) | ('i', 4) | ('i', 8) | ('c', 8) | ('c', 16) => Ok((little_endian, kind, size)),
+        _ => Err(unsupported()),
+    };
+}
+
+fn decode<T>(bytes: &[u8], little_endian: bool, kind: char) -> T where T: NpyScalar
+{
+    let half: usize = bytes.len() / 2;
+
+    return match kind
+    {
+        'f' => T::from_parts(float(bytes, little_endian), 0.0),
+        'i' => T::from_parts(integer(bytes, little_endian), 0.0),
+        _ => T::from_parts(float(&bytes[..half], little_endian), float(&bytes[half..], little_endian)),
+    };
+}
+
+fn float(bytes: &[u8], little_endian: bool) -> f64
+{
+    return match bytes.len()
+    {
+        4 => f32::from_le_bytes(le_bytes(bytes, little_endian)) as f64,
+        _ => f64::from_le_bytes(le_bytes(bytes, little_endian)),
+    };
+}
+
+fn integer(bytes: &[u8], little_endian: bool) -> f64
+{
+    return match bytes.len()
+    {
+        4 => i32::from_le_bytes(le_bytes(bytes, little_endian)) as f64,
+        _ => i64::from_le_bytes(le_bytes(bytes, little_endian)) as f64,
+    };
+}
+
+/// Converts the bytes to little endian order
+fn le_bytes<const N: usize>(bytes: &[u8], little_endian: bool) -> [u8; N]
+{
+    let mut le: [u8; N] = [0; N];
+    le.copy_from_slice(bytes);
+    if !little_endian
+    {
+        le.reverse();
+    }
+
+    return le;
+}
@@ -0,0 +1,176 @@
+use super::{zip, NpyScalar};
+use crate::{
+    algebra::linear::{Matrix, Vector},
+    io::IoError,
+};
+use std::{
+    fs::File,
+    io::{BufWriter, Write},
+    path::Path,
+};
+
+/// Writes a matrix to a .npy file
+///
+/// The matrix is written as two dimensional array in Fortran order and
+/// little endian byte order.
+///
+/// # Example
+///
+/// ```
+/// # #[macro_use]
+/// # extern crate mathru;
+/// # fn main()
+/// # {
+/// use mathru::{algebra::linear::Matrix, io::npy};
+///
+/// let a: Matrix<f32> = matrix![1.0, 2.0; 3.0, 4.0];
+/// let mut bytes: Vec<u8> = Vec::new();
+///
+/// npy::write_matrix(&mut bytes, &a).unwrap();
+///
+/// assert_eq!(128 + 4 * 4, bytes.len());
+/// assert!(String::from_utf8_lossy(&bytes).contains("'descr': '<f4', 'fortran_order': True, 'shape': (2, 2)"));
+/// # }
+/// ```
+pub fn write_matrix<T, W>(mut writer: W, a: &Matrix<T>) -> Result<(), IoError>
+    where T: NpyScalar,
+          W: Write
+{
+    writer.write_all(&matrix_bytes(a))?;
+    writer.flush()?;
+
+    return Ok(());
+}
+
+/// Writes a vector to a .npy file
+///
+/// A column vector is written as one dimensional array, a row vector as two
+/// dimensional array with one row, such that [`read_vector`] returns the same
+/// vector. The byte order is little endian.
+///
+/// [`read_vector`]: super::read_vector
+pub fn write_vector<T, W>(mut writer: W, v: &Vector<T>) -> Result<(), IoError>
+    where T: NpyScalar,
+          W: Write
+{
+    let (m, n): (usize, usize) = v.dim();
+    let shape: Vec<usize> = if m == 1 && n != 1 { vec![1, n] } else { vec![m] };
+    let mut bytes: Vec<u8> = header::<T>(&shape);
+    for v_i in v.iter()
+    {
+        v_i.write_le(&mut bytes);
+    }
+
+    writer.write_all(&bytes)?;
+    writer.flush()?;
+
+    return Ok(());
+}
+
+/// Writes matrices as named arrays to an uncompressed .npz archive
+///
+/// The archive can be read with `numpy.load`, the arrays are stored with the
+/// extension `.npy`.
+///
+/// # Example
+///
+/// ```
+/// # #[macro_use]
+/// # extern crate mathru;
+/// # fn main()
+/// # {
+/// use mathru::{algebra::linear::Matrix, io::npy};
+///
+/// let a: Matrix<f64> = matrix![1.0, 2.0; 3.0, 4.0];
+/// let b: Matrix<f64> = matrix![5.0; 6.0];
+/// let mut bytes: Vec<u8> = Vec::new();
+///
+/// npy::write_npz(&mut bytes, &[("a", &a), ("b", &b)]).unwrap();
+/// let arrays: Vec<(String, Matrix<f64>)> = npy::read_npz(&bytes[..]).unwrap();
+///
+/// assert_eq!(vec![("a".to_string(), a), ("b".to_string(), b)], arrays);
+/// # }
+/// ```
+pub fn write_npz<T, W>(writer: W, arrays: &[(&str, &Matrix<T>)]) -> Result<(), IoError>
+    where T: NpyScalar,
+          W: Write
+{
+    let files: Vec<(String, Vec<u8>)> = arrays.iter()
+                                              .map(|(name, a)| (format!("{}.npy", name), matrix_bytes(a)))
+                                              .collect();
+
+    let mut writer: BufWriter<W> = BufWriter::new(writer);
+    zip::write_archive(&mut writer, &files)?;
+    writer.flush()?;
+
+    return Ok(());
+}
+
+/// Writes a matrix to a .npy file at the given path
+///
+/// See [`write_matrix`]
+pub fn save_matrix<T, P>(path: P, a: &Matrix<T>) -> Result<(), IoError>
+    where T: NpyScalar,
+          P: AsRef<Path>
+{
+    return write_matrix(File::create(path)?, a);
+}
+
+/// Writes a vector to a .npy file at the given path
+///
+/// See [`write_vector`]
+pub fn save_vector<T, P>(path: P, v: &Vector<T>) -> Result<(), IoError>
+    where T: NpyScalar,
+          P: AsRef<Path>
+{
+    return write_vector(File::create(path)?, v);
+}
+
+/// Writes matrices as named arrays to a .npz archive at the given path
+///
+/// See [`write_npz`]
+pub fn save_npz<T, P>(path: P, arrays: &[(&str, &Matrix<T>)]) -> Result<(), IoError>
+    where T: NpyScalar,
+          P: AsRef<Path>
+{
+    return write_npz(File::create(path)?, arrays);
+}
+
+fn matrix_bytes<T>(a: &Matrix<T>) -> Vec<u8> where T: NpyScalar
+{
+    let (m, n): (usize, usize) = a.dim();
+    let mut bytes: Vec<u8> = header::<T>(&[m, n]);
+    // column-major order is Fortran order
+    for a_ij in a.iter()
+    {
+        a_ij.write_le(&mut bytes);
+    }
+
+    return bytes;
+}
+
+/// Magic string, version 1.0 and the header, padded to a multiple of 64
+/// bytes
+fn header<T>(shape: &[usize]) -> Vec<u8> where T: NpyScalar
+{
+    let fortran_order: &str = if shape.len() > 1 { "True" } else { "False" };
+    let shape: String = match shape
+    {
+        [m] => format!("({},)", m),
+        _ => format!("({})", shape.iter().map(|dim| dim.to_string()).collect::<Vec<String>>().join(", ")),
+    };
+    let mut dict: String = format!("{{'descr': '{}', 'fortran_order': {}, 'shape': {}, }}",
+                                   T::descr(),
+                                   fortran_order,
+                                   shape);
+
+    let len: usize = 10 + dict.len() + 1;
+    dict.push_str(&" ".repeat((64 - len % 64) % 64));
+    dict.push('\n');
+
+    let mut bytes: Vec<u8> = b"\x93NUMPY\x01\x00".to_vec();
+    bytes.extend_from_slice(&(dict.len() as u16).to_le_bytes());
+    bytes.extend_from_slice(dict.as_bytes());
+
+    return bytes;
+}
@@ -0,0 +1,237 @@
+//! Minimal zip container for .npz archives
+//!
+//! Only uncompressed (stored) entries are supported, which is what
+//! `numpy.savez` writes. Zip64 records, which numpy writes for every entry,
+//! are understood when reading.
+use crate::io::IoError;
+use std::io::Write;
+
+const LOCAL_HEADER: u32 = 0x0403_4b50;
+const CENTRAL_HEADER: u32 = 0x0201_4b50;
+const END_OF_CENTRAL_DIRECTORY: u32 = 0x0605_4b50;
+const ZIP64_END_OF_CENTRAL_DIRECTORY: u32 = 0x0606_4b50;
+const ZIP64_LOCATOR: u32 = 0x0706_4b50;
+const ZIP64_EXTRA: u16 = 0x0001;
+const STORED: u16 = 0;
+
+/// Returns the names and the contents of the entries of the archive
+pub(super) fn read_archive(data: &[u8]) -> Result<Vec<(String, &[u8])>, IoError>
+{
+    let eocd: usize = find_end_of_central_directory(data)?;
+    let mut entries: u64 = u16_at(data, eocd + 10)? as u64;
+    let mut offset: u64 = u32_at(data, eocd + 16)? as u64;
+
+    if offset == u32::MAX as u64 || entries == u16::MAX as u64
+    {
+        if eocd < 20 || u32_at(data, eocd - 20)? != ZIP64_LOCATOR
+        {
+            return Err(IoError::Format("missing zip64 end of central directory locator".to_string()));
+        }
+        let zip64_eocd: usize = u64_at(data, eocd - 12)? as usize;
+        if u32_at(data, zip64_eocd)? != ZIP64_END_OF_CENTRAL_DIRECTORY
+        {
+            return Err(IoError::Format("invalid zip64 end of central directory".to_string()));
+        }
+        entries = u64_at(data, zip64_eocd + 32)?;
+        offset = u64_at(data, zip64_eocd + 48)?;
+    }
+
+    let mut files: Vec<(String, &[u8])> = Vec::new();
+    let mut pos: usize = offset as usize;
+    for _ in 0..entries
+    {
+        if u32_at(data, pos)? != CENTRAL_HEADER
+        {
+            return Err(IoError::Format("invalid central directory entry".to_string()));
+        }
+        let method: u16 = u16_at(data, pos + 10)?;
+        let crc: u32 = u32_at(data, pos + 16)?;
+        let mut size: u64 = u32_at(data, pos + 20)? as u64;
+        let mut uncompressed_size: u64 = u32_at(data, pos + 24)? as u64;
+        let name_len: usize = u16_at(data, pos + 28)? as usize;
+        let extra_len: usize = u16_at(data, pos + 30)? as usize;
+        let comment_len: usize = u16_at(data, pos + 32)? as usize;
+        let mut header_offset: u64 = u32_at(data, pos + 42)? as u64;
+        let name: String = String::from_utf8_lossy(slice(data, pos + 46, name_len)?).into_owned();
+
+        // the zip64 extra field contains the values, which do not fit into
+        // 32 bit, in this order
+        let extra: &[u8] = slice(data, pos + 46 + name_len, extra_len)?;
+        let mut k: usize = 0;
+        while k + 4 <= extra.len()
+        {
+            let id: u16 = u16_at(extra, k)?;
+            let len: usize = u16_at(extra, k + 2)? as usize;
+            if id == ZIP64_EXTRA
+            {
+                let mut field: usize = k + 4;
+                for value in [&mut uncompressed_size, &mut size, &mut header_offset]
+                {
+                    if *value == u32::MAX as u64
+                    {
+                        *value = u64_at(extra, field)?;
+                        field += 8;
+                    }
+                }
+            }
+            k += 4 + len;
+        }
+
+        if method != STORED || size != uncompressed_size
+        {
+            return Err(IoError::Format(format!("compressed entry '{}' is not supported", name)));
+        }
+
+        let local: usize = header_offset as usize;
+        if u32_at(data, local)? != LOCAL_HEADER
+        {
+            return Err(IoError::Format(format!("invalid local header of entry '{}'", name)));
+        }
+        let local_name_len: usize = u16_at(data, local + 26)? as usize;
+        let local_extra_len: usize = u16_at(data, local + 28)? as usize;
+        let content: &[u8] = slice(data, local + 30 + local_name_len + local_extra_len, size as usize)?;
+
+        if crc32(content) != crc
+        {
+            return Err(IoError::Format(format!("checksum mismatch of entry '{}'", name)));
+        }
+
+        files.push((name, content));
+        pos += 46 + name_len + extra_len + comment_len;
+    }
+
+    return Ok(files);
+}
+
+/// Writes the entries as uncompressed archive
+pub(super) fn write_archive(writer: &mut dyn Write, files: &[(String, Vec<u8>)]) -> Result<(), IoError>
+{
+    let mut central_directory: Vec<u8> = Vec::new();
+    let mut offset: u64 = 0;
+
+    for (name, content) in files
+    {
+        if content.len() as u64 >= u32::MAX as u64 || offset >= u32::MAX as u64
+        {
+            return Err(IoError::Format("archives larger than 4 GiB are not supported".to_string()));
+        }
+        let crc: u32 = crc32(content);
+        let size: u32 = content.len() as u32;
+
+        let mut local: Vec<u8> = Vec::with_capacity(30 + name.len());
+        local.extend_from_slice(&LOCAL_HEADER.to_le_bytes());
+        push_common_header(&mut local, crc, size, name.len());
+        local.extend_from_slice(&0u16.to_le_bytes());
+        local.extend_from_slice(name.as_bytes());
+        writer.write_all(&local)?;
+        writer.write_all(content)?;
+
+        central_directory.extend_from_slice(&CENTRAL_HEADER.to_le_bytes());
+        // version made by
+        central_directory.extend_from_slice(&20u16.to_le_bytes());
+        push_common_header(&mut central_directory, crc, size, name.len());
+        // extra field length, comment length, disk number, internal and
+        // external attributes
+        central_directory.extend_from_slice(&[0; 12]);
+        central_directory.extend_from_slice(&(offset as u32).to_le_bytes());
+        central_directory.extend_from_slice(name.as_bytes());
+
+        offset += (local.len() + content.len()) as u64;
+    }
+
+    if offset >= u32::MAX as u64
+    {
+        return Err(IoError::Format("archives larger than 4 GiB are not supported".to_string()));
+    }
+
+    let mut eocd: Vec<u8> = Vec::with_capacity(22);
+    eocd.extend_from_slice(&END_OF_CENTRAL_DIRECTORY.to_le_bytes());
+    eocd.extend_from_slice(&[0; 4]);
+    eocd.extend_from_slice(&(files.len() as u16).to_le_bytes());
+    eocd.extend_from_slice(&(files.len() as u16).to_le_bytes());
+    eocd.extend_from_slice(&(central_directory.len() as u32).to_le_bytes());
+    eocd.extend_from_slice(&(offset as u32).to_le_bytes());
+    eocd.extend_from_slice(&0u16.to_le_bytes());
+
+    writer.write_all(&central_directory)?;
+    writer.write_all(&eocd)?;
+
+    return Ok(());
+}
+
+/// Version needed, flags, method, time, date, checksum, sizes and the name
+/// length, which are shared by the local and the central header
+fn push_common_header(bytes: &mut Vec<u8>, crc: u32, size: u32, name_len: usize)
+{
+    bytes.extend_from_slice(&20u16.to_le_bytes());
+    bytes.extend_from_slice(&0u16.to_le_bytes());
+    bytes.extend_from_slice(&STORED.to_le_bytes());
+    // 00:00:00, 1980-01-01
+    bytes.extend_from_slice(&0u16.to_le_bytes());
+    bytes.extend_from_slice(&0x21u16.to_le_bytes());
+    bytes.extend_from_slice(&crc.to_le_bytes());
+    bytes.extend_from_slice(&size.to_le_bytes());
+    bytes.extend_from_slice(&size.to_le_bytes());
+    bytes.extend_from_slice(&(name_len as u16).to_le_bytes());
+}
+
+fn find_end_of_central_directory(data: &[u8]) -> Result<usize, IoError>
+{
+    // the record has 22 bytes followed by a comment of at most 65535 bytes
+    if data.len() >= 22
+    {
+        let first: usize = data.len().saturating_sub(22 + u16::MAX as usize);
+        for pos in (first..=data.len() - 22).rev()
+        {
+            if u32_at(data, pos)? == END_OF_CENTRAL_DIRECTORY
+            {
+                return Ok(pos);
+            }
+        }
+    }
+
+    return Err(IoError::Format("not a zip archive".to_string()));
+}
+
+/// CRC-32 checksum with the polynomial 0xEDB88320
+fn crc32(data: &[u8]) -> u32
+{
+    let mut crc: u32 = !0;
+    for byte in data
+    {
+        crc ^= *byte as u32;
+        for _ in 0..8
+        {
+            let mask: u32 = (crc & 1).wrapping_neg();
+            crc = (crc >> 1) ^ (0xEDB8_8320 & mask);
+        }
+    }
+
+    return !crc;
+}
+
+fn slice(data: &[u8], pos: usize, len: usize) -> Result<&[u8], IoError>
+{
+    return data.get(pos..pos + len)
+               .ok_or_else(|| IoError::Format("unexpected end of the zip archive".to_string()));
+}
+
+fn u16_at(data: &[u8], pos: usize) -> Result<u16, IoError>
+{
+    let bytes: &[u8] = slice(data, pos, 2)?;
+    return Ok(u16::from_le_bytes([bytes[0], bytes[1]]));
+}
+
+fn u32_at(data: &[u8], pos: usize) -> Result<u32, IoError>
+{
+    let bytes: &[u8] = slice(data, pos, 4)?;
+    return Ok(u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]));
+}
+
+fn u64_at(data: &[u8], pos: usize) -> Result<u64, IoError>
+{
+    let bytes: &[u8] = slice(data, pos, 8)?;
+    let mut array: [u8; 8] = [0; 8];
+    array.copy_from_slice(bytes);
+    return Ok(u64::from_le_bytes(array));
+}
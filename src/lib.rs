@@ -202,6 +202,8 @@ extern crate lapack;
 pub mod algebra;
 pub mod analysis;
 pub mod elementary;
+pub mod error;
+pub mod io;
 pub mod optimization;
 pub mod special;
 pub mod statistics;
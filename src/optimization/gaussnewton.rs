@@ -3,6 +3,7 @@ use crate::{
         abstr::Real,
         linear::{Matrix, Vector},
     },
+    error::MathError,
     optimization::{Optim, OptimResult},
 };
 use std::marker::PhantomData;
@@ -40,7 +41,7 @@ impl<T> GaussNewton<T> where T: Real
     /// # Return
     ///
     /// local minimum
-    pub fn minimize<F>(self: &Self, func: &F, x_0: &Vector<T>) -> Result<OptimResult<Vector<T>>, ()>
+    pub fn minimize<F>(self: &Self, func: &F, x_0: &Vector<T>) -> Result<OptimResult<Vector<T>>, MathError>
         where F: Optim<T>
     {
         let mut x_n: Vector<T> = x_0.clone();
@@ -3,6 +3,7 @@ use crate::{
         abstr::Real,
         linear::{matrix::{Transpose, Solve}, Matrix, Vector},
     },
+    error::MathError,
     optimization::{Optim, OptimResult},
 };
 
@@ -58,7 +59,7 @@ impl<T> LevenbergMarquardt<T> where T: Real
     /// # Return
     ///
     /// local minimum
-    pub fn minimize<F>(self: &Self, func: &F, x_0: &Vector<T>) -> Result<OptimResult<Vector<T>>, ()>
+    pub fn minimize<F>(self: &Self, func: &F, x_0: &Vector<T>) -> Result<OptimResult<Vector<T>>, MathError>
         where F: Optim<T>
     {
         let mut x_n: Vector<T> = x_0.clone();
@@ -76,7 +77,7 @@ impl<T> LevenbergMarquardt<T> where T: Real
                 let p_n: Vector<T> = -(&jacobian_x_n_tran * &f_x_n);
                 let (_j_m, j_n) = jacobian_x_n.dim();
                 let left_n: Matrix<T> = &jacobian_x_n_tran * &jacobian_x_n + Matrix::one(j_n) * mu_n * mu_n;
-                d_n = left_n.solve(&p_n).unwrap();
+                d_n = left_n.solve(&p_n)?;
 
                 let x_n_1 = &x_n + &d_n;
                 let f_x_n_1: Vector<T> = func.eval(&x_n_1);
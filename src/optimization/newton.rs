@@ -3,6 +3,7 @@ use crate::{
         abstr::Real,
         linear::{matrix::Solve, Matrix, Vector},
     },
+    error::MathError,
     optimization::{Optim, OptimResult},
 };
 #[cfg(feature = "serde")]
@@ -83,7 +84,7 @@ impl<T> Newton<T> where T: Real
         {
             let hessian_x_n: Matrix<T> = func.hessian(&x_n);
             let grad_x_n: Vector<T> = func.jacobian(&x_n).get_row(0).transpose();
-            let res_solve: Result<Vector<T>, ()> = hessian_x_n.solve(&-grad_x_n.clone());
+            let res_solve: Result<Vector<T>, MathError> = hessian_x_n.solve(&-grad_x_n.clone());
             let d_k: Vector<T>;
 
             match res_solve
@@ -0,0 +1,128 @@
+use mathru::algebra::linear::{
+    banded::Banded,
+    matrix::{Solve, Transpose},
+    Matrix, Vector,
+};
+
+#[test]
+fn from_dense()
+{
+    let a: Matrix<f64> = matrix![   1.0, 2.0, 0.0, 0.0;
+                                    3.0, 4.0, 5.0, 0.0;
+                                    6.0, 7.0, 8.0, 9.0;
+                                    0.0, 10.0, 11.0, 12.0;
+                                    0.0, 0.0, 13.0, 14.0];
+
+    let a_banded: Banded<f64> = Banded::from(&a);
+
+    assert_eq!((5, 4), a_banded.dim());
+    assert_eq!(2, a_banded.kl());
+    assert_eq!(1, a_banded.ku());
+    assert_eq!(13.0, a_banded.get(4, 2));
+    assert_eq!(0.0, a_banded.get(4, 0));
+    assert_eq!(a, Matrix::from(&a_banded));
+}
+
+#[test]
+fn new()
+{
+    // 1 4 0
+    // 2 5 7
+    // 0 3 6
+    let a: Banded<f64> = Banded::new(3, 3, 1, 1, vec![0.0, 1.0, 2.0, 4.0, 5.0, 3.0, 7.0, 6.0, 0.0]);
+
+    let a_ref: Matrix<f64> = matrix![   1.0, 4.0, 0.0;
+                                        2.0, 5.0, 7.0;
+                                        0.0, 3.0, 6.0];
+
+    assert_eq!(a_ref, Matrix::from(a));
+}
+
+#[test]
+#[should_panic]
+fn get_mut_outside_band()
+{
+    let mut a: Banded<f64> = Banded::zero(3, 3, 1, 0);
+    *a.get_mut(0, 1) = 1.0;
+}
+
+#[test]
+fn mul_vector()
+{
+    let a: Matrix<f64> = matrix![   1.0, 2.0, 0.0, 0.0;
+                                    3.0, 4.0, 5.0, 0.0;
+                                    6.0, 7.0, 8.0, 9.0;
+                                    0.0, 10.0, 11.0, 12.0;
+                                    0.0, 0.0, 13.0, 14.0];
+    let x: Vector<f64> = vector![1.0; -2.0; 3.0; 0.5];
+
+    assert_eq!(&a * &x, &Banded::from(&a) * &x);
+}
+
+#[test]
+fn solve_lu()
+{
+    let a: Matrix<f64> = matrix![   1.0, 2.0, 3.0, 0.0, 0.0;
+                                    4.0, 1.0, 2.0, 3.0, 0.0;
+                                    5.0, 9.0, 1.0, 2.0, 3.0;
+                                    0.0, 6.0, 4.0, 1.0, 2.0;
+                                    0.0, 0.0, 8.0, 7.0, 1.0];
+    let x_ref: Vector<f64> = vector![1.0; -2.0; 3.0; 0.5; -1.5];
+    let b: Vector<f64> = &a * &x_ref;
+
+    let x: Vector<f64> = Banded::from(&a).solve(&b).unwrap();
+
+    assert_relative_eq!(x_ref, x, epsilon=1.0e-10);
+}
+
+#[test]
+fn solve_lu_pivoting()
+{
+    let a: Matrix<f64> = matrix![   0.0, 1.0, 0.0, 0.0;
+                                    1.0, 0.0, 1.0, 0.0;
+                                    0.0, 1.0, 0.0, 1.0;
+                                    0.0, 0.0, 1.0, 1.0];
+    let x_ref: Vector<f64> = vector![1.0; 2.0; 3.0; 4.0];
+    let b: Vector<f64> = &a * &x_ref;
+
+    let x: Vector<f64> = Banded::from(&a).dec_lu().unwrap().solve(&b).unwrap();
+
+    assert_relative_eq!(x_ref, x, epsilon=1.0e-10);
+}
+
+#[test]
+fn dec_lu_singular()
+{
+    let a: Matrix<f64> = matrix![   1.0, 2.0, 0.0;
+                                    2.0, 4.0, 0.0;
+                                    0.0, 1.0, 1.0];
+
+    assert!(Banded::from(&a).dec_lu().is_err());
+}
+
+#[test]
+fn dec_cholesky()
+{
+    let a: Matrix<f64> = matrix![   4.0, 2.0, 1.0, 0.0;
+                                    2.0, 5.0, 2.0, 1.0;
+                                    1.0, 2.0, 6.0, 2.0;
+                                    0.0, 1.0, 2.0, 7.0];
+
+    let dec = Banded::from(&a).dec_cholesky().unwrap();
+    let x_ref: Vector<f64> = vector![1.0; -2.0; 3.0; 0.5];
+    let x: Vector<f64> = dec.solve(&(&a * &x_ref)).unwrap();
+    assert_relative_eq!(x_ref, x, epsilon=1.0e-10);
+
+    let l: Matrix<f64> = Matrix::from(dec.l());
+    assert_relative_eq!(a, &l * &l.clone().transpose(), epsilon=1.0e-10);
+}
+
+#[test]
+fn dec_cholesky_not_positive_definite()
+{
+    let a: Matrix<f64> = matrix![   1.0, 2.0, 0.0;
+                                    2.0, 1.0, 0.0;
+                                    0.0, 0.0, 1.0];
+
+    assert!(Banded::from(&a).dec_cholesky().is_err());
+}
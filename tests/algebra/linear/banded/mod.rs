@@ -0,0 +1,2 @@
+mod banded;
+mod tridiagonal;
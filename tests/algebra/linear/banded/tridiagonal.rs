@@ -0,0 +1,93 @@
+use mathru::algebra::linear::{
+    banded::{Banded, Tridiagonal},
+    matrix::Solve,
+    Matrix, Vector,
+};
+
+#[test]
+fn get()
+{
+    let a: Tridiagonal<f64> = Tridiagonal::new(vec![1.0, 2.0], vec![3.0, 4.0, 5.0], vec![6.0, 7.0]);
+
+    assert_eq!(3.0, a.get(0, 0));
+    assert_eq!(1.0, a.get(1, 0));
+    assert_eq!(7.0, a.get(1, 2));
+    assert_eq!(0.0, a.get(2, 0));
+    assert_eq!((3, 3), a.dim());
+}
+
+#[test]
+#[should_panic]
+fn new_wrong_length()
+{
+    let _a: Tridiagonal<f64> = Tridiagonal::new(vec![1.0], vec![3.0, 4.0, 5.0], vec![6.0, 7.0]);
+}
+
+#[test]
+fn from_dense()
+{
+    let a: Matrix<f64> = matrix![   1.0, 2.0, 0.0;
+                                    3.0, 4.0, 5.0;
+                                    0.0, 6.0, 7.0];
+
+    let a_tri: Tridiagonal<f64> = Tridiagonal::from(&a);
+
+    assert_eq!(&[3.0, 6.0], a_tri.lower());
+    assert_eq!(&[1.0, 4.0, 7.0], a_tri.diag());
+    assert_eq!(&[2.0, 5.0], a_tri.upper());
+    assert_eq!(a, Matrix::from(&a_tri));
+}
+
+#[test]
+#[should_panic(expected = "Tridiagonal matrix has to have at least one row")]
+fn from_dense_empty()
+{
+    let a: Matrix<f64> = Matrix::zero(0, 0);
+
+    let _a_tri: Tridiagonal<f64> = Tridiagonal::from(&a);
+}
+
+#[test]
+fn mul_vector()
+{
+    let a: Matrix<f64> = matrix![   1.0, 2.0, 0.0, 0.0;
+                                    3.0, 4.0, 5.0, 0.0;
+                                    0.0, 6.0, 7.0, 8.0;
+                                    0.0, 0.0, 9.0, 10.0];
+    let x: Vector<f64> = vector![1.0; -2.0; 3.0; 0.5];
+
+    assert_eq!(&a * &x, &Tridiagonal::from(&a) * &x);
+}
+
+#[test]
+fn solve()
+{
+    let n: usize = 100;
+    let a: Tridiagonal<f64> = Tridiagonal::new(vec![-1.0; n - 1], vec![2.5; n], vec![-1.2; n - 1]);
+    let x_ref: Vector<f64> = Vector::new_column(n, (0..n).map(|i| (i as f64).sin()).collect());
+    let b: Vector<f64> = &a * &x_ref;
+
+    let x: Vector<f64> = a.solve(&b).unwrap();
+
+    assert_relative_eq!(x_ref, x, epsilon=1.0e-10);
+}
+
+#[test]
+fn solve_one_dimensional()
+{
+    let a: Tridiagonal<f64> = Tridiagonal::new(vec![], vec![2.0], vec![]);
+
+    assert_eq!(vector![1.5], a.solve(&vector![3.0]).unwrap());
+}
+
+#[test]
+fn solve_zero_pivot()
+{
+    let a: Tridiagonal<f64> = Tridiagonal::new(vec![1.0], vec![0.0, 1.0], vec![1.0]);
+
+    assert!(a.solve(&vector![1.0; 2.0]).is_err());
+
+    // Partial pivoting of the banded LU decomposition handles the zero pivot
+    let x: Vector<f64> = Banded::from(&a).solve(&vector![1.0; 2.0]).unwrap();
+    assert_relative_eq!(vector![1.0; 1.0], x);
+}
@@ -0,0 +1,57 @@
+use ::nalgebra::{DMatrix, DVector, Matrix3x2, RowDVector};
+use mathru::algebra::linear::{Matrix, Vector};
+use std::convert::TryFrom;
+
+#[test]
+fn matrix_into_dmatrix()
+{
+    let a: Matrix<f64> = matrix![   1.0, 2.0, 3.0;
+                                    4.0, 5.0, 6.0];
+
+    let b: DMatrix<f64> = DMatrix::from(&a);
+
+    assert_eq!((2, 3), b.shape());
+    assert_eq!(6.0, b[(1, 2)]);
+    assert_eq!(a, Matrix::from(b));
+}
+
+#[test]
+fn dmatrix_into_matrix()
+{
+    let a: DMatrix<f32> = DMatrix::from_row_slice(3, 2, &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+
+    assert_eq!(matrix![1.0, 2.0; 3.0, 4.0; 5.0, 6.0], Matrix::from(a));
+}
+
+#[test]
+fn static_matrix_into_matrix()
+{
+    let a: Matrix3x2<f64> = Matrix3x2::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0);
+
+    assert_eq!(matrix![1.0, 2.0; 3.0, 4.0; 5.0, 6.0], Matrix::from(&a));
+    assert_eq!(matrix![3.0, 4.0; 5.0, 6.0], Matrix::from(&a.rows(1, 2)));
+}
+
+#[test]
+fn vector_into_dvector()
+{
+    let v: Vector<f64> = vector![1.0; 2.0; 3.0];
+
+    let w: DVector<f64> = DVector::try_from(v.clone()).unwrap();
+
+    assert_eq!(DVector::from_vec(vec![1.0, 2.0, 3.0]), w);
+    assert_eq!(v, Vector::from(w));
+    assert!(DVector::try_from(vector![1.0, 2.0]).is_err());
+}
+
+#[test]
+fn vector_into_row_dvector()
+{
+    let v: Vector<f64> = vector![1.0, 2.0, 3.0];
+
+    let w: RowDVector<f64> = RowDVector::try_from(v.clone()).unwrap();
+
+    assert_eq!(RowDVector::from_vec(vec![1.0, 2.0, 3.0]), w);
+    assert_eq!(v, Vector::from(w));
+    assert!(RowDVector::try_from(vector![1.0; 2.0]).is_err());
+}
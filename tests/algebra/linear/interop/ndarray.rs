@@ -0,0 +1,58 @@
+use ::ndarray::{arr1, arr2, s, Array1, Array2, ArrayD, IxDyn};
+use mathru::algebra::linear::{Matrix, Vector};
+use std::convert::TryFrom;
+
+#[test]
+fn matrix_into_array2()
+{
+    let a: Matrix<f64> = matrix![   1.0, 2.0, 3.0;
+                                    4.0, 5.0, 6.0];
+
+    let b: Array2<f64> = Array2::from(&a);
+
+    assert_eq!(arr2(&[[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]), b);
+    assert_eq!(a, Matrix::from(b));
+}
+
+#[test]
+fn array2_into_matrix()
+{
+    let a: Array2<f64> = arr2(&[[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]]);
+
+    assert_eq!(matrix![1.0, 3.0, 5.0; 2.0, 4.0, 6.0], Matrix::from(a.t().to_owned()));
+    assert_eq!(matrix![4.0; 6.0], Matrix::from(a.slice(s![1.., 1..])));
+    assert_eq!(matrix![1.0, 2.0; 3.0, 4.0; 5.0, 6.0], Matrix::from(a));
+}
+
+#[test]
+fn sliced_array2_into_matrix()
+{
+    let mut a: Array2<f64> = Array2::from(matrix![  1.0, 2.0, 3.0;
+                                                    4.0, 5.0, 6.0;
+                                                    7.0, 8.0, 9.0]);
+    a.slice_collapse(s![.., 1..]);
+
+    assert_eq!(matrix![2.0, 3.0; 5.0, 6.0; 8.0, 9.0], Matrix::from(a));
+}
+
+#[test]
+fn arrayd_into_matrix()
+{
+    let a: ArrayD<f64> = ArrayD::from_shape_vec(IxDyn(&[1, 2]), vec![1.0, 2.0]).unwrap();
+    let b: ArrayD<f64> = ArrayD::zeros(IxDyn(&[2, 2, 2]));
+
+    assert_eq!(matrix![1.0, 2.0], Matrix::try_from(a).unwrap());
+    assert!(Matrix::<f64>::try_from(b).is_err());
+}
+
+#[test]
+fn vector_into_array1()
+{
+    let v: Vector<f64> = vector![1.0; 2.0; 3.0];
+
+    let w: Array1<f64> = Array1::from(v.clone());
+
+    assert_eq!(arr1(&[1.0, 2.0, 3.0]), w);
+    assert_eq!(v, Vector::from(w));
+    assert_eq!(vector![3.0; 1.0], Vector::from(arr1(&[1.0, 2.0, 3.0]).slice_move(s![..;-2])));
+}
@@ -0,0 +1,147 @@
+use mathru::{
+    algebra::linear::{matrix::Transpose, Matrix},
+    error::MathError,
+};
+
+#[test]
+fn hstack()
+{
+    let a: Matrix<f64> = matrix![   1.0, 2.0;
+                                    3.0, 4.0];
+    let b: Matrix<f64> = matrix![   5.0;
+                                    6.0];
+
+    let c: Matrix<f64> = Matrix::hstack(&[&a, &b, &a]).unwrap();
+
+    assert_eq!(matrix![ 1.0, 2.0, 5.0, 1.0, 2.0;
+                        3.0, 4.0, 6.0, 3.0, 4.0], c);
+}
+
+#[test]
+fn hstack_dimension_mismatch()
+{
+    let a: Matrix<f64> = Matrix::zero(2, 2);
+    let b: Matrix<f64> = Matrix::zero(3, 1);
+
+    assert_eq!(MathError::DimensionMismatch, Matrix::hstack(&[&a, &b]).unwrap_err());
+}
+
+#[test]
+fn vstack()
+{
+    let a: Matrix<f64> = matrix![   1.0, 2.0;
+                                    3.0, 4.0];
+    let b: Matrix<f64> = matrix![   5.0, 6.0];
+
+    let c: Matrix<f64> = Matrix::vstack(&[&a, &b]).unwrap();
+
+    assert_eq!(matrix![ 1.0, 2.0;
+                        3.0, 4.0;
+                        5.0, 6.0], c);
+}
+
+#[test]
+fn vstack_dimension_mismatch()
+{
+    let a: Matrix<f64> = Matrix::zero(2, 2);
+    let b: Matrix<f64> = Matrix::zero(2, 3);
+
+    assert_eq!(MathError::DimensionMismatch, Matrix::vstack(&[&a, &b]).unwrap_err());
+}
+
+#[test]
+fn block()
+{
+    let a: Matrix<f64> = matrix![   4.0, 1.0;
+                                    1.0, 3.0];
+    let b: Matrix<f64> = matrix![   1.0, 2.0];
+    let zero: Matrix<f64> = Matrix::zero(1, 1);
+
+    let k: Matrix<f64> = block![a, b.clone().transpose(); b, zero].unwrap();
+
+    assert_eq!(matrix![ 4.0, 1.0, 1.0;
+                        1.0, 3.0, 2.0;
+                        1.0, 2.0, 0.0], k);
+}
+
+#[test]
+fn block_uneven_rows()
+{
+    let a: Matrix<f64> = matrix![1.0];
+    let b: Matrix<f64> = matrix![2.0, 3.0];
+
+    let c: Matrix<f64> = block![a, a, a; b, a].unwrap();
+
+    assert_eq!(matrix![ 1.0, 1.0, 1.0;
+                        2.0, 3.0, 1.0], c);
+}
+
+#[test]
+fn block_dimension_mismatch()
+{
+    let a: Matrix<f64> = Matrix::zero(2, 2);
+    let b: Matrix<f64> = Matrix::zero(1, 3);
+
+    assert_eq!(MathError::DimensionMismatch, block![a; b].unwrap_err());
+    assert_eq!(MathError::DimensionMismatch, block![a, b].unwrap_err());
+}
+
+#[test]
+fn block_diag()
+{
+    let a: Matrix<f64> = matrix![   1.0, 2.0;
+                                    3.0, 4.0];
+    let b: Matrix<f64> = matrix![5.0];
+    let c: Matrix<f64> = matrix![6.0, 7.0];
+
+    let d: Matrix<f64> = Matrix::block_diag(&[&a, &b, &c]);
+
+    assert_eq!(matrix![ 1.0, 2.0, 0.0, 0.0, 0.0;
+                        3.0, 4.0, 0.0, 0.0, 0.0;
+                        0.0, 0.0, 5.0, 0.0, 0.0;
+                        0.0, 0.0, 0.0, 6.0, 7.0], d);
+}
+
+#[test]
+fn kron()
+{
+    let a: Matrix<f64> = matrix![   1.0, 2.0;
+                                    3.0, 4.0];
+    let b: Matrix<f64> = matrix![   0.0, 5.0;
+                                    6.0, 7.0];
+
+    assert_eq!(matrix![ 0.0, 5.0, 0.0, 10.0;
+                        6.0, 7.0, 12.0, 14.0;
+                        0.0, 15.0, 0.0, 20.0;
+                        18.0, 21.0, 24.0, 28.0], a.kron(&b));
+}
+
+#[test]
+fn kron_identity()
+{
+    let a: Matrix<f64> = matrix![   1.0, 2.0, 3.0];
+    let one: Matrix<f64> = Matrix::one(2);
+
+    assert_eq!(Matrix::block_diag(&[&a, &a]), one.kron(&a));
+}
+
+#[test]
+fn hadamard()
+{
+    let a: Matrix<f64> = matrix![   1.0, 2.0, 3.0;
+                                    4.0, 5.0, 6.0];
+    let b: Matrix<f64> = matrix![   -1.0, 0.5, 0.0;
+                                    2.0, 1.0, -2.0];
+
+    assert_eq!(matrix![ -1.0, 1.0, 0.0;
+                        8.0, 5.0, -12.0], a.hadamard(&b).unwrap());
+}
+
+#[test]
+fn hadamard_dimension_mismatch()
+{
+    let a: Matrix<f64> = Matrix::zero(2, 3);
+    let b: Matrix<f64> = Matrix::zero(3, 2);
+
+    assert_eq!(MathError::DimensionMismatch, a.hadamard(&b).unwrap_err());
+}
@@ -1,5 +1,6 @@
-use mathru::algebra::linear::Matrix;
+use mathru::algebra::linear::{matrix::{CholeskyDec, Transpose}, Matrix, Vector};
 use mathru::algebra::abstr::Complex;
+use mathru::error::MathError;
 use crate::mathru::algebra::abstr::Zero;
 
 #[test]
@@ -66,5 +67,71 @@ fn cholesky_complex_f64()
     assert_relative_eq!(g, g_ref, epsilon=Complex::new(1.0e-10, 1.0e-10));
 }
 
+#[test]
+fn cholesky_not_positive_definite()
+{
+    let a: Matrix<f64> = matrix![   1.0, 2.0;
+                                    2.0, 1.0];
+
+    assert_eq!(MathError::NotPositiveDefinite, a.dec_cholesky().unwrap_err());
+}
 
+#[test]
+fn update_f64()
+{
+    let a: Matrix<f64> = matrix![   4.0, 2.0, 0.0, 1.0;
+                                    2.0, 5.0, 2.0, 0.0;
+                                    0.0, 2.0, 5.0, 2.0;
+                                    1.0, 0.0, 2.0, 6.0];
+    let x: Vector<f64> = vector![1.0; -2.0; 0.5; 3.0];
+
+    let mut dec: CholeskyDec<f64> = a.dec_cholesky().unwrap();
+    dec.update(&x).unwrap();
+    let l: Matrix<f64> = dec.l();
+
+    let x_x: Matrix<f64> = &Matrix::from(x.clone()) * &Matrix::from(x.transpose());
+    assert_relative_eq!(&a + &x_x, &l * &l.clone().transpose(), epsilon=1.0e-10);
+}
 
+#[test]
+fn downdate_f64()
+{
+    let a: Matrix<f64> = matrix![   4.0, 2.0, 0.0, 1.0;
+                                    2.0, 5.0, 2.0, 0.0;
+                                    0.0, 2.0, 5.0, 2.0;
+                                    1.0, 0.0, 2.0, 6.0];
+    let x: Vector<f64> = vector![1.0; 0.5; -1.0; 1.0];
+
+    let mut dec: CholeskyDec<f64> = a.dec_cholesky().unwrap();
+    dec.downdate(&x).unwrap();
+    let l: Matrix<f64> = dec.l();
+
+    let x_x: Matrix<f64> = &Matrix::from(x.clone()) * &Matrix::from(x.transpose());
+    assert_relative_eq!(&a - &x_x, &l * &l.clone().transpose(), epsilon=1.0e-10);
+}
+
+#[test]
+fn downdate_not_positive_definite()
+{
+    let a: Matrix<f64> = matrix![   2.0, -1.0, 0.0;
+                                    -1.0, 2.0, -1.0;
+                                    0.0, -1.0,  2.0];
+    let x: Vector<f64> = vector![0.0; 2.0; 0.0];
+
+    let mut dec: CholeskyDec<f64> = a.dec_cholesky().unwrap();
+
+    assert_eq!(Err(MathError::NotPositiveDefinite), dec.downdate(&x));
+    assert_relative_eq!(a.dec_cholesky().unwrap().l(), dec.l(), epsilon=1.0e-10);
+}
+
+#[test]
+fn update_dimension_mismatch()
+{
+    let a: Matrix<f64> = matrix![   2.0, -1.0;
+                                    -1.0, 2.0];
+    let x: Vector<f64> = vector![1.0; 2.0; 3.0];
+
+    let mut dec: CholeskyDec<f64> = a.dec_cholesky().unwrap();
+
+    assert_eq!(Err(MathError::DimensionMismatch), dec.update(&x));
+}
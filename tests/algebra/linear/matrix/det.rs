@@ -84,4 +84,13 @@ fn determinant_complex_f64()
     assert_relative_eq!(Complex::new(-1.0, 16.0), det, epsilon=Complex::new(1.0e-5, 1.0e-5));
 }
 
+#[test]
+fn determinant_complex_3x3_f64()
+{
+    let a: Matrix<Complex<f64>> = matrix![  Complex::new(1.0, 2.0), Complex::new(0.5, -1.0), Complex::new(3.0, 0.0);
+                                            Complex::new(0.0, 1.0), Complex::new(2.0, 2.0), Complex::new(-1.0, 1.0);
+                                            Complex::new(4.0, -3.0), Complex::new(1.0, 0.0), Complex::new(0.0, -2.0)];
+    let det: Complex<f64> = a.det();
 
+    assert_relative_eq!(Complex::new(-21.5, 8.5), det, epsilon=Complex::new(1.0e-10, 1.0e-10));
+}
@@ -1,6 +1,12 @@
-use mathru::algebra::linear::{Matrix, Vector};
+use mathru::algebra::linear::{
+    matrix::{Inverse, Transpose},
+    Matrix, Vector,
+};
 use mathru::algebra::abstr::Complex;
+use mathru::analysis::Jacobian;
 use crate::mathru::algebra::abstr::cast::FromPrimitive;
+use mathru::error::MathError;
+use rand::{rngs::StdRng, Rng, SeedableRng};
 
 #[cfg(not(feature = "intel-mkl"))]
 #[test]
@@ -10,17 +16,17 @@ fn eigen_f32()
                                     3.0, -5.0,  3.0;
                                     6.0, -6.0,  4.0];
 
-    let eig_ref: Vector<f32> = vector![ 4.0;
-                                        -2.0;
-                                        -2.0];
+    let eig_ref: Vector<Complex<f32>> = vector![ Complex::new(4.0, 0.0);
+                                                Complex::new(-2.0, 0.0);
+                                                Complex::new(-2.0, 0.0)];
 
     // let eig_vector_ref: Matrix<f32> = matrix![  1.0, 1.0, 2.0;
     //                                             -1.0, 0.0, 1.0;
     //                                             1.0, 1.0, 0.0];
 
-    let value: Vector<f32> = a.dec_eigen().unwrap().value();
+    let value: Vector<Complex<f32>> = a.dec_eigen().unwrap().value();
 
-    assert_relative_eq!(value, eig_ref, epsilon=1.0e-5);
+    assert_relative_eq!(value, eig_ref, epsilon=Complex::new(1.0e-5, 1.0e-5));
     // assert_relative_eq!(vector, eig_vector_ref, epsilon=1.0e-5);
 }
 
@@ -31,17 +37,17 @@ fn eigen_f64()
                                     3.0, -5.0, 3.0;
                                     6.0, -6.0, 4.0];
 
-    let eig_ref: Vector<f64> = vector![ 4.0;
-                                        -2.0;
-                                        -2.0];
+    let eig_ref: Vector<Complex<f64>> = vector![ Complex::new(4.0, 0.0);
+                                                Complex::new(-2.0, 0.0);
+                                                Complex::new(-2.0, 0.0)];
 
     // let eig_vector_ref: Matrix<f64> = matrix![  1.0, 1.0, 2.0;
     //                                             -1.0, 0.0, 1.0;
     //                                             1.0, 1.0, 0.0];
 
-    let value: Vector<f64> = a.dec_eigen().unwrap().value();
+    let value: Vector<Complex<f64>> = a.dec_eigen().unwrap().value();
 
-    assert_relative_eq!(value, eig_ref, epsilon=1.0e-5);
+    assert_relative_eq!(value, eig_ref, epsilon=Complex::new(1.0e-5, 1.0e-5));
     // assert_relative_eq!(vector, eig_vector_ref, epsilon=1.0e-5);
 }
 
@@ -66,29 +72,6 @@ fn eigen_f64()
 //     // assert_relative_eq!(vector, eig_vector_ref, epsilon=1.0e-5);
 // }
 
-#[cfg(feature = "native")]
-#[test]
-fn eigen_complex_f32()
-{
-    let a: Matrix<Complex<f32>> = matrix![  Complex::from_f32(1.0), Complex::from_f32(-3.0), Complex::from_f32(3.0);
-                                            Complex::from_f32(3.0), Complex::from_f32(-5.0), Complex::from_f32(3.0);
-                                            Complex::from_f32(6.0), Complex::from_f32(-6.0), Complex::from_f32(4.0)];
-
-    let eig_value_ref: Vector<Complex<f32>> = vector![  Complex::from_f32(4.0);
-                                                        Complex::from_f32(-2.0);
-                                                        Complex::from_f32(-2.0)];
-
-    // let eig_vector_ref: Matrix<Complex<f32>> = matrix![ Complex::new(0.5773503, 0.0), Complex::new(0.5773502, 0.0), Complex::new(-0.45936325, -0.0);
-    //                                                     Complex::new(0.5773502, 0.0), Complex::new(-0.5773502, 0.0), Complex::new(0.864806, 0.0);
-    //                                                     Complex::new(-0.57735026, 0.0), Complex::new(0.57735026, 0.0), Complex::new(-0.20272121, 0.0)];
-
-    let value: Vector<Complex<f32>> = a.dec_eigen().unwrap().value();
-
-    assert_relative_eq!(value, eig_value_ref, epsilon=Complex::new(2.0e-5, 2.0e-5));
-    // assert_relative_eq!(vector, eig_vector_ref, epsilon=Complex::new(2.0e-5, 2.0e-5));
-}
-
-#[cfg(feature = "lapack")]
 #[test]
 fn eigen_complex_f32()
 {
@@ -152,3 +135,298 @@ fn eigen_complex_f64()
 //     assert_relative_eq!(vector, eig_vector_ref, epsilon=Complex::new(1.0e-5, 1.0e-5));
 // }
 
+
+#[test]
+fn eigen_symmetric_f64()
+{
+    let a: Matrix<f64> = matrix![   4.0, 1.0, -2.0, 2.0;
+                                    1.0, 2.0, 0.0, 1.0;
+                                    -2.0, 0.0, 3.0, -2.0;
+                                    2.0, 1.0, -2.0, -1.0];
+
+    let (value, vector): (Vector<f64>, Matrix<f64>) = a.dec_eigen_symmetric().unwrap().pair();
+
+    let value_ref: Vector<f64> = vector![   -2.1975169774394265;
+                                            1.0843644637732168;
+                                            2.268531406431242;
+                                            6.8446211072349605];
+
+    assert_relative_eq!(value_ref, value, epsilon=1.0e-10);
+
+    // orthonormal eigenvectors
+    assert_relative_eq!(Matrix::one(4), &vector.clone().transpose() * &vector, epsilon=1.0e-10);
+
+    for i in 0..4
+    {
+        let x: Vector<f64> = vector.get_column(i);
+        assert_relative_eq!(&a * &x, &x * value.get(i), epsilon=1.0e-10);
+    }
+}
+
+#[test]
+fn eigen_symmetric_f32()
+{
+    let a: Matrix<f32> = matrix![   2.0, -1.0, 0.0;
+                                    -1.0, 2.0, -1.0;
+                                    0.0, -1.0, 2.0];
+
+    let value: Vector<f32> = a.dec_eigen_symmetric().unwrap().value();
+
+    let sqrt_2: f32 = 2.0f32.sqrt();
+    assert_relative_eq!(vector![2.0 - sqrt_2; 2.0; 2.0 + sqrt_2], value, epsilon=1.0e-5);
+}
+
+#[test]
+fn eigen_symmetric_diagonal()
+{
+    let a: Matrix<f64> = matrix![   3.0, 0.0, 0.0;
+                                    0.0, -1.0, 0.0;
+                                    0.0, 0.0, 2.0];
+
+    let (value, vector): (Vector<f64>, Matrix<f64>) = a.dec_eigen_symmetric().unwrap().pair();
+
+    let vector_ref: Matrix<f64> = matrix![  0.0, 0.0, 1.0;
+                                            1.0, 0.0, 0.0;
+                                            0.0, 1.0, 0.0];
+
+    assert_relative_eq!(vector![-1.0; 2.0; 3.0], value);
+    assert_relative_eq!(vector_ref, vector.apply(&|x: &f64| x.abs()));
+}
+
+#[test]
+fn eigen_symmetric_repeated_eigenvalue()
+{
+    let a: Matrix<f64> = matrix![   2.0, 1.0, 1.0;
+                                    1.0, 2.0, 1.0;
+                                    1.0, 1.0, 2.0];
+
+    let (value, vector): (Vector<f64>, Matrix<f64>) = a.dec_eigen_symmetric().unwrap().pair();
+
+    assert_relative_eq!(vector![1.0; 1.0; 4.0], value, epsilon=1.0e-10);
+    assert_relative_eq!(Matrix::one(3), &vector.clone().transpose() * &vector, epsilon=1.0e-10);
+}
+
+#[test]
+fn eigen_symmetric_not_finite()
+{
+    let a: Matrix<f64> = matrix![   2.0, std::f64::NAN;
+                                    std::f64::NAN, 2.0];
+
+    assert_eq!(MathError::NotFinite, a.dec_eigen_symmetric().unwrap_err());
+}
+
+fn complexify(a: &Matrix<f64>) -> Matrix<Complex<f64>>
+{
+    let (m, n): (usize, usize) = a.dim();
+    let data: Vec<Complex<f64>> = a.clone().convert_to_vec().iter().map(|a_ij| Complex::new(*a_ij, 0.0)).collect();
+    return Matrix::new(m, n, data);
+}
+
+fn assert_eigen_pairs(a: &Matrix<f64>, value: &Vector<Complex<f64>>, vector: &Matrix<Complex<f64>>)
+{
+    assert_eigen_pairs_complex(&complexify(a), value, vector);
+}
+
+fn assert_eigen_pairs_complex(a_complex: &Matrix<Complex<f64>>, value: &Vector<Complex<f64>>, vector: &Matrix<Complex<f64>>)
+{
+    let (n, _): (usize, usize) = a_complex.dim();
+
+    for i in 0..n
+    {
+        let x: Vector<Complex<f64>> = vector.get_column(i);
+        let x_norm: f64 = x.iter().fold(0.0, |sum, x_i| sum + x_i.re * x_i.re + x_i.im * x_i.im);
+
+        assert_relative_eq!(1.0, x_norm, epsilon=1.0e-10);
+        assert_relative_eq!(a_complex * &x, &x * value.get(i), epsilon=Complex::new(1.0e-10, 1.0e-10));
+    }
+}
+
+#[test]
+fn eigen_rotation()
+{
+    let phi: f64 = 0.3;
+    let a: Matrix<f64> = matrix![   phi.cos(), -phi.sin();
+                                    phi.sin(), phi.cos()];
+
+    let (value, vector): (Vector<Complex<f64>>, Matrix<Complex<f64>>) = a.clone().dec_eigen().unwrap().pair();
+
+    let value_ref: Vector<Complex<f64>> = vector![  Complex::new(phi.cos(), phi.sin());
+                                                    Complex::new(phi.cos(), -phi.sin())];
+
+    assert_relative_eq!(value_ref, value, epsilon=Complex::new(1.0e-10, 1.0e-10));
+    assert_eigen_pairs(&a, &value, &vector);
+}
+
+#[test]
+fn eigen_complex_and_real_eigenvalues()
+{
+    // eigenvalues: -1, 2, 1 + 2i, 1 - 2i
+    let d: Matrix<f64> = matrix![   -1.0, 0.0, 0.0, 0.0;
+                                    0.0, 1.0, -2.0, 0.0;
+                                    0.0, 2.0, 1.0, 0.0;
+                                    0.0, 0.0, 0.0, 2.0];
+    let s: Matrix<f64> = matrix![   1.0, 2.0, 0.0, 1.0;
+                                    0.0, 1.0, 3.0, -1.0;
+                                    2.0, 0.0, 1.0, 0.0;
+                                    1.0, 1.0, 0.0, 2.0];
+    let s_inv: Matrix<f64> = s.inv().unwrap();
+    let a: Matrix<f64> = &(&s * &d) * &s_inv;
+
+    let (value, vector): (Vector<Complex<f64>>, Matrix<Complex<f64>>) = a.clone().dec_eigen().unwrap().pair();
+
+    let mut value_sorted: Vec<Complex<f64>> = value.clone().convert_to_vec();
+    value_sorted.sort_by(|a, b| (a.re, a.im).partial_cmp(&(b.re, b.im)).unwrap());
+    let value_ref: Vector<Complex<f64>> = vector![  Complex::new(-1.0, 0.0);
+                                                    Complex::new(1.0, -2.0);
+                                                    Complex::new(1.0, 2.0);
+                                                    Complex::new(2.0, 0.0)];
+
+    assert_relative_eq!(value_ref, Vector::new_column(4, value_sorted), epsilon=Complex::new(1.0e-10, 1.0e-10));
+    assert_eigen_pairs(&a, &value, &vector);
+}
+
+#[test]
+fn eigen_vector_f64()
+{
+    let a: Matrix<f64> = matrix![   1.0, 2.0, 3.0;
+                                    4.0, 5.0, 6.0;
+                                    7.0, 8.0, 10.0];
+
+    let (value, vector): (Vector<Complex<f64>>, Matrix<Complex<f64>>) = a.clone().dec_eigen().unwrap().pair();
+
+    assert_eigen_pairs(&a, &value, &vector);
+}
+
+#[test]
+fn eigen_complex_symmetric()
+{
+    // complex symmetric, but not hermitian, eigenvalues 1 + i and 1 - i
+    let a: Matrix<Complex<f64>> = matrix![  Complex::new(1.0, 0.0), Complex::new(0.0, 1.0);
+                                            Complex::new(0.0, 1.0), Complex::new(1.0, 0.0)];
+
+    let (value, vector): (Vector<Complex<f64>>, Matrix<Complex<f64>>) = a.clone().dec_eigen().unwrap().pair();
+
+    let mut value_sorted: Vec<Complex<f64>> = value.clone().convert_to_vec();
+    value_sorted.sort_by(|a, b| a.im.partial_cmp(&b.im).unwrap());
+    assert_relative_eq!(vector![Complex::new(1.0, -1.0); Complex::new(1.0, 1.0)], Vector::new_column(2, value_sorted), epsilon=Complex::new(1.0e-10, 1.0e-10));
+    assert_eigen_pairs_complex(&a, &value, &vector);
+}
+
+#[test]
+fn eigen_complex_random()
+{
+    let mut rng: StdRng = StdRng::seed_from_u64(5);
+    let n: usize = 6;
+    let data: Vec<Complex<f64>> = (0..n * n).map(|_| Complex::new(rng.gen_range(-0.5..0.5), rng.gen_range(-0.5..0.5))).collect();
+    let a: Matrix<Complex<f64>> = Matrix::new(n, n, data);
+
+    let (value, vector): (Vector<Complex<f64>>, Matrix<Complex<f64>>) = a.clone().dec_eigen().unwrap().pair();
+
+    // the spectral radius is bounded by the Frobenius norm
+    let norm_f: f64 = a.iter().fold(0.0, |sum, a_ij| sum + a_ij.re * a_ij.re + a_ij.im * a_ij.im).sqrt();
+    assert!(value.iter().all(|lambda| (lambda.re * lambda.re + lambda.im * lambda.im).sqrt() <= norm_f));
+    assert_eigen_pairs_complex(&a, &value, &vector);
+}
+
+#[test]
+fn eigen_complex_non_normal()
+{
+    let a: Matrix<Complex<f64>> = matrix![  Complex::new(1.0, 0.0), Complex::new(0.0, 2.0), Complex::new(3.0, 0.0);
+                                            Complex::new(0.5, 0.0), Complex::new(3.0, -1.0), Complex::new(0.0, 0.0);
+                                            Complex::new(0.0, -1.0), Complex::new(1.0, 1.0), Complex::new(-2.0, 0.5)];
+
+    let (value, vector): (Vector<Complex<f64>>, Matrix<Complex<f64>>) = a.clone().dec_eigen().unwrap().pair();
+
+    assert_eigen_pairs_complex(&a, &value, &vector);
+}
+
+#[test]
+fn eigen_complex_triangular()
+{
+    let a: Matrix<Complex<f64>> = matrix![  Complex::new(2.0, 1.0), Complex::new(1.0, -1.0), Complex::new(0.0, 3.0);
+                                            Complex::new(0.0, 0.0), Complex::new(-1.0, 0.5), Complex::new(2.0, 0.0);
+                                            Complex::new(0.0, 0.0), Complex::new(0.0, 0.0), Complex::new(0.5, -2.0)];
+
+    let (value, vector): (Vector<Complex<f64>>, Matrix<Complex<f64>>) = a.clone().dec_eigen().unwrap().pair();
+
+    assert_relative_eq!(vector![Complex::new(2.0, 1.0); Complex::new(-1.0, 0.5); Complex::new(0.5, -2.0)], value, epsilon=Complex::new(1.0e-10, 1.0e-10));
+    assert_eigen_pairs_complex(&a, &value, &vector);
+}
+
+struct DampedOscillator
+{
+    damping: f64,
+    stiffness: f64,
+}
+
+impl Jacobian<f64> for DampedOscillator
+{
+    fn jacobian(self: &Self, _x: &Vector<f64>) -> Matrix<f64>
+    {
+        return matrix![ 0.0, 1.0;
+                        -self.stiffness, -self.damping];
+    }
+}
+
+#[test]
+fn eigen_stability_linearised_ode()
+{
+    let oscillator: DampedOscillator = DampedOscillator { damping: 0.5, stiffness: 4.0 };
+    let j: Matrix<f64> = oscillator.jacobian(&vector![0.0; 0.0]);
+
+    let value: Vector<Complex<f64>> = j.dec_eigen().unwrap().value();
+
+    // -0.25 +- i sqrt(4 - 0.0625)
+    let im: f64 = (4.0f64 - 0.0625).sqrt();
+    assert_relative_eq!(vector![Complex::new(-0.25, im); Complex::new(-0.25, -im)], value, epsilon=Complex::new(1.0e-10, 1.0e-10));
+    assert!(value.iter().all(|lambda| lambda.re < 0.0));
+}
+
+#[test]
+fn eigenvector_r_zero_matrix()
+{
+    let a: Matrix<f64> = Matrix::zero(3, 3);
+
+    let vector: Matrix<f64> = a.eigenvector_r(&vector![0.0; 0.0; 0.0]).unwrap();
+
+    for i in 0..3
+    {
+        let x: Vector<f64> = vector.get_column(i);
+        assert_relative_eq!(1.0, x.iter().fold(0.0f64, |max, x_i| max.max(x_i.abs())), epsilon=1.0e-10);
+    }
+}
+
+#[test]
+fn eigenvector_r_residual()
+{
+    let a: Matrix<f64> = matrix![   1.0, -3.0, 3.0;
+                                    3.0, -5.0, 3.0;
+                                    6.0, -6.0, 4.0];
+    let value: Vector<f64> = vector![4.0; -2.0];
+
+    let vector: Matrix<f64> = a.eigenvector_r(&value).unwrap();
+
+    for i in 0..2
+    {
+        let x: Vector<f64> = vector.get_column(i);
+        assert_relative_eq!(1.0, x.iter().fold(0.0f64, |max, x_i| max.max(x_i.abs())), epsilon=1.0e-10);
+        assert_relative_eq!(&a * &x, &x * value.get(i), epsilon=1.0e-8);
+    }
+}
+
+#[test]
+fn eigenvector_r_triangular()
+{
+    let a: Matrix<f64> = matrix![   2.0, 1.0, 0.5;
+                                    0.0, -1.0, 3.0;
+                                    0.0, 0.0, 0.5];
+    let value: Vector<f64> = vector![2.0; -1.0; 0.5];
+
+    let vector: Matrix<f64> = a.eigenvector_r(&value).unwrap();
+
+    for i in 0..3
+    {
+        let x: Vector<f64> = vector.get_column(i);
+        assert_relative_eq!(&a * &x, &x * value.get(i), epsilon=1.0e-8);
+    }
+}
@@ -0,0 +1,217 @@
+use mathru::algebra::{abstr::Complex, linear::Matrix};
+use mathru::elementary::{Exponential, Trigonometry};
+use mathru::error::MathError;
+
+#[test]
+fn expm_zero()
+{
+    let a: Matrix<f64> = Matrix::zero(3, 3);
+
+    assert_relative_eq!(Matrix::one(3), a.expm().unwrap(), epsilon=1.0e-14);
+}
+
+#[test]
+fn expm_not_finite()
+{
+    let a: Matrix<f64> = matrix![   1.0, std::f64::INFINITY;
+                                    0.0, 1.0];
+    let b: Matrix<f64> = matrix![   1.0, std::f64::NAN;
+                                    0.0, 1.0];
+
+    assert_eq!(MathError::NotFinite, a.expm().unwrap_err());
+    assert_eq!(MathError::NotFinite, b.expm().unwrap_err());
+}
+
+#[test]
+fn expm_diagonal()
+{
+    let a: Matrix<f64> = matrix![   1.0, 0.0;
+                                    0.0, -2.0];
+    let exp_a_ref: Matrix<f64> = matrix![   1.0f64.exp(), 0.0;
+                                            0.0, (-2.0f64).exp()];
+
+    assert_relative_eq!(exp_a_ref, a.expm().unwrap(), epsilon=1.0e-12);
+}
+
+#[test]
+fn expm_rotation()
+{
+    let theta: f64 = 0.7;
+    let a: Matrix<f64> = matrix![   0.0, -theta;
+                                    theta, 0.0];
+    let exp_a_ref: Matrix<f64> = matrix![   theta.cos(), -theta.sin();
+                                            theta.sin(), theta.cos()];
+
+    assert_relative_eq!(exp_a_ref, a.expm().unwrap(), epsilon=1.0e-12);
+}
+
+#[test]
+fn expm_scaling_and_squaring()
+{
+    // exp([a c; 0 b]) = [e^a c (e^b - e^a) / (b - a); 0 e^b]
+    let a: Matrix<f64> = matrix![   5.0, 10.0;
+                                    0.0, 15.0];
+    let e_5: f64 = 5.0f64.exp();
+    let e_15: f64 = 15.0f64.exp();
+    let exp_a_ref: Matrix<f64> = matrix![   e_5, (e_15 - e_5);
+                                            0.0, e_15];
+
+    assert_relative_eq!(exp_a_ref, a.expm().unwrap(), epsilon=1.0e-10, max_relative=1.0e-12);
+}
+
+#[test]
+fn expm_f32()
+{
+    let a: Matrix<f32> = matrix![   0.0, 1.0;
+                                    -1.0, 0.0];
+    let exp_a_ref: Matrix<f32> = matrix![   1.0f32.cos(), 1.0f32.sin();
+                                            -1.0f32.sin(), 1.0f32.cos()];
+
+    assert_relative_eq!(exp_a_ref, a.expm().unwrap(), epsilon=1.0e-5);
+}
+
+#[test]
+fn sqrtm()
+{
+    let a: Matrix<f64> = matrix![   4.0, -1.0, 0.0;
+                                    1.0, 4.0, 0.0;
+                                    2.0, 1.0, 9.0];
+
+    let x: Matrix<f64> = a.sqrtm().unwrap();
+
+    assert_relative_eq!(a, &x * &x, epsilon=1.0e-10);
+}
+
+#[test]
+fn sqrtm_symmetric()
+{
+    let a: Matrix<f64> = matrix![   2.0, 1.0;
+                                    1.0, 2.0];
+    let s: f64 = 3.0f64.sqrt();
+    let x_ref: Matrix<f64> = matrix![   (s + 1.0) / 2.0, (s - 1.0) / 2.0;
+                                        (s - 1.0) / 2.0, (s + 1.0) / 2.0];
+
+    assert_relative_eq!(x_ref, a.sqrtm().unwrap(), epsilon=1.0e-10);
+}
+
+#[test]
+fn sqrtm_negative_eigenvalue()
+{
+    let a: Matrix<f64> = matrix![   -1.0, 0.0;
+                                    0.0, 4.0];
+
+    assert!(a.sqrtm().is_err());
+}
+
+#[test]
+fn logm()
+{
+    let a: Matrix<f64> = matrix![   4.0, -1.0, 0.0;
+                                    1.0, 4.0, 0.0;
+                                    2.0, 1.0, 9.0];
+
+    let x: Matrix<f64> = a.logm().unwrap();
+
+    assert_relative_eq!(a, x.expm().unwrap(), epsilon=1.0e-10);
+}
+
+#[test]
+fn logm_expm()
+{
+    let a: Matrix<f64> = matrix![   0.5, -0.2, 0.1;
+                                    0.3, 0.1, -0.4;
+                                    0.0, 0.2, -0.3];
+
+    assert_relative_eq!(a, a.expm().unwrap().logm().unwrap(), epsilon=1.0e-10);
+}
+
+#[test]
+fn logm_singular()
+{
+    let a: Matrix<f64> = matrix![   1.0, 2.0;
+                                    2.0, 4.0];
+
+    assert!(a.logm().is_err());
+}
+
+#[test]
+fn logm_negative_eigenvalue()
+{
+    let a: Matrix<f64> = matrix![   -2.0, 0.0;
+                                    0.0, 1.0];
+
+    assert!(a.logm().is_err());
+}
+
+#[test]
+fn funm_exp()
+{
+    let a: Matrix<f64> = matrix![   1.0, 2.0, 0.0;
+                                    -1.0, 0.5, 0.3;
+                                    0.2, 0.0, -1.0];
+
+    let exp_a: Matrix<f64> = a.funm(|z: Complex<f64>| z.exp()).unwrap();
+
+    assert_relative_eq!(a.expm().unwrap(), exp_a, epsilon=1.0e-10);
+}
+
+#[test]
+fn funm_cos()
+{
+    let theta: f64 = 0.3;
+    let a: Matrix<f64> = matrix![   theta, 0.0;
+                                    0.0, -theta];
+
+    let cos_a: Matrix<f64> = a.funm(|z: Complex<f64>| z.cos()).unwrap();
+
+    assert_relative_eq!(&Matrix::one(2) * &theta.cos(), cos_a, epsilon=1.0e-10);
+}
+
+#[test]
+fn funm_repeated_eigenvalues()
+{
+    let a: Matrix<f64> = Matrix::one(3);
+
+    let exp_a: Matrix<f64> = a.funm(|z: Complex<f64>| z.exp()).unwrap();
+    assert_relative_eq!(&Matrix::one(3) * &1.0f64.exp(), exp_a, epsilon=1.0e-10);
+
+    let jordan: Matrix<f64> = matrix![  2.0, 1.0;
+                                        0.0, 2.0];
+    assert!(jordan.funm(|z: Complex<f64>| z.exp()).is_err());
+}
+
+#[test]
+fn powm_integer()
+{
+    let a: Matrix<f64> = matrix![   1.0, 2.0;
+                                    3.0, 4.0];
+
+    assert_relative_eq!(Matrix::one(2), a.powm(0.0).unwrap(), epsilon=1.0e-10);
+    assert_relative_eq!(&(&a * &a) * &a, a.powm(3.0).unwrap(), epsilon=1.0e-10);
+
+    let a_inv_2: Matrix<f64> = a.powm(-2.0).unwrap();
+    assert_relative_eq!(Matrix::one(2), &a_inv_2 * &(&a * &a), epsilon=1.0e-10);
+}
+
+#[test]
+fn powm_real()
+{
+    let a: Matrix<f64> = matrix![   4.0, -1.0, 0.0;
+                                    1.0, 4.0, 0.0;
+                                    2.0, 1.0, 9.0];
+
+    let x: Matrix<f64> = a.powm(1.0 / 3.0).unwrap();
+
+    assert_relative_eq!(a, &(&x * &x) * &x, epsilon=1.0e-9);
+    assert_relative_eq!(a.sqrtm().unwrap(), a.powm(0.5).unwrap(), epsilon=1.0e-9);
+}
+
+#[test]
+fn schur_based_non_square()
+{
+    let a: Matrix<f64> = Matrix::zero(2, 3);
+
+    assert_eq!(MathError::DimensionMismatch, a.sqrtm().unwrap_err());
+    assert_eq!(MathError::DimensionMismatch, a.logm().unwrap_err());
+    assert_eq!(MathError::DimensionMismatch, a.funm(|z: Complex<f64>| z.exp()).unwrap_err());
+}
@@ -72,6 +72,23 @@ fn dec_f64_2()
     assert_relative_eq!(&(&q * &h) * &q.transpose(), a, epsilon=1.0e-10);
 }
 
+#[test]
+fn dec_f64_4x4()
+{
+    let a: Matrix<f64> = matrix![   4.0, 1.0, -2.0, 2.0;
+                                    1.0, 2.0, 0.0, -2.0;
+                                    0.0, 3.0, -2.0, 2.0;
+                                    2.0, 1.0, -2.0, -1.0];
+
+    let (q, h): (Matrix<f64>, Matrix<f64>) = a.dec_hessenberg().qh();
+
+    assert_relative_eq!(0.0, *h.get(2, 0), epsilon=1.0e-10);
+    assert_relative_eq!(0.0, *h.get(3, 0), epsilon=1.0e-10);
+    assert_relative_eq!(0.0, *h.get(3, 1), epsilon=1.0e-10);
+    assert_relative_eq!(Matrix::one(4), &q.clone().transpose() * &q, epsilon=1.0e-10);
+    assert_relative_eq!(&(&q * &h) * &q.transpose(), a, epsilon=1.0e-10);
+}
+
 #[test]
 fn dec_complex_f32()
 {
@@ -101,3 +101,16 @@ fn inv_2()
 
     assert_relative_eq!(a_inv, a_inv_ref, epsilon=1.0e-10);
 }
+
+#[test]
+fn inv_complex_imaginary_f64()
+{
+    let a: Matrix<Complex<f64>> = matrix![  Complex::new(1.0, 2.0), Complex::new(0.5, -1.0), Complex::new(3.0, 0.0);
+                                            Complex::new(0.0, 1.0), Complex::new(2.0, 2.0), Complex::new(-1.0, 1.0);
+                                            Complex::new(4.0, -3.0), Complex::new(1.0, 0.0), Complex::new(0.0, -2.0)];
+
+    let a_inv: Matrix<Complex<f64>> = a.inv().unwrap();
+
+    assert_relative_eq!(Matrix::one(3), &a * &a_inv, epsilon=Complex::new(1.0e-10, 1.0e-10));
+    assert_relative_eq!(Matrix::one(3), &a_inv * &a, epsilon=Complex::new(1.0e-10, 1.0e-10));
+}
@@ -0,0 +1,113 @@
+use mathru::algebra::linear::{
+    matrix::{LDLDec, Solve, Transpose},
+    Matrix, Vector,
+};
+use mathru::error::MathError;
+
+fn kkt() -> Matrix<f64>
+{
+    matrix![    4.0, 1.0, 0.0, 1.0, 2.0;
+                1.0, 3.0, 1.0, 0.0, 1.0;
+                0.0, 1.0, 2.0, 1.0, -1.0;
+                1.0, 0.0, 1.0, 0.0, 0.0;
+                2.0, 1.0, -1.0, 0.0, 0.0]
+}
+
+#[test]
+fn dec_kkt()
+{
+    let a: Matrix<f64> = kkt();
+
+    let (l, d, p): (Matrix<f64>, Matrix<f64>, Matrix<f64>) = a.dec_ldl().unwrap().ldp();
+
+    for i in 0..5
+    {
+        assert_eq!(1.0, *l.get(i, i));
+        for j in (i + 1)..5
+        {
+            assert_eq!(0.0, *l.get(i, j));
+        }
+    }
+    assert_relative_eq!(&(&p * &a) * &p.transpose(), &(&l * &d) * &l.transpose(), epsilon=1.0e-10);
+}
+
+#[test]
+fn dec_two_by_two_pivot()
+{
+    let a: Matrix<f64> = matrix![   0.0, 1.0, 2.0;
+                                    1.0, 0.0, 3.0;
+                                    2.0, 3.0, 0.0];
+
+    let (l, d, p): (Matrix<f64>, Matrix<f64>, Matrix<f64>) = a.dec_ldl().unwrap().ldp();
+
+    assert!(*d.get(1, 0) != 0.0 || *d.get(2, 1) != 0.0);
+    assert_relative_eq!(&(&p * &a) * &p.transpose(), &(&l * &d) * &l.transpose(), epsilon=1.0e-10);
+}
+
+#[test]
+fn solve_kkt()
+{
+    let a: Matrix<f64> = kkt();
+    let x_ref: Vector<f64> = vector![1.0; -2.0; 3.0; 0.5; -1.0];
+    let b: Vector<f64> = &a * &x_ref;
+
+    let ldl: LDLDec<f64> = a.dec_ldl().unwrap();
+
+    assert_relative_eq!(x_ref, ldl.solve(&b).unwrap(), epsilon=1.0e-10);
+}
+
+#[test]
+fn solve_matrix_kkt()
+{
+    let a: Matrix<f64> = kkt();
+    let x_ref: Matrix<f64> = matrix![   1.0, 0.0;
+                                        -2.0, 1.0;
+                                        3.0, 2.0;
+                                        0.5, -1.0;
+                                        -1.0, 4.0];
+    let b: Matrix<f64> = &a * &x_ref;
+
+    let ldl: LDLDec<f64> = a.dec_ldl().unwrap();
+
+    assert_relative_eq!(x_ref, ldl.solve(&b).unwrap(), epsilon=1.0e-10);
+}
+
+#[test]
+fn inertia_kkt()
+{
+    let a: Matrix<f64> = kkt();
+
+    assert_eq!((3, 2, 0), a.dec_ldl().unwrap().inertia());
+}
+
+#[test]
+fn det_kkt()
+{
+    let a: Matrix<f64> = kkt();
+
+    assert_relative_eq!(a.det(), a.dec_ldl().unwrap().det(), epsilon=1.0e-10);
+}
+
+#[test]
+fn singular()
+{
+    let a: Matrix<f64> = matrix![   1.0, 2.0, 3.0;
+                                    2.0, 4.0, 6.0;
+                                    3.0, 6.0, -1.0];
+    let b: Vector<f64> = vector![1.0; 2.0; 3.0];
+
+    let ldl: LDLDec<f64> = a.dec_ldl().unwrap();
+
+    assert_eq!((1, 1, 1), ldl.inertia());
+    assert_relative_eq!(0.0, ldl.det(), epsilon=1.0e-10);
+    assert!(matches!(ldl.solve(&b), Err(MathError::Singular { .. })));
+}
+
+#[test]
+fn non_square()
+{
+    let a: Matrix<f64> = matrix![   1.0, 2.0, 3.0;
+                                    2.0, 4.0, 6.0];
+
+    assert!(matches!(a.dec_ldl(), Err(MathError::DimensionMismatch)));
+}
@@ -0,0 +1,81 @@
+use mathru::algebra::linear::{matrix::LstsqResult, Matrix, Vector};
+
+#[test]
+fn lstsq_square()
+{
+    let a: Matrix<f64> = matrix![   6.0, 2.0, -1.0;
+                                    -3.0, 5.0, 3.0;
+                                    -2.0, 1.0, 3.0];
+    let b: Vector<f64> = vector![48.0; 49.0; 24.0];
+
+    let result: LstsqResult<f64> = a.lstsq(&b).unwrap();
+
+    assert_eq!(3, result.rank());
+    assert_relative_eq!(0.0, result.residual(), epsilon=1.0e-10);
+    assert_relative_eq!(vector![7.0; 8.0; 10.0], result.x(), epsilon=1.0e-10);
+}
+
+#[test]
+fn lstsq_overdetermined()
+{
+    let a: Matrix<f64> = matrix![   1.0, 1.0;
+                                    1.0, 2.0;
+                                    1.0, 3.0;
+                                    1.0, 4.0];
+    let b: Vector<f64> = vector![6.0; 5.0; 7.0; 10.0];
+
+    let result: LstsqResult<f64> = a.lstsq(&b).unwrap();
+
+    assert_eq!(2, result.rank());
+    assert_eq!((2, 1), result.singular_values().dim());
+    assert_relative_eq!(4.2f64.sqrt(), result.residual(), epsilon=1.0e-10);
+    assert_relative_eq!(vector![3.5; 1.4], result.x(), epsilon=1.0e-10);
+}
+
+#[test]
+fn lstsq_underdetermined()
+{
+    // the minimum norm solution of x_0 + x_1 = 2 is [1, 1]
+    let a: Matrix<f64> = matrix![1.0, 1.0];
+    let b: Vector<f64> = vector![2.0];
+
+    let result: LstsqResult<f64> = a.lstsq(&b).unwrap();
+
+    assert_eq!(1, result.rank());
+    assert_relative_eq!(0.0, result.residual(), epsilon=1.0e-10);
+    assert_relative_eq!(vector![1.0; 1.0], result.x(), epsilon=1.0e-10);
+}
+
+#[test]
+fn lstsq_rank_deficient()
+{
+    // the second column is twice the first one
+    let a: Matrix<f64> = matrix![   1.0, 2.0;
+                                    1.0, 2.0;
+                                    1.0, 2.0];
+    let b: Vector<f64> = vector![1.0; 2.0; 3.0];
+
+    let result: LstsqResult<f64> = a.lstsq(&b).unwrap();
+
+    assert_eq!(1, result.rank());
+    assert_relative_eq!(15.0f64.sqrt(), *result.singular_values().get(0), epsilon=1.0e-10);
+    assert_relative_eq!(0.0, *result.singular_values().get(1), epsilon=1.0e-10);
+    assert_relative_eq!(2.0f64.sqrt(), result.residual(), epsilon=1.0e-10);
+    // x_0 + 2 x_1 = 2 with minimal norm
+    assert_relative_eq!(vector![0.4; 0.8], result.x(), epsilon=1.0e-10);
+}
+
+#[test]
+fn lstsq_agrees_with_pinv()
+{
+    let a: Matrix<f64> = matrix![   1.0, 2.0, 3.0;
+                                    4.0, 5.0, 6.0;
+                                    7.0, 8.0, 9.0;
+                                    1.0, 0.0, 1.0];
+    let b: Vector<f64> = vector![1.0; -1.0; 2.0; 0.5];
+
+    let x: Vector<f64> = a.lstsq(&b).unwrap().x();
+    let x_pinv: Vector<f64> = &a.pinv().unwrap() * &b;
+
+    assert_relative_eq!(x_pinv, x, epsilon=1.0e-10);
+}
@@ -3,6 +3,7 @@ use mathru::algebra::linear::{
     Matrix, Vector,
 };
 use mathru::algebra::abstr::Complex;
+use mathru::error::MathError;
 use crate::mathru::algebra::abstr::Zero;
 
 #[test]
@@ -257,3 +258,25 @@ fn inv_2()
 
     assert_relative_eq!(a_inv, a_inv_ref, epsilon=1.0e-10);
 }
+
+#[test]
+fn dec_singular()
+{
+    let a: Matrix<f64> = matrix![   1.0, 2.0, 3.0;
+                                    2.0, 4.0, 6.0;
+                                    1.0, 0.0, 1.0];
+
+    assert_eq!(MathError::Singular { pivot: 2 }, a.dec_lu().unwrap_err());
+}
+
+#[test]
+fn dec_complex_imaginary_f64()
+{
+    let a: Matrix<Complex<f64>> = matrix![  Complex::new(1.0, 2.0), Complex::new(0.5, -1.0), Complex::new(3.0, 0.0);
+                                            Complex::new(0.0, 1.0), Complex::new(2.0, 2.0), Complex::new(-1.0, 1.0);
+                                            Complex::new(4.0, -3.0), Complex::new(1.0, 0.0), Complex::new(0.0, -2.0)];
+
+    let (l, u, p): (Matrix<Complex<f64>>, Matrix<Complex<f64>>, Matrix<Complex<f64>>) = a.dec_lu().unwrap().lup();
+
+    assert_relative_eq!(&p * &a, &l * &u, epsilon=Complex::new(1.0e-10, 1.0e-10));
+}
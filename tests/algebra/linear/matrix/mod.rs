@@ -1,15 +1,23 @@
 mod add;
+mod block;
 mod transpose;
 mod cholesky;
 mod eigen;
+mod function;
 mod hessenberg;
 mod inverse;
+mod ldl;
 mod lu;
+mod lstsq;
 mod matrix;
 mod mul;
+mod norm;
 mod qr;
+mod qz;
+mod schur;
 mod sub;
 mod iterator;
 mod solve;
 mod det;
 mod singular;
+mod view;
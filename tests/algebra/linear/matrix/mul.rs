@@ -1,5 +1,5 @@
 
-use mathru::algebra::linear::{Matrix, Vector};
+use mathru::algebra::linear::{matrix::Transpose, Matrix, Vector};
 use mathru::algebra::abstr::Complex;
 use mathru::algebra::abstr::cast::FromPrimitive;
 
@@ -239,4 +239,84 @@ fn matrix_mul_vector_complex_f32()
                                                     Complex::from_f64(22.0)];
 
     assert_relative_eq!(prod_ref, m * v);
-}
\ No newline at end of file
+}
+fn mul_reference(a: &Matrix<f64>, b: &Matrix<f64>) -> Matrix<f64>
+{
+    let (m, k): (usize, usize) = a.dim();
+    let (_, n): (usize, usize) = b.dim();
+    let mut c: Matrix<f64> = Matrix::zero(m, n);
+
+    for i in 0..m
+    {
+        for j in 0..n
+        {
+            let mut sum: f64 = 0.0;
+            for l in 0..k
+            {
+                sum += *a.get(i, l) * *b.get(l, j);
+            }
+            *c.get_mut(i, j) = sum;
+        }
+    }
+    return c;
+}
+
+#[test]
+fn mul_blocked()
+{
+    // The dimensions exceed the block sizes and are not multiples of them
+    let a: Matrix<f64> = Matrix::new_random(130, 261);
+    let b: Matrix<f64> = Matrix::new_random(261, 11);
+
+    assert_relative_eq!(mul_reference(&a, &b), &a * &b, epsilon=1.0e-10);
+}
+
+#[test]
+fn mul_blocked_f32()
+{
+    let a: Matrix<f32> = Matrix::new_random(37, 5);
+    let b: Matrix<f32> = Matrix::new_random(5, 102);
+
+    let prod: Matrix<f32> = &a * &b;
+
+    for i in 0..37
+    {
+        for j in 0..102
+        {
+            let mut sum: f32 = 0.0;
+            for l in 0..5
+            {
+                sum += *a.get(i, l) * *b.get(l, j);
+            }
+            assert_relative_eq!(sum, *prod.get(i, j), epsilon=1.0e-5);
+        }
+    }
+}
+
+#[test]
+fn gemm()
+{
+    let a: Matrix<f64> = Matrix::new_random(9, 6);
+    let b: Matrix<f64> = Matrix::new_random(5, 9);
+    let c: Matrix<f64> = Matrix::new_random(6, 5);
+
+    let mut d: Matrix<f64> = c.clone();
+    d.gemm(-2.0, &a, true, &b, true, 0.5);
+
+    let ab: Matrix<f64> = mul_reference(&a.transpose(), &b.transpose());
+    let reference: Matrix<f64> = &(&ab * &-2.0) + &(&c * &0.5);
+    assert_relative_eq!(reference, d, epsilon=1.0e-10);
+}
+
+#[test]
+fn gemm_beta_zero()
+{
+    let a: Matrix<f64> = matrix![   1.0, 2.0;
+                                    3.0, 4.0];
+    let mut c: Matrix<f64> = matrix![   f64::NAN, 1.0;
+                                        2.0, f64::NAN];
+
+    c.gemm(1.0, &a, false, &a, true, 0.0);
+
+    assert_eq!(matrix![5.0, 11.0; 11.0, 25.0], c);
+}
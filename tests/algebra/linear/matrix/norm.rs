@@ -0,0 +1,135 @@
+use mathru::{
+    algebra::linear::{
+        matrix::{Inverse, LUDec},
+        Matrix,
+    },
+    error::MathError,
+};
+
+#[test]
+fn norm_1()
+{
+    let a: Matrix<f64> = matrix![   1.0, -7.0, 2.0;
+                                    -3.0, 4.0, 0.5];
+
+    assert_eq!(11.0, a.norm_1());
+}
+
+#[test]
+fn norm_inf()
+{
+    let a: Matrix<f64> = matrix![   1.0, -7.0, 2.0;
+                                    -3.0, 4.0, 0.5];
+
+    assert_eq!(10.0, a.norm_inf());
+}
+
+#[test]
+fn norm_frobenius()
+{
+    let a: Matrix<f64> = matrix![   1.0, -2.0, 2.0;
+                                    0.0, 4.0, 0.0];
+
+    assert_relative_eq!(5.0, a.norm_frobenius(), epsilon=1.0e-10);
+    assert_eq!(0.0, Matrix::<f64>::zero(2, 3).norm_frobenius());
+}
+
+#[test]
+fn norm_frobenius_no_overflow()
+{
+    let a: Matrix<f64> = matrix![   3.0e200, 4.0e200];
+
+    assert_relative_eq!(5.0e200, a.norm_frobenius(), max_relative=1.0e-10);
+}
+
+#[test]
+fn norm_max()
+{
+    let a: Matrix<f64> = matrix![   1.0, -7.0, 2.0;
+                                    -3.0, 4.0, 0.5];
+
+    assert_eq!(7.0, a.norm_max());
+}
+
+#[test]
+fn norm_2()
+{
+    let a: Matrix<f64> = matrix![   2.0, 0.0, 0.0;
+                                    0.0, -5.0, 0.0;
+                                    0.0, 0.0, 1.0];
+
+    assert_relative_eq!(5.0, a.norm_2().unwrap(), epsilon=1.0e-10);
+}
+
+#[test]
+fn norm_2_rectangular()
+{
+    let a: Matrix<f64> = matrix![   3.0, 0.0;
+                                    4.0, 5.0;
+                                    0.0, 0.0];
+
+    assert_relative_eq!(45.0f64.sqrt(), a.norm_2().unwrap(), epsilon=1.0e-10);
+}
+
+#[test]
+fn norm_1_inv_est()
+{
+    let a: Matrix<f64> = matrix![   4.0, -2.0, 1.0, 0.5;
+                                    3.0, 6.0, -4.0, 2.0;
+                                    2.0, 1.0, 8.0, -1.0;
+                                    -1.0, 0.5, 2.0, 5.0];
+
+    let lu: LUDec<f64> = a.dec_lu().unwrap();
+    let exact: f64 = a.inv().unwrap().norm_1();
+    let est: f64 = lu.norm_1_inv_est().unwrap();
+
+    assert!(est <= exact * (1.0 + 1.0e-10));
+    assert!(est >= exact / 3.0);
+}
+
+#[test]
+fn rcond_hilbert()
+{
+    let n: usize = 6;
+    let mut a: Matrix<f64> = Matrix::zero(n, n);
+    for i in 0..n
+    {
+        for j in 0..n
+        {
+            *a.get_mut(i, j) = 1.0 / ((i + j + 1) as f64);
+        }
+    }
+
+    let exact: f64 = 1.0 / (a.norm_1() * a.inv().unwrap().norm_1());
+    let rcond: f64 = a.rcond().unwrap();
+
+    assert!(rcond >= exact * (1.0 - 1.0e-6));
+    assert!(rcond <= 3.0 * exact);
+    assert!(rcond < 1.0e-6);
+}
+
+#[test]
+fn rcond_identity()
+{
+    let a: Matrix<f64> = Matrix::one(4);
+
+    assert_relative_eq!(1.0, a.rcond().unwrap(), epsilon=1.0e-10);
+}
+
+#[test]
+fn rcond_singular()
+{
+    let a: Matrix<f64> = matrix![   1.0, 2.0;
+                                    2.0, 4.0];
+
+    assert_eq!(0.0, a.rcond().unwrap());
+}
+
+#[test]
+fn rcond_not_square()
+{
+    let a: Matrix<f64> = matrix![   1.0, 2.0, 3.0;
+                                    2.0, 4.0, 5.0];
+
+    assert_eq!(MathError::DimensionMismatch, a.rcond().unwrap_err());
+}
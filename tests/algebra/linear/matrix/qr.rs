@@ -1,4 +1,5 @@
-use mathru::{algebra::linear::Matrix};
+use mathru::algebra::linear::{matrix::{ConjugateTranspose, QRDec, QRPDec, Transpose}, Matrix, Vector};
+use mathru::error::MathError;
 use mathru::algebra::abstr::Complex;
 
 #[cfg(feature = "native")]
@@ -318,4 +319,187 @@ fn decompose_qr2()
     assert_relative_eq!(q, q_ref, epsilon=1.0e-10);
     assert_relative_eq!(r, r_ref, epsilon=1.0e-10);
     assert_relative_eq!(a, &q * &r, epsilon=1.0e-10);
-}
\ No newline at end of file
+}
+#[test]
+fn dec_qrp_tall()
+{
+    let a: Matrix<f64> = matrix![   1.0, 2.0, 0.0;
+                                    3.0, 4.0, -1.0;
+                                    5.0, 6.0, 2.0;
+                                    7.0, 8.0, 1.0];
+
+    let dec: QRPDec<f64> = a.dec_qrp().unwrap();
+    assert_eq!(3, dec.rank(1.0e-10));
+
+    let (q, r, p): (Matrix<f64>, Matrix<f64>, Matrix<f64>) = dec.qrp();
+    assert_eq!((4, 3), q.dim());
+    assert_eq!((3, 3), r.dim());
+    assert_relative_eq!(Matrix::one(3), &q.clone().transpose() * &q, epsilon=1.0e-10);
+    assert_relative_eq!(&a * &p, &q * &r, epsilon=1.0e-10);
+
+    // the diagonal of r is non-increasing in absolute value
+    assert!(r.get(0, 0).abs() >= r.get(1, 1).abs());
+    assert!(r.get(1, 1).abs() >= r.get(2, 2).abs());
+    assert_relative_eq!(0.0, *r.get(1, 0), epsilon=1.0e-10);
+}
+
+#[test]
+fn dec_qrp_wide()
+{
+    let a: Matrix<f64> = matrix![   1.0, 2.0, 3.0, 4.0;
+                                    2.0, 4.0, 6.0, 8.0];
+
+    let dec: QRPDec<f64> = a.dec_qrp().unwrap();
+    assert_eq!(1, dec.rank(1.0e-10));
+    assert_eq!(3, dec.pivot()[0]);
+
+    let (q, r, p): (Matrix<f64>, Matrix<f64>, Matrix<f64>) = dec.qrp();
+    assert_eq!((2, 2), q.dim());
+    assert_eq!((2, 4), r.dim());
+    assert_relative_eq!(&a * &p, &q * &r, epsilon=1.0e-10);
+}
+
+#[test]
+fn dec_complex_unitary()
+{
+    let a: Matrix<Complex<f64>> = matrix![  Complex::new(1.0, 2.0), Complex::new(0.5, -1.0), Complex::new(3.0, 0.0);
+                                            Complex::new(0.0, 1.0), Complex::new(2.0, 2.0), Complex::new(-1.0, 1.0);
+                                            Complex::new(4.0, -3.0), Complex::new(1.0, 0.0), Complex::new(0.0, -2.0)];
+
+    let (q, r): (Matrix<Complex<f64>>, Matrix<Complex<f64>>) = a.dec_qr().unwrap().qr();
+
+    assert_relative_eq!(Matrix::one(3), &q.clone().conj_transpose() * &q, epsilon=Complex::new(1.0e-10, 1.0e-10));
+    assert_relative_eq!(a, &q * &r, epsilon=Complex::new(1.0e-10, 1.0e-10));
+    assert_relative_eq!(Complex::new(0.0, 0.0), *r.get(1, 0), epsilon=Complex::new(1.0e-10, 1.0e-10));
+    assert_relative_eq!(Complex::new(0.0, 0.0), *r.get(2, 0), epsilon=Complex::new(1.0e-10, 1.0e-10));
+    assert_relative_eq!(Complex::new(0.0, 0.0), *r.get(2, 1), epsilon=Complex::new(1.0e-10, 1.0e-10));
+}
+
+fn assert_qr(a: &Matrix<f64>, dec: QRDec<f64>)
+{
+    let (q, r): (Matrix<f64>, Matrix<f64>) = dec.qr();
+    let (m, n): (usize, usize) = a.dim();
+
+    assert_relative_eq!(Matrix::one(m), &q.clone().transpose() * &q, epsilon=1.0e-10);
+    for j in 0..n
+    {
+        for i in (j + 1)..m
+        {
+            assert_eq!(0.0, *r.get(i, j));
+        }
+    }
+    assert_relative_eq!(*a, &q * &r, epsilon=1.0e-10);
+}
+
+#[test]
+fn insert_row()
+{
+    let a: Matrix<f64> = matrix![   1.0, 2.0, 0.0;
+                                    3.0, -1.0, 2.0;
+                                    0.0, 4.0, 1.0;
+                                    2.0, 1.0, -3.0];
+    let x: Vector<f64> = vector![5.0, -2.0, 1.0];
+
+    for k in 0..5
+    {
+        let mut dec: QRDec<f64> = a.dec_qr().unwrap();
+        dec.insert_row(k, &x).unwrap();
+
+        let mut rows: Vec<Matrix<f64>> = (0..4).map(|i| Matrix::from(a.get_row(i))).collect();
+        rows.insert(k, Matrix::from(x.clone()));
+        let rows_ref: Vec<&Matrix<f64>> = rows.iter().collect();
+
+        assert_qr(&Matrix::vstack(&rows_ref).unwrap(), dec);
+    }
+}
+
+#[test]
+fn insert_row_dimension_mismatch()
+{
+    let a: Matrix<f64> = matrix![   1.0, 2.0;
+                                    3.0, 4.0];
+    let x: Vector<f64> = vector![5.0, 6.0, 7.0];
+
+    let mut dec: QRDec<f64> = a.dec_qr().unwrap();
+
+    assert_eq!(Err(MathError::DimensionMismatch), dec.insert_row(0, &x));
+}
+
+#[test]
+fn update_index_out_of_range()
+{
+    let a: Matrix<f64> = matrix![   1.0, 2.0;
+                                    3.0, 4.0;
+                                    5.0, 6.0];
+
+    let mut dec: QRDec<f64> = a.dec_qr().unwrap();
+
+    assert_eq!(Err(MathError::DimensionMismatch), dec.insert_row(4, &vector![1.0, 2.0]));
+    assert_eq!(Err(MathError::DimensionMismatch), dec.delete_row(3));
+    assert_eq!(Err(MathError::DimensionMismatch), dec.insert_column(3, &vector![1.0; 2.0; 3.0]));
+    assert_eq!(Err(MathError::DimensionMismatch), dec.delete_column(2));
+}
+
+#[test]
+fn delete_row()
+{
+    let a: Matrix<f64> = matrix![   1.0, 2.0, 0.0;
+                                    3.0, -1.0, 2.0;
+                                    0.0, 4.0, 1.0;
+                                    2.0, 1.0, -3.0;
+                                    -1.0, 1.0, 1.0];
+
+    for k in 0..5
+    {
+        let mut dec: QRDec<f64> = a.dec_qr().unwrap();
+        dec.delete_row(k).unwrap();
+
+        let rows: Vec<Matrix<f64>> = (0..5).filter(|i| *i != k).map(|i| Matrix::from(a.get_row(i))).collect();
+        let rows_ref: Vec<&Matrix<f64>> = rows.iter().collect();
+
+        assert_qr(&Matrix::vstack(&rows_ref).unwrap(), dec);
+    }
+}
+
+#[test]
+fn insert_column()
+{
+    let a: Matrix<f64> = matrix![   1.0, 2.0, 0.0;
+                                    3.0, -1.0, 2.0;
+                                    0.0, 4.0, 1.0;
+                                    2.0, 1.0, -3.0;
+                                    -1.0, 1.0, 1.0];
+    let x: Vector<f64> = vector![1.0; 0.0; -2.0; 3.0; 1.0];
+
+    for k in 0..4
+    {
+        let mut dec: QRDec<f64> = a.dec_qr().unwrap();
+        dec.insert_column(k, &x).unwrap();
+
+        let mut columns: Vec<Matrix<f64>> = (0..3).map(|j| Matrix::from(a.get_column(j))).collect();
+        columns.insert(k, Matrix::from(x.clone()));
+        let columns_ref: Vec<&Matrix<f64>> = columns.iter().collect();
+
+        assert_qr(&Matrix::hstack(&columns_ref).unwrap(), dec);
+    }
+}
+
+#[test]
+fn delete_column()
+{
+    let a: Matrix<f64> = matrix![   1.0, 2.0, 0.0, 1.0;
+                                    3.0, -1.0, 2.0, 0.0;
+                                    0.0, 4.0, 1.0, 2.0;
+                                    2.0, 1.0, -3.0, 1.0];
+
+    for k in 0..4
+    {
+        let mut dec: QRDec<f64> = a.dec_qr().unwrap();
+        dec.delete_column(k).unwrap();
+
+        let columns: Vec<Matrix<f64>> = (0..4).filter(|j| *j != k).map(|j| Matrix::from(a.get_column(j))).collect();
+        let columns_ref: Vec<&Matrix<f64>> = columns.iter().collect();
+
+        assert_qr(&Matrix::hstack(&columns_ref).unwrap(), dec);
+    }
+}
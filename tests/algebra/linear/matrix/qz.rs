@@ -0,0 +1,170 @@
+use mathru::algebra::{
+    abstr::Complex,
+    linear::{
+        matrix::{ConjugateTranspose, QZDec},
+        Matrix, Vector,
+    },
+};
+use mathru::error::MathError;
+
+fn complexify(a: &Matrix<f64>) -> Matrix<Complex<f64>>
+{
+    let (m, n): (usize, usize) = a.dim();
+    let data: Vec<Complex<f64>> = a.iter().map(|a_ij| Complex::new(*a_ij, 0.0)).collect();
+
+    return Matrix::new(m, n, data);
+}
+
+fn assert_qz(a: &Matrix<Complex<f64>>, b: &Matrix<Complex<f64>>, qz: QZDec<f64>)
+{
+    let (n, _): (usize, usize) = a.dim();
+    let (q, z, s, t) = qz.qzst();
+    let epsilon: Complex<f64> = Complex::new(1.0e-10, 1.0e-10);
+
+    assert_relative_eq!(Matrix::one(n), &q.clone().conj_transpose() * &q, epsilon=epsilon);
+    assert_relative_eq!(Matrix::one(n), &z.clone().conj_transpose() * &z, epsilon=epsilon);
+    assert_relative_eq!(*a, &(&q * &s) * &z.clone().conj_transpose(), epsilon=epsilon);
+    assert_relative_eq!(*b, &(&q * &t) * &z.clone().conj_transpose(), epsilon=epsilon);
+    for j in 0..n
+    {
+        assert_eq!(0.0, t.get(j, j).im);
+        assert!(t.get(j, j).re >= 0.0);
+        for i in (j + 1)..n
+        {
+            assert_eq!(Complex::new(0.0, 0.0), *s.get(i, j));
+            assert_eq!(Complex::new(0.0, 0.0), *t.get(i, j));
+        }
+    }
+}
+
+#[test]
+fn dec_qz_regular()
+{
+    let a: Matrix<f64> = matrix![   1.0, 2.0, 0.0;
+                                    -1.0, 3.0, 1.0;
+                                    2.0, 0.0, 4.0];
+    let b: Matrix<f64> = matrix![   2.0, 0.0, 1.0;
+                                    0.0, 1.0, 0.0;
+                                    1.0, 0.0, 3.0];
+
+    let qz: QZDec<f64> = a.dec_qz(&b).unwrap();
+    let value: Vector<Complex<f64>> = qz.value();
+
+    // the generalized eigenvalues are the roots of det(A - λB)
+    for i in 0..3
+    {
+        let lambda: Complex<f64> = *value.get(i);
+        let pencil: Matrix<Complex<f64>> = &complexify(&a) - &(&complexify(&b) * &lambda);
+        assert_relative_eq!(Complex::new(0.0, 0.0), pencil.det(), epsilon=Complex::new(1.0e-10, 1.0e-10));
+    }
+
+    assert_qz(&complexify(&a), &complexify(&b), qz);
+}
+
+#[test]
+fn dec_qz_identity()
+{
+    let a: Matrix<f64> = matrix![   0.0, -1.0;
+                                    1.0, 0.0];
+    let b: Matrix<f64> = Matrix::one(2);
+
+    let value: Vector<Complex<f64>> = a.dec_qz(&b).unwrap().value();
+
+    let mut im: Vec<f64> = value.iter().map(|v| v.im).collect();
+    im.sort_by(|x, y| x.partial_cmp(y).unwrap());
+
+    assert_relative_eq!(0.0, value.get(0).re, epsilon=1.0e-10);
+    assert_relative_eq!(0.0, value.get(1).re, epsilon=1.0e-10);
+    assert_relative_eq!(-1.0, im[0], epsilon=1.0e-10);
+    assert_relative_eq!(1.0, im[1], epsilon=1.0e-10);
+}
+
+#[test]
+fn dec_qz_infinite()
+{
+    // det(A - λB) = 1 - λ, two eigenvalues are infinite
+    let a: Matrix<f64> = Matrix::one(3);
+    let b: Matrix<f64> = matrix![   1.0, 0.0, 0.0;
+                                    0.0, 0.0, 1.0;
+                                    0.0, 0.0, 0.0];
+
+    let qz: QZDec<f64> = a.dec_qz(&b).unwrap();
+    let beta: Vector<f64> = qz.beta();
+    let value: Vector<Complex<f64>> = qz.value();
+
+    assert_eq!(2, beta.iter().filter(|b_i| **b_i == 0.0).count());
+    assert_eq!(2, value.iter().filter(|v| v.re.is_infinite()).count());
+
+    let finite: Vec<Complex<f64>> = value.iter().filter(|v| v.re.is_finite()).copied().collect();
+    assert_relative_eq!(Complex::new(1.0, 0.0), finite[0], epsilon=Complex::new(1.0e-10, 1.0e-10));
+
+    assert_qz(&complexify(&a), &complexify(&b), qz);
+}
+
+#[test]
+fn dec_qz_infinite_dense()
+{
+    let a: Matrix<f64> = matrix![   2.0, 1.0, -1.0, 0.5;
+                                    1.0, 3.0, 0.0, 1.0;
+                                    0.0, 1.0, 4.0, -2.0;
+                                    1.0, 0.0, 1.0, 1.0];
+    // rank 2
+    let b: Matrix<f64> = matrix![   1.0, 2.0, 0.0, 1.0;
+                                    0.0, 1.0, 1.0, 0.0;
+                                    1.0, 3.0, 1.0, 1.0;
+                                    2.0, 4.0, 0.0, 2.0];
+
+    let qz: QZDec<f64> = a.dec_qz(&b).unwrap();
+    let value: Vector<Complex<f64>> = qz.value();
+
+    assert_eq!(2, value.iter().filter(|v| v.re.is_infinite()).count());
+    for lambda in value.iter().filter(|v| v.re.is_finite())
+    {
+        let pencil: Matrix<Complex<f64>> = &complexify(&a) - &(&complexify(&b) * lambda);
+        assert_relative_eq!(Complex::new(0.0, 0.0), pencil.det(), epsilon=Complex::new(1.0e-9, 1.0e-9));
+    }
+
+    assert_qz(&complexify(&a), &complexify(&b), qz);
+}
+
+#[test]
+fn dec_qz_complex()
+{
+    let a: Matrix<Complex<f64>> = matrix![  Complex::new(1.0, 2.0), Complex::new(0.5, -1.0), Complex::new(3.0, 0.0);
+                                            Complex::new(0.0, 1.0), Complex::new(2.0, 2.0), Complex::new(-1.0, 1.0);
+                                            Complex::new(4.0, -3.0), Complex::new(1.0, 0.0), Complex::new(0.0, -2.0)];
+    let b: Matrix<Complex<f64>> = matrix![  Complex::new(1.0, 0.0), Complex::new(0.0, 1.0), Complex::new(0.0, 0.0);
+                                            Complex::new(0.0, 0.0), Complex::new(2.0, 0.0), Complex::new(1.0, -1.0);
+                                            Complex::new(1.0, 0.0), Complex::new(0.0, 0.0), Complex::new(3.0, 0.0)];
+
+    let qz: QZDec<f64> = a.dec_qz(&b).unwrap();
+
+    for lambda in qz.value().iter()
+    {
+        let pencil: Matrix<Complex<f64>> = &a - &(&b * lambda);
+        assert_relative_eq!(Complex::new(0.0, 0.0), pencil.det(), epsilon=Complex::new(1.0e-9, 1.0e-9));
+    }
+
+    assert_qz(&a, &b, qz);
+}
+
+#[test]
+fn dec_qz_dimension_mismatch()
+{
+    let a: Matrix<f64> = Matrix::one(3);
+    let b: Matrix<f64> = Matrix::one(2);
+
+    assert_eq!(MathError::DimensionMismatch, a.dec_qz(&b).unwrap_err());
+}
+
+#[test]
+fn dec_qz_non_square()
+{
+    let a: Matrix<f64> = Matrix::zero(2, 3);
+    let b: Matrix<f64> = Matrix::zero(2, 3);
+
+    assert_eq!(MathError::DimensionMismatch, a.dec_qz(&b).unwrap_err());
+
+    let empty: Matrix<f64> = Matrix::zero(0, 0);
+    assert_eq!(MathError::DimensionMismatch, empty.dec_qz(&empty).unwrap_err());
+}
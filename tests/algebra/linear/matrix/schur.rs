@@ -0,0 +1,88 @@
+use mathru::algebra::linear::{matrix::Transpose, Matrix};
+use mathru::error::MathError;
+
+fn assert_quasi_triangular(t: &Matrix<f64>)
+{
+    let (n, _): (usize, usize) = t.dim();
+    for j in 0..n
+    {
+        for i in (j + 2)..n
+        {
+            assert_eq!(0.0, *t.get(i, j));
+        }
+    }
+    // no two consecutive subdiagonal elements are nonzero
+    for j in 1..n.saturating_sub(1)
+    {
+        assert!(*t.get(j, j - 1) == 0.0 || *t.get(j + 1, j) == 0.0);
+    }
+}
+
+#[test]
+fn dec_schur_real_eigenvalues()
+{
+    let a: Matrix<f64> = matrix![   4.0, 1.0, -2.0;
+                                    1.0, 2.0, 0.0;
+                                    -2.0, 0.0, 3.0];
+
+    let (z, t): (Matrix<f64>, Matrix<f64>) = a.dec_schur().unwrap().zt();
+
+    assert_relative_eq!(Matrix::one(3), &z.clone().transpose() * &z, epsilon=1.0e-10);
+    assert_relative_eq!(a, &(&z * &t) * &z.clone().transpose(), epsilon=1.0e-10);
+    assert_eq!(0.0, *t.get(1, 0));
+    assert_eq!(0.0, *t.get(2, 1));
+    assert_quasi_triangular(&t);
+}
+
+#[test]
+fn dec_schur_complex_eigenvalues()
+{
+    let a: Matrix<f64> = matrix![   1.0, -3.0, 2.0;
+                                    4.0, 1.0, -1.0;
+                                    0.0, 2.0, 5.0];
+
+    let (z, t): (Matrix<f64>, Matrix<f64>) = a.dec_schur().unwrap().zt();
+
+    assert_relative_eq!(Matrix::one(3), &z.clone().transpose() * &z, epsilon=1.0e-10);
+    assert_relative_eq!(a, &(&z * &t) * &z.clone().transpose(), epsilon=1.0e-10);
+    assert_quasi_triangular(&t);
+    assert_relative_eq!(a.trace(), t.trace(), epsilon=1.0e-10);
+}
+
+#[test]
+fn dec_schur_f32()
+{
+    let a: Matrix<f32> = matrix![   0.0, -1.0;
+                                    1.0, 0.0];
+
+    let (z, t): (Matrix<f32>, Matrix<f32>) = a.dec_schur().unwrap().zt();
+
+    assert_relative_eq!(a, &(&z * &t) * &z.transpose(), epsilon=1.0e-5);
+}
+
+#[test]
+fn dec_schur_larger()
+{
+    let a: Matrix<f64> = matrix![   2.0, -1.0, 0.5, 3.0, 0.0, 1.0;
+                                    1.0, 4.0, -2.0, 0.0, 1.5, 0.0;
+                                    0.0, 3.0, 1.0, -1.0, 2.0, 0.5;
+                                    -2.0, 0.0, 1.0, 0.0, -3.0, 1.0;
+                                    1.0, 1.0, 0.0, 2.0, 1.0, -1.0;
+                                    0.5, -1.0, 2.0, 0.0, 1.0, 3.0];
+
+    let (z, t): (Matrix<f64>, Matrix<f64>) = a.dec_schur().unwrap().zt();
+
+    assert_relative_eq!(Matrix::one(6), &z.clone().transpose() * &z, epsilon=1.0e-10);
+    assert_relative_eq!(a, &(&z * &t) * &z.clone().transpose(), epsilon=1.0e-10);
+    assert_quasi_triangular(&t);
+}
+
+#[test]
+fn dec_schur_non_square()
+{
+    let a: Matrix<f64> = Matrix::zero(2, 3);
+    assert_eq!(MathError::DimensionMismatch, a.dec_schur().unwrap_err());
+
+    let empty: Matrix<f64> = Matrix::zero(0, 0);
+    assert_eq!(MathError::DimensionMismatch, empty.dec_schur().unwrap_err());
+}
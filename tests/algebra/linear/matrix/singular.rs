@@ -1,4 +1,37 @@
-use mathru::algebra::linear::Matrix;
+use mathru::algebra::{
+    abstr::Real,
+    linear::{
+        matrix::{SVDDec, Transpose},
+        Matrix, Vector,
+    },
+};
+use mathru::error::MathError;
+use rand::{rngs::StdRng, SeedableRng};
+
+/// Flips the signs of the pairs of singular vectors, such that the columns of
+/// u point in the direction of the columns of u_ref
+fn align_signs<T>(u_ref: &Matrix<T>, mut u: Matrix<T>, mut v: Matrix<T>) -> (Matrix<T>, Matrix<T>)
+    where T: Real
+{
+    let (m, n): (usize, usize) = u.dim();
+    for j in 0..n
+    {
+        let mut dot: T = T::zero();
+        for i in 0..m
+        {
+            dot += *u_ref.get(i, j) * *u.get(i, j);
+        }
+        if dot < T::zero()
+        {
+            let u_j: Vector<T> = &u.get_column(j) * &-T::one();
+            let v_j: Vector<T> = &v.get_column(j) * &-T::one();
+            u.set_column(&u_j, j);
+            v.set_column(&v_j, j);
+        }
+    }
+
+    return (u, v);
+}
 
 #[test]
 fn svd_f32()
@@ -8,7 +41,7 @@ fn svd_f32()
                                     0.0, 3.0, -2.0, 2.0;
                                     2.0, 1.0, -2.0, -1.0];
 
-    let (u, s, v): (Matrix<f32>, Matrix<f32>, Matrix<f32>) = a.dec_sv();
+    let (u, s, v): (Matrix<f32>, Matrix<f32>, Matrix<f32>) = a.dec_sv().unwrap();
 
     let u_ref: Matrix<f32> = matrix![   0.750618975420566, -0.10396419803551285, -0.5550030946996072, -0.3431241235169467;
                                         0.16660611095451502, 0.7151480228364393, 0.42071909480760994, -0.5327299057590199;
@@ -25,6 +58,7 @@ fn svd_f32()
                                         0.0, 0.0, 3.0918649662716553, 0.0;
                                         0.0, 0.0, 0.0, 1.1694937978293738];
 
+    let (u, v): (Matrix<f32>, Matrix<f32>) = align_signs(&u_ref, u, v);
     assert_relative_eq!(u_ref, u, epsilon=1.0e-5);
     assert_relative_eq!(s_ref, s, epsilon=1.0e-5);
     assert_relative_eq!(v_ref, v, epsilon=1.0e-5);
@@ -39,7 +73,7 @@ fn svd_f64()
                                     0.0, 3.0, -2.0, 2.0;
                                     2.0, 1.0, -2.0, -1.0];
 
-    let (u, s, v): (Matrix<f64>, Matrix<f64>, Matrix<f64>) = a.dec_sv();
+    let (u, s, v): (Matrix<f64>, Matrix<f64>, Matrix<f64>) = a.dec_sv().unwrap();
 
     let u_ref: Matrix<f64> = matrix![   0.750618975420566, -0.10396419803551285, -0.5550030946996072, -0.3431241235169467;
                                         0.16660611095451502, 0.7151480228364393, 0.42071909480760994, -0.5327299057590199;
@@ -56,8 +90,279 @@ fn svd_f64()
                                         0.0, 0.0, 3.0918649662716553, 0.0;
                                         0.0, 0.0, 0.0, 1.1694937978293738];
 
+    let (u, v): (Matrix<f64>, Matrix<f64>) = align_signs(&u_ref, u, v);
     assert_relative_eq!(u_ref, u, epsilon=0.0000001, max_relative=1.0e-10);
     assert_relative_eq!(s_ref, s, epsilon=0.0000001, max_relative=1.0e-10);
     assert_relative_eq!(v_ref, v, epsilon=0.0000001, max_relative=1.0e-10);
 }
 
+
+fn assert_orthonormal_columns(q: &Matrix<f64>)
+{
+    let (_m, n): (usize, usize) = q.dim();
+    let q_t: Matrix<f64> = q.clone().transpose();
+    assert_relative_eq!(Matrix::one(n), &q_t * q, epsilon=1.0e-10);
+}
+
+#[test]
+fn dec_svd_f64()
+{
+    let a: Matrix<f64> = matrix![   4.0, 1.0, -2.0, 2.0;
+                                    1.0, 2.0, 0.0, -2.0;
+                                    0.0, 3.0, -2.0, 2.0;
+                                    2.0, 1.0, -2.0, -1.0];
+
+    let dec: SVDDec<f64> = a.dec_svd().unwrap();
+    let s_ref: Vector<f64> = vector![6.216089837372844; 3.3812545631600996; 3.0918649662716553; 1.1694937978293738];
+    assert_relative_eq!(s_ref, dec.clone().singular_values(), epsilon=1.0e-10);
+
+    let (u, s, vt): (Matrix<f64>, Matrix<f64>, Matrix<f64>) = dec.usvt();
+    assert_orthonormal_columns(&u);
+    assert_orthonormal_columns(&vt.clone().transpose());
+    assert_relative_eq!(a, &(&u * &s) * &vt, epsilon=1.0e-10);
+}
+
+#[test]
+fn dec_svd_f32()
+{
+    let a: Matrix<f32> = matrix![   4.0, 1.0, -2.0, 2.0;
+                                    1.0, 2.0, 0.0, -2.0;
+                                    0.0, 3.0, -2.0, 2.0;
+                                    2.0, 1.0, -2.0, -1.0];
+
+    let (u, s, vt): (Matrix<f32>, Matrix<f32>, Matrix<f32>) = a.dec_svd().unwrap().usvt();
+    let s_ref: Matrix<f32> = matrix![   6.216089837372844, 0.0, 0.0, 0.0;
+                                        0.0, 3.3812545631600996, 0.0, 0.0;
+                                        0.0, 0.0, 3.0918649662716553, 0.0;
+                                        0.0, 0.0, 0.0, 1.1694937978293738];
+
+    assert_relative_eq!(s_ref, s, epsilon=1.0e-5);
+    assert_relative_eq!(a, &(&u * &s) * &vt, epsilon=1.0e-5);
+}
+
+#[test]
+fn dec_svd_tall()
+{
+    let a: Matrix<f64> = matrix![   1.0, 2.0;
+                                    3.0, 4.0;
+                                    5.0, 6.0;
+                                    7.0, 8.0];
+
+    let (u, s, vt): (Matrix<f64>, Matrix<f64>, Matrix<f64>) = a.dec_svd().unwrap().usvt();
+    assert_eq!((4, 4), u.dim());
+    assert_eq!((4, 2), s.dim());
+    assert_eq!((2, 2), vt.dim());
+    assert_orthonormal_columns(&u);
+    assert_relative_eq!(a, &(&u * &s) * &vt, epsilon=1.0e-10);
+
+    let (u, s, vt): (Matrix<f64>, Matrix<f64>, Matrix<f64>) = a.dec_svd_thin().unwrap().usvt();
+    assert_eq!((4, 2), u.dim());
+    assert_eq!((2, 2), s.dim());
+    assert_eq!((2, 2), vt.dim());
+    assert_orthonormal_columns(&u);
+    assert_relative_eq!(a, &(&u * &s) * &vt, epsilon=1.0e-10);
+}
+
+#[test]
+fn dec_svd_tall_full()
+{
+    let mut rng: StdRng = StdRng::seed_from_u64(1);
+    let a: Matrix<f64> = Matrix::new_random_with_rng(400, 5, &mut rng);
+
+    let (u, s, vt): (Matrix<f64>, Matrix<f64>, Matrix<f64>) = a.dec_svd().unwrap().usvt();
+    assert_eq!((400, 400), u.dim());
+    assert_eq!((400, 5), s.dim());
+    assert_eq!((5, 5), vt.dim());
+    assert_orthonormal_columns(&u);
+    assert_relative_eq!(a, &(&u * &s) * &vt, epsilon=1.0e-10);
+}
+
+#[test]
+fn dec_svd_wide()
+{
+    let a: Matrix<f64> = matrix![   1.0, 0.0, 2.0;
+                                    0.0, 3.0, 0.0];
+
+    let dec: SVDDec<f64> = a.dec_svd().unwrap();
+    assert_relative_eq!(vector![3.0; 5.0f64.sqrt()], dec.clone().singular_values(), epsilon=1.0e-10);
+
+    let (u, s, vt): (Matrix<f64>, Matrix<f64>, Matrix<f64>) = dec.usvt();
+    assert_eq!((2, 2), u.dim());
+    assert_eq!((2, 3), s.dim());
+    assert_eq!((3, 3), vt.dim());
+    assert_orthonormal_columns(&vt.clone().transpose());
+    assert_relative_eq!(a, &(&u * &s) * &vt, epsilon=1.0e-10);
+
+    let (u, s, vt): (Matrix<f64>, Matrix<f64>, Matrix<f64>) = a.dec_svd_thin().unwrap().usvt();
+    assert_eq!((2, 2), u.dim());
+    assert_eq!((2, 2), s.dim());
+    assert_eq!((2, 3), vt.dim());
+    assert_relative_eq!(a, &(&u * &s) * &vt, epsilon=1.0e-10);
+}
+
+#[test]
+fn dec_svd_rank_deficient()
+{
+    let a: Matrix<f64> = matrix![   1.0, 2.0, 3.0;
+                                    4.0, 5.0, 6.0;
+                                    7.0, 8.0, 9.0];
+
+    let dec: SVDDec<f64> = a.dec_svd().unwrap();
+    assert_eq!(2, dec.rank(1.0e-10));
+    assert!(dec.cond() > 1.0e15);
+
+    let null: Matrix<f64> = dec.null_space(1.0e-10);
+    assert_eq!((3, 1), null.dim());
+    assert_relative_eq!(Matrix::zero(3, 1), &a * &null, epsilon=1.0e-10);
+
+    let range: Matrix<f64> = dec.range(1.0e-10);
+    assert_eq!((3, 2), range.dim());
+    assert_orthonormal_columns(&range);
+    // the columns of a lie in the range
+    let range_t: Matrix<f64> = range.clone().transpose();
+    assert_relative_eq!(a, &range * &(&range_t * &a), epsilon=1.0e-10);
+
+    assert_relative_eq!(a, dec.truncate(2), epsilon=1.0e-10);
+}
+
+#[test]
+fn dec_svd_truncate()
+{
+    let a: Matrix<f64> = matrix![   4.0, 1.0, -2.0, 2.0;
+                                    1.0, 2.0, 0.0, -2.0;
+                                    0.0, 3.0, -2.0, 2.0;
+                                    2.0, 1.0, -2.0, -1.0];
+
+    let dec: SVDDec<f64> = a.dec_svd().unwrap();
+    assert_relative_eq!(a, dec.truncate(4), epsilon=1.0e-10);
+    assert_relative_eq!(Matrix::zero(4, 4), dec.truncate(0), epsilon=1.0e-10);
+
+    // the error of the best rank k approximation in the 2-norm is the
+    // singular value k + 1
+    let a_2: Matrix<f64> = dec.truncate(2);
+    let s: Vector<f64> = (&a - &a_2).dec_svd().unwrap().singular_values();
+    assert_relative_eq!(3.0918649662716553, *s.get(0), epsilon=1.0e-10);
+    assert_relative_eq!(0.0, *s.get(2), epsilon=1.0e-10);
+}
+
+#[test]
+fn dec_svd_cond()
+{
+    let a: Matrix<f64> = matrix![   0.0, 3.0;
+                                    -1.0, 0.0];
+
+    assert_relative_eq!(3.0, a.dec_svd().unwrap().cond(), epsilon=1.0e-10);
+
+    let b: Matrix<f64> = matrix![   1.0, 0.0;
+                                    0.0, 0.0];
+
+    assert_eq!(std::f64::INFINITY, b.dec_svd().unwrap().cond());
+}
+
+#[test]
+fn dec_svd_not_finite()
+{
+    let a: Matrix<f64> = matrix![   1.0, std::f64::NAN;
+                                    0.0, 1.0];
+
+    assert_eq!(MathError::NotFinite, a.dec_svd().unwrap_err());
+    assert_eq!(MathError::NotFinite, a.dec_svd_thin().unwrap_err());
+}
+
+#[test]
+fn pinv_rank_deficient()
+{
+    let a: Matrix<f64> = matrix![   1.0, 2.0, 3.0;
+                                    2.0, 4.0, 6.0];
+
+    let a_pinv: Matrix<f64> = a.pinv().unwrap();
+    assert_eq!((3, 2), a_pinv.dim());
+
+    assert_relative_eq!(a, &(&a * &a_pinv) * &a, epsilon=1.0e-10);
+    assert_relative_eq!(a_pinv, &(&a_pinv * &a) * &a_pinv, epsilon=1.0e-10);
+}
+
+/// m x n matrix with the singular values s and random singular vectors
+fn with_singular_values(m: usize, n: usize, s: &[f64], rng: &mut StdRng) -> Matrix<f64>
+{
+    let k: usize = s.len();
+    let u: Matrix<f64> = Matrix::new_random_with_rng(m, k, rng).dec_qrp().unwrap().q();
+    let v: Matrix<f64> = Matrix::new_random_with_rng(n, k, rng).dec_qrp().unwrap().q();
+    let mut sigma: Matrix<f64> = Matrix::zero(k, k);
+    for (i, s_i) in s.iter().enumerate()
+    {
+        *sigma.get_mut(i, i) = *s_i;
+    }
+
+    return &(&u * &sigma) * &v.transpose();
+}
+
+#[test]
+fn range_randomized()
+{
+    let mut rng: StdRng = StdRng::seed_from_u64(3);
+    let a: Matrix<f64> = with_singular_values(30, 20, &[5.0, 3.0, 1.0, 0.5], &mut rng);
+
+    let q: Matrix<f64> = a.range_randomized(6, 0, &mut rng).unwrap();
+
+    assert_eq!((30, 6), q.dim());
+    assert_orthonormal_columns(&q);
+    assert_relative_eq!(a, &q * &(&q.clone().transpose() * &a), epsilon=1.0e-10);
+}
+
+#[test]
+fn dec_svd_randomized_low_rank()
+{
+    let mut rng: StdRng = StdRng::seed_from_u64(5);
+    let a: Matrix<f64> = with_singular_values(60, 40, &[10.0, 7.0, 4.0, 2.0, 1.0], &mut rng);
+
+    let dec: SVDDec<f64> = a.dec_svd_randomized(5, 5, 0, &mut rng).unwrap();
+    let (u, s, vt): (Matrix<f64>, Matrix<f64>, Matrix<f64>) = dec.clone().usvt();
+
+    assert_eq!((60, 5), u.dim());
+    assert_eq!((5, 5), s.dim());
+    assert_eq!((5, 40), vt.dim());
+    assert_orthonormal_columns(&u);
+    assert_orthonormal_columns(&vt.clone().transpose());
+    assert_relative_eq!(vector![10.0; 7.0; 4.0; 2.0; 1.0], dec.singular_values(), epsilon=1.0e-10);
+    assert_relative_eq!(a, &(&u * &s) * &vt, epsilon=1.0e-10);
+}
+
+#[test]
+fn dec_svd_randomized_power_iterations()
+{
+    let mut rng: StdRng = StdRng::seed_from_u64(11);
+    let s_ref: Vec<f64> = (0..40).map(|i| 0.8f64.powi(i)).collect();
+    let a: Matrix<f64> = with_singular_values(80, 50, &s_ref, &mut rng);
+
+    let error = |power_iterations: usize| -> f64 {
+        let s: Vector<f64> = a.dec_svd_randomized(5, 5, power_iterations, &mut StdRng::seed_from_u64(1))
+                              .unwrap()
+                              .singular_values();
+        (0..5).map(|i| (s_ref[i] - *s.get(i)).abs() / s_ref[i]).fold(0.0, f64::max)
+    };
+
+    assert!(error(1) < error(0) / 10.0);
+    assert!(error(2) < error(1) / 10.0);
+    assert!(error(2) < 1.0e-4);
+}
+
+#[test]
+fn dec_svd_randomized_reproducible()
+{
+    let a: Matrix<f64> = with_singular_values(20, 30, &[3.0, 2.0, 1.0], &mut StdRng::seed_from_u64(2));
+
+    let u_1: Matrix<f64> = a.dec_svd_randomized(2, 2, 1, &mut StdRng::seed_from_u64(42)).unwrap().u();
+    let u_2: Matrix<f64> = a.dec_svd_randomized(2, 2, 1, &mut StdRng::seed_from_u64(42)).unwrap().u();
+
+    assert_eq!(u_1, u_2);
+}
+
+#[test]
+#[should_panic]
+fn dec_svd_randomized_too_many_values()
+{
+    let a: Matrix<f64> = matrix![1.0, 2.0; 3.0, 4.0; 5.0, 6.0];
+
+    let _ = a.dec_svd_randomized(3, 0, 0, &mut StdRng::seed_from_u64(0));
+}
@@ -1,5 +1,6 @@
 use mathru::algebra::linear::{Matrix, Vector, matrix::Solve};
 use mathru::algebra::abstr::Complex;
+use mathru::error::MathError;
 
 #[test]
 fn solve_matrix_f32()
@@ -205,17 +206,29 @@ fn negative_pivot_in_lu_decomposition()
 //     assert_relative_eq!(b, &a * &x);
 // }
 
-// #[cfg(feature = "native")]
-// #[test]
-// fn solve_vector_no_solution()
-// {
-//     let a: Matrix<f32> = matrix![   1.0, 1.0, 1.0;
-//                                     1.0, 1.0, 2.0;
-//                                     1.0, 1.0, 3.0];
-//
-//     let b: Vector<f32> = vector![   1.0;
-//                                     3.0;
-//                                     -1.0];
-//
-//     assert_eq!(a.solve(&b), Err(()));
-// }
\ No newline at end of file
+#[test]
+fn solve_vector_no_solution()
+{
+    let a: Matrix<f32> = matrix![   1.0, 1.0, 1.0;
+                                    1.0, 1.0, 2.0;
+                                    1.0, 1.0, 3.0];
+
+    let b: Vector<f32> = vector![   1.0;
+                                    3.0;
+                                    -1.0];
+
+    assert_eq!(a.solve(&b), Err(MathError::Singular { pivot: 1 }));
+}
+
+#[test]
+fn solve_vector_dimension_mismatch()
+{
+    let a: Matrix<f64> = matrix![   1.0, 0.0;
+                                    0.0, 1.0];
+
+    let b: Vector<f64> = vector![   1.0;
+                                    2.0;
+                                    3.0];
+
+    assert_eq!(a.solve(&b), Err(MathError::DimensionMismatch));
+}
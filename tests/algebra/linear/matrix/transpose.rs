@@ -1,6 +1,6 @@
 use mathru::algebra::linear::Matrix;
 use mathru::algebra::abstr::Complex;
-use mathru::algebra::linear::matrix::Transpose;
+use mathru::algebra::linear::matrix::{ConjugateTranspose, Transpose};
 
 #[test]
 fn transpose_f32()
@@ -99,3 +99,26 @@ fn transpose_complex_f64()
 
     assert_relative_eq!(uut_t_ref, uut.transpose());
 }
+
+#[test]
+fn conj_transpose_complex_f64()
+{
+    let uut: Matrix<Complex<f64>> = matrix![Complex::new(1.0, 2.0), Complex::new(0.0, -1.0), Complex::new(3.0, 0.0);
+                                            Complex::new(-4.0, 1.0), Complex::new(5.0, 5.0), Complex::new(0.0, 0.0)];
+
+    let refer: Matrix<Complex<f64>> = matrix![  Complex::new(1.0, -2.0), Complex::new(-4.0, -1.0);
+                                                Complex::new(0.0, 1.0), Complex::new(5.0, -5.0);
+                                                Complex::new(3.0, 0.0), Complex::new(0.0, 0.0)];
+
+    assert_eq!(refer, uut.conj_transpose());
+}
+
+#[test]
+fn conj_transpose_f64()
+{
+    let uut: Matrix<f64> = matrix![ 1.0, 0.0;
+                                    3.0, 0.0;
+                                    1.0, -7.0];
+
+    assert_eq!(uut.clone().transpose(), uut.conj_transpose());
+}
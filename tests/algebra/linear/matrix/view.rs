@@ -0,0 +1,198 @@
+use mathru::algebra::linear::{
+    matrix::{Inverse, MatrixView, MatrixViewMut, Solve, Transpose},
+    Matrix, Vector,
+};
+
+#[test]
+fn slice()
+{
+    let a: Matrix<f64> = matrix![   1.0, 2.0, 3.0;
+                                    4.0, 5.0, 6.0;
+                                    7.0, 8.0, 9.0];
+
+    let b: MatrixView<f64> = a.slice(1, 2, 1, 2);
+
+    assert_eq!((2, 2), b.dim());
+    assert_eq!(a.get_slice(1, 2, 1, 2), Matrix::from(b));
+    assert_eq!(matrix![9.0], Matrix::from(b.slice(1, 1, 1, 1)));
+}
+
+#[test]
+fn row_column()
+{
+    let a: Matrix<f64> = matrix![   1.0, 2.0, 3.0;
+                                    4.0, 5.0, 6.0];
+
+    assert_eq!(matrix![4.0, 5.0, 6.0], Matrix::from(a.row(1)));
+    assert_eq!(matrix![3.0; 6.0], Matrix::from(a.column(2)));
+    assert_eq!(matrix![5.0; 6.0], Matrix::from(a.slice(0, 1, 1, 2).row(1).transpose()));
+}
+
+#[test]
+fn transpose()
+{
+    let a: Matrix<f64> = matrix![   1.0, 2.0, 3.0;
+                                    4.0, 5.0, 6.0];
+
+    let a_t: MatrixView<f64> = a.view().transpose();
+
+    assert_eq!((3, 2), a_t.dim());
+    assert_eq!(a.clone().transpose(), Matrix::from(a_t));
+    assert_eq!(matrix![2.0, 5.0; 3.0, 6.0], Matrix::from(a_t.slice(1, 2, 0, 1)));
+}
+
+#[test]
+fn mul()
+{
+    let a: Matrix<f64> = matrix![   1.0, 2.0, 3.0;
+                                    4.0, 5.0, 6.0;
+                                    7.0, 8.0, 9.0];
+
+    let b: Matrix<f64> = matrix![   1.0, -1.0;
+                                    2.0, 0.0];
+
+    let prod_ref: Matrix<f64> = &a.get_slice(0, 2, 1, 2) * &b;
+
+    assert_eq!(prod_ref, &a.slice(0, 2, 1, 2) * &b);
+    assert_eq!(prod_ref, &a.slice(0, 2, 1, 2) * &b.view());
+    assert_eq!(&a * &a.clone().transpose(), &a * &a.view().transpose());
+    assert_eq!(vector![8.0; 17.0; 26.0], &a.slice(0, 2, 1, 2) * &vector![1.0; 2.0]);
+}
+
+#[test]
+fn add_sub()
+{
+    let a: Matrix<f64> = matrix![   1.0, 2.0;
+                                    3.0, 4.0];
+
+    assert_eq!(matrix![2.0, 5.0; 5.0, 8.0], &a.view() + &a.view().transpose());
+    assert_eq!(matrix![0.0, -1.0; 1.0, 0.0], &a - &a.view().transpose());
+}
+
+#[test]
+fn assign()
+{
+    let mut a: Matrix<f64> = Matrix::zero(3, 3);
+    let b: Matrix<f64> = matrix![   1.0, 2.0;
+                                    3.0, 4.0];
+
+    a.slice_mut(1, 2, 0, 1).assign(b.view());
+    a.column_mut(2).fill(5.0);
+
+    assert_eq!(matrix![0.0, 0.0, 5.0; 1.0, 2.0, 5.0; 3.0, 4.0, 5.0], a);
+}
+
+#[test]
+fn add_assign()
+{
+    let mut a: Matrix<f64> = matrix![   1.0, 2.0;
+                                        3.0, 4.0];
+    let b: Matrix<f64> = matrix![1.0, 1.0];
+
+    let mut row: MatrixViewMut<f64> = a.row_mut(1);
+    row += &b;
+    row *= 2.0;
+
+    let mut column: MatrixViewMut<f64> = a.column_mut(0);
+    column -= b.view().transpose();
+
+    assert_eq!(matrix![0.0, 2.0; 7.0, 10.0], a);
+}
+
+#[test]
+fn split_at_column_mut()
+{
+    let mut a: Matrix<f64> = matrix![   1.0, 2.0, 3.0;
+                                        4.0, 5.0, 6.0];
+
+    let (mut left, mut right): (MatrixViewMut<f64>, MatrixViewMut<f64>) =
+        a.view_mut().split_at_column_mut(1);
+    let mut right_0: MatrixViewMut<f64> = right.column_mut(0);
+    right_0 -= left.view();
+    left.fill(0.0);
+    *right.get_mut(1, 1) = -1.0;
+
+    assert_eq!(matrix![0.0, 1.0, 3.0; 0.0, 1.0, -1.0], a);
+}
+
+#[test]
+fn split_at_row_mut()
+{
+    let mut a: Matrix<f64> = matrix![   1.0, 2.0;
+                                        3.0, 4.0;
+                                        5.0, 6.0];
+
+    let (mut top, mut bottom): (MatrixViewMut<f64>, MatrixViewMut<f64>) =
+        a.view_mut().transpose().split_at_row_mut(1);
+    assert_eq!((1, 3), top.dim());
+    assert_eq!((1, 3), bottom.dim());
+    top.fill(0.0);
+    bottom *= 2.0;
+
+    assert_eq!(matrix![0.0, 4.0; 0.0, 8.0; 0.0, 12.0], a);
+}
+
+#[test]
+fn solve()
+{
+    let a: Matrix<f64> = matrix![   0.0, 0.0, 0.0;
+                                    0.0, 6.0, 2.0;
+                                    0.0, 4.0, 3.0];
+    let b: Vector<f64> = vector![2.0; 1.0];
+
+    let x: Vector<f64> = a.slice(1, 2, 1, 2).solve(&b).unwrap();
+
+    assert_relative_eq!(vector![0.4; -0.2], x, epsilon=1.0e-10);
+}
+
+#[test]
+fn inv()
+{
+    let a: Matrix<f64> = matrix![   1.0, 3.0;
+                                    0.0, -7.0];
+
+    let a_inv: Matrix<f64> = a.view().transpose().inv().unwrap();
+
+    assert_relative_eq!(a.transpose().inv().unwrap(), a_inv, epsilon=1.0e-10);
+}
+
+#[test]
+fn dec_lu()
+{
+    let a: Matrix<f64> = matrix![   1.0, -2.0, 0.0;
+                                    3.0, -7.0, 0.0;
+                                    0.0, 0.0, 1.0];
+
+    let (l, u, p): (Matrix<f64>, Matrix<f64>, Matrix<f64>) = a.slice(0, 1, 0, 1).dec_lu().unwrap().lup();
+
+    assert_relative_eq!(&p * &a.get_slice(0, 1, 0, 1), &l * &u, epsilon=1.0e-10);
+}
+
+#[test]
+fn row_iter()
+{
+    let a: Matrix<f64> = matrix![   1.0, 2.0;
+                                    3.0, 4.0;
+                                    5.0, 6.0];
+
+    let sums: Vec<f64> = a.row_iter().map(|r| *r.get(0, 0) + *r.get(0, 1)).collect();
+
+    assert_eq!(vec![3.0, 7.0, 11.0], sums);
+}
+
+#[test]
+fn column_iter()
+{
+    let mut a: Matrix<f64> = matrix![   1.0, 2.0;
+                                        3.0, 4.0];
+
+    assert_eq!(2, a.column_iter().count());
+    assert_eq!(matrix![2.0; 4.0], Matrix::from(a.column_iter().last().unwrap()));
+
+    for (j, mut column) in a.column_iter_mut().enumerate()
+    {
+        column *= (j + 1) as f64;
+    }
+
+    assert_eq!(matrix![1.0, 4.0; 3.0, 8.0], a);
+}
@@ -5,3 +5,10 @@
 mod vector;
 #[macro_use]
 mod matrix;
+mod smatrix;
+mod sparse;
+mod banded;
+mod solver;
+
+#[cfg(any(feature = "nalgebra", feature = "ndarray"))]
+mod interop;
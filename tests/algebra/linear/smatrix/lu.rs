@@ -0,0 +1,76 @@
+use mathru::{
+    algebra::linear::{matrix::{Solve, Transpose}, SMatrix, SVector},
+    error::MathError,
+};
+
+#[test]
+fn dec_lu()
+{
+    let a: SMatrix<f64, 3, 3> = SMatrix::from_rows([[1.0, -2.0, 3.0], [2.0, -5.0, 12.0], [0.0, 2.0, -10.0]]);
+
+    let (l, u, p): (SMatrix<f64, 3, 3>, SMatrix<f64, 3, 3>, SMatrix<f64, 3, 3>) = a.dec_lu().unwrap().lup();
+
+    assert_relative_eq!(p * a, l * u, epsilon=1.0e-10);
+    assert_eq!(0.0, *u.get(2, 0));
+    assert_eq!(0.0, *l.get(0, 2));
+}
+
+#[test]
+fn dec_lu_singular()
+{
+    let a: SMatrix<f64, 2, 2> = SMatrix::from_rows([[1.0, 2.0], [2.0, 4.0]]);
+
+    assert_eq!(MathError::Singular { pivot: 1 }, a.dec_lu().unwrap_err());
+    assert_eq!(0.0, a.det());
+}
+
+#[test]
+fn det()
+{
+    let a: SMatrix<f64, 3, 3> = SMatrix::from_rows([[1.0, -2.0, 3.0], [2.0, -5.0, 12.0], [0.0, 2.0, -10.0]]);
+
+    assert_relative_eq!(-2.0, a.det(), epsilon=1.0e-10);
+}
+
+#[test]
+fn inv()
+{
+    let a: SMatrix<f64, 3, 3> = SMatrix::from_rows([[1.0, -2.0, 3.0], [2.0, -5.0, 12.0], [0.0, 2.0, -10.0]]);
+
+    let a_inv: SMatrix<f64, 3, 3> = a.inv().unwrap();
+
+    assert_relative_eq!(SMatrix::one(), a * a_inv, epsilon=1.0e-10);
+}
+
+#[test]
+fn solve()
+{
+    let a: SMatrix<f64, 3, 3> = SMatrix::from_rows([[1.0, -2.0, 3.0], [2.0, -5.0, 12.0], [0.0, 2.0, -10.0]]);
+    let b: SVector<f64, 3> = SVector::new_column([1.0, 2.0, 3.0]);
+
+    let x: SVector<f64, 3> = a.solve(&b).unwrap();
+
+    assert_relative_eq!(b, a * x, epsilon=1.0e-10);
+}
+
+#[test]
+fn dec_cholesky()
+{
+    let a: SMatrix<f64, 3, 3> = SMatrix::from_rows([[4.0, 2.0, 0.0], [2.0, 5.0, 2.0], [0.0, 2.0, 5.0]]);
+    let b: SVector<f64, 3> = SVector::new_column([1.0, -1.0, 2.0]);
+
+    let dec = a.dec_cholesky().unwrap();
+    let x: SVector<f64, 3> = dec.solve(&b).unwrap();
+    let l: SMatrix<f64, 3, 3> = dec.l();
+
+    assert_relative_eq!(a, l * l.transpose(), epsilon=1.0e-10);
+    assert_relative_eq!(b, a * x, epsilon=1.0e-10);
+}
+
+#[test]
+fn dec_cholesky_not_positive_definite()
+{
+    let a: SMatrix<f64, 2, 2> = SMatrix::from_rows([[1.0, 2.0], [2.0, 1.0]]);
+
+    assert_eq!(MathError::NotPositiveDefinite, a.dec_cholesky().unwrap_err());
+}
@@ -0,0 +1,2 @@
+mod lu;
+mod smatrix;
@@ -0,0 +1,97 @@
+use mathru::algebra::linear::{matrix::Transpose, Matrix, SMatrix, SVector, Vector};
+use std::convert::TryFrom;
+
+#[test]
+fn from_rows()
+{
+    let a: SMatrix<f64, 2, 3> = SMatrix::from_rows([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+
+    assert_eq!((2, 3), a.dim());
+    assert_eq!(SMatrix::new([[1.0, 4.0], [2.0, 5.0], [3.0, 6.0]]), a);
+    assert_eq!(6.0, *a.get(1, 2));
+}
+
+#[test]
+fn add_sub()
+{
+    let a: SMatrix<f64, 2, 2> = SMatrix::from_rows([[1.0, 2.0], [3.0, 4.0]]);
+    let b: SMatrix<f64, 2, 2> = SMatrix::one();
+
+    assert_eq!(SMatrix::from_rows([[2.0, 2.0], [3.0, 5.0]]), a + b);
+    assert_eq!(SMatrix::from_rows([[0.0, 2.0], [3.0, 3.0]]), a - b);
+}
+
+#[test]
+fn mul()
+{
+    let a: SMatrix<f64, 2, 3> = SMatrix::from_rows([[1.0, 2.0, 5.0], [3.0, 4.0, 6.0]]);
+    let x: SVector<f64, 3> = SVector::new_column([1.0, 1.0, -1.0]);
+
+    assert_eq!(SVector::new_column([-2.0, 1.0]), a * x);
+    assert_eq!(SMatrix::from_rows([[2.0, 4.0, 10.0], [6.0, 8.0, 12.0]]), a * 2.0);
+}
+
+#[test]
+fn mul_dynamic()
+{
+    let a: SMatrix<f64, 3, 3> = SMatrix::from_rows([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 10.0]]);
+    let b: SMatrix<f64, 3, 2> = SMatrix::from_rows([[1.0, -1.0], [0.5, 2.0], [3.0, 0.0]]);
+
+    let prod: Matrix<f64> = &Matrix::from(a) * &Matrix::from(b);
+
+    assert_eq!(prod, Matrix::from(a * b));
+}
+
+#[test]
+fn transpose()
+{
+    let a: SMatrix<f64, 2, 3> = SMatrix::from_rows([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+
+    let a_t: SMatrix<f64, 3, 2> = a.transpose();
+
+    assert_eq!(SMatrix::from_rows([[1.0, 4.0], [2.0, 5.0], [3.0, 6.0]]), a_t);
+}
+
+#[test]
+fn convert_matrix()
+{
+    let a: Matrix<f64> = matrix![   1.0, 2.0, 3.0;
+                                    4.0, 5.0, 6.0];
+
+    let b: SMatrix<f64, 2, 3> = SMatrix::try_from(&a).unwrap();
+
+    assert_eq!(SMatrix::from_rows([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]), b);
+    assert_eq!(a, Matrix::from(b));
+    assert!(SMatrix::<f64, 3, 2>::try_from(&a).is_err());
+}
+
+#[test]
+fn convert_vector()
+{
+    let x: Vector<f64> = vector![1.0; 2.0; 3.0];
+
+    let y: SVector<f64, 3> = SVector::try_from(&x).unwrap();
+
+    assert_eq!([1.0, 2.0, 3.0], y.convert_to_array());
+    assert_eq!(x, Vector::from(y));
+    assert!(SVector::<f64, 2>::try_from(&x).is_err());
+    assert!(SVector::<f64, 3>::try_from(&x.transpose()).is_err());
+}
+
+#[test]
+fn dotp()
+{
+    let x: SVector<f64, 3> = SVector::new_column([1.0, 2.0, 2.0]);
+    let y: SVector<f64, 3> = SVector::new_column([-1.0, 0.0, 4.0]);
+
+    assert_eq!(7.0, x.dotp(&y));
+    assert_eq!(3.0, x.eucl_norm());
+}
+
+#[test]
+fn trace()
+{
+    let a: SMatrix<f64, 2, 2> = SMatrix::from_rows([[1.0, 2.0], [3.0, 4.0]]);
+
+    assert_eq!(5.0, a.trace());
+}
@@ -0,0 +1,119 @@
+use super::tridiagonal;
+use mathru::{
+    algebra::{
+        abstr::Complex,
+        linear::{
+            matrix::EigenDec,
+            solver::{Arnoldi, EigenTarget},
+            sparse::CSRMatrix,
+            Matrix, Vector,
+        },
+    },
+    error::MathError,
+};
+
+fn start(n: usize) -> Vector<f64>
+{
+    return Vector::new_column(n, (0..n).map(|i| 1.0 + (i as f64).sin()).collect());
+}
+
+/// Block diagonal matrix with the 2x2 blocks [[a_k, b_k], [-b_k, a_k]] and
+/// the eigenvalues a_k ± i b_k
+fn rotation_blocks(n: usize) -> Matrix<f64>
+{
+    let mut a: Matrix<f64> = Matrix::zero(2 * n, 2 * n);
+    for k in 0..n
+    {
+        let re: f64 = 1.0 + k as f64;
+        let im: f64 = 0.5 * (k + 1) as f64;
+        *a.get_mut(2 * k, 2 * k) = re;
+        *a.get_mut(2 * k + 1, 2 * k + 1) = re;
+        *a.get_mut(2 * k, 2 * k + 1) = im;
+        *a.get_mut(2 * k + 1, 2 * k) = -im;
+    }
+    return a;
+}
+
+/// Checks A x = λ x for all computed eigenpairs
+fn assert_eigenpairs(a: &Matrix<f64>, dec: EigenDec<Complex<f64>>, epsilon: f64)
+{
+    let (value, vector): (Vector<Complex<f64>>, Matrix<Complex<f64>>) = dec.pair();
+    let (n, k): (usize, usize) = vector.dim();
+    for j in 0..k
+    {
+        let lambda: Complex<f64> = *value.get(j);
+        let mut norm: f64 = 0.0;
+        for i in 0..n
+        {
+            let mut ax_i: Complex<f64> = Complex::new(0.0, 0.0);
+            for l in 0..n
+            {
+                ax_i += *vector.get(l, j) * Complex::new(*a.get(i, l), 0.0);
+            }
+            let r_i: Complex<f64> = ax_i - lambda * *vector.get(i, j);
+            assert!(r_i.re.abs() < epsilon && r_i.im.abs() < epsilon);
+            norm += vector.get(i, j).re * vector.get(i, j).re + vector.get(i, j).im * vector.get(i, j).im;
+        }
+        assert_relative_eq!(1.0, norm.sqrt(), epsilon=1.0e-10);
+    }
+}
+
+#[test]
+fn largest_magnitude_complex()
+{
+    let a: Matrix<f64> = rotation_blocks(20);
+    let n: usize = 40;
+
+    let dec: EigenDec<Complex<f64>> = Arnoldi::new(2, 16, 500, 1.0e-10).solve(&a, &start(n), EigenTarget::LargestMagnitude)
+                                                                        .unwrap();
+    let value: Vector<Complex<f64>> = dec.clone().value();
+
+    // the conjugate pair 20 ± 10 i
+    assert_relative_eq!(20.0, value.get(0).re, epsilon=1.0e-8);
+    assert_relative_eq!(20.0, value.get(1).re, epsilon=1.0e-8);
+    assert_relative_eq!(10.0, value.get(0).im.abs(), epsilon=1.0e-8);
+    assert_relative_eq!(-value.get(0).im, value.get(1).im, epsilon=1.0e-8);
+
+    assert_eigenpairs(&a, dec, 1.0e-6);
+}
+
+#[test]
+fn smallest_real_nonsymmetric()
+{
+    let n: usize = 40;
+    let a: CSRMatrix<f64> = tridiagonal(n, -1.2, 3.0, -0.8);
+
+    let dec: EigenDec<Complex<f64>> = Arnoldi::new(2, 20, 500, 1.0e-10).solve(&a, &start(n), EigenTarget::SmallestReal)
+                                                                        .unwrap();
+    let value: Vector<Complex<f64>> = dec.clone().value();
+
+    // the eigenvalues are 3 - 2 sqrt(0.96) cos(k pi / (n + 1))
+    let h: f64 = std::f64::consts::PI / (n + 1) as f64;
+    for k in 0..2
+    {
+        assert_relative_eq!(3.0 - 2.0 * 0.96f64.sqrt() * (h * (k + 1) as f64).cos(), value.get(k).re, epsilon=1.0e-8);
+        assert_relative_eq!(0.0, value.get(k).im, epsilon=1.0e-8);
+    }
+
+    assert_eigenpairs(&Matrix::from(a), dec, 1.0e-6);
+}
+
+#[test]
+fn no_convergence()
+{
+    let a: Matrix<f64> = rotation_blocks(20);
+
+    let result: Result<EigenDec<Complex<f64>>, MathError> = Arnoldi::new(2, 5, 0, 1.0e-12).solve(&a, &start(40), EigenTarget::SmallestMagnitude);
+
+    assert_eq!(Err(MathError::NoConvergence { iterations: 0 }), result.map(|_| ()));
+}
+
+#[test]
+fn zero_start_vector()
+{
+    let a: Matrix<f64> = rotation_blocks(5);
+
+    let result: Result<EigenDec<Complex<f64>>, MathError> = Arnoldi::new(2, 6, 10, 1.0e-12).solve(&a, &Vector::zero(10), EigenTarget::LargestReal);
+
+    assert_eq!(Err(MathError::Breakdown { iterations: 0 }), result.map(|_| ()));
+}
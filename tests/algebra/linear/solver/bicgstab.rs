@@ -0,0 +1,35 @@
+use super::tridiagonal;
+use mathru::algebra::linear::{
+    solver::{BiCGSTAB, IterativeResult, Jacobi},
+    sparse::CSRMatrix,
+    Matrix, Vector,
+};
+
+#[test]
+fn solve_dense()
+{
+    let a: Matrix<f64> = matrix![   4.0, 1.0, 2.0;
+                                    -1.0, 3.0, -1.0;
+                                    0.5, -1.0, 2.0];
+    let b: Vector<f64> = vector![1.0; 2.0; 3.0];
+
+    let result: IterativeResult<f64> = BiCGSTAB::new(20, 1.0e-12).solve(&a, &b, &Vector::zero(3)).unwrap();
+
+    assert!(result.converged());
+    assert_relative_eq!(b, &a * &result.x(), epsilon=1.0e-10);
+}
+
+#[test]
+fn solve_preconditioned()
+{
+    let n: usize = 40;
+    let a: CSRMatrix<f64> = tridiagonal(n, -1.3, 3.0, -0.7);
+    let x_ref: Vector<f64> = Vector::new_column(n, (0..n).map(|i| (i as f64).cos()).collect());
+    let b: Vector<f64> = &a * &x_ref;
+
+    let jacobi: Jacobi<f64> = Jacobi::new(&a).unwrap();
+    let result: IterativeResult<f64> = BiCGSTAB::new(200, 1.0e-12).solve_preconditioned(&a, &b, &Vector::zero(n), &jacobi).unwrap();
+
+    assert!(result.converged());
+    assert_relative_eq!(x_ref, result.x(), epsilon=1.0e-9);
+}
@@ -0,0 +1,94 @@
+use super::tridiagonal;
+use mathru::algebra::linear::{
+    solver::{IterativeResult, Jacobi, LinearOperator, CG},
+    sparse::CSRMatrix,
+    Matrix, Vector,
+};
+
+#[test]
+fn solve_dense()
+{
+    let a: Matrix<f64> = matrix![   4.0, 1.0, 0.0;
+                                    1.0, 3.0, -1.0;
+                                    0.0, -1.0, 2.0];
+    let b: Vector<f64> = vector![1.0; 2.0; 3.0];
+
+    let result: IterativeResult<f64> = CG::new(10, 1.0e-12).solve(&a, &b, &Vector::zero(3)).unwrap();
+
+    assert!(result.converged());
+    assert!(result.iterations() <= 3);
+    assert_relative_eq!(b, &a * &result.x(), epsilon=1.0e-10);
+}
+
+#[test]
+fn solve_sparse()
+{
+    let n: usize = 50;
+    let a: CSRMatrix<f64> = tridiagonal(n, -1.0, 2.0, -1.0);
+    let x_ref: Vector<f64> = Vector::new_column(n, (0..n).map(|i| (i as f64).sin()).collect());
+    let b: Vector<f64> = &a * &x_ref;
+
+    let result: IterativeResult<f64> = CG::new(100, 1.0e-12).solve(&a, &b, &Vector::zero(n)).unwrap();
+
+    assert!(result.converged());
+    assert_eq!(result.iterations() + 1, result.residuals().len());
+    assert_relative_eq!(x_ref, result.x(), epsilon=1.0e-8);
+}
+
+#[test]
+fn solve_preconditioned()
+{
+    let n: usize = 30;
+    let mut a: CSRMatrix<f64> = tridiagonal(n, -1.0, 4.0, -1.0);
+    a = CSRMatrix::new(n,
+                       n,
+                       a.row_ptr().to_vec(),
+                       a.col_idx().to_vec(),
+                       a.values().iter().enumerate().map(|(k, v)| if *v > 0.0 { *v * (1.0 + k as f64) } else { *v }).collect());
+    let b: Vector<f64> = Vector::one(n);
+
+    let jacobi: Jacobi<f64> = Jacobi::new(&a).unwrap();
+    let cg: CG<f64> = CG::new(200, 1.0e-10);
+    let plain: IterativeResult<f64> = cg.solve(&a, &b, &Vector::zero(n)).unwrap();
+    let preconditioned: IterativeResult<f64> = cg.solve_preconditioned(&a, &b, &Vector::zero(n), &jacobi).unwrap();
+
+    assert!(preconditioned.converged());
+    assert!(preconditioned.iterations() < plain.iterations());
+    assert_relative_eq!(b, a.apply(&preconditioned.x()), epsilon=1.0e-8);
+}
+
+#[test]
+fn solve_matrix_free()
+{
+    let n: usize = 20;
+    let laplacian = |x: &Vector<f64>| -> Vector<f64> {
+        let mut y: Vector<f64> = Vector::zero(n);
+        for i in 0..n
+        {
+            let left: f64 = if i > 0 { *x.get(i - 1) } else { 0.0 };
+            let right: f64 = if i + 1 < n { *x.get(i + 1) } else { 0.0 };
+            *y.get_mut(i) = 2.0 * *x.get(i) - left - right;
+        }
+        y
+    };
+    let b: Vector<f64> = Vector::one(n);
+
+    let result: IterativeResult<f64> = CG::new(100, 1.0e-12).solve(&laplacian, &b, &Vector::zero(n)).unwrap();
+
+    assert!(result.converged());
+    assert_relative_eq!(b, laplacian(&result.x()), epsilon=1.0e-9);
+}
+
+#[test]
+fn not_converged()
+{
+    let n: usize = 50;
+    let a: CSRMatrix<f64> = tridiagonal(n, -1.0, 2.0, -1.0);
+    let b: Vector<f64> = Vector::one(n);
+
+    let result: IterativeResult<f64> = CG::new(3, 1.0e-12).solve(&a, &b, &Vector::zero(n)).unwrap();
+
+    assert!(!result.converged());
+    assert_eq!(3, result.iterations());
+    assert_eq!(4, result.residuals().len());
+}
@@ -0,0 +1,52 @@
+use super::tridiagonal;
+use mathru::algebra::linear::{
+    solver::{IterativeResult, ILU0, GMRES},
+    sparse::CSRMatrix,
+    Matrix, Vector,
+};
+
+#[test]
+fn solve_dense()
+{
+    let a: Matrix<f64> = matrix![   4.0, 1.0, 2.0;
+                                    -1.0, 3.0, -1.0;
+                                    0.5, -1.0, 2.0];
+    let b: Vector<f64> = vector![1.0; 2.0; 3.0];
+
+    let result: IterativeResult<f64> = GMRES::new(3, 10, 1.0e-12).solve(&a, &b, &Vector::zero(3)).unwrap();
+
+    assert!(result.converged());
+    assert!(result.iterations() <= 3);
+    assert_relative_eq!(b, &a * &result.x(), epsilon=1.0e-10);
+}
+
+#[test]
+fn solve_restarted()
+{
+    let n: usize = 40;
+    let a: CSRMatrix<f64> = tridiagonal(n, -1.3, 3.0, -0.7);
+    let x_ref: Vector<f64> = Vector::new_column(n, (0..n).map(|i| (i as f64).cos()).collect());
+    let b: Vector<f64> = &a * &x_ref;
+
+    let result: IterativeResult<f64> = GMRES::new(5, 500, 1.0e-12).solve(&a, &b, &Vector::zero(n)).unwrap();
+
+    assert!(result.converged());
+    assert!(result.iterations() > 5);
+    assert_relative_eq!(x_ref, result.x(), epsilon=1.0e-9);
+}
+
+#[test]
+fn solve_preconditioned()
+{
+    let n: usize = 40;
+    let a: CSRMatrix<f64> = tridiagonal(n, -1.3, 3.0, -0.7);
+    let b: Vector<f64> = Vector::one(n);
+
+    // ILU(0) of a tridiagonal matrix is its exact LU decomposition
+    let ilu: ILU0<f64> = ILU0::new(&a).unwrap();
+    let result: IterativeResult<f64> = GMRES::new(10, 100, 1.0e-12).solve_preconditioned(&a, &b, &Vector::zero(n), &ilu).unwrap();
+
+    assert!(result.converged());
+    assert_eq!(1, result.iterations());
+    assert_relative_eq!(b, &a * &result.x(), epsilon=1.0e-10);
+}
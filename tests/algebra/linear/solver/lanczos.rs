@@ -0,0 +1,121 @@
+use super::tridiagonal;
+use mathru::{
+    algebra::linear::{
+        matrix::{EigenDec, LUDec, Solve},
+        solver::{EigenTarget, Lanczos},
+        sparse::CSRMatrix,
+        Matrix, Vector,
+    },
+    error::MathError,
+};
+
+/// Eigenvalue 2 - 2 cos(k pi / (n + 1)) of the 1D Laplacian
+fn laplacian_eigenvalue(n: usize, k: usize) -> f64
+{
+    let h: f64 = std::f64::consts::PI / (n + 1) as f64;
+    return 2.0 - 2.0 * (h * k as f64).cos();
+}
+
+fn start(n: usize) -> Vector<f64>
+{
+    return Vector::new_column(n, (0..n).map(|i| 1.0 + (i as f64).sin()).collect());
+}
+
+#[test]
+fn largest_magnitude()
+{
+    let n: usize = 100;
+    let a: CSRMatrix<f64> = tridiagonal(n, -1.0, 2.0, -1.0);
+
+    let (value, vector): (Vector<f64>, Matrix<f64>) = Lanczos::new(4, 20, 500, 1.0e-10).solve(&a, &start(n), EigenTarget::LargestMagnitude)
+                                                                                        .unwrap()
+                                                                                        .pair();
+
+    for k in 0..4
+    {
+        assert_relative_eq!(laplacian_eigenvalue(n, n - k), *value.get(k), epsilon=1.0e-8);
+
+        let x: Vector<f64> = vector.get_column(k);
+        assert_relative_eq!(1.0, x.p_norm(&2.0), epsilon=1.0e-10);
+        assert_relative_eq!(&a * &x, x.clone() * *value.get(k), epsilon=1.0e-6);
+    }
+}
+
+#[test]
+fn smallest_real()
+{
+    let n: usize = 30;
+    let a: CSRMatrix<f64> = tridiagonal(n, -1.0, 2.0, -1.0);
+
+    let value: Vector<f64> = Lanczos::new(2, 29, 1000, 1.0e-10).solve(&a, &start(n), EigenTarget::SmallestReal)
+                                                               .unwrap()
+                                                               .value();
+
+    assert_relative_eq!(laplacian_eigenvalue(n, 1), *value.get(0), epsilon=1.0e-8);
+    assert_relative_eq!(laplacian_eigenvalue(n, 2), *value.get(1), epsilon=1.0e-8);
+}
+
+#[test]
+fn shift_invert()
+{
+    let n: usize = 60;
+    let a: Matrix<f64> = Matrix::from(tridiagonal(n, -1.0, 2.0, -1.0));
+
+    // eigenvalues closest to 1
+    let sigma: f64 = 1.0;
+    let lu: LUDec<f64> = (&a - &(Matrix::one(n) * sigma)).dec_lu().unwrap();
+    let a_inv = |x: &Vector<f64>| lu.solve(x).unwrap();
+
+    let dec: EigenDec<f64> = Lanczos::new(2, 12, 100, 1.0e-12).solve(&a_inv, &start(n), EigenTarget::ShiftInvert(sigma))
+                                                              .unwrap();
+    let (value, vector): (Vector<f64>, Matrix<f64>) = dec.pair();
+
+    let mut reference: Vec<f64> = (1..=n).map(|k| laplacian_eigenvalue(n, k)).collect();
+    reference.sort_by(|a, b| (a - sigma).abs().partial_cmp(&(b - sigma).abs()).unwrap());
+
+    for (k, lambda) in reference.iter().take(2).enumerate()
+    {
+        assert_relative_eq!(*lambda, *value.get(k), epsilon=1.0e-10);
+
+        let x: Vector<f64> = vector.get_column(k);
+        assert_relative_eq!(&a * &x, x.clone() * *value.get(k), epsilon=1.0e-8);
+    }
+}
+
+#[test]
+fn no_convergence()
+{
+    let n: usize = 100;
+    let a: CSRMatrix<f64> = tridiagonal(n, -1.0, 2.0, -1.0);
+
+    let result: Result<EigenDec<f64>, MathError> = Lanczos::new(3, 6, 0, 1.0e-12).solve(&a, &start(n), EigenTarget::SmallestReal);
+
+    assert_eq!(Err(MathError::NoConvergence { iterations: 0 }), result.map(|_| ()));
+}
+
+#[test]
+fn dimension_mismatch()
+{
+    let a: CSRMatrix<f64> = tridiagonal(2, -1.0, 2.0, -1.0);
+
+    let result: Result<EigenDec<f64>, MathError> = Lanczos::new(3, 6, 10, 1.0e-12).solve(&a, &start(2), EigenTarget::LargestMagnitude);
+
+    assert_eq!(Err(MathError::DimensionMismatch), result.map(|_| ()));
+}
+
+#[test]
+fn zero_start_vector()
+{
+    let a: CSRMatrix<f64> = tridiagonal(10, -1.0, 2.0, -1.0);
+
+    let result: Result<EigenDec<f64>, MathError> = Lanczos::new(2, 6, 10, 1.0e-12).solve(&a, &Vector::zero(10), EigenTarget::LargestMagnitude);
+
+    assert_eq!(Err(MathError::Breakdown { iterations: 0 }), result.map(|_| ()));
+}
+
+#[test]
+#[should_panic]
+fn new_ncv_too_small()
+{
+    let _: Lanczos<f64> = Lanczos::new(3, 3, 10, 1.0e-12);
+}
@@ -0,0 +1,28 @@
+use mathru::algebra::linear::{sparse::COOMatrix, sparse::CSRMatrix};
+
+mod arnoldi;
+mod bicgstab;
+mod cg;
+mod gmres;
+mod lanczos;
+mod preconditioner;
+mod refinement;
+
+/// Tridiagonal matrix with the entries (lower, diag, upper)
+fn tridiagonal(n: usize, lower: f64, diag: f64, upper: f64) -> CSRMatrix<f64>
+{
+    let mut a: COOMatrix<f64> = COOMatrix::new(n, n);
+    for i in 0..n
+    {
+        a.push(i, i, diag);
+        if i > 0
+        {
+            a.push(i, i - 1, lower);
+        }
+        if i + 1 < n
+        {
+            a.push(i, i + 1, upper);
+        }
+    }
+    return CSRMatrix::from(a);
+}
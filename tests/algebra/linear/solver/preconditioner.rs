@@ -0,0 +1,81 @@
+use super::tridiagonal;
+use mathru::algebra::linear::{
+    solver::{Jacobi, Preconditioner, ILU0, SSOR},
+    sparse::CSRMatrix,
+    Matrix, Vector,
+};
+
+#[test]
+fn jacobi()
+{
+    let a: CSRMatrix<f64> = tridiagonal(3, -1.0, 4.0, -1.0);
+    let r: Vector<f64> = vector![4.0; 8.0; -2.0];
+
+    let z: Vector<f64> = Jacobi::new(&a).unwrap().apply(&r);
+
+    assert_relative_eq!(vector![1.0; 2.0; -0.5], z);
+}
+
+#[test]
+fn jacobi_zero_diagonal()
+{
+    let a: Matrix<f64> = matrix![   0.0, 1.0;
+                                    1.0, 2.0];
+
+    assert!(Jacobi::new(&CSRMatrix::from(&a)).is_err());
+}
+
+#[test]
+fn ssor()
+{
+    let a: Matrix<f64> = matrix![   4.0, -1.0, 0.0;
+                                    -1.0, 4.0, -1.0;
+                                    0.0, -1.0, 4.0];
+    let omega: f64 = 1.2;
+    let r: Vector<f64> = vector![1.0; 2.0; 3.0];
+
+    let z: Vector<f64> = SSOR::new(&CSRMatrix::from(&a), omega).unwrap().apply(&r);
+
+    // M = 1/(omega (2 - omega)) (D + omega L) D^-1 (D + omega U)
+    let d_l: Matrix<f64> = matrix![ 4.0, 0.0, 0.0;
+                                    -omega, 4.0, 0.0;
+                                    0.0, -omega, 4.0];
+    let d_u: Matrix<f64> = matrix![ 4.0, -omega, 0.0;
+                                    0.0, 4.0, -omega;
+                                    0.0, 0.0, 4.0];
+    let m: Matrix<f64> = &(&d_l * &d_u) * &(1.0 / (4.0 * omega * (2.0 - omega)));
+
+    assert_relative_eq!(r, &m * &z, epsilon=1.0e-12);
+}
+
+#[test]
+fn ilu0_tridiagonal()
+{
+    let a: CSRMatrix<f64> = tridiagonal(5, -1.0, 3.0, -2.0);
+    let x: Vector<f64> = vector![1.0; -2.0; 3.0; 0.5; 1.0];
+    let b: Vector<f64> = &a * &x;
+
+    let z: Vector<f64> = ILU0::new(&a).unwrap().apply(&b);
+
+    assert_relative_eq!(x, z, epsilon=1.0e-12);
+}
+
+#[test]
+fn ilu0_drops_fill_in()
+{
+    let a: Matrix<f64> = matrix![   4.0, 1.0, 1.0;
+                                    1.0, 4.0, 0.0;
+                                    1.0, 0.0, 4.0];
+    let ilu: ILU0<f64> = ILU0::new(&CSRMatrix::from(&a)).unwrap();
+
+    // L = [1 0 0; 1/4 1 0; 1/4 0 1], U = [4 1 1; 0 15/4 0; 0 0 15/4]
+    let l: Matrix<f64> = matrix![   1.0, 0.0, 0.0;
+                                    0.25, 1.0, 0.0;
+                                    0.25, 0.0, 1.0];
+    let u: Matrix<f64> = matrix![   4.0, 1.0, 1.0;
+                                    0.0, 3.75, 0.0;
+                                    0.0, 0.0, 3.75];
+    let r: Vector<f64> = vector![1.0; 2.0; 3.0];
+
+    assert_relative_eq!(r, &(&l * &u) * &ilu.apply(&r), epsilon=1.0e-12);
+}
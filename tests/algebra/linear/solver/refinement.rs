@@ -0,0 +1,88 @@
+use mathru::{
+    algebra::linear::{
+        matrix::{LUDec, Solve},
+        solver::{IterativeRefinement, RefinementResult},
+        Matrix, Vector,
+    },
+    error::MathError,
+};
+
+/// Diagonally dominant matrix with entries in all positions
+fn dense(n: usize) -> Matrix<f64>
+{
+    let mut a: Matrix<f64> = Matrix::zero(n, n);
+    for i in 0..n
+    {
+        for j in 0..n
+        {
+            *a.get_mut(i, j) = ((i * n + j) as f64).sin();
+        }
+        *a.get_mut(i, i) += n as f64;
+    }
+    return a;
+}
+
+fn hilbert(n: usize) -> Matrix<f64>
+{
+    let mut a: Matrix<f64> = Matrix::zero(n, n);
+    for i in 0..n
+    {
+        for j in 0..n
+        {
+            *a.get_mut(i, j) = 1.0 / ((i + j + 1) as f64);
+        }
+    }
+    return a;
+}
+
+#[test]
+fn solve_single_precision()
+{
+    let n: usize = 40;
+    let a: Matrix<f64> = dense(n);
+    let x_ref: Vector<f64> = Vector::new_column(n, (0..n).map(|i| 1.0 + (i as f64).cos() / 3.0).collect());
+    let b: Vector<f64> = &a * &x_ref;
+
+    let result: RefinementResult<f64> = IterativeRefinement::new(20, 1.0e-15).solve(&a, &b).unwrap();
+
+    assert!(result.converged());
+    assert!(result.iterations() >= 1);
+    assert!(result.backward_error() <= 1.0e-15);
+    assert!(result.forward_error() <= 1.0e-13);
+    assert_relative_eq!(x_ref, result.x(), epsilon=1.0e-13);
+}
+
+#[test]
+fn solve_lu_double_precision()
+{
+    let a: Matrix<f64> = dense(10);
+    let b: Vector<f64> = Vector::new_column(10, (0..10).map(|i| i as f64).collect());
+    let lu: LUDec<f64> = a.dec_lu().unwrap();
+
+    let result: RefinementResult<f64> = IterativeRefinement::new(5, 1.0e-15).solve_lu(&a, &lu, &b).unwrap();
+
+    assert!(result.converged());
+    assert!(result.iterations() <= 1);
+    assert_relative_eq!(lu.solve(&b).unwrap(), result.x(), epsilon=1.0e-13);
+}
+
+#[test]
+fn solve_ill_conditioned()
+{
+    let a: Matrix<f64> = hilbert(10);
+    let b: Vector<f64> = &a * &Vector::new_column(10, vec![1.0; 10]);
+
+    let result: RefinementResult<f64> = IterativeRefinement::new(50, 1.0e-15).solve(&a, &b).unwrap();
+
+    assert!(!result.converged());
+    assert!(result.iterations() < 50);
+}
+
+#[test]
+fn solve_dimension_mismatch()
+{
+    let refinement: IterativeRefinement<f64> = IterativeRefinement::new(5, 1.0e-15);
+
+    assert_eq!(MathError::DimensionMismatch, refinement.solve(&Matrix::zero(2, 3), &Vector::zero(2)).unwrap_err());
+    assert_eq!(MathError::DimensionMismatch, refinement.solve(&dense(3), &Vector::zero(2)).unwrap_err());
+}
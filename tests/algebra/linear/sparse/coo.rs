@@ -0,0 +1,77 @@
+use mathru::algebra::linear::{
+    matrix::Transpose,
+    sparse::{COOMatrix, CSCMatrix, CSRMatrix},
+    Matrix, Vector,
+};
+
+#[test]
+fn from_triplets()
+{
+    let a: COOMatrix<f64> = COOMatrix::from_triplets(3,
+                                                     2,
+                                                     vec![0, 2, 1, 2],
+                                                     vec![1, 0, 1, 0],
+                                                     vec![1.0, 2.0, 3.0, 4.0]);
+
+    let a_ref: Matrix<f64> = matrix![   0.0, 1.0;
+                                        0.0, 3.0;
+                                        6.0, 0.0];
+
+    assert_eq!(4, a.nnz());
+    assert_relative_eq!(a_ref, Matrix::from(a));
+}
+
+#[test]
+fn to_csr()
+{
+    let mut a: COOMatrix<f64> = COOMatrix::new(3, 3);
+    a.push(2, 2, 1.0);
+    a.push(0, 1, 2.0);
+    a.push(2, 0, 3.0);
+    a.push(0, 1, -1.0);
+
+    let a_csr: CSRMatrix<f64> = CSRMatrix::from(a);
+
+    assert_eq!(&[0, 1, 1, 3], a_csr.row_ptr());
+    assert_eq!(&[1, 0, 2], a_csr.col_idx());
+    assert_eq!(&[1.0, 3.0, 1.0], a_csr.values());
+}
+
+#[test]
+fn to_csc()
+{
+    let mut a: COOMatrix<f64> = COOMatrix::new(3, 3);
+    a.push(2, 2, 1.0);
+    a.push(0, 1, 2.0);
+    a.push(2, 0, 3.0);
+    a.push(0, 1, -1.0);
+
+    let a_csc: CSCMatrix<f64> = CSCMatrix::from(a);
+
+    assert_eq!(&[0, 1, 2, 3], a_csc.col_ptr());
+    assert_eq!(&[2, 0, 2], a_csc.row_idx());
+    assert_eq!(&[3.0, 1.0, 1.0], a_csc.values());
+}
+
+#[test]
+fn transpose()
+{
+    let a: Matrix<f64> = matrix![   1.0, 0.0, 2.0;
+                                    0.0, -3.0, 0.0];
+
+    let a_coo: COOMatrix<f64> = COOMatrix::from(&a);
+
+    assert_relative_eq!(a.transpose(), Matrix::from(a_coo.transpose()));
+}
+
+#[test]
+fn from_vector()
+{
+    let v: Vector<f64> = vector![1.0; 0.0; 3.0];
+
+    let v_coo: COOMatrix<f64> = COOMatrix::from(&v);
+
+    assert_eq!((3, 1), v_coo.dim());
+    assert_eq!(2, v_coo.nnz());
+    assert_relative_eq!(Matrix::from(v), Matrix::from(v_coo));
+}
@@ -0,0 +1,86 @@
+use mathru::algebra::linear::{
+    matrix::Transpose,
+    sparse::{CSCMatrix, CSRMatrix},
+    Matrix, Vector,
+};
+
+#[test]
+fn from_matrix()
+{
+    let a: Matrix<f64> = matrix![   1.0, 0.0, 2.0;
+                                    0.0, 0.0, 0.0;
+                                    0.0, -3.0, 4.0];
+
+    let a_csc: CSCMatrix<f64> = CSCMatrix::from(&a);
+
+    assert_eq!(&[0, 1, 2, 4], a_csc.col_ptr());
+    assert_eq!(&[0, 2, 0, 2], a_csc.row_idx());
+    assert_eq!(&[1.0, -3.0, 2.0, 4.0], a_csc.values());
+    assert_relative_eq!(a, Matrix::from(a_csc));
+}
+
+#[test]
+fn mul_vector()
+{
+    let a: Matrix<f64> = matrix![   1.0, 0.0, 2.0;
+                                    0.0, 0.0, 0.0;
+                                    0.0, -3.0, 4.0;
+                                    7.0, 0.0, 0.0];
+    let x: Vector<f64> = vector![1.0; 2.0; -1.0];
+
+    let a_csc: CSCMatrix<f64> = CSCMatrix::from(&a);
+
+    assert_relative_eq!(&a * &x, &a_csc * &x);
+}
+
+#[test]
+fn mul_matrix()
+{
+    let a: Matrix<f64> = matrix![   1.0, 0.0, 2.0;
+                                    0.0, -3.0, 4.0];
+    let b: Matrix<f64> = matrix![   1.0, 2.0;
+                                    3.0, 4.0;
+                                    5.0, 6.0];
+
+    let a_csc: CSCMatrix<f64> = CSCMatrix::from(&a);
+
+    assert_relative_eq!(&a * &b, &a_csc * &b);
+}
+
+#[test]
+fn mul_sparse()
+{
+    let a: Matrix<f64> = matrix![   1.0, 0.0, 2.0;
+                                    0.0, -3.0, 4.0];
+    let b: Matrix<f64> = matrix![   0.0, 2.0, 0.0, 0.0;
+                                    3.0, 0.0, 0.0, 1.0;
+                                    5.0, 0.0, 0.0, 0.0];
+
+    let c: CSCMatrix<f64> = CSCMatrix::from(&a) * CSCMatrix::from(&b);
+
+    assert_eq!((2, 4), c.dim());
+    assert_relative_eq!(&a * &b, Matrix::from(c));
+}
+
+#[test]
+fn transpose()
+{
+    let a: Matrix<f64> = matrix![   1.0, 0.0, 2.0;
+                                    0.0, -3.0, 4.0];
+
+    let a_t: CSCMatrix<f64> = CSCMatrix::from(&a).transpose();
+
+    assert_eq!((3, 2), a_t.dim());
+    assert_relative_eq!(a.transpose(), Matrix::from(a_t));
+}
+
+#[test]
+fn to_csr()
+{
+    let a: Matrix<f64> = matrix![   1.0, 0.0, 2.0;
+                                    0.0, -3.0, 4.0];
+
+    let a_csr: CSRMatrix<f64> = CSRMatrix::from(CSCMatrix::from(&a));
+
+    assert_relative_eq!(a, Matrix::from(a_csr));
+}
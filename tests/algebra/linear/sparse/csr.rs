@@ -0,0 +1,111 @@
+use mathru::algebra::linear::{
+    matrix::Transpose,
+    sparse::{CSCMatrix, CSRMatrix},
+    Matrix, Vector,
+};
+
+#[test]
+fn from_matrix()
+{
+    let a: Matrix<f64> = matrix![   1.0, 0.0, 2.0;
+                                    0.0, 0.0, 0.0;
+                                    0.0, -3.0, 4.0];
+
+    let a_csr: CSRMatrix<f64> = CSRMatrix::from(&a);
+
+    assert_eq!(&[0, 2, 2, 4], a_csr.row_ptr());
+    assert_eq!(&[0, 2, 1, 2], a_csr.col_idx());
+    assert_eq!(&[1.0, 2.0, -3.0, 4.0], a_csr.values());
+    assert_relative_eq!(a, Matrix::from(a_csr));
+}
+
+#[test]
+fn get()
+{
+    let a: Matrix<f64> = matrix![   1.0, 0.0, 2.0;
+                                    0.0, 5.0, 0.0];
+
+    let a_csr: CSRMatrix<f64> = CSRMatrix::from(&a);
+
+    for i in 0..2
+    {
+        for j in 0..3
+        {
+            assert_eq!(*a.get(i, j), a_csr.get(i, j));
+        }
+    }
+}
+
+#[test]
+#[should_panic]
+fn new_unsorted()
+{
+    let _a: CSRMatrix<f64> = CSRMatrix::new(1, 3, vec![0, 2], vec![2, 0], vec![1.0, 2.0]);
+}
+
+#[test]
+fn mul_vector()
+{
+    let a: Matrix<f64> = matrix![   1.0, 0.0, 2.0;
+                                    0.0, 0.0, 0.0;
+                                    0.0, -3.0, 4.0;
+                                    7.0, 0.0, 0.0];
+    let x: Vector<f64> = vector![1.0; 2.0; -1.0];
+
+    let a_csr: CSRMatrix<f64> = CSRMatrix::from(&a);
+
+    assert_relative_eq!(&a * &x, &a_csr * &x);
+}
+
+#[test]
+fn mul_matrix()
+{
+    let a: Matrix<f64> = matrix![   1.0, 0.0, 2.0;
+                                    0.0, -3.0, 4.0];
+    let b: Matrix<f64> = matrix![   1.0, 2.0;
+                                    3.0, 4.0;
+                                    5.0, 6.0];
+
+    let a_csr: CSRMatrix<f64> = CSRMatrix::from(&a);
+
+    assert_relative_eq!(&a * &b, &a_csr * &b);
+}
+
+#[test]
+fn mul_sparse()
+{
+    let a: Matrix<f64> = matrix![   1.0, 0.0, 2.0;
+                                    0.0, -3.0, 4.0];
+    let b: Matrix<f64> = matrix![   0.0, 2.0, 0.0, 0.0;
+                                    3.0, 0.0, 0.0, 1.0;
+                                    5.0, 0.0, 0.0, 0.0];
+
+    let c: CSRMatrix<f64> = CSRMatrix::from(&a) * CSRMatrix::from(&b);
+
+    assert_eq!((2, 4), c.dim());
+    assert_eq!(4, c.nnz());
+    assert_relative_eq!(&a * &b, Matrix::from(c));
+}
+
+#[test]
+fn transpose()
+{
+    let a: Matrix<f64> = matrix![   1.0, 0.0, 2.0;
+                                    0.0, -3.0, 4.0];
+
+    let a_t: CSRMatrix<f64> = CSRMatrix::from(&a).transpose();
+
+    assert_eq!((3, 2), a_t.dim());
+    assert_relative_eq!(a.transpose(), Matrix::from(a_t));
+}
+
+#[test]
+fn to_csc()
+{
+    let a: Matrix<f64> = matrix![   1.0, 0.0, 2.0;
+                                    0.0, -3.0, 4.0];
+
+    let a_csc: CSCMatrix<f64> = CSCMatrix::from(CSRMatrix::from(&a));
+
+    assert_relative_eq!(a, Matrix::from(a_csc));
+}
@@ -0,0 +1,3 @@
+mod coo;
+mod csc;
+mod csr;
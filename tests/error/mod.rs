@@ -0,0 +1,28 @@
+use mathru::{algebra::linear::Matrix, error::MathError};
+use std::error::Error;
+
+#[test]
+fn display()
+{
+    assert_eq!("matrix is singular, pivot 2 is zero",
+               MathError::Singular { pivot: 2 }.to_string());
+    assert_eq!("no convergence after 100 iterations",
+               MathError::NoConvergence { iterations: 100 }.to_string());
+    assert_eq!("input is not finite", MathError::NotFinite.to_string());
+    assert_eq!("LAPACK routine returned info = -4", MathError::LapackInfo(-4).to_string());
+}
+
+#[test]
+fn boxed_error()
+{
+    fn cholesky(a: &Matrix<f64>) -> Result<Matrix<f64>, Box<dyn Error>>
+    {
+        return Ok(a.dec_cholesky()?.l());
+    }
+
+    let a: Matrix<f64> = matrix![   -1.0, 0.0;
+                                    0.0, 1.0];
+
+    let err: Box<dyn Error> = cholesky(&a).unwrap_err();
+    assert_eq!(Some(&MathError::NotPositiveDefinite), err.downcast_ref::<MathError>());
+}
@@ -0,0 +1,194 @@
+use mathru::{
+    algebra::linear::{Matrix, Vector},
+    io::{
+        csv::{self, CsvOptions, MissingValue},
+        IoError,
+    },
+};
+
+fn is_parse_error<T>(result: Result<T, IoError>, line: usize) -> bool
+{
+    matches!(result, Err(IoError::Parse { line: l, .. }) if l == line)
+}
+
+#[test]
+fn read_matrix_header()
+{
+    let file: &str = "# first day\n\
+                      time;\"temperature; outside\"\n\
+                      \n\
+                      0.0; 20.5\n\
+                      # second day\n\
+                      1.0; 21.25\n";
+    let mut options: CsvOptions = CsvOptions::new(';', true);
+    options.set_comment(Some('#'));
+
+    let (names, a): (Vec<String>, Matrix<f64>) = csv::read_matrix(file.as_bytes(), &options).unwrap();
+
+    assert_eq!(vec!["time", "temperature; outside"], names);
+    assert_eq!(matrix![0.0, 20.5; 1.0, 21.25], a);
+}
+
+#[test]
+fn read_matrix_quoted()
+{
+    let file: &str = "\"a \"\"b\"\"\",c\n\"1.5\",2\n";
+
+    let (names, a): (Vec<String>, Matrix<f32>) = csv::read_matrix(file.as_bytes(), &CsvOptions::new(',', true)).unwrap();
+
+    assert_eq!(vec!["a \"b\"", "c"], names);
+    assert_eq!(matrix![1.5, 2.0], a);
+}
+
+#[test]
+fn read_matrix_tab()
+{
+    let file: &str = "1\t2\t3\n4\t5\t6\n";
+
+    let (names, a): (Vec<String>, Matrix<f64>) = csv::read_matrix(file.as_bytes(), &CsvOptions::new('\t', false)).unwrap();
+
+    assert!(names.is_empty());
+    assert_eq!(matrix![1.0, 2.0, 3.0; 4.0, 5.0, 6.0], a);
+}
+
+#[test]
+fn read_matrix_missing_error()
+{
+    let file: &str = "1,2\n3,\n";
+
+    assert!(is_parse_error(csv::read_matrix::<f64, _>(file.as_bytes(), &CsvOptions::default()), 2));
+}
+
+#[test]
+fn read_matrix_missing_nan()
+{
+    let file: &str = "1,NA\n,4\n";
+    let mut options: CsvOptions = CsvOptions::default();
+    options.set_missing(MissingValue::NaN);
+
+    let (_, a): (Vec<String>, Matrix<f64>) = csv::read_matrix(file.as_bytes(), &options).unwrap();
+
+    assert_eq!(1.0, *a.get(0, 0));
+    assert!(a.get(0, 1).is_nan());
+    assert!(a.get(1, 0).is_nan());
+    assert_eq!(4.0, *a.get(1, 1));
+}
+
+#[test]
+fn read_matrix_missing_skip()
+{
+    let file: &str = "x,y\n1,2\n3,NA\n,6\n7,8\n";
+    let mut options: CsvOptions = CsvOptions::new(',', true);
+    options.set_missing(MissingValue::Skip);
+
+    let (_, a): (Vec<String>, Matrix<f64>) = csv::read_matrix(file.as_bytes(), &options).unwrap();
+
+    assert_eq!(matrix![1.0, 2.0; 7.0, 8.0], a);
+}
+
+#[test]
+fn read_matrix_invalid()
+{
+    let file: &str = "1,2\n3,x\n";
+
+    assert!(is_parse_error(csv::read_matrix::<f64, _>(file.as_bytes(), &CsvOptions::default()), 2));
+}
+
+#[test]
+fn read_matrix_ragged()
+{
+    let file: &str = "1,2\n\n3,4,5\n";
+
+    assert!(is_parse_error(csv::read_matrix::<f64, _>(file.as_bytes(), &CsvOptions::default()), 3));
+}
+
+#[test]
+fn read_vector()
+{
+    let options: CsvOptions = CsvOptions::new(',', true);
+
+    let (names, v): (Vec<String>, Vector<f64>) = csv::read_vector("x\n1\n2\n3\n".as_bytes(), &options).unwrap();
+    assert_eq!(vec!["x"], names);
+    assert_eq!(vector![1.0; 2.0; 3.0], v);
+
+    let (_, v): (Vec<String>, Vector<f64>) = csv::read_vector("a,b\n1,2\n".as_bytes(), &options).unwrap();
+    assert_eq!(vector![1.0, 2.0], v);
+
+    assert!(csv::read_vector::<f64, _>("a,b\n1,2\n3,4\n".as_bytes(), &options).is_err());
+}
+
+#[test]
+fn write_read_matrix_exact()
+{
+    let a: Matrix<f64> = matrix![   0.1, 1.0 / 3.0, -2.0e-300;
+                                    1.0e20, std::f64::consts::PI, 0.0;
+                                    -7.0, 123456.789, 5.0e-5];
+    let mut file: Vec<u8> = Vec::new();
+
+    csv::write_matrix(&mut file, &a, &["x", "y, z", "w"], &CsvOptions::default()).unwrap();
+    let (names, b): (Vec<String>, Matrix<f64>) = csv::read_matrix(&file[..], &CsvOptions::new(',', true)).unwrap();
+
+    assert_eq!(vec!["x", "y, z", "w"], names);
+    assert_eq!(a, b);
+}
+
+#[test]
+fn write_read_matrix_exact_f32()
+{
+    let a: Matrix<f32> = matrix![0.1, 1.0 / 3.0, 1.0e-30, 3.4e38];
+    let mut file: Vec<u8> = Vec::new();
+
+    csv::write_matrix(&mut file, &a, &[], &CsvOptions::default()).unwrap();
+    let (_, b): (Vec<String>, Matrix<f32>) = csv::read_matrix(&file[..], &CsvOptions::default()).unwrap();
+
+    assert_eq!(a, b);
+}
+
+#[test]
+fn write_matrix_precision()
+{
+    let a: Matrix<f64> = matrix![1.0, 0.123456; -2500.0, 0.0];
+    let mut options: CsvOptions = CsvOptions::new(';', false);
+    options.set_precision(Some(2));
+    let mut file: Vec<u8> = Vec::new();
+
+    csv::write_matrix(&mut file, &a, &[], &options).unwrap();
+
+    assert_eq!("1.00e0;1.23e-1\n-2.50e3;0.00e0\n", String::from_utf8(file).unwrap());
+}
+
+#[test]
+fn write_matrix_wrong_names()
+{
+    let a: Matrix<f64> = matrix![1.0, 2.0; 3.0, 4.0];
+    let mut file: Vec<u8> = Vec::new();
+
+    let result: Result<(), IoError> = csv::write_matrix(&mut file, &a, &["x"], &CsvOptions::default());
+
+    assert!(matches!(result, Err(IoError::Format(_))));
+    assert!(file.is_empty());
+}
+
+#[test]
+fn write_vector()
+{
+    let v: Vector<f64> = vector![1.5; -2.0];
+    let mut file: Vec<u8> = Vec::new();
+
+    csv::write_vector(&mut file, &v, &["v"], &CsvOptions::default()).unwrap();
+
+    assert_eq!("v\n1.5\n-2\n", String::from_utf8(file).unwrap());
+}
+
+#[test]
+fn save_load_vector()
+{
+    let path = std::env::temp_dir().join("mathru_csv_save_load_vector.csv");
+    let v: Vector<f64> = vector![1.0, 2.5, -3.0e-10];
+
+    csv::save_vector(&path, &v, &[], &CsvOptions::default()).unwrap();
+    let (_, w): (Vec<String>, Vector<f64>) = csv::load_vector(&path, &CsvOptions::default()).unwrap();
+    std::fs::remove_file(&path).unwrap();
+
+    assert_eq!(v, w);
+}
@@ -0,0 +1,255 @@
+use mathru::{
+    algebra::{
+        abstr::Complex,
+        linear::{sparse::COOMatrix, Matrix},
+    },
+    io::{
+        matrixmarket::{self, Symmetry},
+        IoError,
+    },
+};
+
+fn parse_error_line<T>(result: Result<T, IoError>) -> usize
+{
+    match result
+    {
+        Err(IoError::Parse { line, .. }) => line,
+        _ => panic!("expected a parse error"),
+    }
+}
+
+#[test]
+fn read_coordinate_real_general()
+{
+    let mtx: &str = "%%MatrixMarket matrix coordinate real general
+% comment
+2 3 3
+1 1 1.5
+2 3 -2e1
+1 2 3
+";
+
+    let a: Matrix<f64> = matrixmarket::read_matrix(mtx.as_bytes()).unwrap();
+
+    assert_eq!(matrix![1.5, 3.0, 0.0; 0.0, 0.0, -20.0], a);
+}
+
+#[test]
+fn read_coordinate_symmetric_coo()
+{
+    let mtx: &str = "%%MatrixMarket matrix coordinate real symmetric
+3 3 3
+1 1 4.0
+3 1 -1.0
+2 2 5.0
+";
+
+    let a: COOMatrix<f64> = matrixmarket::read_coo(mtx.as_bytes()).unwrap();
+
+    assert_eq!(4, a.nnz());
+    assert_eq!(matrix![4.0, 0.0, -1.0; 0.0, 5.0, 0.0; -1.0, 0.0, 0.0], Matrix::from(a));
+}
+
+#[test]
+fn read_array_skew_symmetric()
+{
+    let mtx: &str = "%%MatrixMarket matrix array real skew-symmetric
+3 3
+1.0
+2.0
+3.0
+";
+
+    let a: Matrix<f64> = matrixmarket::read_matrix(mtx.as_bytes()).unwrap();
+
+    assert_eq!(matrix![0.0, -1.0, -2.0; 1.0, 0.0, -3.0; 2.0, 3.0, 0.0], a);
+}
+
+#[test]
+fn read_coordinate_complex_hermitian()
+{
+    let mtx: &str = "%%MatrixMarket matrix coordinate complex hermitian
+2 2 2
+1 1 1.0 0.0
+2 1 2.0 -3.0
+";
+
+    let a: Matrix<Complex<f64>> = matrixmarket::read_matrix(mtx.as_bytes()).unwrap();
+
+    assert_eq!(Complex::new(2.0, -3.0), *a.get(1, 0));
+    assert_eq!(Complex::new(2.0, 3.0), *a.get(0, 1));
+    assert_eq!(Complex::new(0.0, 0.0), *a.get(1, 1));
+}
+
+#[test]
+fn read_integer_and_pattern()
+{
+    let integer: &str = "%%MatrixMarket matrix array integer general
+1 2
+-3
+7
+";
+    let pattern: &str = "%%matrixmarket matrix coordinate pattern general
+2 2 1
+2 1
+";
+
+    let a: Matrix<f32> = matrixmarket::read_matrix(integer.as_bytes()).unwrap();
+    let b: Matrix<f64> = matrixmarket::read_matrix(pattern.as_bytes()).unwrap();
+
+    assert_eq!(matrix![-3.0, 7.0], a);
+    assert_eq!(matrix![0.0, 0.0; 1.0, 0.0], b);
+}
+
+#[test]
+fn read_complex_into_real()
+{
+    let mtx: &str = "%%MatrixMarket matrix coordinate complex general
+1 1 1
+1 1 1.0 2.0
+";
+
+    assert_eq!(1, parse_error_line(matrixmarket::read_matrix::<f64, _>(mtx.as_bytes())));
+}
+
+#[test]
+fn read_index_out_of_bounds()
+{
+    let mtx: &str = "%%MatrixMarket matrix coordinate real general
+% comment
+
+2 2 2
+1 1 1.0
+3 1 1.0
+";
+
+    let result: Result<Matrix<f64>, IoError> = matrixmarket::read_matrix(mtx.as_bytes());
+
+    assert_eq!("line 6: index 3 is out of bounds 1..2", result.unwrap_err().to_string());
+}
+
+#[test]
+fn read_invalid_value()
+{
+    let mtx: &str = "%%MatrixMarket matrix array real general
+2 1
+1.0
+one
+";
+
+    assert_eq!(4, parse_error_line(matrixmarket::read_coo::<f64, _>(mtx.as_bytes())));
+}
+
+#[test]
+fn read_missing_entries()
+{
+    let mtx: &str = "%%MatrixMarket matrix coordinate real general
+2 2 3
+1 1 1.0
+2 2 1.0
+";
+
+    assert_eq!(4, parse_error_line(matrixmarket::read_matrix::<f64, _>(mtx.as_bytes())));
+}
+
+#[test]
+fn read_invalid_header()
+{
+    let mtx: &str = "%%MatrixMarket matrix coordinate real unsymmetric
+1 1 1
+1 1 1.0
+";
+
+    assert_eq!(1, parse_error_line(matrixmarket::read_matrix::<f64, _>(mtx.as_bytes())));
+}
+
+#[test]
+fn read_too_large()
+{
+    let coordinate: String = format!("%%MatrixMarket matrix coordinate real general\n{} 2 0\n", usize::MAX);
+    let array: String = format!("%%MatrixMarket matrix array real symmetric\n{} {}\n", usize::MAX, usize::MAX);
+
+    assert!(matches!(matrixmarket::read_matrix::<f64, _>(coordinate.as_bytes()), Err(IoError::Format(_))));
+    assert_eq!(0, matrixmarket::read_coo::<f64, _>(coordinate.as_bytes()).unwrap().nnz());
+    assert!(matches!(matrixmarket::read_coo::<f64, _>(array.as_bytes()), Err(IoError::Format(_))));
+}
+
+#[test]
+fn write_read_matrix()
+{
+    let a: Matrix<f64> = matrix![   1.0, 0.1, -3.5;
+                                    2.0e-20, 5.0, 6.0];
+    let mut mtx: Vec<u8> = Vec::new();
+
+    matrixmarket::write_matrix(&mut mtx, &a, Symmetry::General).unwrap();
+
+    assert_eq!(a, matrixmarket::read_matrix(&mtx[..]).unwrap());
+}
+
+#[test]
+fn write_read_matrix_symmetric()
+{
+    let a: Matrix<f64> = matrix![   4.0, 1.0, 2.0;
+                                    1.0, 5.0, 3.0;
+                                    2.0, 3.0, 6.0];
+    let mut mtx: Vec<u8> = Vec::new();
+
+    matrixmarket::write_matrix(&mut mtx, &a, Symmetry::Symmetric).unwrap();
+
+    assert_eq!(8, String::from_utf8(mtx.clone()).unwrap().lines().count());
+    assert_eq!(a, matrixmarket::read_matrix(&mtx[..]).unwrap());
+}
+
+#[test]
+fn write_read_coo_complex()
+{
+    let a: COOMatrix<Complex<f64>> = COOMatrix::from_triplets(3,
+                                                              2,
+                                                              vec![0, 2],
+                                                              vec![1, 0],
+                                                              vec![Complex::new(1.0, -1.0), Complex::new(0.5, 2.0)]);
+    let mut mtx: Vec<u8> = Vec::new();
+
+    matrixmarket::write_coo(&mut mtx, &a, Symmetry::General).unwrap();
+    let b: COOMatrix<Complex<f64>> = matrixmarket::read_coo(&mtx[..]).unwrap();
+
+    assert_eq!(a.dim(), b.dim());
+    assert_eq!(a.row_idx(), b.row_idx());
+    assert_eq!(a.col_idx(), b.col_idx());
+    assert_eq!(a.values(), b.values());
+}
+
+#[test]
+fn write_invalid_symmetry()
+{
+    let a: Matrix<f64> = matrix![   1.0, 2.0, 3.0;
+                                    2.0, 4.0, 5.0];
+    let b: COOMatrix<f64> = COOMatrix::from_triplets(2, 2, vec![0], vec![0], vec![1.0]);
+    let mut mtx: Vec<u8> = Vec::new();
+
+    assert!(matches!(matrixmarket::write_matrix(&mut mtx, &a, Symmetry::Symmetric), Err(IoError::Format(_))));
+    assert!(matches!(matrixmarket::write_coo(&mut mtx, &b, Symmetry::Hermitian), Err(IoError::Format(_))));
+    assert!(mtx.is_empty());
+}
+
+#[test]
+fn save_load_matrix()
+{
+    let a: Matrix<f32> = matrix![   0.0, -1.0;
+                                    1.0, 0.0];
+    let path = std::env::temp_dir().join("mathru_save_load_matrix.mtx");
+
+    matrixmarket::save_matrix(&path, &a, Symmetry::SkewSymmetric).unwrap();
+    let b: Matrix<f32> = matrixmarket::load_matrix(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+
+    assert_eq!(a, b);
+}
+
+#[test]
+fn load_missing_file()
+{
+    let path = std::env::temp_dir().join("mathru_missing_file.mtx");
+
+    assert!(matches!(matrixmarket::load_coo::<f64, _>(&path), Err(IoError::Io(_))));
+}
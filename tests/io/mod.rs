@@ -0,0 +1,3 @@
+mod csv;
+mod matrixmarket;
+mod npy;
@@ -0,0 +1,202 @@
+use mathru::{
+    algebra::{
+        abstr::Complex,
+        linear::{matrix::Transpose, Matrix, Vector},
+    },
+    io::{npy, IoError},
+};
+
+/// Archive written like numpy.savez(f, x=numpy.array([1, -2, 3], dtype='<i8'),
+/// y=numpy.array([[1.5, 2.5], [3.5, 4.5]], dtype='>f8')), the entries have
+/// zip64 local headers
+const SAVEZ: &[u8] = include_bytes!("data/savez.npz");
+
+fn npy_bytes(descr: &str, fortran_order: bool, shape: &str, data: &[u8]) -> Vec<u8>
+{
+    let header: String = format!("{{'descr': '{}', 'fortran_order': {}, 'shape': {}, }}",
+                                 descr,
+                                 if fortran_order { "True" } else { "False" },
+                                 shape);
+    let mut bytes: Vec<u8> = b"\x93NUMPY\x01\x00".to_vec();
+    bytes.extend_from_slice(&((header.len() + 1) as u16).to_le_bytes());
+    bytes.extend_from_slice(header.as_bytes());
+    bytes.push(b'\n');
+    bytes.extend_from_slice(data);
+
+    bytes
+}
+
+fn is_format_error<T>(result: Result<T, IoError>) -> bool
+{
+    matches!(result, Err(IoError::Format(_)))
+}
+
+#[test]
+fn write_read_matrix_f64()
+{
+    let a: Matrix<f64> = matrix![   1.0, 0.1, -3.5;
+                                    2.0e-300, 5.0, 6.0];
+    let mut bytes: Vec<u8> = Vec::new();
+
+    npy::write_matrix(&mut bytes, &a).unwrap();
+
+    assert_eq!(0, bytes.len() % 8);
+    assert_eq!(a, npy::read_matrix(&bytes[..]).unwrap());
+}
+
+#[test]
+fn write_read_matrix_complex_f32()
+{
+    let a: Matrix<Complex<f32>> = Matrix::new(2, 1, vec![Complex::new(1.0, -1.0), Complex::new(0.25, 3.0)]);
+    let mut bytes: Vec<u8> = Vec::new();
+
+    npy::write_matrix(&mut bytes, &a).unwrap();
+
+    assert!(String::from_utf8_lossy(&bytes).contains("'<c8'"));
+    assert_eq!(a, npy::read_matrix(&bytes[..]).unwrap());
+}
+
+#[test]
+fn read_c_order_i32()
+{
+    let data: Vec<u8> = [1i32, 2, 3, 4, 5, 6].iter().flat_map(|x| x.to_le_bytes().to_vec()).collect();
+    let bytes: Vec<u8> = npy_bytes("<i4", false, "(2, 3)", &data);
+
+    let a: Matrix<f64> = npy::read_matrix(&bytes[..]).unwrap();
+
+    assert_eq!(matrix![1.0, 2.0, 3.0; 4.0, 5.0, 6.0], a);
+}
+
+#[test]
+fn read_big_endian_fortran_order_f64()
+{
+    let data: Vec<u8> = [1.0f64, 2.0, 3.0, 4.0].iter().flat_map(|x| x.to_be_bytes().to_vec()).collect();
+    let bytes: Vec<u8> = npy_bytes(">f8", true, "(2, 2)", &data);
+
+    let a: Matrix<f32> = npy::read_matrix(&bytes[..]).unwrap();
+
+    assert_eq!(matrix![1.0, 3.0; 2.0, 4.0], a);
+}
+
+#[test]
+fn read_big_endian_i64_complex()
+{
+    let data: Vec<u8> = [-7i64, 9].iter().flat_map(|x| x.to_be_bytes().to_vec()).collect();
+    let bytes: Vec<u8> = npy_bytes(">i8", false, "(2,)", &data);
+
+    let a: Matrix<Complex<f64>> = npy::read_matrix(&bytes[..]).unwrap();
+
+    assert_eq!(Matrix::new(2, 1, vec![Complex::new(-7.0, 0.0), Complex::new(9.0, 0.0)]), a);
+}
+
+#[test]
+fn read_complex_into_real()
+{
+    let data: Vec<u8> = [1.0f64, 2.0].iter().flat_map(|x| x.to_le_bytes().to_vec()).collect();
+    let bytes: Vec<u8> = npy_bytes("<c16", false, "(1, 1)", &data);
+
+    assert!(is_format_error(npy::read_matrix::<f64, _>(&bytes[..])));
+    assert_eq!(Complex::new(1.0, 2.0), *npy::read_matrix::<Complex<f64>, _>(&bytes[..]).unwrap().get(0, 0));
+}
+
+#[test]
+fn read_invalid()
+{
+    let three_dim: Vec<u8> = npy_bytes("<f8", false, "(1, 1, 1)", &1.0f64.to_le_bytes());
+    let truncated: Vec<u8> = npy_bytes("<f8", false, "(2, 2)", &1.0f64.to_le_bytes());
+    let unsupported: Vec<u8> = npy_bytes("<u2", false, "(1,)", &[0, 1]);
+
+    assert!(is_format_error(npy::read_matrix::<f64, _>(&three_dim[..])));
+    assert!(is_format_error(npy::read_matrix::<f64, _>(&truncated[..])));
+    assert!(is_format_error(npy::read_matrix::<f64, _>(&unsupported[..])));
+    assert!(is_format_error(npy::read_matrix::<f64, _>(&b"1.0, 2.0"[..])));
+}
+
+#[test]
+fn read_shape_overflow()
+{
+    let elements: Vec<u8> = npy_bytes("<f8", false, "(4294967296, 4294967296)", &[]);
+    let bytes: Vec<u8> = npy_bytes("<f8", false, "(3074457345618258603,)", &[]);
+
+    assert!(is_format_error(npy::read_matrix::<f64, _>(&elements[..])));
+    assert!(is_format_error(npy::read_matrix::<f64, _>(&bytes[..])));
+}
+
+#[test]
+fn write_read_vector()
+{
+    let v: Vector<f64> = vector![1.0; -2.0; 3.0];
+    let mut bytes: Vec<u8> = Vec::new();
+
+    npy::write_vector(&mut bytes, &v).unwrap();
+
+    assert!(String::from_utf8_lossy(&bytes).contains("'fortran_order': False, 'shape': (3,)"));
+    assert_eq!(v, npy::read_vector(&bytes[..]).unwrap());
+
+    let w: Vector<f64> = v.transpose();
+    let mut bytes: Vec<u8> = Vec::new();
+
+    npy::write_vector(&mut bytes, &w).unwrap();
+
+    assert!(String::from_utf8_lossy(&bytes).contains("'fortran_order': True, 'shape': (1, 3)"));
+    assert_eq!(w, npy::read_vector(&bytes[..]).unwrap());
+}
+
+#[test]
+fn read_row_vector()
+{
+    let data: Vec<u8> = [1.0f32, 2.0].iter().flat_map(|x| x.to_le_bytes().to_vec()).collect();
+    let row: Vec<u8> = npy_bytes("<f4", false, "(1, 2)", &data);
+    let matrix: Vec<u8> = npy_bytes("<f4", false, "(2, 1, 1)", &data);
+
+    assert_eq!(vector![1.0, 2.0], npy::read_vector::<f64, _>(&row[..]).unwrap());
+    assert!(is_format_error(npy::read_vector::<f64, _>(&matrix[..])));
+}
+
+#[test]
+fn read_npz_savez()
+{
+    let arrays: Vec<(String, Matrix<f64>)> = npy::read_npz(SAVEZ).unwrap();
+
+    assert_eq!(2, arrays.len());
+    assert_eq!("x", arrays[0].0);
+    assert_eq!(matrix![1.0; -2.0; 3.0], arrays[0].1);
+    assert_eq!("y", arrays[1].0);
+    assert_eq!(matrix![1.5, 2.5; 3.5, 4.5], arrays[1].1);
+}
+
+#[test]
+fn read_npz_invalid()
+{
+    let mut corrupted: Vec<u8> = SAVEZ.to_vec();
+    // first data byte of the array x
+    corrupted[183] ^= 1;
+
+    assert!(is_format_error(npy::read_npz::<f64, _>(&corrupted[..])));
+    assert!(is_format_error(npy::read_npz::<f64, _>(&b"not an archive"[..])));
+}
+
+#[test]
+fn save_load()
+{
+    let a: Matrix<f64> = matrix![1.0, 2.0; 3.0, 4.0];
+    let v: Vector<f32> = vector![5.0, 6.0];
+    let dir = std::env::temp_dir();
+
+    npy::save_matrix(dir.join("mathru_save_load.npy"), &a).unwrap();
+    npy::save_vector(dir.join("mathru_save_load_vector.npy"), &v).unwrap();
+    npy::save_npz(dir.join("mathru_save_load.npz"), &[("a", &a), ("a_t", &a.clone().transpose())]).unwrap();
+
+    let b: Matrix<f64> = npy::load_matrix(dir.join("mathru_save_load.npy")).unwrap();
+    let w: Vector<f32> = npy::load_vector(dir.join("mathru_save_load_vector.npy")).unwrap();
+    let arrays: Vec<(String, Matrix<f64>)> = npy::load_npz(dir.join("mathru_save_load.npz")).unwrap();
+
+    for name in &["mathru_save_load.npy", "mathru_save_load_vector.npy", "mathru_save_load.npz"]
+    {
+        std::fs::remove_file(dir.join(name)).unwrap();
+    }
+
+    assert_eq!(a, b);
+    assert_eq!(v, w);
+    assert_eq!(vec![("a".to_string(), a.clone()), ("a_t".to_string(), a.transpose())], arrays);
+}
@@ -4,6 +4,8 @@ extern crate mathru;
 mod algebra;
 mod analysis;
 mod elementary;
+mod error;
+mod io;
 mod optimization;
 mod special;
 mod statistics;